@@ -2,7 +2,7 @@
 
 mod ui;
 
-use bevy::prelude::*;
+use bevy::{input_focus::InputFocus, prelude::*};
 use bevy_editor_styles::Theme;
 
 use crate::ui::spawn_context_menu;
@@ -12,10 +12,39 @@ pub struct ContextMenuPlugin;
 
 impl Plugin for ContextMenuPlugin {
     fn build(&self, app: &mut App) {
-        app.add_observer(on_secondary_button_down_entity_with_context_menu);
+        app.add_observer(on_secondary_button_down_entity_with_context_menu)
+            .add_observer(on_open_context_menu)
+            .add_observer(restore_focus_on_close)
+            .add_systems(
+                Update,
+                (navigate_context_menus, update_context_menu_highlights).chain(),
+            );
     }
 }
 
+/// Triggered on an entity with a [`ContextMenu`] to open it at the given position, in logical
+/// pixels, like from a keyboard shortcut.
+///
+/// The keyboard focus is taken away from the entity while the menu is open, so the keys
+/// navigating the menu don't also reach it, and given back once the menu closes.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct OpenContextMenu(pub Vec2);
+
+/// The window-wide node holding an open menu, closing it when clicked.
+#[derive(Component)]
+struct ContextMenuRoot {
+    /// The entity the menu was opened on.
+    target: Entity,
+    /// The option highlighted with the pointer or the keyboard.
+    highlighted: Option<usize>,
+    /// The entity focused before the menu was opened from the keyboard.
+    restore_focus: Option<Entity>,
+}
+
+/// An option of an open menu, by its index in the [`ContextMenu`].
+#[derive(Component)]
+struct ContextMenuOptionIndex(usize);
+
 fn on_secondary_button_down_entity_with_context_menu(
     mut trigger: On<Pointer<Release>>,
     mut commands: Commands,
@@ -33,11 +62,53 @@ fn on_secondary_button_down_entity_with_context_menu(
 
     trigger.propagate(false);
 
-    let event = trigger.event();
+    let root = ContextMenuRoot {
+        target,
+        highlighted: None,
+        restore_focus: None,
+    };
+    open_context_menu(
+        &mut commands,
+        &theme,
+        menu,
+        trigger.event().pointer_location.position,
+        root,
+    );
+}
 
+fn on_open_context_menu(
+    trigger: On<OpenContextMenu>,
+    mut commands: Commands,
+    query: Query<&ContextMenu>,
+    focus: Option<ResMut<InputFocus>>,
+    theme: Res<Theme>,
+) {
+    let target = trigger.target();
+    let Ok(menu) = query.get(target) else {
+        return;
+    };
+
+    let root = ContextMenuRoot {
+        target,
+        // Opened from the keyboard, the first option is ready to be picked with Enter
+        highlighted: (!menu.options.is_empty()).then_some(0),
+        restore_focus: focus.and_then(|mut focus| focus.0.take()),
+    };
+    open_context_menu(&mut commands, &theme, menu, trigger.event().0, root);
+}
+
+/// Spawn the menu of the target of the [`ContextMenuRoot`] at the position.
+fn open_context_menu(
+    commands: &mut Commands,
+    theme: &Theme,
+    menu: &ContextMenu,
+    position: Vec2,
+    root: ContextMenuRoot,
+) {
     // Prevent all other entities from being picked by placing a node over the entire window.
     let root = commands
         .spawn((
+            root,
             Node {
                 width: Val::Percent(100.),
                 height: Val::Percent(100.),
@@ -50,22 +121,105 @@ fn on_secondary_button_down_entity_with_context_menu(
         })
         .id();
 
-    spawn_context_menu(
-        &mut commands,
-        &theme,
-        menu,
-        event.pointer_location.position,
-        target,
-    )
-    .observe(|mut trigger: On<Pointer<Press>>| {
-        // Prevent the context menu root from despawning the context menu when clicking on the menu
-        trigger.propagate(false);
-    })
-    .insert(ChildOf(root));
+    spawn_context_menu(commands, theme, menu, position, root)
+        .observe(|mut trigger: On<Pointer<Press>>| {
+            // Prevent the context menu root from despawning the context menu when clicking on the menu
+            trigger.propagate(false);
+        })
+        .insert(ChildOf(root));
+}
+
+/// Close the menu of the [`ContextMenuRoot`] and run its option at `index`.
+pub(crate) fn select_context_menu_option(
+    In((root, index)): In<(Entity, usize)>,
+    mut commands: Commands,
+    roots: Query<&ContextMenuRoot>,
+    mut menus: Query<&mut ContextMenu>,
+) {
+    let Ok(&ContextMenuRoot { target, .. }) = roots.get(root) else {
+        return;
+    };
+    commands.entity(root).despawn();
+
+    if let Ok(mut menu) = menus.get_mut(target)
+        && let Some(option) = menu.options.get_mut(index)
+    {
+        (option.f)(commands.reborrow(), target);
+    }
+}
+
+/// Give the focus back to the entity a menu was opened on from the keyboard, unless something
+/// else was focused in the meantime, like a dialog opened by the option.
+fn restore_focus_on_close(
+    trigger: On<Remove, ContextMenuRoot>,
+    roots: Query<&ContextMenuRoot>,
+    focus: Option<ResMut<InputFocus>>,
+) {
+    let (Ok(root), Some(mut focus)) = (roots.get(trigger.target()), focus) else {
+        return;
+    };
+    if focus.0.is_none() {
+        focus.0 = root.restore_focus;
+    }
+}
+
+/// Move the highlight of the open menus with the arrow keys, run the highlighted option with
+/// <kbd>Enter</kbd> and close with <kbd>Escape</kbd>.
+///
+/// The keys handled are cleared, so <kbd>Escape</kbd> doesn't also close what is under the menu.
+fn navigate_context_menus(
+    mut commands: Commands,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut roots: Query<(Entity, &mut ContextMenuRoot)>,
+    menus: Query<&ContextMenu>,
+) {
+    for (entity, mut root) in &mut roots {
+        let count = menus.get(root.target).map_or(0, |menu| menu.options.len());
+        if keys.clear_just_pressed(KeyCode::Escape) || count == 0 {
+            commands.entity(entity).despawn();
+        } else if keys.clear_just_pressed(KeyCode::ArrowDown) {
+            root.highlighted = Some(root.highlighted.map_or(0, |index| (index + 1) % count));
+        } else if keys.clear_just_pressed(KeyCode::ArrowUp) {
+            root.highlighted = Some(
+                root.highlighted
+                    .map_or(count - 1, |index| (index + count - 1) % count),
+            );
+        } else if (keys.clear_just_pressed(KeyCode::Enter)
+            || keys.clear_just_pressed(KeyCode::NumpadEnter))
+            && let Some(index) = root.highlighted
+        {
+            commands.run_system_cached_with(select_context_menu_option, (entity, index));
+        }
+    }
+}
+
+/// Color the highlighted option of the open menus.
+fn update_context_menu_highlights(
+    roots: Query<(Entity, &ContextMenuRoot), Changed<ContextMenuRoot>>,
+    children: Query<&Children>,
+    mut options: Query<(&ContextMenuOptionIndex, &mut BackgroundColor)>,
+    theme: Res<Theme>,
+) {
+    for (entity, root) in &roots {
+        for option in children.iter_descendants(entity) {
+            let Ok((ContextMenuOptionIndex(index), mut background)) = options.get_mut(option)
+            else {
+                continue;
+            };
+            let color = if root.highlighted == Some(*index) {
+                theme.context_menu.hover_color.0
+            } else {
+                Color::NONE
+            };
+            background.set_if_neq(BackgroundColor(color));
+        }
+    }
 }
 
 /// Entities with this component will have a context menu.
-/// The menu can be opened by pressing the secondary mouse button over the entity.
+/// The menu can be opened by pressing the secondary mouse button over the entity, or by triggering
+/// [`OpenContextMenu`] on it. The open menu can be navigated with the arrow keys and
+/// <kbd>Enter</kbd>, and closed with <kbd>Escape</kbd>.
 #[derive(Component)]
 pub struct ContextMenu {
     options: Vec<ContextMenuOption>,
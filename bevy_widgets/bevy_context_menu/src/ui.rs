@@ -1,14 +1,14 @@
 use bevy::{feathers::cursor::EntityCursor, prelude::*, window::SystemCursorIcon};
 use bevy_editor_styles::Theme;
 
-use crate::ContextMenu;
+use crate::{ContextMenu, ContextMenuOptionIndex, ContextMenuRoot, select_context_menu_option};
 
 pub(crate) fn spawn_context_menu<'a>(
     commands: &'a mut Commands,
     theme: &Theme,
     menu: &ContextMenu,
     position: Vec2,
-    target: Entity,
+    menu_root: Entity,
 ) -> EntityCommands<'a> {
     let root = commands
         .spawn((
@@ -25,7 +25,7 @@ pub(crate) fn spawn_context_menu<'a>(
                 blur_radius: Val::Px(3.),
                 x_offset: Val::ZERO,
                 y_offset: Val::ZERO,
-                color: Color::BLACK.with_alpha(0.8),
+                color: theme.general.elevation_color,
                 ..Default::default()
             }),
             theme.context_menu.background_color,
@@ -34,7 +34,7 @@ pub(crate) fn spawn_context_menu<'a>(
         .id();
 
     for (i, option) in menu.options.iter().enumerate() {
-        spawn_option(commands, theme, &option.label, i, target).insert(ChildOf(root));
+        spawn_option(commands, theme, &option.label, i, menu_root).insert(ChildOf(root));
     }
 
     commands.entity(root)
@@ -45,48 +45,43 @@ pub(crate) fn spawn_option<'a>(
     theme: &Theme,
     label: &String,
     index: usize,
-    target: Entity,
+    menu_root: Entity,
 ) -> EntityCommands<'a> {
     let root = commands
         .spawn((
+            ContextMenuOptionIndex(index),
             Node {
                 padding: UiRect::all(Val::Px(5.)),
                 flex_grow: 1.,
                 ..default()
             },
+            BackgroundColor(Color::NONE),
             theme.context_menu.option_border_radius,
             EntityCursor::System(SystemCursorIcon::Pointer),
         ))
         .observe(
-            |trigger: On<Pointer<Over>>,
-             theme: Res<Theme>,
-             mut query: Query<&mut BackgroundColor>| {
-                *query.get_mut(trigger.target()).unwrap() = theme.context_menu.hover_color;
+            move |_trigger: On<Pointer<Over>>, mut roots: Query<&mut ContextMenuRoot>| {
+                if let Ok(mut menu_root) = roots.get_mut(menu_root) {
+                    menu_root.highlighted = Some(index);
+                }
             },
         )
         .observe(
-            |trigger: On<Pointer<Out>>, mut query: Query<&mut BackgroundColor>| {
-                query.get_mut(trigger.target()).unwrap().0 = Color::NONE;
+            move |_trigger: On<Pointer<Out>>, mut roots: Query<&mut ContextMenuRoot>| {
+                if let Ok(mut menu_root) = roots.get_mut(menu_root)
+                    && menu_root.highlighted == Some(index)
+                {
+                    menu_root.highlighted = None;
+                }
             },
         )
         .observe(
-            move |trigger: On<Pointer<Release>>,
-                  mut commands: Commands,
-                  child_of_query: Query<&ChildOf>,
-                  mut query: Query<&mut ContextMenu>| {
+            move |trigger: On<Pointer<Release>>, mut commands: Commands| {
                 if trigger.event().button != PointerButton::Primary {
                     return;
                 }
-                // Despawn the context menu when an option is selected
-                let root = child_of_query
-                    .iter_ancestors(trigger.target())
-                    .last()
-                    .unwrap();
-                commands.entity(root).despawn();
-
-                // Run the option callback
-                let callback = &mut query.get_mut(target).unwrap().options[index].f;
-                (callback)(commands.reborrow(), target);
+                // Despawns the context menu and runs the option callback
+                commands.run_system_cached_with(select_context_menu_option, (menu_root, index));
             },
         )
         .id();
@@ -1,9 +1,19 @@
 //! A consistently-styled, cross-platform Footer bar for Bevy applications.
 //!
-//! This runs along the top of the screen and provides a list of options to the user,
-//! such as "File", "Edit", "View", etc.
+//! This runs along the bottom of the screen and displays the status of the application.
+//!
+//! The bar is split in three [`FooterSlot`]s: the left one, the center one and the right one. The
+//! bar doesn't show anything by itself, the applications register the widgets spawned into the
+//! slots with [`FooterBarAppExt::add_footer_widget`]. Within a slot the widgets are laid out from
+//! left to right by ascending order, and in the order they were registered for equal orders.
+//!
+//! The usual widgets are provided: [`spawn_footer_status`], [`spawn_footer_progress`],
+//! [`spawn_footer_info`] and [`footer_version`].
 
-use bevy::prelude::*;
+use bevy::{
+    ecs::system::{BoxedSystem, SystemId},
+    prelude::*,
+};
 
 use bevy_editor_styles::Theme;
 
@@ -11,12 +21,48 @@ use bevy_editor_styles::Theme;
 #[derive(Component)]
 pub struct FooterBarNode;
 
+/// The text used to display status messages, spawned by [`spawn_footer_status`].
+///
+/// Applications update its [`Text`] to give feedback to the user.
+#[derive(Component)]
+pub struct FooterBarStatus;
+
+/// The text used to display lasting information like the number of running tasks, spawned by
+/// [`spawn_footer_info`].
+///
+/// Applications update its [`Text`], it is empty by default.
+#[derive(Component)]
+pub struct FooterBarInfo;
+
+/// A slim progress bar, hidden while the progress is `None`, spawned by [`spawn_footer_progress`].
+///
+/// The progress is a fraction between 0 and 1.
+#[derive(Component, Default, PartialEq)]
+pub struct FooterBarProgress(pub Option<f32>);
+
+/// The filled part of the [`FooterBarProgress`].
+#[derive(Component)]
+struct FooterBarProgressFill;
+
+/// A part of the Footer bar the widgets are spawned into.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FooterSlot {
+    /// The start of the bar, usually for the status.
+    Left,
+    /// The middle of the bar.
+    Center,
+    /// The end of the bar, usually for the version.
+    Right,
+}
+
 /// The Bevy Footer Bar Plugin.
 pub struct FooterBarPlugin;
 
 impl Plugin for FooterBarPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, footer_setup.in_set(FooterBarSet));
+        app.init_resource::<FooterBarRegistry>()
+            .add_systems(Startup, footer_setup.in_set(FooterBarSet))
+            .add_systems(Update, update_footer_progress);
     }
 }
 
@@ -24,51 +70,257 @@ impl Plugin for FooterBarPlugin {
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FooterBarSet;
 
-const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The widgets registered with [`FooterBarAppExt::add_footer_widget`].
+#[derive(Resource, Default)]
+pub struct FooterBarRegistry {
+    widgets: Vec<FooterWidget>,
+}
 
-/// The setup system for the Footer bar.
-fn footer_setup(
-    mut commands: Commands,
-    root: Query<Entity, With<FooterBarNode>>,
-    theme: Res<Theme>,
-) {
-    commands
-        .entity(root.single().unwrap())
-        .insert((
+impl FooterBarRegistry {
+    /// Register a widget spawned into the `slot` of the Footer bars, at the given `order`.
+    ///
+    /// The system is given the container of the widget, a child of the slot, to spawn the widget
+    /// into.
+    pub fn register<M>(
+        &mut self,
+        slot: FooterSlot,
+        order: i32,
+        system: impl IntoSystem<In<Entity>, (), M>,
+    ) {
+        self.widgets.push(FooterWidget {
+            slot,
+            order,
+            spawn: Some(Box::new(IntoSystem::into_system(system))),
+            id: None,
+        });
+    }
+}
+
+struct FooterWidget {
+    slot: FooterSlot,
+    order: i32,
+    spawn: Option<BoxedSystem<In<Entity>>>,
+    id: Option<SystemId<In<Entity>>>,
+}
+
+/// Extension trait for [`App`].
+pub trait FooterBarAppExt {
+    /// Register a widget spawned into the `slot` of the Footer bars, see
+    /// [`FooterBarRegistry::register`].
+    fn add_footer_widget<M>(
+        &mut self,
+        slot: FooterSlot,
+        order: i32,
+        system: impl IntoSystem<In<Entity>, (), M>,
+    ) -> &mut Self;
+}
+
+impl FooterBarAppExt for App {
+    fn add_footer_widget<M>(
+        &mut self,
+        slot: FooterSlot,
+        order: i32,
+        system: impl IntoSystem<In<Entity>, (), M>,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<FooterBarRegistry>()
+            .register(slot, order, system);
+        self
+    }
+}
+
+/// The indices of the widgets in the order they are spawned, by slot then by order, keeping the
+/// registration order of the widgets of equal orders.
+fn layout_order(widgets: &[(FooterSlot, i32)]) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..widgets.len()).collect();
+    indices.sort_by_key(|&index| widgets[index]);
+    indices
+}
+
+/// The setup system for the Footer bar, spawning its slots and their widgets.
+fn footer_setup(world: &mut World, roots: &mut QueryState<Entity, With<FooterBarNode>>) {
+    let roots: Vec<Entity> = roots.iter(world).collect();
+    for root in roots {
+        let theme = world.resource::<Theme>();
+        let background = theme.general.background_color;
+        let mut slots = Vec::new();
+        world
+            .entity_mut(root)
+            .insert((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(20.0),
+                    align_items: AlignItems::Center,
+                    padding: UiRect::axes(Val::Px(5.0), Val::Px(0.0)),
+                    ..Default::default()
+                },
+                background,
+            ))
+            .with_children(|parent| {
+                for (slot, justify_content, flex_grow) in [
+                    (FooterSlot::Left, JustifyContent::FlexStart, 1.0),
+                    (FooterSlot::Center, JustifyContent::Center, 0.0),
+                    (FooterSlot::Right, JustifyContent::FlexEnd, 1.0),
+                ] {
+                    let entity = parent
+                        .spawn((
+                            slot,
+                            Node {
+                                flex_grow,
+                                flex_basis: Val::Px(0.0),
+                                height: Val::Percent(100.0),
+                                justify_content,
+                                align_items: AlignItems::Center,
+                                ..Default::default()
+                            },
+                        ))
+                        .id();
+                    slots.push((slot, entity));
+                }
+            });
+
+        world.resource_scope(|world, mut registry: Mut<FooterBarRegistry>| {
+            let keys: Vec<_> = registry
+                .widgets
+                .iter()
+                .map(|widget| (widget.slot, widget.order))
+                .collect();
+            for index in layout_order(&keys) {
+                let widget = &mut registry.widgets[index];
+                let Some(&(_, slot)) = slots.iter().find(|(slot, _)| *slot == widget.slot) else {
+                    continue;
+                };
+                let id = match (widget.id, widget.spawn.take()) {
+                    (Some(id), _) => id,
+                    (None, Some(spawn)) => *widget.id.insert(world.register_boxed_system(spawn)),
+                    (None, None) => continue,
+                };
+                let container = world
+                    .spawn((
+                        Node {
+                            height: Val::Percent(100.0),
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        ChildOf(slot),
+                    ))
+                    .id();
+                if let Err(error) = world.run_system_with(id, container) {
+                    warn!("Couldn't spawn a footer widget: {error}");
+                }
+            }
+        });
+    }
+}
+
+/// Spawn the [`FooterBarStatus`] into the container.
+pub fn spawn_footer_status(In(container): In<Entity>, mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn((
+        FooterBarStatus,
+        Text::default(),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 10.,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(container),
+    ));
+}
+
+/// Spawn a [`FooterBarProgress`] into the container.
+pub fn spawn_footer_progress(In(container): In<Entity>, mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn((
+        FooterBarProgress::default(),
+        Node {
+            display: Display::None,
+            width: Val::Px(120.0),
+            height: Val::Px(4.0),
+            margin: UiRect::left(Val::Px(8.0)),
+            ..Default::default()
+        },
+        theme.button.background_color,
+        children![(
+            FooterBarProgressFill,
             Node {
-                width: Val::Percent(100.0),
-                height: Val::Px(20.0),
-                align_items: AlignItems::Center,
-                padding: UiRect::axes(Val::Px(5.0), Val::Px(0.0)),
-                ..Default::default()
-            },
-            theme.general.background_color,
-        ))
-        .with_children(|parent| {
-            parent.spawn(Node {
-                width: Val::Percent(50.0),
+                width: Val::Percent(0.0),
                 height: Val::Percent(100.0),
-                justify_content: JustifyContent::FlexStart,
-                align_items: AlignItems::Center,
                 ..Default::default()
-            });
-            parent
-                .spawn(Node {
-                    width: Val::Percent(50.0),
-                    height: Val::Percent(100.0),
-                    justify_content: JustifyContent::FlexEnd,
-                    align_items: AlignItems::Center,
+            },
+            BackgroundColor(theme.text.high_priority),
+        )],
+        ChildOf(container),
+    ));
+}
 
-                    ..Default::default()
-                })
-                .with_child((
-                    Text::new(VERSION),
-                    TextFont {
-                        font: theme.text.font.clone(),
-                        font_size: 10.,
-                        ..default()
-                    },
-                    TextColor(theme.text.low_priority),
-                ));
-        });
+/// Spawn the [`FooterBarInfo`] into the container.
+pub fn spawn_footer_info(In(container): In<Entity>, mut commands: Commands, theme: Res<Theme>) {
+    commands.spawn((
+        FooterBarInfo,
+        Text::default(),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 10.,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        Node {
+            margin: UiRect::right(Val::Px(8.0)),
+            ..Default::default()
+        },
+        ChildOf(container),
+    ));
+}
+
+/// A widget system showing the version of the application, like `env!("CARGO_PKG_VERSION")`.
+pub fn footer_version(
+    version: &'static str,
+) -> impl FnMut(In<Entity>, Commands, Res<Theme>) + Send + Sync + 'static {
+    move |In(container), mut commands, theme| {
+        commands.spawn((
+            Text::new(version),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 10.,
+                ..default()
+            },
+            TextColor(theme.text.low_priority),
+            ChildOf(container),
+        ));
+    }
+}
+
+/// Show, hide and fill the [`FooterBarProgress`] bars when their progress changes.
+fn update_footer_progress(
+    mut bars: Query<(&FooterBarProgress, &mut Node, &Children), Changed<FooterBarProgress>>,
+    mut fills: Query<&mut Node, (With<FooterBarProgressFill>, Without<FooterBarProgress>)>,
+) {
+    for (progress, mut node, children) in &mut bars {
+        node.display = match progress.0 {
+            Some(_) => Display::Flex,
+            None => Display::None,
+        };
+        for child in children.iter() {
+            if let Ok(mut fill) = fills.get_mut(child) {
+                fill.width = Val::Percent(progress.0.unwrap_or(0.0).clamp(0.0, 1.0) * 100.0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lay_out_the_widgets_by_slot_and_order() {
+        let widgets = [
+            (FooterSlot::Right, 10),
+            (FooterSlot::Left, 0),
+            (FooterSlot::Right, 0),
+            (FooterSlot::Left, 0),
+            (FooterSlot::Center, -5),
+        ];
+        assert_eq!(layout_order(&widgets), vec![1, 3, 4, 2, 0]);
+    }
 }
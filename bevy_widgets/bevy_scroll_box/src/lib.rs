@@ -1,7 +1,12 @@
 //! A scroll widget for Bevy applications.
 
 use bevy::{
-    input::mouse::{MouseScrollUnit, MouseWheel},
+    input::{
+        ButtonState,
+        keyboard::KeyboardInput,
+        mouse::{MouseScrollUnit, MouseWheel},
+    },
+    input_focus::FocusedInput,
     prelude::*,
     ui::RelativeCursorPosition,
 };
@@ -12,18 +17,44 @@ pub struct ScrollBoxPlugin;
 
 const SCROLL_LINE_SIZE_VALUE: f32 = 20.0;
 
+/// The height of the [`ScrollShadow`]s.
+const SCROLL_SHADOW_SIZE: f32 = 12.0;
+
+/// The size of the [`ScrollJumpButton`]s.
+const SCROLL_JUMP_BUTTON_SIZE: f32 = 20.0;
+
 impl Plugin for ScrollBoxPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, (on_scroll, update_scroll_box, update_scroll_bars));
+        app.add_systems(
+            Update,
+            (
+                (
+                    on_scroll,
+                    measure_scroll_boxes,
+                    (update_scroll_box, update_scroll_bars),
+                )
+                    .chain(),
+                (spawn_scroll_shadows, update_scroll_shadows).chain(),
+                (spawn_scroll_jump_buttons, update_scroll_jump_buttons).chain(),
+            ),
+        )
+        .add_observer(scroll_to_edge_on_key);
     }
 }
 
 /// A `ScrollBox` is a UI component that allows for content to be scrolled within a defined area.
-#[derive(Component, Default)]
+///
+/// The size of its [`ScrollBoxContent`] is measured again whenever its layout changes, like when
+/// children are added or removed, so the scroll bars and the scroll limits follow it.
+#[derive(Component, Default, Clone, PartialEq)]
 #[require(Node, RelativeCursorPosition)]
 pub struct ScrollBox {
     position: ScrollPosition,
     overflow: Overflow,
+    /// The size of the [`ScrollBoxContent`] when last measured.
+    content_size: Vec2,
+    /// The size of the box when last measured.
+    viewport_size: Vec2,
 }
 
 impl ScrollBox {
@@ -31,6 +62,44 @@ impl ScrollBox {
     pub fn scroll_to_top(&mut self) {
         self.position = ScrollPosition::default();
     }
+
+    /// Scroll vertically so the content `offset` pixels below its top is at the top of the box.
+    pub fn scroll_to(&mut self, offset: f32) {
+        self.position.y = -offset.max(0.0);
+    }
+
+    /// Scroll vertically to the top or the bottom of the content.
+    ///
+    /// The bottom is reached once the box is laid out, as the position is kept within the content.
+    pub fn scroll_to_edge(&mut self, edge: ScrollEdge) {
+        match edge {
+            ScrollEdge::Top => self.position.y = 0.0,
+            ScrollEdge::Bottom => self.position.y = f32::MIN,
+        }
+    }
+
+    /// Whether the content is scrolled to the top or the bottom edge, always when it fits.
+    pub fn is_at_edge(&self, edge: ScrollEdge) -> bool {
+        !edge.has_hidden_content(-self.position.y, self.max_scroll().y)
+    }
+
+    /// How far the content can be scrolled in each direction, 0 when it fits in the box.
+    pub fn max_scroll(&self) -> Vec2 {
+        (self.content_size - self.viewport_size).max(Vec2::ZERO)
+    }
+
+    /// The box with the sizes of its content and itself, its position kept within the content.
+    fn measured(&self, content_size: Vec2, viewport_size: Vec2) -> Self {
+        let mut measured = Self {
+            content_size,
+            viewport_size,
+            ..self.clone()
+        };
+        let max_scroll = measured.max_scroll();
+        measured.position.x = measured.position.x.clamp(-max_scroll.x, 0.0);
+        measured.position.y = measured.position.y.clamp(-max_scroll.y, 0.0);
+        measured
+    }
 }
 
 /// Represents the content within a [`ScrollBox`].
@@ -60,6 +129,47 @@ pub enum ScrollBarHandleDirection {
 #[require(Node)]
 pub struct ScrollBarHandle(pub ScrollBarHandleDirection);
 
+/// Add to a vertical [`ScrollBox`] to show a shadow at the top and bottom edges
+/// while there is hidden content in that direction.
+#[derive(Component, Default)]
+pub struct ScrollShadows;
+
+/// Add to a vertical [`ScrollBox`] to show buttons jumping to the top and the bottom of its
+/// content, each shown while there is hidden content in its direction.
+///
+/// The <kbd>Home</kbd> and <kbd>End</kbd> keys jump to the edges of any scroll box holding the
+/// focused entity, with or without the buttons.
+#[derive(Component, Default)]
+pub struct ScrollJumpButtons;
+
+/// A vertical edge of a [`ScrollBox`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollEdge {
+    /// The start of the content.
+    Top,
+    /// The end of the content.
+    Bottom,
+}
+
+impl ScrollEdge {
+    /// Whether some content is hidden past the edge, `offset` pixels down a content that can be
+    /// scrolled up to `max_scroll` pixels.
+    fn has_hidden_content(self, offset: f32, max_scroll: f32) -> bool {
+        match self {
+            ScrollEdge::Top => offset > 0.5,
+            ScrollEdge::Bottom => offset < max_scroll - 0.5,
+        }
+    }
+}
+
+/// A shadow spawned over the edge of a [`ScrollBox`] with [`ScrollShadows`].
+#[derive(Component)]
+struct ScrollShadow(ScrollEdge);
+
+/// A button spawned over a [`ScrollBox`] with [`ScrollJumpButtons`], jumping to its edge.
+#[derive(Component)]
+struct ScrollJumpButton(ScrollEdge);
+
 /// Spawn a new [`ScrollBox`]
 pub fn spawn_scroll_box<'a>(
     commands: &'a mut Commands,
@@ -70,8 +180,8 @@ pub fn spawn_scroll_box<'a>(
     let scrollbox = commands
         .spawn((
             ScrollBox {
-                position: ScrollPosition::default(),
                 overflow: direction,
+                ..default()
             },
             RelativeCursorPosition::default(),
             Node {
@@ -146,8 +256,27 @@ fn spawn_scroll_bar<'a>(
                 },
             },
             theme.scroll_box.background_color,
-            BorderRadius::all(Val::Px(5.0)),
+            theme.scroll_box.border_radius,
         ))
+        // Hovering the handle also hovers the track, as the events propagate to it
+        .observe(
+            |trigger: On<Pointer<Over>>,
+             theme: Res<Theme>,
+             mut query_background: Query<&mut BackgroundColor>| {
+                if let Ok(mut background) = query_background.get_mut(trigger.target()) {
+                    background.0 = theme.scroll_box.background_hover_color;
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Out>>,
+             theme: Res<Theme>,
+             mut query_background: Query<&mut BackgroundColor>| {
+                if let Ok(mut background) = query_background.get_mut(trigger.target()) {
+                    background.0 = theme.scroll_box.background_color.0;
+                }
+            },
+        )
         .id();
 
     // Handle
@@ -170,6 +299,24 @@ fn spawn_scroll_bar<'a>(
             theme.scroll_box.border_radius,
             ChildOf(scrollbar),
         ))
+        .observe(
+            |trigger: On<Pointer<Over>>,
+             theme: Res<Theme>,
+             mut query_background: Query<&mut BackgroundColor>| {
+                if let Ok(mut background) = query_background.get_mut(trigger.target()) {
+                    background.0 = theme.scroll_box.handle_hover_color;
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Out>>,
+             theme: Res<Theme>,
+             mut query_background: Query<&mut BackgroundColor>| {
+                if let Ok(mut background) = query_background.get_mut(trigger.target()) {
+                    background.0 = theme.scroll_box.handle_color;
+                }
+            },
+        )
         .observe(
             |trigger: On<Pointer<Drag>>,
              query_handle: Query<&ScrollBarHandle>,
@@ -236,15 +383,11 @@ fn spawn_scroll_bar<'a>(
 
 fn on_scroll(
     mut mouse_wheel_events: EventReader<MouseWheel>,
-    mut query_scrollbox: Query<(&RelativeCursorPosition, Entity, &mut ScrollBox, &Children)>,
-    query_scrollbox_content: Query<&ComputedNode, With<ScrollBoxContent>>,
-    query_computed_node: Query<&ComputedNode>,
+    mut query_scrollbox: Query<(&RelativeCursorPosition, &mut ScrollBox)>,
     keys: Res<ButtonInput<KeyCode>>,
 ) {
     for mouse_wheel_event in mouse_wheel_events.read() {
-        for (cursor_pos, scrollbox_entity, mut scrollbox, children) in
-            &mut query_scrollbox.iter_mut()
-        {
+        for (cursor_pos, mut scrollbox) in &mut query_scrollbox.iter_mut() {
             // Only scroll the ScrollBox that the cursor is over
             // TODO: Get the scrollbox with the highest z-index
             if !cursor_pos.cursor_over() {
@@ -256,20 +399,13 @@ fn on_scroll(
                 MouseScrollUnit::Pixel => mouse_wheel_event.y,
             };
 
-            let content_sizes = query_scrollbox_content
-                .get(children[0])
-                .expect("Scrollbox children 0 should be a ScrollBoxContent")
-                .size();
-            let scrollbox_sizes = query_computed_node.get(scrollbox_entity).unwrap().size();
-
+            let max_scroll = scrollbox.max_scroll();
             if keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight) {
-                let max_scroll = (content_sizes.x - scrollbox_sizes.x).max(0.0);
                 scrollbox.position.x =
-                    (scrollbox.position.x + scroll_delta).clamp(-max_scroll, 0.0);
+                    (scrollbox.position.x + scroll_delta).clamp(-max_scroll.x, 0.0);
             } else {
-                let max_scroll = (content_sizes.y - scrollbox_sizes.y).max(0.);
                 scrollbox.position.y =
-                    (scrollbox.position.y + scroll_delta).clamp(-max_scroll, 0.0);
+                    (scrollbox.position.y + scroll_delta).clamp(-max_scroll.y, 0.0);
             }
 
             return; // We only want to scroll 1 ScrollBox
@@ -277,6 +413,29 @@ fn on_scroll(
     }
 }
 
+/// Measure the boxes and their content again once their layout changed, like when the panel
+/// holding a box is resized or the content gains or loses children, and keep the scroll positions
+/// within the content.
+///
+/// The children of a content are only laid out at the end of the frame they changed in, so a
+/// change of [`Children`] is seen in its [`ComputedNode`] the frame after.
+fn measure_scroll_boxes(
+    mut query_scrollboxes: Query<(&mut ScrollBox, &ComputedNode, &Children)>,
+    query_scrollbox_content: Query<&ComputedNode, With<ScrollBoxContent>>,
+) {
+    for (mut scrollbox, scrollbox_computed, children) in &mut query_scrollboxes {
+        let Some(content) = children
+            .first()
+            .and_then(|content| query_scrollbox_content.get(*content).ok())
+        else {
+            continue;
+        };
+        let measured = scrollbox.measured(content.size(), scrollbox_computed.size());
+        // Only touch the boxes whose sizes or position changed, to keep the change detection
+        scrollbox.set_if_neq(measured);
+    }
+}
+
 fn update_scroll_box(
     query_scrolllboxes: Query<(&ScrollBox, &Children), Changed<ScrollBox>>,
     mut query_node: Query<&mut Node>,
@@ -301,30 +460,22 @@ fn update_scroll_box(
 }
 
 /// Recalacule the scroll bar handle position and size
+///
+/// The sizes measured by [`measure_scroll_boxes`] are used, changing the [`ScrollBox`] when the
+/// content is resized.
 fn update_scroll_bars(
-    query_scrollboxes: Query<
-        (&ScrollBox, &ComputedNode, &Children),
-        (
-            With<ScrollBox>,
-            Or<(Changed<ComputedNode>, Changed<ScrollBox>)>,
-        ),
-    >,
-    query_scrollbox_content: Query<&ComputedNode, With<ScrollBoxContent>>,
+    query_scrollboxes: Query<(&ScrollBox, &Children), Changed<ScrollBox>>,
     query_children: Query<&Children>,
     mut query_node: Query<&mut Node>,
 ) {
-    for (scrollbox, scrollbox_computed, scrollbox_children) in query_scrollboxes.iter() {
-        let content_children = query_scrollbox_content
-            .get(scrollbox_children[0])
-            .expect("Scrollbox children 0 should be a ScrollBoxContent");
-
+    for (scrollbox, scrollbox_children) in query_scrollboxes.iter() {
         if scrollbox.overflow.y == OverflowAxis::Scroll {
             let scrollbar_children = query_children.get(scrollbox_children[1]).expect(
                 "Scrollbox children 1 should be a ScrollBar and have 1 child (ScrollBarHandle)",
             );
 
-            let content_height = content_children.size().y;
-            let scrollbox_height = scrollbox_computed.size().y;
+            let content_height = scrollbox.content_size.y;
+            let scrollbox_height = scrollbox.viewport_size.y;
             let (handle_height, handle_pos) = if content_height == 0.0 {
                 (100.0, 0.0)
             } else {
@@ -364,8 +515,8 @@ fn update_scroll_bars(
                     "Scrollbox children 2 should be a ScrollBar and have 1 child (ScrollBarHandle)",
                 );
 
-            let content_width = content_children.size().x;
-            let scrollbox_width = scrollbox_computed.size().x;
+            let content_width = scrollbox.content_size.x;
+            let scrollbox_width = scrollbox.viewport_size.x;
             let (handle_width, handle_pos) = if content_width == 0.0 {
                 (100.0, 0.0)
             } else {
@@ -393,3 +544,251 @@ fn update_scroll_bars(
         }
     }
 }
+
+/// Spawn the [`ScrollShadow`]s of the new [`ScrollShadows`], after the scroll bars
+/// so they are drawn over the content.
+fn spawn_scroll_shadows(
+    mut commands: Commands,
+    query_scrollboxes: Query<Entity, (With<ScrollBox>, Added<ScrollShadows>)>,
+    theme: Res<Theme>,
+) {
+    for scrollbox_entity in &query_scrollboxes {
+        for edge in [ScrollEdge::Top, ScrollEdge::Bottom] {
+            let (angle, top, bottom) = match edge {
+                ScrollEdge::Top => (LinearGradient::TO_BOTTOM, Val::Px(0.0), Val::Auto),
+                ScrollEdge::Bottom => (LinearGradient::TO_TOP, Val::Auto, Val::Px(0.0)),
+            };
+            commands.spawn((
+                ScrollShadow(edge),
+                Node {
+                    grid_column: GridPlacement::start(1),
+                    grid_row: GridPlacement::start(1),
+                    position_type: PositionType::Absolute,
+                    top,
+                    bottom,
+                    width: Val::Percent(100.0),
+                    height: Val::Px(SCROLL_SHADOW_SIZE),
+                    ..default()
+                },
+                BackgroundGradient::from(LinearGradient::new(
+                    angle,
+                    vec![
+                        ColorStop::auto(theme.scroll_box.shadow_color),
+                        ColorStop::auto(Color::NONE),
+                    ],
+                )),
+                Visibility::Hidden,
+                Pickable::IGNORE,
+                ChildOf(scrollbox_entity),
+            ));
+        }
+    }
+}
+
+/// Show the [`ScrollShadow`]s only while there is hidden content past their edge.
+fn update_scroll_shadows(
+    query_scrollboxes: Query<(&ScrollBox, &Children), With<ScrollShadows>>,
+    mut query_shadows: Query<(&ScrollShadow, &mut Visibility)>,
+) {
+    for (scrollbox, scrollbox_children) in &query_scrollboxes {
+        let max_scroll = scrollbox.max_scroll().y;
+        let offset = -scrollbox.position.y;
+
+        for child in scrollbox_children.iter() {
+            let Ok((shadow, mut visibility)) = query_shadows.get_mut(child) else {
+                continue;
+            };
+            visibility.set_if_neq(if shadow.0.has_hidden_content(offset, max_scroll) {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            });
+        }
+    }
+}
+
+/// Jump to the top or the bottom of the nearest [`ScrollBox`] holding the focused entity on
+/// <kbd>Home</kbd> or <kbd>End</kbd>.
+///
+/// The keyboard input bubbles up from the focused entity, so the boxes without the focus never
+/// receive it. The focused text lines consume these keys to move their cursor instead.
+fn scroll_to_edge_on_key(
+    mut trigger: On<FocusedInput<KeyboardInput>>,
+    mut query_scrollbox: Query<&mut ScrollBox>,
+) {
+    let input = &trigger.event().input;
+    if input.state != ButtonState::Pressed {
+        return;
+    }
+    let edge = match input.key_code {
+        KeyCode::Home => ScrollEdge::Top,
+        KeyCode::End => ScrollEdge::Bottom,
+        _ => return,
+    };
+    let Ok(mut scrollbox) = query_scrollbox.get_mut(trigger.target()) else {
+        return;
+    };
+    if scrollbox.overflow.y == OverflowAxis::Scroll {
+        trigger.propagate(false);
+        scrollbox.scroll_to_edge(edge);
+    }
+}
+
+/// Spawn the [`ScrollJumpButton`]s of the new [`ScrollJumpButtons`] in the bottom right corner of
+/// the box, after the scroll bars so they are drawn over the content.
+fn spawn_scroll_jump_buttons(
+    mut commands: Commands,
+    query_scrollboxes: Query<Entity, (With<ScrollBox>, Added<ScrollJumpButtons>)>,
+    theme: Res<Theme>,
+) {
+    for scrollbox_entity in &query_scrollboxes {
+        let buttons = commands
+            .spawn((
+                Node {
+                    grid_column: GridPlacement::start(1),
+                    grid_row: GridPlacement::start(1),
+                    position_type: PositionType::Absolute,
+                    right: Val::Px(4.0),
+                    bottom: Val::Px(4.0),
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                },
+                Pickable::IGNORE,
+                ChildOf(scrollbox_entity),
+            ))
+            .id();
+        for (edge, label) in [(ScrollEdge::Top, "▴"), (ScrollEdge::Bottom, "▾")] {
+            commands
+                .spawn((
+                    ScrollJumpButton(edge),
+                    Button,
+                    Node {
+                        width: Val::Px(SCROLL_JUMP_BUTTON_SIZE),
+                        height: Val::Px(SCROLL_JUMP_BUTTON_SIZE),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    theme.button.background_color,
+                    theme.button.border_radius,
+                    Visibility::Hidden,
+                    ChildOf(buttons),
+                    children![(
+                        Text::new(label),
+                        TextFont {
+                            font: theme.text.font.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(theme.text.text_color),
+                        Pickable::IGNORE,
+                    )],
+                ))
+                .observe(
+                    move |mut trigger: On<Pointer<Click>>,
+                          mut query_scrollbox: Query<&mut ScrollBox>| {
+                        trigger.propagate(false);
+                        if let Ok(mut scrollbox) = query_scrollbox.get_mut(scrollbox_entity) {
+                            scrollbox.scroll_to_edge(edge);
+                        }
+                    },
+                )
+                .observe(
+                    |trigger: On<Pointer<Over>>,
+                     theme: Res<Theme>,
+                     mut query_background: Query<&mut BackgroundColor>| {
+                        if let Ok(mut background) = query_background.get_mut(trigger.target()) {
+                            background.0 = theme.button.hover_color;
+                        }
+                    },
+                )
+                .observe(
+                    |trigger: On<Pointer<Out>>,
+                     theme: Res<Theme>,
+                     mut query_background: Query<&mut BackgroundColor>| {
+                        if let Ok(mut background) = query_background.get_mut(trigger.target()) {
+                            background.0 = theme.button.background_color.0;
+                        }
+                    },
+                );
+        }
+    }
+}
+
+/// Show the [`ScrollJumpButton`]s only while there is hidden content past their edge.
+fn update_scroll_jump_buttons(
+    query_scrollboxes: Query<&ScrollBox, With<ScrollJumpButtons>>,
+    mut query_buttons: Query<(&ScrollJumpButton, &ChildOf, &mut Visibility)>,
+    query_parent: Query<&ChildOf>,
+) {
+    for (button, button_parent, mut visibility) in &mut query_buttons {
+        let Ok(scrollbox_entity) = query_parent
+            .get(button_parent.parent())
+            .map(ChildOf::parent)
+        else {
+            continue;
+        };
+        let Ok(scrollbox) = query_scrollboxes.get(scrollbox_entity) else {
+            continue;
+        };
+        let max_scroll = scrollbox.max_scroll().y;
+        let offset = -scrollbox.position.y;
+        visibility.set_if_neq(if button.0.has_hidden_content(offset, max_scroll) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn show_the_edges_with_hidden_content() {
+        // The content is 300 pixels taller than the box
+        let max_scroll = 300.0;
+        assert!(!ScrollEdge::Top.has_hidden_content(0.0, max_scroll));
+        assert!(ScrollEdge::Bottom.has_hidden_content(0.0, max_scroll));
+        assert!(ScrollEdge::Top.has_hidden_content(150.0, max_scroll));
+        assert!(ScrollEdge::Bottom.has_hidden_content(150.0, max_scroll));
+        assert!(ScrollEdge::Top.has_hidden_content(300.0, max_scroll));
+        assert!(!ScrollEdge::Bottom.has_hidden_content(300.0, max_scroll));
+
+        // Nothing is hidden when the content fits
+        assert!(!ScrollEdge::Top.has_hidden_content(0.0, 0.0));
+        assert!(!ScrollEdge::Bottom.has_hidden_content(0.0, 0.0));
+    }
+
+    #[test]
+    fn follow_the_size_of_the_content() {
+        let box_size = Vec2::new(300.0, 400.0);
+        let scrollbox = ScrollBox::default().measured(box_size, box_size);
+        assert_eq!(scrollbox.max_scroll(), Vec2::ZERO);
+
+        // Children were added, the content can be scrolled further
+        let mut scrollbox = scrollbox.measured(Vec2::new(300.0, 1000.0), box_size);
+        assert_eq!(scrollbox.max_scroll(), Vec2::new(0.0, 600.0));
+        scrollbox.scroll_to(500.0);
+        let scrollbox = scrollbox.measured(Vec2::new(300.0, 1000.0), box_size);
+        assert_eq!(scrollbox.position.y, -500.0);
+
+        // Children were removed, the position is brought back within the content
+        let mut scrollbox = scrollbox.measured(Vec2::new(300.0, 600.0), box_size);
+        assert_eq!(scrollbox.max_scroll(), Vec2::new(0.0, 200.0));
+        assert_eq!(scrollbox.position.y, -200.0);
+
+        assert!(scrollbox.is_at_edge(ScrollEdge::Bottom));
+        scrollbox.scroll_to_edge(ScrollEdge::Top);
+        assert!(!scrollbox.is_at_edge(ScrollEdge::Bottom));
+        scrollbox.scroll_to_edge(ScrollEdge::Bottom);
+        // Still at the bottom before being measured again
+        assert!(scrollbox.is_at_edge(ScrollEdge::Bottom));
+        let scrollbox = scrollbox.measured(Vec2::new(300.0, 600.0), box_size);
+        assert_eq!(scrollbox.position.y, -200.0);
+        let scrollbox = scrollbox.measured(box_size, box_size);
+        assert_eq!(scrollbox.position.y, 0.0);
+    }
+}
@@ -15,6 +15,7 @@ use bevy_clipboard::ClipboardPlugin;
 
 use crate::{
     CharPosition, HasFocus, SetCursorPosition, SetText, TEXT_SELECTION_COLOR, TextChanged,
+    TextSelectionColor,
     cursor::{Cursor, CursorPlugin},
 };
 
@@ -252,15 +253,24 @@ impl RenderWidget {
 fn spawn_system(
     mut commands: Commands,
     mut q_texts: Query<
-        (Entity, &EditableTextLine, &mut Node, Option<&TextFont>),
+        (
+            Entity,
+            &EditableTextLine,
+            &mut Node,
+            Option<&TextFont>,
+            Option<&TextColor>,
+            Option<&TextSelectionColor>,
+        ),
         Without<EditableTextInner>,
     >,
 ) {
-    for (e, text, mut node, font) in q_texts.iter_mut() {
+    for (e, text, mut node, font, color, selection_color) in q_texts.iter_mut() {
         // Set important properties of the node
         node.overflow = Overflow::clip();
 
         let font = font.cloned().unwrap_or_default();
+        let color = color.copied().unwrap_or_default();
+        let selection_color = selection_color.map_or(TEXT_SELECTION_COLOR, |selection| selection.0);
 
         let cursor = commands
             .spawn((
@@ -270,6 +280,7 @@ fn spawn_system(
                     position_type: PositionType::Absolute,
                     ..default()
                 },
+                BackgroundColor(color.0),
                 Visibility::Hidden,
             ))
             .id();
@@ -314,7 +325,7 @@ fn spawn_system(
         let fake_selection_text = commands
             .spawn((
                 Text::new("".to_string()),
-                BackgroundColor(TEXT_SELECTION_COLOR),
+                BackgroundColor(selection_color),
                 Visibility::Hidden,
                 Node { ..default() },
                 TextLayout::new_with_no_wrap(),
@@ -353,6 +364,7 @@ fn spawn_system(
                 },
                 TextLayout::new_with_no_wrap(),
                 font.clone(),
+                color,
             ))
             .id();
 
@@ -55,13 +55,13 @@ pub fn on_click(
 }
 
 pub fn on_key_input(
-    trigger: On<FocusedInput<KeyboardInput>>,
+    mut trigger: On<FocusedInput<KeyboardInput>>,
     mut commands: Commands,
     mut q_text_fields: Query<(Entity, &mut EditableTextLine)>,
     key_states: Res<ButtonInput<KeyCode>>,
-    mut clipboard: ResMut<BevyClipboard>,
+    mut clipboard: Option<ResMut<BevyClipboard>>,
 ) {
-    let input = &trigger.event().input;
+    let input = trigger.event().input.clone();
     if !input.state.is_pressed() {
         // If the key is released, we don't need to process it
         return;
@@ -89,13 +89,14 @@ pub fn on_key_input(
         match &input.key_code {
             KeyCode::KeyC => {
                 if let Some(selected_text) = text_field.get_selected_text()
+                    && let Some(clipboard) = clipboard.as_mut()
                     && let Err(e) = clipboard.set_text(selected_text)
                 {
                     warn!("Clipboard error: {}", e);
                 }
             }
-            KeyCode::KeyV => match clipboard.get_text() {
-                Ok(mut text) => {
+            KeyCode::KeyV => match clipboard.as_mut().map(|clipboard| clipboard.get_text()) {
+                Some(Ok(mut text)) => {
                     if let Some(allowed_chars) = &text_field.allowed_chars {
                         text.retain(|c| allowed_chars.contains(&c));
                     }
@@ -109,12 +110,14 @@ pub fn on_key_input(
                         text_field.selection_start = None;
                     }
                 }
-                Err(e) => {
+                Some(Err(e)) => {
                     warn!("Clipboard error: {}", e);
                 }
+                None => {}
             },
             KeyCode::KeyX => {
                 if let Some(selected_text) = text_field.get_selected_text()
+                    && let Some(clipboard) = clipboard.as_mut()
                     && let Err(e) = clipboard.set_text(selected_text)
                 {
                     warn!("Clipboard error: {}", e);
@@ -208,6 +211,22 @@ pub fn on_key_input(
                     return;
                 }
             }
+            Key::Home | Key::End => {
+                // Consumed, so the scroll boxes holding the line don't jump to their edges too
+                trigger.propagate(false);
+                if key_states.pressed(KeyCode::ShiftLeft) {
+                    if text_field.selection_start.is_none() {
+                        text_field.selection_start = Some(current_cursor);
+                    }
+                } else {
+                    text_field.selection_start = None;
+                }
+                current_cursor = if input.logical_key == Key::Home {
+                    CharPosition(0)
+                } else {
+                    CharPosition(text_field.text.chars().count())
+                };
+            }
             Key::ArrowRight => {
                 if current_cursor < CharPosition(text_field.text.chars().count()) {
                     if key_states.pressed(KeyCode::ShiftLeft) {
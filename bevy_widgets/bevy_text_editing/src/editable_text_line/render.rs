@@ -30,11 +30,9 @@ pub fn render_system(
                 .unwrap_or_default();
 
             if !q_cursors.contains(inner.cursor) {
-                commands.entity(inner.cursor).insert((
-                    Cursor::default(),
-                    Visibility::Visible,
-                    BackgroundColor(Color::srgb(1.0, 1.0, 1.0)),
-                ));
+                commands
+                    .entity(inner.cursor)
+                    .insert((Cursor::default(), Visibility::Visible));
             }
 
             if trigger.show_cursor {
@@ -18,6 +18,13 @@ use text_change::TextChange;
 /// Color of text selection
 pub const TEXT_SELECTION_COLOR: Color = Color::srgb(0.0 / 255.0, 122.0 / 255.0, 1.0);
 
+/// The color of the text selection of an [`EditableTextLine`], [`TEXT_SELECTION_COLOR`] without it.
+///
+/// Like the [`TextColor`] of the line, which colors its text and cursor, it is read when the line
+/// is spawned.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TextSelectionColor(pub Color);
+
 /// An event used to set the text of an editable text widget.
 /// Will be propagated to the first child of the entity it's sent to.
 #[derive(Clone, Component, Event)]
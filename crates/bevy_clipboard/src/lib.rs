@@ -8,14 +8,29 @@ pub struct ClipboardPlugin;
 
 impl Plugin for ClipboardPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<BevyClipboard>();
+        // Headless environments have no clipboard, the resource is missing there
+        match BevyClipboard::new() {
+            Ok(clipboard) => {
+                app.insert_resource(clipboard);
+            }
+            Err(error) => warn!("The clipboard isn't available: {}", error),
+        }
     }
 }
 
 /// Contains clipboard api instance
+///
+/// Only exists when the clipboard is available, systems should take it as an `Option`.
 #[derive(Resource, Deref, DerefMut)]
 pub struct BevyClipboard(pub Clipboard);
 
+impl BevyClipboard {
+    /// Connect to the clipboard of the system
+    pub fn new() -> Result<Self, arboard::Error> {
+        Clipboard::new().map(Self)
+    }
+}
+
 impl Default for BevyClipboard {
     fn default() -> Self {
         Self(Clipboard::new().unwrap())
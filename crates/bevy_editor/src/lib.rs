@@ -40,9 +40,11 @@ use bevy_3d_viewport::Viewport3dPanePlugin;
 use bevy_asset_browser::AssetBrowserPanePlugin;
 
 use crate::load_gltf::LoadGltfPlugin;
+use crate::thumbnail::ThumbnailCapturePlugin;
 
 mod load_gltf;
 pub mod project;
+mod thumbnail;
 mod ui;
 
 /// The plugin that handle the bare minimum to run the application
@@ -81,6 +83,7 @@ impl Plugin for EditorPlugin {
                 InputDispatchPlugin,
                 TabNavigationPlugin,
                 FeathersPlugin,
+                ThumbnailCapturePlugin,
             ))
             .insert_resource(WinitSettings {
                 focused_mode: UpdateMode::reactive(Duration::from_secs_f64(1.0 / 60.0)),
@@ -88,7 +91,14 @@ impl Plugin for EditorPlugin {
             })
             .insert_resource(UiTheme(create_dark_theme()))
             .init_resource::<ActiveTool>()
-            .add_systems(Startup, dummy_setup);
+            .add_systems(Startup, (dummy_setup, acknowledge_open_to_launcher));
+    }
+}
+
+/// Tell the launcher the editor has started, see [`project::open_ack`].
+fn acknowledge_open_to_launcher() {
+    if let Err(error) = project::open_ack::acknowledge_open() {
+        warn!("Couldn't acknowledge the opening to the launcher: {error}");
     }
 }
 
@@ -0,0 +1,147 @@
+//! Checking that the assets a new project loads are there, see [`missing_assets`].
+//!
+//! A project missing an asset builds fine and only fails once it runs and loads it, so the asset
+//! paths loaded by its sources and declared by its template manifest, see
+//! [`TemplateManifest::assets`](super::templates::TemplateManifest::assets), are checked once it
+//! is created.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The folder of a project the assets are loaded from, relative to the project.
+pub const ASSETS_FOLDER: &str = "assets";
+
+/// The calls taking an asset path as their first argument, like `asset_server.load("tile.glb")`.
+const LOADING_CALLS: [&str; 3] = ["load", "load_with_settings", "from_asset"];
+
+/// The asset paths loaded with a string literal in the Rust source, without their label.
+///
+/// The paths built at runtime, the remote ones and the ones without an extension, like folders,
+/// can't be checked and are left out.
+pub fn referenced_asset_paths(source: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    for (start, _) in source.match_indices('(') {
+        let before = &source[..start];
+        let name_start = before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| !(c.is_alphanumeric() || *c == '_'))
+            .map_or(0, |(index, c)| index + c.len_utf8());
+        let called = &before[name_start..];
+        if !LOADING_CALLS.contains(&called) {
+            continue;
+        }
+        let Some(literal) = source[start + 1..].trim_start().strip_prefix('"') else {
+            continue;
+        };
+        let Some(end) = literal.find('"') else {
+            continue;
+        };
+        let path = literal[..end].split('#').next().unwrap_or_default();
+        let checkable = !path.contains("://")
+            && !path.contains(['{', '\\'])
+            && Path::new(path).extension().is_some();
+        if checkable {
+            paths.push(path.to_string());
+        }
+    }
+    paths
+}
+
+/// The Rust files in the folder and its subfolders, none when it doesn't exist.
+fn rust_sources(folder: &Path, sources: &mut Vec<PathBuf>) -> io::Result<()> {
+    let entries = match fs::read_dir(folder) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(error),
+    };
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            rust_sources(&path, sources)?;
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            sources.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// The asset paths loaded by the sources in the `src` folder of the project or `declared` by its
+/// template, that aren't in its [`ASSETS_FOLDER`], sorted.
+pub fn missing_assets(project: &Path, declared: &[String]) -> io::Result<Vec<String>> {
+    let mut sources = Vec::new();
+    rust_sources(&project.join("src"), &mut sources)?;
+    let mut paths = declared.to_vec();
+    for source in sources {
+        paths.extend(referenced_asset_paths(&fs::read_to_string(source)?));
+    }
+    paths.sort();
+    paths.dedup();
+
+    let assets = project.join(ASSETS_FOLDER);
+    paths.retain(|path| !assets.join(path).exists());
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_the_loaded_paths() {
+        let source = r#"
+            let tile = asset_server.load(GltfAssetLabel::Scene(0).from_asset("models/tile.glb"));
+            let font: Handle<Font> = assets.load( "fonts/FiraSans-Bold.ttf" );
+            let icon = asset_server.load("icons/app.png#Main");
+            let image = server.load_with_settings("textures/grass.ktx2", settings);
+            let level = asset_server.load(format!("levels/{index}.ron"));
+            let remote = asset_server.load("https://example.com/logo.png");
+            let folder = asset_server.load_folder("sounds");
+            reload("config.ron");
+        "#;
+        assert_eq!(
+            referenced_asset_paths(source),
+            vec![
+                "models/tile.glb",
+                "fonts/FiraSans-Bold.ttf",
+                "icons/app.png",
+                "textures/grass.ktx2",
+            ]
+        );
+    }
+
+    #[test]
+    fn report_the_missing_assets() {
+        let project =
+            std::env::temp_dir().join(format!("bevy_editor_assets_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&project);
+        fs::create_dir_all(project.join("src/player")).unwrap();
+        fs::create_dir_all(project.join("assets/models")).unwrap();
+        fs::write(project.join("assets/models/tile.glb"), "").unwrap();
+        fs::write(
+            project.join("src/main.rs"),
+            r#"fn setup() { server.load("models/tile.glb"); server.load("models/alien.glb"); }"#,
+        )
+        .unwrap();
+        fs::write(
+            project.join("src/player/mod.rs"),
+            r#"fn spawn() { server.load("models/alien.glb"); }"#,
+        )
+        .unwrap();
+
+        let declared = vec!["music/theme.ogg".to_string(), "models/tile.glb".to_string()];
+        assert_eq!(
+            missing_assets(&project, &declared).unwrap(),
+            vec!["models/alien.glb", "music/theme.ogg"]
+        );
+        // Without sources, only the declared assets are checked
+        fs::remove_dir_all(project.join("src")).unwrap();
+        assert_eq!(
+            missing_assets(&project, &declared).unwrap(),
+            vec!["music/theme.ogg"]
+        );
+        let _ = fs::remove_dir_all(&project);
+    }
+}
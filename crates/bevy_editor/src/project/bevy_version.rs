@@ -0,0 +1,254 @@
+//! Module to change the Bevy version a project depends on.
+//!
+//! The `bevy` dependency of the `Cargo.toml` is edited in place, keeping the formatting and the
+//! comments of the manifest. Dependencies inherited from a workspace aren't edited, as their
+//! version is declared in another manifest.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use bevy::log::info;
+use toml_edit::{DocumentMut, Item, TableLike, Value};
+
+use super::ProjectError;
+
+/// The name of the copy of the `Cargo.toml` made before changing the version.
+pub const MANIFEST_BACKUP: &str = "Cargo.toml.bak";
+
+/// Make sure the version requirement is one Cargo understands, like `0.16`, `^0.15.3` or
+/// `=0.17.0-rc.1`.
+///
+/// Only the simple requirements are accepted: an optional `^`, `~` or `=` operator, followed by
+/// a major, minor and optional patch version, the latter possibly with a pre-release suffix.
+pub fn validate_bevy_version(version: &str) -> Result<(), ProjectError> {
+    let invalid = || ProjectError::VersionInvalid(version.to_string());
+    let requirement = version.trim_start_matches(['^', '~', '=']);
+    if requirement.len() + 1 < version.len() {
+        return Err(invalid());
+    }
+    let (numbers, pre_release) = match requirement.split_once('-') {
+        Some((numbers, pre_release)) => (numbers, Some(pre_release)),
+        None => (requirement, None),
+    };
+    // The number of dot separated parts, if they are all made of valid characters
+    let parts = |text: &str, valid: fn(char) -> bool| -> Option<usize> {
+        let parts: Vec<_> = text.split('.').collect();
+        parts
+            .iter()
+            .all(|part| !part.is_empty() && part.chars().all(valid))
+            .then_some(parts.len())
+    };
+    let valid = match (parts(numbers, |c| c.is_ascii_digit()), pre_release) {
+        (Some(2 | 3), None) => true,
+        (Some(3), Some(pre_release)) => parts(pre_release, |c| c.is_ascii_alphanumeric()).is_some(),
+        _ => false,
+    };
+    if valid { Ok(()) } else { Err(invalid()) }
+}
+
+/// Set the version requirement of the `bevy` dependency of the project.
+///
+/// The `Cargo.toml` is copied to [`MANIFEST_BACKUP`] first, its path is returned.
+/// Returns [`ProjectError::ManualEditRequired`] for dependencies inherited from a workspace,
+/// declared in `[workspace.dependencies]` or without a version, like git ones.
+pub fn set_bevy_version(path: &Path, version: &str) -> Result<PathBuf, ProjectError> {
+    validate_bevy_version(version)?;
+    let manifest_path = path.join("Cargo.toml");
+    let content = fs::read_to_string(&manifest_path).map_err(|_| ProjectError::NotAProject {
+        path: path.to_path_buf(),
+        reason: "missing the Cargo.toml".to_string(),
+    })?;
+    let mut manifest: DocumentMut = content.parse().map_err(|error| ProjectError::NotAProject {
+        path: path.to_path_buf(),
+        reason: format!("malformed Cargo.toml: {error}"),
+    })?;
+
+    let manual = |reason: &str| ProjectError::ManualEditRequired {
+        path: manifest_path.clone(),
+        reason: reason.to_string(),
+    };
+    let in_workspace = manifest
+        .get("workspace")
+        .and_then(|workspace| workspace.get("dependencies"))
+        .and_then(|dependencies| dependencies.get("bevy"))
+        .is_some();
+    let Some(bevy) = manifest
+        .get_mut("dependencies")
+        .and_then(Item::as_table_like_mut)
+        .and_then(|dependencies| dependencies.get_mut("bevy"))
+    else {
+        return Err(if in_workspace {
+            manual("bevy is declared in the [workspace.dependencies] of the workspace")
+        } else {
+            ProjectError::NotAProject {
+                path: path.to_path_buf(),
+                reason: "not depending on Bevy".to_string(),
+            }
+        });
+    };
+    let current = match bevy {
+        Item::Value(value @ Value::String(_)) => value,
+        Item::Value(Value::InlineTable(dependency)) => version_entry(dependency).map_err(manual)?,
+        Item::Table(dependency) => version_entry(dependency).map_err(manual)?,
+        _ => return Err(manual("the bevy dependency has an unexpected format")),
+    };
+    // Keep the spaces and comments around the version
+    let decor = current.decor().clone();
+    *current = Value::from(version);
+    *current.decor_mut() = decor;
+
+    let backup = path.join(MANIFEST_BACKUP);
+    fs::copy(&manifest_path, &backup).map_err(|error| ProjectError::from_io(path, error))?;
+    fs::write(&manifest_path, manifest.to_string())
+        .map_err(|error| ProjectError::from_io(path, error))?;
+    info!(
+        "Set the Bevy version of '{}' to {version}, the previous manifest is saved to '{}'",
+        path.display(),
+        backup.display()
+    );
+    Ok(backup)
+}
+
+/// The version of a dependency declared as a table, failing with the reason it can't be edited.
+fn version_entry(dependency: &mut dyn TableLike) -> Result<&mut Value, &'static str> {
+    if dependency.contains_key("workspace") {
+        return Err(
+            "bevy is inherited from the workspace, its version is set in the workspace manifest",
+        );
+    }
+    dependency
+        .get_mut("version")
+        .and_then(Item::as_value_mut)
+        .ok_or("the bevy dependency has no version, like a git or path one")
+}
+
+/// Run `cargo update -p bevy` in the project, updating its `Cargo.lock` to the new version.
+///
+/// Returns the output of cargo, which reports the updated crates.
+pub fn update_bevy_lockfile(path: &Path) -> Result<String, ProjectError> {
+    let output = Command::new("cargo")
+        .args(["update", "-p", "bevy"])
+        .current_dir(path)
+        .output()
+        .map_err(|error| ProjectError::Cargo(format!("Failed to run cargo update: {error}")))?;
+    // Cargo reports its progress on stderr
+    let report = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    if output.status.success() {
+        Ok(report)
+    } else {
+        Err(ProjectError::Cargo(report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_versions() {
+        for version in [
+            "0.16",
+            "0.15.3",
+            "^0.14",
+            "~0.16.1",
+            "=0.17.0-rc.1",
+            "1.0.0",
+        ] {
+            assert!(validate_bevy_version(version).is_ok(), "{version}");
+        }
+        for version in [
+            "", "0", "0.16.", "v0.16", "^^0.16", ">=0.16", "0.16-dev", "0.16.0-", "0.1x", "*",
+        ] {
+            assert!(
+                matches!(
+                    validate_bevy_version(version),
+                    Err(ProjectError::VersionInvalid(_))
+                ),
+                "{version}"
+            );
+        }
+    }
+
+    #[test]
+    fn edit_the_bevy_dependency() {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_editor_bevy_version_test_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        let write = |name: &str, manifest: &str| {
+            fs::create_dir_all(root.join(name)).unwrap();
+            fs::write(root.join(name).join("Cargo.toml"), manifest).unwrap();
+            root.join(name)
+        };
+
+        let simple = write("simple", "[dependencies]\nbevy = \"0.15\" # The engine\n");
+        let backup = set_bevy_version(&simple, "0.16").unwrap();
+        assert_eq!(
+            fs::read_to_string(simple.join("Cargo.toml")).unwrap(),
+            "[dependencies]\nbevy = \"0.16\" # The engine\n"
+        );
+        assert_eq!(
+            fs::read_to_string(backup).unwrap(),
+            "[dependencies]\nbevy = \"0.15\" # The engine\n"
+        );
+
+        let features = write(
+            "features",
+            "[dependencies]\nbevy = { version = \"0.15\", features = [\"dynamic_linking\"] }\n",
+        );
+        set_bevy_version(&features, "^0.16.1").unwrap();
+        assert_eq!(
+            fs::read_to_string(features.join("Cargo.toml")).unwrap(),
+            "[dependencies]\nbevy = { version = \"^0.16.1\", features = [\"dynamic_linking\"] }\n"
+        );
+
+        let table = write(
+            "table",
+            "[dependencies.bevy]\nversion = \"0.14\"\ndefault-features = false\n",
+        );
+        set_bevy_version(&table, "0.16").unwrap();
+        assert_eq!(
+            fs::read_to_string(table.join("Cargo.toml")).unwrap(),
+            "[dependencies.bevy]\nversion = \"0.16\"\ndefault-features = false\n"
+        );
+
+        for (name, manifest) in [
+            ("inherited", "[dependencies]\nbevy.workspace = true\n"),
+            ("workspace", "[workspace.dependencies]\nbevy = \"0.15\"\n"),
+            (
+                "git",
+                "[dependencies]\nbevy = { git = \"https://github.com/bevyengine/bevy\" }\n",
+            ),
+        ] {
+            let project = write(name, manifest);
+            assert!(
+                matches!(
+                    set_bevy_version(&project, "0.16"),
+                    Err(ProjectError::ManualEditRequired { .. })
+                ),
+                "{name}"
+            );
+            assert_eq!(
+                fs::read_to_string(project.join("Cargo.toml")).unwrap(),
+                manifest
+            );
+            assert!(!project.join(MANIFEST_BACKUP).exists());
+        }
+
+        let tool = write("tool", "[dependencies]\nserde = \"1\"\n");
+        assert!(matches!(
+            set_bevy_version(&tool, "0.16"),
+            Err(ProjectError::NotAProject { .. })
+        ));
+        assert!(matches!(
+            set_bevy_version(&simple, "latest"),
+            Err(ProjectError::VersionInvalid(_))
+        ));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -1,7 +1,12 @@
+use bevy::log::warn;
 use serde::{Deserialize, Serialize};
-use std::{fs::File, io, path::PathBuf};
+use std::{
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+};
 
-use super::ProjectInfo;
+use super::{ProjectInfo, ProjectLocation};
 
 /// The name of the project cache file
 const CACHE_FILE: &str = "projects.ron";
@@ -9,8 +14,11 @@ const CACHE_FILE: &str = "projects.ron";
 const CACHE_FOLDER_NAME: &str = "Bevy Editor";
 
 /// This is the structure that is saved in the [`CACHE_FILE`]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 struct ProjectsCache {
+    /// The folder the [`ProjectLocation::RootRelative`] paths are relative to
+    #[serde(default)]
+    root: Option<PathBuf>,
     projects: Vec<ProjectInfo>,
 }
 
@@ -18,7 +26,7 @@ struct ProjectsCache {
 /// `Windows`: %LOCALAPPDATA%/[`CACHE_FOLDER_NAME`]
 /// `MacOS`: ~/Library/Caches/[`CACHE_FOLDER_NAME`]
 /// `Linux`: ~/.cache/[`CACHE_FOLDER_NAME`]
-fn get_cache_folder() -> PathBuf {
+pub(super) fn get_cache_folder() -> PathBuf {
     #[cfg(target_os = "windows")]
     let path = PathBuf::from(std::env::var("LOCALAPPDATA").unwrap());
 
@@ -31,11 +39,96 @@ fn get_cache_folder() -> PathBuf {
     path.join(CACHE_FOLDER_NAME)
 }
 
-/// Load the projects from the cache file
+/// Load the projects from the cache file, resolving the root-relative paths
 pub(super) fn load_projects() -> io::Result<Vec<ProjectInfo>> {
-    let cache_folder = get_cache_folder();
-    let cache_file = cache_folder.join(CACHE_FILE);
+    let cache = load_cache(&cache_file())?;
+    let projects = cache
+        .projects
+        .into_iter()
+        .map(|project| resolve_project(project, cache.root.as_deref()))
+        .collect();
+    Ok(projects)
+}
+
+/// Save the projects to the cache file, storing the paths under the root relative to it
+pub(super) fn save_projects(projects: Vec<ProjectInfo>) -> io::Result<()> {
+    let root = load_root();
+    let projects = projects
+        .into_iter()
+        .map(|project| store_project(project, root.as_deref()))
+        .collect();
+    save_cache(&cache_file(), &ProjectsCache { root, projects })
+}
+
+/// Load the root the project paths are relative to
+pub(super) fn load_root() -> Option<PathBuf> {
+    load_cache(&cache_file()).ok().and_then(|cache| cache.root)
+}
+
+/// Change the root the project paths are relative to, without touching the stored paths
+pub(super) fn save_root(root: PathBuf) -> io::Result<()> {
+    save_root_to(&cache_file(), root)
+}
+
+/// Change the root stored in the cache file, starting a new cache only when there is none.
+///
+/// An unreadable or corrupt cache file is reported rather than replaced, which would lose the
+/// projects it lists.
+fn save_root_to(cache_file: &Path, root: PathBuf) -> io::Result<()> {
+    let mut cache = match load_cache(cache_file) {
+        Ok(cache) => cache,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => ProjectsCache::default(),
+        Err(error) => return Err(error),
+    };
+    cache.root = Some(root);
+    save_cache(cache_file, &cache)
+}
+
+/// Turn the stored path of a project into an absolute one.
+///
+/// Absolute paths under the root are migrated to [`ProjectLocation::RootRelative`].
+fn resolve_project(mut project: ProjectInfo, root: Option<&Path>) -> ProjectInfo {
+    match (project.location, root) {
+        (ProjectLocation::RootRelative, Some(root)) => {
+            project.path = root.join(&project.path);
+        }
+        (ProjectLocation::RootRelative, None) => {
+            warn!(
+                "Project '{}' is relative to the projects root but none is configured",
+                project.path.display()
+            );
+        }
+        (ProjectLocation::Absolute, Some(root)) => {
+            if project.path.starts_with(root) {
+                project.location = ProjectLocation::RootRelative;
+            }
+        }
+        (ProjectLocation::Absolute, None) => {}
+    }
+    project
+}
+
+/// Turn the absolute path of a project into the one stored in the cache file.
+///
+/// Projects outside of the root fall back to [`ProjectLocation::Absolute`].
+fn store_project(mut project: ProjectInfo, root: Option<&Path>) -> ProjectInfo {
+    let relative = root.and_then(|root| project.path.strip_prefix(root).ok());
+    match relative {
+        Some(relative) => {
+            project.path = relative.to_path_buf();
+            project.location = ProjectLocation::RootRelative;
+        }
+        None => project.location = ProjectLocation::Absolute,
+    }
+    project
+}
+
+/// The path of the [`CACHE_FILE`], in the Bevy Editor's cache folder
+fn cache_file() -> PathBuf {
+    get_cache_folder().join(CACHE_FILE)
+}
 
+fn load_cache(cache_file: &Path) -> io::Result<ProjectsCache> {
     if !cache_file.exists() {
         return Err(io::Error::new(
             io::ErrorKind::NotFound,
@@ -44,27 +137,23 @@ pub(super) fn load_projects() -> io::Result<Vec<ProjectInfo>> {
     }
 
     let file = File::open(cache_file)?;
-    let cache_value: ProjectsCache = ron::de::from_reader(file).map_err(|error| {
+    ron::de::from_reader(file).map_err(|error| {
         io::Error::new(
             io::ErrorKind::InvalidData,
             format!("Couldn't parse project cache file: {error}"),
         )
-    })?;
-
-    Ok(cache_value.projects)
+    })
 }
 
-/// Save the projects to the cache file
-pub(super) fn save_projects(projects: Vec<ProjectInfo>) -> io::Result<()> {
-    let cache_folder = get_cache_folder();
-    let cache_file = cache_folder.join(CACHE_FILE);
-    if !cache_folder.exists() {
-        std::fs::create_dir(&cache_folder)?;
+fn save_cache(cache_file: &Path, cache: &ProjectsCache) -> io::Result<()> {
+    if let Some(cache_folder) = cache_file.parent()
+        && !cache_folder.exists()
+    {
+        std::fs::create_dir(cache_folder)?;
     }
-    let cache_value = ProjectsCache { projects };
     let file = File::create(cache_file)?;
     ron::Options::default()
-        .to_io_writer(file, &cache_value)
+        .to_io_writer(file, cache)
         .map_err(|error| {
             io::Error::new(
                 io::ErrorKind::InvalidData,
@@ -72,3 +161,73 @@ pub(super) fn save_projects(projects: Vec<ProjectInfo>) -> io::Result<()> {
             )
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(path: &str, location: ProjectLocation) -> ProjectInfo {
+        ProjectInfo {
+            location,
+            ..ProjectInfo::new(path)
+        }
+    }
+
+    #[test]
+    fn paths_follow_the_root() {
+        let root = Path::new("/home/me/projects");
+        let stored = store_project(
+            project("/home/me/projects/game", ProjectLocation::Absolute),
+            Some(root),
+        );
+        assert_eq!(stored.path, Path::new("game"));
+        assert_eq!(stored.location, ProjectLocation::RootRelative);
+
+        let moved = resolve_project(stored, Some(Path::new("/mnt/projects")));
+        assert_eq!(moved.path, Path::new("/mnt/projects/game"));
+
+        let outside = store_project(
+            project("/tmp/game", ProjectLocation::RootRelative),
+            Some(root),
+        );
+        assert_eq!(outside.path, Path::new("/tmp/game"));
+        assert_eq!(outside.location, ProjectLocation::Absolute);
+    }
+
+    #[test]
+    fn migrate_absolute_paths_under_root() {
+        let root = Path::new("/home/me/projects");
+        let migrated = resolve_project(
+            project("/home/me/projects/game", ProjectLocation::Absolute),
+            Some(root),
+        );
+        assert_eq!(migrated.path, Path::new("/home/me/projects/game"));
+        assert_eq!(migrated.location, ProjectLocation::RootRelative);
+
+        let outside = resolve_project(project("/tmp/game", ProjectLocation::Absolute), Some(root));
+        assert_eq!(outside.location, ProjectLocation::Absolute);
+    }
+
+    #[test]
+    fn keep_a_corrupt_cache_when_saving_the_root() {
+        let folder = std::env::temp_dir().join(format!("bevy_editor_cache_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&folder);
+        std::fs::create_dir_all(&folder).unwrap();
+        let cache_file = folder.join(CACHE_FILE);
+        let root = PathBuf::from("/mnt/projects");
+
+        std::fs::write(&cache_file, "(projects: [").unwrap();
+        let error = save_root_to(&cache_file, root.clone()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            std::fs::read_to_string(&cache_file).unwrap(),
+            "(projects: ["
+        );
+
+        // Without a cache file, a new one is started
+        std::fs::remove_file(&cache_file).unwrap();
+        save_root_to(&cache_file, root.clone()).unwrap();
+        assert_eq!(load_cache(&cache_file).unwrap().root, Some(root));
+        let _ = std::fs::remove_dir_all(&folder);
+    }
+}
@@ -0,0 +1,60 @@
+//! Cancelling the long project operations, like creating a project or scanning for projects.
+//!
+//! The operation is given a [`CancellationToken`] and checks it between its steps, so it stops at
+//! the next one once the token is cancelled and undoes what it did, like removing the partially
+//! created project folder.
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use super::ProjectError;
+
+/// A flag shared between an operation and the code that may cancel it.
+///
+/// The clones share the flag, so the caller keeps one and gives another to the operation.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// A token that isn't cancelled yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask the operations holding the token to stop.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [`cancel`](Self::cancel) was called on the token or one of its clones.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Fail with [`ProjectError::Cancelled`] once the token is cancelled, for the checkpoints of the
+    /// operations.
+    pub fn check(&self) -> Result<(), ProjectError> {
+        if self.is_cancelled() {
+            Err(ProjectError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_the_cancellation() {
+        let token = CancellationToken::new();
+        let operation = token.clone();
+        assert!(operation.check().is_ok());
+        token.cancel();
+        assert!(operation.is_cancelled());
+        assert!(matches!(operation.check(), Err(ProjectError::Cancelled)));
+    }
+}
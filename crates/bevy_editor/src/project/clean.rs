@@ -0,0 +1,145 @@
+//! Module to reclaim the disk space used by the build artifacts of the projects.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use bevy::log::{info, warn};
+
+use super::{ProjectError, project_exists};
+
+/// The folder cargo writes the build artifacts of the project to.
+///
+/// Only the default `target` folder is known, custom `build.target-dir`s are left alone.
+pub fn target_folder(path: &Path) -> PathBuf {
+    path.join("target")
+}
+
+/// The total size of the files under `path`, in bytes.
+///
+/// The symlinks aren't followed, and the entries that can't be read are skipped.
+pub fn folder_size(path: &Path) -> u64 {
+    let mut size = 0;
+    let mut pending = vec![path.to_path_buf()];
+    while let Some(folder) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&folder) else {
+            continue;
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() {
+                size += entry.metadata().map_or(0, |metadata| metadata.len());
+            }
+        }
+    }
+    size
+}
+
+/// Remove the build artifacts of the project, returning the amount of bytes freed.
+///
+/// `cargo clean` is run in the project folder, the [`target_folder`] is deleted directly when cargo
+/// can't be run or fails, like for projects with a broken manifest.
+/// Folders without a `Cargo.toml` are never cleaned, failing with [`ProjectError::NotAProject`].
+/// This blocks until done, which can take a while for large folders.
+pub fn clean_target(path: &Path) -> Result<u64, ProjectError> {
+    if !project_exists(path) {
+        return Err(ProjectError::NotFound(path.to_path_buf()));
+    }
+    if !path.join("Cargo.toml").is_file() {
+        return Err(ProjectError::NotAProject {
+            path: path.to_path_buf(),
+            reason: "no Cargo.toml found".to_string(),
+        });
+    }
+    let target = target_folder(path);
+    if !target.exists() {
+        return Ok(0);
+    }
+    let before = folder_size(&target);
+
+    let cleaned = match Command::new("cargo")
+        .arg("clean")
+        .current_dir(path)
+        .output()
+    {
+        Ok(output) if output.status.success() => true,
+        Ok(output) => {
+            warn!(
+                "cargo clean failed in '{}': {}",
+                path.display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+            false
+        }
+        Err(error) => {
+            warn!("Couldn't run cargo clean: {error}");
+            false
+        }
+    };
+    if !cleaned && target.exists() {
+        fs::remove_dir_all(&target).map_err(|error| ProjectError::from_io(&target, error))?;
+    }
+
+    let freed = before.saturating_sub(folder_size(&target));
+    info!("Cleaned '{}', freeing {freed} bytes", path.display());
+    Ok(freed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_folder(name: &str) -> PathBuf {
+        let folder =
+            std::env::temp_dir().join(format!("bevy_editor_clean_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&folder);
+        folder
+    }
+
+    #[test]
+    fn size_of_project_folders() {
+        let folder = test_folder("size");
+        fs::create_dir_all(folder.join("src")).unwrap();
+        fs::write(folder.join("Cargo.toml"), [0; 100]).unwrap();
+        fs::write(folder.join("src/main.rs"), [0; 50]).unwrap();
+
+        assert_eq!(folder_size(&folder), 150);
+        assert_eq!(folder_size(&folder.join("missing")), 0);
+        let _ = fs::remove_dir_all(&folder);
+    }
+
+    #[test]
+    fn clean_the_build_artifacts() {
+        let folder = test_folder("target");
+        fs::create_dir_all(folder.join("target/debug/deps")).unwrap();
+        fs::create_dir_all(folder.join("src")).unwrap();
+        fs::write(folder.join("target/debug/game"), [0; 300]).unwrap();
+        fs::write(folder.join("target/debug/deps/libbevy.rlib"), [0; 200]).unwrap();
+        fs::write(folder.join("src/main.rs"), [0; 50]).unwrap();
+
+        // Only the folders of a project are cleaned
+        assert!(matches!(
+            clean_target(&folder),
+            Err(ProjectError::NotAProject { .. })
+        ));
+        assert!(folder.join("target/debug/game").exists());
+
+        // With a broken manifest cargo fails, and the folder is deleted directly
+        fs::write(folder.join("Cargo.toml"), "not a manifest").unwrap();
+        assert_eq!(clean_target(&folder).unwrap(), 500);
+        assert!(!target_folder(&folder).exists());
+        assert!(folder.join("src/main.rs").exists());
+        assert_eq!(clean_target(&folder).unwrap(), 0, "already clean");
+        assert!(matches!(
+            clean_target(&folder.join("missing")),
+            Err(ProjectError::NotFound(_))
+        ));
+        let _ = fs::remove_dir_all(&folder);
+    }
+}
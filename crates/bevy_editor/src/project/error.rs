@@ -0,0 +1,116 @@
+//! The errors of the project operations.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+/// An error returned by the project operations, like [`create_new_project`](super::create_new_project).
+///
+/// The variants the UI can act on are kept apart, the others are wrapped with their original error.
+#[derive(Debug, thiserror::Error)]
+pub enum ProjectError {
+    /// The name can't be used as a project folder name.
+    #[error("'{0}' isn't a valid project name")]
+    NameInvalid(String),
+    /// The folder already exists and holds files.
+    #[error("'{}' already exists and isn't empty", .0.display())]
+    DirectoryNotEmpty(PathBuf),
+    /// The project folder doesn't exist.
+    #[error("'{}' doesn't exist", .0.display())]
+    NotFound(PathBuf),
+    /// The folder exists but doesn't hold a Bevy project.
+    #[error("'{}' isn't a Bevy project: {reason}", path.display())]
+    NotAProject {
+        /// The folder that was expected to hold a project.
+        path: PathBuf,
+        /// What is missing from the folder.
+        reason: String,
+    },
+    /// The folder can't be modified.
+    #[error("'{}' can't be written to", path.display())]
+    NotWritable {
+        /// The folder that couldn't be modified.
+        path: PathBuf,
+        /// The error reported by the file system.
+        #[source]
+        source: io::Error,
+    },
+    /// The template is invalid, or values it needs are missing.
+    #[error("invalid template: {0}")]
+    Template(String),
+    /// The version requirement isn't a valid one, like `0.16` or `^0.15.3`.
+    #[error("'{0}' isn't a valid version requirement")]
+    VersionInvalid(String),
+    /// The manifest can't be modified automatically, like when the dependency is inherited from a workspace.
+    #[error("'{}' needs to be edited manually: {reason}", path.display())]
+    ManualEditRequired {
+        /// The manifest that needs to be edited.
+        path: PathBuf,
+        /// Why it can't be edited automatically.
+        reason: String,
+    },
+    /// The name can't be used for an environment variable.
+    #[error("'{0}' isn't a valid environment variable name")]
+    EnvVarInvalid(String),
+    /// The workspace has no member with this package name.
+    #[error("the workspace has no member named '{0}'")]
+    MemberNotFound(String),
+    /// The [`PostCreateHook`](super::templates::PostCreateHook) of the template failed or timed
+    /// out.
+    #[error("the post-create command failed: {0}")]
+    PostCreate(String),
+    /// Running `cargo` failed.
+    #[error("cargo failed: {0}")]
+    Cargo(String),
+    /// The operation was stopped through its [`CancellationToken`](super::CancellationToken).
+    #[error("the operation was cancelled")]
+    Cancelled,
+    /// Any other I/O error.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+impl ProjectError {
+    /// Wrap an I/O error that happened while modifying `path`, telling permission errors apart.
+    pub(super) fn from_io(path: &Path, error: io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => {
+                ProjectError::NotWritable {
+                    path: path.to_path_buf(),
+                    source: error,
+                }
+            }
+            _ => ProjectError::Io(error),
+        }
+    }
+}
+
+/// Make sure the name can be used as a folder name on every platform.
+pub fn validate_project_name(name: &str) -> Result<(), ProjectError> {
+    let valid = !name.trim().is_empty()
+        && name != "."
+        && name != ".."
+        && !name.ends_with(['.', ' '])
+        && !name.chars().any(|c| {
+            c.is_control() || matches!(c, '/' | '\\' | '<' | '>' | ':' | '"' | '|' | '?' | '*')
+        });
+    if valid {
+        Ok(())
+    } else {
+        Err(ProjectError::NameInvalid(name.to_string()))
+    }
+}
+
+/// Make sure the name can be given to a process as an environment variable, like `RUST_LOG`.
+pub fn validate_env_var_name(name: &str) -> Result<(), ProjectError> {
+    let valid = !name.is_empty()
+        && !name
+            .chars()
+            .any(|c| c == '=' || c.is_whitespace() || c.is_control());
+    if valid {
+        Ok(())
+    } else {
+        Err(ProjectError::EnvVarInvalid(name.to_string()))
+    }
+}
@@ -0,0 +1,157 @@
+//! Exporting the project list to JSON, for the scripts and tools working with the projects.
+//!
+//! Unlike the project list stored by the editor, the export is an interface: its fields, described
+//! on [`ProjectExport`] and [`ExportedProject`], only change along with [`EXPORT_SCHEMA_VERSION`].
+//! An export looks like:
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "projects": [
+//!     {
+//!       "name": "my_game",
+//!       "path": "/home/me/projects/my_game",
+//!       "last_opened": 1718000000,
+//!       "pinned": true,
+//!       "open_count": 12,
+//!       "bevy_version": "0.16",
+//!       "default_member": null,
+//!       "env": { "RUST_LOG": "info" },
+//!       "icon": "🚀"
+//!     }
+//!   ]
+//! }
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{ProjectInfo, scan};
+
+/// The version of the export format, increased when a field is removed or changes meaning.
+///
+/// Adding a field keeps the version, the readers should ignore the fields they don't know.
+pub const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// The exported project list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectExport {
+    /// The [`EXPORT_SCHEMA_VERSION`] the export follows.
+    pub schema_version: u32,
+    /// The projects, in the order of the project list.
+    pub projects: Vec<ExportedProject>,
+}
+
+/// A project of a [`ProjectExport`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedProject {
+    /// The name of the project folder, `null` when it can't be represented.
+    pub name: Option<String>,
+    /// The absolute path of the project folder.
+    pub path: PathBuf,
+    /// When the project was last opened, in seconds since the Unix epoch.
+    pub last_opened: u64,
+    /// Whether the project is pinned to the quick access of the launcher.
+    pub pinned: bool,
+    /// How many times the project was opened from the launcher.
+    pub open_count: u32,
+    /// The Bevy version requirement of the project, like `0.16`, `null` when it can't be read.
+    pub bevy_version: Option<String>,
+    /// The workspace member run by default, `null` to let cargo pick it.
+    pub default_member: Option<String>,
+    /// The environment variables set when running the project.
+    pub env: BTreeMap<String, String>,
+    /// The icon of the project, an emoji or an image path, `null` for the default one.
+    #[serde(default)]
+    pub icon: Option<String>,
+}
+
+impl ProjectExport {
+    /// Export the projects, reading their Bevy version from their manifest.
+    pub fn new(projects: &[ProjectInfo]) -> Self {
+        Self {
+            schema_version: EXPORT_SCHEMA_VERSION,
+            projects: projects.iter().map(ExportedProject::new).collect(),
+        }
+    }
+
+    /// The export as indented JSON.
+    pub fn to_json(&self) -> String {
+        // The fields are strings, numbers and string maps, which always serialize
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Read an export back from its JSON.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+}
+
+impl ExportedProject {
+    /// Export the project, reading its Bevy version from its manifest.
+    pub fn new(project: &ProjectInfo) -> Self {
+        Self {
+            name: project.name(),
+            path: project.path.clone(),
+            last_opened: unix_seconds(project.last_opened),
+            pinned: project.pinned,
+            open_count: project.open_count,
+            bevy_version: scan::bevy_version(&project.path),
+            default_member: project.default_member.clone(),
+            env: project.env.clone(),
+            icon: project.icon.clone(),
+        }
+    }
+}
+
+/// The seconds since the Unix epoch, 0 for the times before it.
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn export_a_stable_structure() {
+        let project = ProjectInfo {
+            last_opened: UNIX_EPOCH + Duration::from_secs(1_718_000_000),
+            pinned: true,
+            env: BTreeMap::from([("RUST_LOG".to_string(), "info".to_string())]),
+            default_member: Some("client".to_string()),
+            open_count: 12,
+            ..ProjectInfo::new("/projects/missing_game")
+        };
+        let export = ProjectExport::new(&[project]);
+        let json = export.to_json();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+            json!({
+                "schema_version": EXPORT_SCHEMA_VERSION,
+                "projects": [{
+                    "name": "missing_game",
+                    "path": "/projects/missing_game",
+                    "last_opened": 1_718_000_000,
+                    "pinned": true,
+                    "open_count": 12,
+                    "bevy_version": null,
+                    "default_member": "client",
+                    "env": { "RUST_LOG": "info" },
+                    "icon": null,
+                }],
+            })
+        );
+        assert_eq!(ProjectExport::from_json(&json).unwrap(), export);
+    }
+}
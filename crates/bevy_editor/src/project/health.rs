@@ -0,0 +1,189 @@
+//! Checking that the projects of the project list are still usable, like after moving them or
+//! switching machines.
+//!
+//! Each project is checked to exist, to have a valid `Cargo.toml` and to depend on Bevy, and
+//! optionally to pass `cargo check`. The problems are reported with the [`ProjectError`] that
+//! opening the project would run into.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+};
+
+use bevy::log::info;
+
+use super::{CancellationToken, ProjectError, scan, toolchain};
+
+/// How many projects are checked at once by default.
+pub const DEFAULT_CHECK_PARALLELISM: usize = 4;
+
+/// A problem found with a project of the list.
+#[derive(Debug)]
+pub struct HealthIssue {
+    /// The project folder.
+    pub path: PathBuf,
+    /// What is wrong with the project.
+    pub error: ProjectError,
+}
+
+impl HealthIssue {
+    /// Whether the project isn't one anymore, and so is better removed from the project list than
+    /// fixed.
+    pub fn is_missing(&self) -> bool {
+        matches!(
+            self.error,
+            ProjectError::NotFound(_) | ProjectError::NotAProject { .. }
+        )
+    }
+}
+
+/// Check that the project exists, has a valid `Cargo.toml` and depends on Bevy.
+///
+/// With `cargo_check`, the project also has to pass `cargo check`, which can take minutes for a
+/// project that was never built.
+pub fn check_project(path: &Path, cargo_check: bool) -> Result<(), ProjectError> {
+    if !path.is_dir() {
+        return Err(ProjectError::NotFound(path.to_path_buf()));
+    }
+    let not_a_project = |reason: &str| ProjectError::NotAProject {
+        path: path.to_path_buf(),
+        reason: reason.to_string(),
+    };
+    if !path.join("Cargo.toml").is_file() {
+        return Err(not_a_project("it has no Cargo.toml"));
+    }
+    if scan::read_manifest(path).is_none() {
+        return Err(not_a_project("its Cargo.toml can't be parsed"));
+    }
+    if !scan::is_bevy_project(path) {
+        return Err(not_a_project("it doesn't depend on Bevy"));
+    }
+    if cargo_check {
+        run_cargo_check(path)?;
+    }
+    Ok(())
+}
+
+/// Run `cargo check` in the project, failing with its errors.
+fn run_cargo_check(path: &Path) -> Result<(), ProjectError> {
+    let mut command = Command::new("cargo");
+    if let Some(toolchain) = toolchain::use_project_toolchain(&mut command, path, &BTreeMap::new())
+    {
+        info!(
+            "Checking '{}' with the Rust toolchain {toolchain}",
+            path.display()
+        );
+    }
+    let output = command
+        .args(["check", "--quiet", "--message-format", "short"])
+        .current_dir(path)
+        .output()
+        .map_err(|error| ProjectError::Cargo(format!("Failed to run cargo check: {error}")))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        // Cargo reports the errors on stderr
+        Err(ProjectError::Cargo(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ))
+    }
+}
+
+/// Check the projects with [`check_project`], at most `parallelism` of them at once.
+///
+/// Returns the issues in the order of `paths`. Stops early with [`ProjectError::Cancelled`] once
+/// `cancel` is cancelled.
+pub fn check_projects(
+    paths: &[PathBuf],
+    parallelism: usize,
+    cargo_check: bool,
+    cancel: &CancellationToken,
+) -> Result<Vec<HealthIssue>, ProjectError> {
+    let next = AtomicUsize::new(0);
+    let issues = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..parallelism.clamp(1, paths.len().max(1)) {
+            scope.spawn(|| {
+                while !cancel.is_cancelled() {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(path) = paths.get(index) else {
+                        break;
+                    };
+                    if let Err(error) = check_project(path, cargo_check) {
+                        let issue = HealthIssue {
+                            path: path.clone(),
+                            error,
+                        };
+                        issues.lock().unwrap().push((index, issue));
+                    }
+                }
+            });
+        }
+    });
+    cancel.check()?;
+
+    let mut issues = issues.into_inner().unwrap();
+    issues.sort_by_key(|(index, _)| *index);
+    Ok(issues.into_iter().map(|(_, issue)| issue).collect())
+}
+
+/// A plain text report of the issues found among `checked` projects, one issue per line.
+pub fn health_report(issues: &[HealthIssue], checked: usize) -> String {
+    let mut report = format!(
+        "Checked {checked} projects, found {} issues\n",
+        issues.len()
+    );
+    for issue in issues {
+        // Cargo errors span several lines, indent them under the project
+        let error = issue.error.to_string().replace('\n', "\n    ");
+        let _ = writeln!(report, "{}: {error}", issue.path.display());
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn report_the_broken_projects() {
+        let root = std::env::temp_dir().join(format!("bevy_editor_health_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let manifest = |name: &str, manifest: &str| {
+            fs::create_dir_all(root.join(name)).unwrap();
+            fs::write(root.join(name).join("Cargo.toml"), manifest).unwrap();
+        };
+        manifest("game", "[dependencies]\nbevy = \"0.16\"\n");
+        manifest("tool", "[dependencies]\nserde = \"1\"\n");
+        manifest("broken", "[dependencies\n");
+        fs::create_dir_all(root.join("empty")).unwrap();
+
+        let paths = ["game", "missing", "tool", "broken", "empty"].map(|name| root.join(name));
+        let issues = check_projects(&paths, 2, false, &CancellationToken::new()).unwrap();
+        assert_eq!(
+            issues.iter().map(|issue| &issue.path).collect::<Vec<_>>(),
+            paths[1..].iter().collect::<Vec<_>>()
+        );
+        assert!(matches!(issues[0].error, ProjectError::NotFound(_)));
+        assert!(issues.iter().all(HealthIssue::is_missing));
+        assert!(health_report(&issues, paths.len()).starts_with("Checked 5 projects, found 4"));
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        assert!(matches!(
+            check_projects(&paths, 2, false, &cancel),
+            Err(ProjectError::Cancelled)
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
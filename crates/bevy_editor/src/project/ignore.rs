@@ -0,0 +1,182 @@
+//! Module to skip some paths when copying a folder, like the build outputs of a template.
+//!
+//! The patterns are read from an [`IGNORE_FILE`] at the root of the copied folder, one per line,
+//! in addition to the [`DEFAULT_IGNORED`] ones:
+//!
+//! ```text
+//! # Comments and empty lines are skipped
+//! *.blend1
+//! assets/raw/
+//! /notes.md
+//! ```
+//!
+//! A pattern ending with `/` only matches folders. A pattern containing another `/` is matched
+//! against the whole path relative to the root, the others against the name of the entry.
+//! `*` matches anything but `/`, `**` matches anything and `?` a single character.
+
+use std::{
+    io,
+    path::{Component, Path},
+};
+
+/// The name of the file listing the ignored patterns, at the root of the copied folder.
+pub const IGNORE_FILE: &str = ".bevyignore";
+
+/// The patterns always ignored: build outputs, version control and editor files.
+pub const DEFAULT_IGNORED: &[&str] = &[
+    "target/",
+    ".git/",
+    ".idea/",
+    ".vscode/",
+    ".DS_Store",
+    "Thumbs.db",
+    "*.swp",
+    "*~",
+];
+
+/// A set of patterns of paths to skip when copying a folder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IgnorePatterns(Vec<IgnorePattern>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct IgnorePattern {
+    /// The glob, without its leading and trailing `/`.
+    glob: String,
+    /// Whether the pattern only matches folders.
+    directory_only: bool,
+    /// Whether the pattern matches the whole relative path instead of the name of the entry.
+    anchored: bool,
+}
+
+impl IgnorePattern {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let directory_only = line.ends_with('/');
+        let line = line.trim_end_matches('/');
+        let anchored = line.contains('/');
+        let glob = line.trim_start_matches('/');
+        (!glob.is_empty()).then(|| Self {
+            glob: glob.to_string(),
+            directory_only,
+            anchored,
+        })
+    }
+}
+
+impl Default for IgnorePatterns {
+    fn default() -> Self {
+        Self::parse("")
+    }
+}
+
+impl IgnorePatterns {
+    /// The [`DEFAULT_IGNORED`] patterns and the ones of the content of an [`IGNORE_FILE`].
+    pub fn parse(content: &str) -> Self {
+        Self(
+            DEFAULT_IGNORED
+                .iter()
+                .copied()
+                .chain(content.lines())
+                .filter_map(IgnorePattern::parse)
+                .collect(),
+        )
+    }
+
+    /// The patterns of the [`IGNORE_FILE`] of the folder, only the default ones if it has none.
+    pub fn load(root: &Path) -> io::Result<Self> {
+        match std::fs::read_to_string(root.join(IGNORE_FILE)) {
+            Ok(content) => Ok(Self::parse(&content)),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Whether the entry at the `relative` path from the root is ignored.
+    pub fn is_ignored(&self, relative: &Path, is_dir: bool) -> bool {
+        let components: Vec<_> = relative
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(name.to_string_lossy()),
+                _ => None,
+            })
+            .collect();
+        let Some(name) = components.last() else {
+            return false;
+        };
+        let path = components.join("/");
+        self.0.iter().any(|pattern| {
+            (is_dir || !pattern.directory_only)
+                && if pattern.anchored {
+                    glob_matches(&pattern.glob, &path)
+                } else {
+                    glob_matches(&pattern.glob, name)
+                }
+        })
+    }
+}
+
+/// Whether the text matches the glob, see the [module documentation](self) for the syntax.
+fn glob_matches(glob: &str, text: &str) -> bool {
+    fn matches(glob: &[char], text: &[char]) -> bool {
+        match glob {
+            [] => text.is_empty(),
+            // `**/` matches any amount of whole folders, including none
+            ['*', '*', '/', rest @ ..] => {
+                matches(rest, text)
+                    || (0..text.len())
+                        .any(|slash| text[slash] == '/' && matches(rest, &text[slash + 1..]))
+            }
+            ['*', '*', rest @ ..] => {
+                (0..=text.len()).any(|skipped| matches(rest, &text[skipped..]))
+            }
+            ['*', rest @ ..] => (0..=text.len())
+                .take_while(|&skipped| skipped == 0 || text[skipped - 1] != '/')
+                .any(|skipped| matches(rest, &text[skipped..])),
+            ['?', rest @ ..] => text
+                .split_first()
+                .is_some_and(|(first, text)| *first != '/' && matches(rest, text)),
+            [literal, rest @ ..] => text
+                .split_first()
+                .is_some_and(|(first, text)| first == literal && matches(rest, text)),
+        }
+    }
+    let glob: Vec<char> = glob.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    matches(&glob, &text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_globs() {
+        assert!(glob_matches("*.swp", "main.rs.swp"));
+        assert!(!glob_matches("*.swp", "src/main.swp"));
+        assert!(glob_matches("assets/*.png", "assets/icon.png"));
+        assert!(!glob_matches("assets/*.png", "assets/ui/icon.png"));
+        assert!(glob_matches("assets/**/*.png", "assets/ui/icon.png"));
+        assert!(glob_matches("assets/**/*.png", "assets/icon.png"));
+        assert!(glob_matches("**/raw", "assets/raw"));
+        assert!(glob_matches("file?.txt", "file1.txt"));
+        assert!(!glob_matches("file?.txt", "file10.txt"));
+    }
+
+    #[test]
+    fn ignore_paths() {
+        let ignore = IgnorePatterns::parse("# Raw files\n*.blend1\n/notes.md\nassets/raw/\n\n");
+        assert!(ignore.is_ignored(Path::new("target"), true));
+        assert!(!ignore.is_ignored(Path::new("target"), false));
+        assert!(ignore.is_ignored(Path::new("crates/game/target"), true));
+        assert!(ignore.is_ignored(Path::new("assets/level.blend1"), false));
+        assert!(ignore.is_ignored(Path::new("notes.md"), false));
+        assert!(!ignore.is_ignored(Path::new("docs/notes.md"), false));
+        assert!(ignore.is_ignored(Path::new("assets/raw"), true));
+        assert!(!ignore.is_ignored(Path::new("assets/raw"), false));
+        assert!(!ignore.is_ignored(Path::new("src/main.rs"), false));
+        assert!(!ignore.is_ignored(Path::new(""), true));
+    }
+}
@@ -2,19 +2,124 @@
 
 use bevy::log::{error, info, warn};
 use serde::{Deserialize, Serialize};
-use std::{path::PathBuf, time::SystemTime};
-use templates::{Templates, copy_template};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs, io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+use templates::{
+    PostCreateHook, Templates, copy_template, run_post_create, substitute_placeholders,
+};
 
+pub use cancel::CancellationToken;
+pub use error::{ProjectError, validate_env_var_name, validate_project_name};
+pub use retry::RetryPolicy;
+pub use store::{FileProjectStore, MemoryProjectStore, ProjectStore};
+
+pub mod assets;
+pub mod bevy_version;
 mod cache;
+pub mod cancel;
+pub mod clean;
+mod error;
+pub mod export;
+pub mod health;
+pub mod ignore;
+pub mod open_ack;
+pub mod report;
+pub mod retry;
+pub mod scan;
+pub mod store;
 pub mod templates;
+pub mod thumbnail;
+pub mod toolchain;
+pub mod workspace;
 
 /// Basic information about a project.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectInfo {
     /// The path to the root of the project.
+    ///
+    /// Always absolute once loaded, see [`ProjectLocation`] for how it is stored.
     pub path: PathBuf,
     /// The last time the project was opened.
     pub last_opened: SystemTime,
+    /// Whether the path is stored relative to the [`projects_root`].
+    #[serde(default)]
+    pub location: ProjectLocation,
+    /// Whether the project is pinned to the quick access of the launcher.
+    #[serde(default)]
+    pub pinned: bool,
+    /// The environment variables set when running the project, like `RUST_LOG`.
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    /// The package name of the member run by default when the project is a cargo workspace.
+    ///
+    /// `None` lets cargo pick it, which fails when the workspace has several binaries and no
+    /// `default-members`. See [`workspace`] for finding the members.
+    #[serde(default)]
+    pub default_member: Option<String>,
+    /// How many times the project was opened from the launcher.
+    #[serde(default)]
+    pub open_count: u32,
+    /// The icon shown for the project in the launcher, an emoji like `🚀` or the path of an image.
+    ///
+    /// `None` shows a tile with the first letter of the name. See [`ProjectIcon`] for how it is
+    /// interpreted.
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// The tags the project is labeled with in the launcher, like `prototype` or `jam`.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The collection the project is grouped in, `None` when it isn't in any.
+    #[serde(default)]
+    pub collection: Option<String>,
+}
+
+/// How the [`icon`](ProjectInfo::icon) of a project is displayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProjectIcon {
+    /// A text drawn as is, like an emoji.
+    Text(String),
+    /// An image file, either absolute or relative to the project folder.
+    Image(PathBuf),
+}
+
+/// The image extensions an [`icon`](ProjectInfo::icon) is read as an image path with.
+const ICON_IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "bmp", "webp"];
+
+impl ProjectIcon {
+    /// Interpret the icon of a project: paths with an image extension are images, relative to the
+    /// project folder, anything else is text. Empty icons are `None`.
+    pub fn parse(icon: &str, project: &Path) -> Option<Self> {
+        let icon = icon.trim();
+        if icon.is_empty() {
+            return None;
+        }
+        let path = Path::new(icon);
+        let is_image = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| {
+                ICON_IMAGE_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str())
+            });
+        Some(if is_image {
+            ProjectIcon::Image(project.join(path))
+        } else {
+            ProjectIcon::Text(icon.to_string())
+        })
+    }
+}
+
+/// How the path of a [`ProjectInfo`] is stored in the project list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProjectLocation {
+    /// The path is stored as is.
+    #[default]
+    Absolute,
+    /// The path is stored relative to the [`projects_root`], so that it follows the root when it moves.
+    RootRelative,
 }
 
 impl PartialEq for ProjectInfo {
@@ -24,6 +129,34 @@ impl PartialEq for ProjectInfo {
 }
 
 impl ProjectInfo {
+    /// A project at the given path, never opened yet and with the default settings.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            last_opened: SystemTime::UNIX_EPOCH,
+            location: ProjectLocation::Absolute,
+            pinned: false,
+            env: BTreeMap::new(),
+            default_member: None,
+            open_count: 0,
+            icon: None,
+            tags: Vec::new(),
+            collection: None,
+        }
+    }
+
+    /// Label the project with the tag, returning whether it wasn't already.
+    ///
+    /// The tag is trimmed, and empty tags are never added.
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        let tag = tag.trim();
+        if tag.is_empty() || self.tags.iter().any(|listed| listed == tag) {
+            return false;
+        }
+        self.tags.push(tag.to_string());
+        true
+    }
+
     /// Get the name of the project.
     pub fn name(&self) -> Option<String> {
         Some(self.path.file_name()?.to_str()?.to_string())
@@ -35,41 +168,354 @@ impl ProjectInfo {
 pub async fn create_new_project(
     template: Templates,
     path: PathBuf,
-) -> std::io::Result<ProjectInfo> {
-    let info = ProjectInfo {
+) -> Result<ProjectInfo, ProjectError> {
+    create_new_project_with_variables(template, path, HashMap::new()).await
+}
+
+/// Create a new project with the given name and path, substituting the template placeholders.
+///
+/// `variables` holds the user-provided values of the variables declared by the template manifest,
+/// see [`TemplateManifest::resolve_variables`](templates::TemplateManifest::resolve_variables).
+pub async fn create_new_project_with_variables(
+    template: Templates,
+    path: PathBuf,
+    variables: HashMap<String, String>,
+) -> Result<ProjectInfo, ProjectError> {
+    create_new_project_with_progress(
+        template,
         path,
-        last_opened: SystemTime::now(),
-    };
+        variables,
+        false,
+        &RetryPolicy::default(),
+        &CancellationToken::new(),
+        &FileProjectStore,
+        |_| {},
+    )
+    .await
+}
 
-    if let Err(error) = copy_template(template, info.path.as_path()).await {
-        error!("Failed to create new project");
-        return Err(error);
+/// A step of the creation of a project, reported by [`create_new_project_with_progress`]
+/// through [`ProgressEvent::Phase`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreationPhase {
+    /// The template files are being copied to the project folder.
+    CopyingTemplate,
+    /// The template placeholders are being replaced in the project files.
+    SubstitutingPlaceholders,
+    /// The [`PostCreateHook`] of the template is running in the project folder.
+    ///
+    /// Only reported when the template declares one and it is allowed to run.
+    RunningPostCreate,
+    /// The project is being added to the project list.
+    RegisteringProject,
+}
+
+impl CreationPhase {
+    /// The number of phases of the creation of a project.
+    pub const COUNT: usize = 4;
+
+    /// The position of the phase in the creation, starting at 0.
+    pub fn index(self) -> usize {
+        match self {
+            CreationPhase::CopyingTemplate => 0,
+            CreationPhase::SubstitutingPlaceholders => 1,
+            CreationPhase::RunningPostCreate => 2,
+            CreationPhase::RegisteringProject => 3,
+        }
     }
 
-    let mut projects = get_local_projects();
+    /// The fraction of the creation done when the phase starts, between 0 and 1.
+    pub fn progress(self) -> f32 {
+        self.index() as f32 / Self::COUNT as f32
+    }
+
+    /// A short description of the phase, meant to be displayed.
+    pub fn description(self) -> &'static str {
+        match self {
+            CreationPhase::CopyingTemplate => "Copying template",
+            CreationPhase::SubstitutingPlaceholders => "Filling in template values",
+            CreationPhase::RunningPostCreate => "Running the template setup",
+            CreationPhase::RegisteringProject => "Registering project",
+        }
+    }
+}
+
+/// Something reported by [`create_new_project_with_progress`] while a project is created, in order.
+///
+/// A successful creation reports its [`CreationPhase`]s, the files and output of the phases as they
+/// are handled and [`ProgressEvent::Finished`] last. A failed one stops reporting at the failing
+/// phase.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgressEvent {
+    /// A new phase of the creation starts.
+    Phase(CreationPhase),
+    /// Entries of the template were skipped as they are ignored, see [`ignore`].
+    Skipped(usize),
+    /// The placeholders of the template were replaced in the file.
+    Substituted(PathBuf),
+    /// A line printed by the [`PostCreateHook`], on its standard output or error.
+    Output(String),
+    /// The [`PostCreateHook`] exited, with its exit code unless it was stopped by a signal.
+    ///
+    /// The creation fails after it if the code isn't 0.
+    PostCreateExited(Option<i32>),
+    /// An asset loaded by the project sources or declared by the template isn't in the
+    /// [`assets`](assets::ASSETS_FOLDER) folder of the project, the project would fail to load it.
+    ///
+    /// Reported once the files are written, the creation doesn't fail.
+    MissingAsset(String),
+    /// A network step, like cloning a Git template, failed transiently and is retried.
+    Retrying {
+        /// The retry about to be made, starting at 1.
+        retry: u32,
+        /// How many retries the [`RetryPolicy`] allows.
+        retries: u32,
+        /// Why the previous attempt failed.
+        error: String,
+    },
+    /// The project was created and added to the project list.
+    Finished(PathBuf),
+}
+
+/// Create a new project like [`create_new_project_with_variables`],
+/// reporting every step of the creation to `progress` as it happens.
+///
+/// Fails with [`ProjectError::NameInvalid`] if the folder name can't be used on every platform,
+/// and with [`ProjectError::DirectoryNotEmpty`] if the folder already holds files.
+///
+/// The [`PostCreateHook`] of the template, which runs an arbitrary command, only runs when
+/// `run_post_create` is set, once the user accepted it. Otherwise the creation skips it. It fails
+/// the creation with [`ProjectError::PostCreate`] if it exits with an error or outlives its timeout.
+///
+/// The network steps, like cloning a Git template, are retried as set by the `retry` policy when
+/// they fail transiently, each retry reported with [`ProgressEvent::Retrying`].
+///
+/// Cancelling the token stops the creation with [`ProjectError::Cancelled`] at the next step,
+/// killing the post-create command, until the [`CreationPhase::RegisteringProject`] phase. A
+/// creation failing or cancelled after it started writing files removes them, leaving the folder
+/// as it was.
+///
+/// The created project is added to the project list of the `store`.
+#[expect(clippy::too_many_arguments)]
+pub async fn create_new_project_with_progress(
+    template: Templates,
+    path: PathBuf,
+    variables: HashMap<String, String>,
+    run_post_create: bool,
+    retry: &RetryPolicy,
+    cancel: &CancellationToken,
+    store: &dyn ProjectStore,
+    progress: impl Fn(ProgressEvent) + Send,
+) -> Result<ProjectInfo, ProjectError> {
+    let info = create_project_files(
+        template,
+        path,
+        variables,
+        run_post_create,
+        retry,
+        cancel,
+        &progress,
+    )
+    .await?;
+
+    progress(ProgressEvent::Phase(CreationPhase::RegisteringProject));
+    let mut projects = store.load_projects();
     projects.push(info.clone());
-    set_project_list(projects);
+    store.set_projects(projects);
 
+    progress(ProgressEvent::Finished(info.path.clone()));
     Ok(info)
 }
 
-/// Get all projects that have been opened in the editor.
-pub fn get_local_projects() -> Vec<ProjectInfo> {
-    match cache::load_projects() {
-        Ok(projects) => projects,
+/// Copy the template to the project folder, fill in its placeholders and run its post-create
+/// command if allowed, without registering it.
+///
+/// The files written are removed if the creation fails or is cancelled.
+async fn create_project_files(
+    template: Templates,
+    path: PathBuf,
+    variables: HashMap<String, String>,
+    run_post_create: bool,
+    retry: &RetryPolicy,
+    cancel: &CancellationToken,
+    progress: &(impl Fn(ProgressEvent) + Send),
+) -> Result<ProjectInfo, ProjectError> {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    validate_project_name(&name)?;
+    if !is_empty_or_missing(&path) {
+        return Err(ProjectError::DirectoryNotEmpty(path));
+    }
+
+    cancel.check()?;
+
+    let manifest = template.manifest()?;
+    let variables = manifest
+        .resolve_variables(&path, &variables)
+        .map_err(ProjectError::Template)?;
+    let post_create = manifest.post_create.as_ref().filter(|hook| {
+        if !run_post_create {
+            info!(
+                "Skipping the post-create command of the template: {}",
+                hook.command_line(&variables)
+            );
+        }
+        run_post_create
+    });
+
+    let info = ProjectInfo {
+        last_opened: SystemTime::now(),
+        ..ProjectInfo::new(path)
+    };
+
+    let existed = info.path.exists();
+    if let Err(error) = write_project_files(
+        &template,
+        &info.path,
+        &variables,
+        post_create,
+        retry,
+        cancel,
+        progress,
+    )
+    .await
+    {
+        remove_partial_project(&info.path, existed);
+        return Err(error);
+    }
+    match assets::missing_assets(&info.path, &manifest.assets) {
+        Ok(missing) => {
+            for asset in missing {
+                warn!("The project doesn't have the asset '{asset}' it loads");
+                progress(ProgressEvent::MissingAsset(asset));
+            }
+        }
+        Err(error) => warn!("Couldn't check the assets of the project: {error}"),
+    }
+    Ok(info)
+}
+
+/// The steps of [`create_project_files`] writing to the project folder, checking `cancel` between
+/// them.
+async fn write_project_files(
+    template: &Templates,
+    path: &Path,
+    variables: &HashMap<String, String>,
+    post_create: Option<&PostCreateHook>,
+    retry: &RetryPolicy,
+    cancel: &CancellationToken,
+    progress: &(impl Fn(ProgressEvent) + Send),
+) -> Result<(), ProjectError> {
+    progress(ProgressEvent::Phase(CreationPhase::CopyingTemplate));
+    let on_retry = |attempt: retry::Retry| {
+        progress(ProgressEvent::Retrying {
+            retry: attempt.retry,
+            retries: attempt.retries,
+            error: attempt.error.to_string(),
+        });
+    };
+    match copy_template(template, path, retry, cancel, on_retry).await {
+        Ok(0) => {}
+        Ok(skipped) => progress(ProgressEvent::Skipped(skipped)),
+        Err(_) if cancel.is_cancelled() => return Err(ProjectError::Cancelled),
         Err(error) => {
-            warn!("Failed to load projects from cache file: {:?}", error);
-            Vec::new()
+            error!("Failed to create new project: {:?}", error);
+            return Err(ProjectError::from_io(path, error));
+        }
+    }
+    cancel.check()?;
+
+    progress(ProgressEvent::Phase(
+        CreationPhase::SubstitutingPlaceholders,
+    ));
+    let mut substituted = substitute_placeholders(path, variables)
+        .map_err(|error| ProjectError::from_io(path, error))?;
+    // The walk order depends on the platform, the files are reported sorted instead
+    substituted.sort();
+    for file in substituted {
+        info!("Substituted template placeholders in '{}'", file.display());
+        progress(ProgressEvent::Substituted(file));
+    }
+    cancel.check()?;
+
+    let Some(hook) = post_create else {
+        return Ok(());
+    };
+    progress(ProgressEvent::Phase(CreationPhase::RunningPostCreate));
+    info!(
+        "Running the post-create command of the template: {}",
+        hook.command_line(variables)
+    );
+    let status = run_post_create(hook, path, variables, cancel, &|line| {
+        info!("{line}");
+        progress(ProgressEvent::Output(line));
+    })?;
+    progress(ProgressEvent::PostCreateExited(status.code()));
+    if !status.success() {
+        return Err(ProjectError::PostCreate(format!(
+            "'{}' exited with {status}",
+            hook.command_line(variables)
+        )));
+    }
+    cancel.check()
+}
+
+/// Remove what a failed creation wrote to the project folder, keeping the folder if it existed.
+fn remove_partial_project(path: &Path, existed: bool) {
+    let result = fs::remove_dir_all(path).and_then(|()| {
+        if existed {
+            fs::create_dir(path)
+        } else {
+            Ok(())
         }
+    });
+    if let Err(error) = result
+        && error.kind() != io::ErrorKind::NotFound
+    {
+        warn!(
+            "Couldn't remove the partially created project '{}': {error}",
+            path.display()
+        );
+    }
+}
+
+/// Get the folder where the Bevy Editor stores its data, such as the project list.
+pub fn data_dir() -> PathBuf {
+    cache::get_cache_folder()
+}
+
+/// Get the folder the project paths are stored relative to, if one has been configured.
+pub fn projects_root() -> Option<PathBuf> {
+    cache::load_root()
+}
+
+/// Set the folder the project paths are stored relative to.
+///
+/// Projects already stored relative to the previous root now resolve under the new one,
+/// which is how a moved projects folder is followed.
+/// Absolute paths under the new root are converted the next time the list is loaded.
+pub fn set_projects_root(root: PathBuf) {
+    if let Err(error) = cache::save_root(root) {
+        error!("Unable to save the projects root: {:?}", error);
     }
 }
 
+/// Get all projects that have been opened in the editor, from the [`FileProjectStore`].
+pub fn get_local_projects() -> Vec<ProjectInfo> {
+    FileProjectStore.load_projects()
+}
+
 /// Update the current project info or create new ones if doesn't exist.
 pub fn update_project_info() {
     let mut projects = get_local_projects();
     let current_dir = std::env::current_dir().unwrap();
 
-    match projects.iter_mut().find(|p| p.path == current_dir) {
+    match projects
+        .iter_mut()
+        .find(|p| is_same_project(&p.path, &current_dir))
+    {
         Some(project) => {
             // Update info
             project.last_opened = SystemTime::now();
@@ -77,8 +523,8 @@ pub fn update_project_info() {
         None => {
             // Create new info
             let project = ProjectInfo {
-                path: current_dir.clone(),
                 last_opened: SystemTime::now(),
+                ..ProjectInfo::new(current_dir.clone())
             };
             projects.push(project);
         }
@@ -89,48 +535,790 @@ pub fn update_project_info() {
     }
 }
 
-/// Set the project list to the given list of projects.
+/// Set the project list of the [`FileProjectStore`] to the given list of projects.
+///
+/// The duplicated projects are merged before saving, see [`deduplicate_projects`].
 pub fn set_project_list(projects: Vec<ProjectInfo>) {
-    if let Err(error) = cache::save_projects(projects) {
-        error!("Unable to save project list: {:?}", error);
-    }
+    FileProjectStore.set_projects(projects);
+}
+
+/// Like [`set_project_list`], but returning the error instead of logging it.
+pub fn save_project_list(projects: Vec<ProjectInfo>) -> io::Result<()> {
+    FileProjectStore.save_projects(projects)
 }
 
+/// The environment variable holding the file the editor should open, set by [`run_project_at`].
+///
+/// See [`open_target`] for how the editor interprets it.
+pub const OPEN_TARGET_VAR: &str = "BEVY_EDITOR_OPEN_TARGET";
+
 /// Run a project in editor mode.
-pub fn run_project(project: &ProjectInfo) -> std::io::Result<()> {
+pub fn run_project(project: &ProjectInfo) -> Result<(), ProjectError> {
+    run_project_at(project, None).map(|_| ())
+}
+
+/// Run a project in editor mode, asking the editor to open the `target` file once started.
+///
+/// The [`env`](ProjectInfo::env) of the project is applied to the cargo process, and the
+/// [`default_member`](ProjectInfo::default_member) is the package run in a workspace.
+/// The target is relative to the project root, like `src/main.rs` or `assets/level.scn.ron`,
+/// and is passed to the editor through the [`OPEN_TARGET_VAR`] environment variable.
+/// The editor is also asked to capture the [`thumbnail`] of the project, and to acknowledge the
+/// opening once started, see [`open_ack`].
+///
+/// Returns the cargo process, which exits once the project is closed or when it fails to build.
+pub fn run_project_at(
+    project: &ProjectInfo,
+    target: Option<&Path>,
+) -> Result<std::process::Child, ProjectError> {
     // Make sure the project folder exist
-    if !project.path.exists() {
-        return std::io::Result::Err(std::io::Error::new(
-            std::io::ErrorKind::NotFound,
-            "Project root folder not found",
-        ));
+    if !project_exists(&project.path) {
+        return Err(ProjectError::NotFound(project.path.clone()));
     }
 
     // Make sure it has the minimum file to be a valid project
     let cargo_toml = project.path.join("Cargo.toml");
-    let src_folder = project.path.join("src");
-    let main_rs = src_folder.join("main.rs");
-    if !cargo_toml.exists() || !src_folder.exists() || !main_rs.exists() {
-        return std::io::Result::Err(std::io::Error::new(
-            std::io::ErrorKind::InvalidData,
-            "Project isn't a valid one of the following missing: Cargo.toml, src folder or main.rs file",
-        ));
+    if !cargo_toml.exists() {
+        return Err(ProjectError::NotAProject {
+            path: project.path.clone(),
+            reason: "missing the Cargo.toml file".to_string(),
+        });
     }
+    // The binaries of a workspace are in its members, a virtual manifest has no sources at all
+    let member = match workspace::workspace_members(&project.path) {
+        Some(members) => match &project.default_member {
+            Some(name) if !members.iter().any(|member| member.name == *name) => {
+                return Err(ProjectError::MemberNotFound(name.clone()));
+            }
+            default_member => default_member.as_deref(),
+        },
+        None => {
+            let src_folder = project.path.join("src");
+            if !src_folder.exists() || !src_folder.join("main.rs").exists() {
+                return Err(ProjectError::NotAProject {
+                    path: project.path.clone(),
+                    reason: "missing the src folder or the main.rs file".to_string(),
+                });
+            }
+            None
+        }
+    };
+    let member_args = match member {
+        Some(member) => vec!["-p", member],
+        None => Vec::new(),
+    };
 
     #[cfg(target_os = "windows")]
-    std::process::Command::new("cmd")
-        .current_dir(&project.path)
-        .args(["/C", "cargo", "run"])
-        .spawn()
-        .map_err(|error| std::io::Error::other(format!("Failed to run project: {error}")))?;
+    let mut command = std::process::Command::new("cmd");
+    #[cfg(target_os = "windows")]
+    command.args(["/C", "cargo", "run"]).args(&member_args);
 
+    // The member is given to cargo as an argument, the shell never interprets it
+    #[cfg(not(target_os = "windows"))]
+    let mut command = std::process::Command::new("sh");
     #[cfg(not(target_os = "windows"))]
-    std::process::Command::new("sh")
+    command
+        .args(["-c", "cargo run \"$@\"", "sh"])
+        .args(&member_args);
+
+    if let Some(toolchain) =
+        toolchain::use_project_toolchain(&mut command, &project.path, &project.env)
+    {
+        info!("Running the project with the Rust toolchain {toolchain}");
+    }
+    command.envs(&project.env);
+    // An inherited target would be opened by a project that wasn't asked to
+    match target {
+        Some(target) => command.env(OPEN_TARGET_VAR, target),
+        None => command.env_remove(OPEN_TARGET_VAR),
+    };
+    command.env(
+        thumbnail::THUMBNAIL_REQUEST_VAR,
+        thumbnail::thumbnail_path(&project.path),
+    );
+    // The acknowledgment of a previous run would acknowledge this one
+    let ack = open_ack::open_ack_path(&project.path);
+    if let Err(error) = fs::remove_file(&ack)
+        && error.kind() != io::ErrorKind::NotFound
+    {
+        warn!("Couldn't remove '{}': {error}", ack.display());
+    }
+    command.env(open_ack::OPEN_ACK_VAR, ack);
+    let child = command
         .current_dir(&project.path)
-        .args(["-c", "cargo run"])
         .spawn()
-        .map_err(|error| std::io::Error::other(format!("Failed to run project: {error}")))?;
+        .map_err(|error| ProjectError::Cargo(format!("Failed to run project: {error}")))?;
 
     info!("Project started successfully");
+    Ok(child)
+}
+
+/// The file the launcher asked the editor to open, from the [`OPEN_TARGET_VAR`] environment variable.
+///
+/// The path is resolved against the project root, the working directory of the editor when run by
+/// the launcher. The editor should open the scenes in the scene tree, and reveal the other files,
+/// like sources, in the asset browser. A target that doesn't exist should be reported and ignored.
+pub fn open_target() -> Option<PathBuf> {
+    let target = std::env::var_os(OPEN_TARGET_VAR).filter(|target| !target.is_empty())?;
+    let target = PathBuf::from(target);
+    match std::env::current_dir() {
+        Ok(root) if target.is_relative() => Some(root.join(target)),
+        _ => Some(target),
+    }
+}
+
+/// Rename the folder of a project, updating the project list of the `store`.
+///
+/// Returns the renamed project.
+pub fn rename_project(
+    project: &ProjectInfo,
+    new_name: &str,
+    store: &dyn ProjectStore,
+) -> Result<ProjectInfo, ProjectError> {
+    validate_project_name(new_name)?;
+    if !project_exists(&project.path) {
+        return Err(ProjectError::NotFound(project.path.clone()));
+    }
+    let new_path = project.path.with_file_name(new_name);
+    if !is_empty_or_missing(&new_path) {
+        return Err(ProjectError::DirectoryNotEmpty(new_path));
+    }
+    if new_path.exists() {
+        // Renaming over an empty folder isn't supported everywhere
+        fs::remove_dir(&new_path).map_err(|error| ProjectError::from_io(&new_path, error))?;
+    }
+    fs::rename(&project.path, &new_path)
+        .map_err(|error| ProjectError::from_io(&project.path, error))?;
+
+    let renamed = ProjectInfo {
+        path: new_path,
+        ..project.clone()
+    };
+    let mut projects = store.load_projects();
+    for listed in projects.iter_mut().filter(|listed| **listed == *project) {
+        listed.path.clone_from(&renamed.path);
+    }
+    store.set_projects(projects);
+    info!(
+        "Renamed '{}' to '{}'",
+        project.path.display(),
+        renamed.path.display()
+    );
+    Ok(renamed)
+}
+
+/// Delete the folder of a project from the disk and remove it from the project list of the
+/// `store`.
+///
+/// Folders that don't look like a project are never deleted, failing with [`ProjectError::NotAProject`].
+pub fn delete_project(project: &ProjectInfo, store: &dyn ProjectStore) -> Result<(), ProjectError> {
+    delete_project_folder(project)?;
+
+    let mut projects = store.load_projects();
+    projects.retain(|listed| listed != project);
+    store.set_projects(projects);
+    Ok(())
+}
+
+/// Delete the folders of several projects like [`delete_project`], saving the project list once.
+///
+/// Returns the result of every deletion, in order. The projects that failed stay in the list.
+pub fn delete_projects(
+    projects: &[ProjectInfo],
+    store: &dyn ProjectStore,
+) -> Vec<Result<(), ProjectError>> {
+    let results: Vec<_> = projects.iter().map(delete_project_folder).collect();
+
+    let mut listed = store.load_projects();
+    listed.retain(|listed| {
+        !projects
+            .iter()
+            .zip(&results)
+            .any(|(project, result)| result.is_ok() && project == listed)
+    });
+    store.set_projects(listed);
+    results
+}
+
+/// Delete the folder of a project, if it looks like one.
+fn delete_project_folder(project: &ProjectInfo) -> Result<(), ProjectError> {
+    if !project_exists(&project.path) {
+        return Err(ProjectError::NotFound(project.path.clone()));
+    }
+    if !project.path.join("Cargo.toml").exists() {
+        return Err(ProjectError::NotAProject {
+            path: project.path.clone(),
+            reason: "no Cargo.toml found".to_string(),
+        });
+    }
+    fs::remove_dir_all(&project.path)
+        .map_err(|error| ProjectError::from_io(&project.path, error))?;
+    info!("Deleted '{}'", project.path.display());
     Ok(())
 }
+
+/// Add an existing Bevy project to the project list of the `store`, returning its info.
+///
+/// Projects already in the list are returned as they are.
+pub fn import_project(path: &Path, store: &dyn ProjectStore) -> Result<ProjectInfo, ProjectError> {
+    let mut projects = store.load_projects();
+    let listed = projects.len();
+    let project = add_imported_project(&mut projects, path)?;
+    if projects.len() > listed {
+        store.set_projects(projects);
+    }
+    Ok(project)
+}
+
+/// Add several projects like [`import_project`], saving the project list once.
+///
+/// Returns the result of every import, in order. A project that can't be added doesn't prevent
+/// adding the others.
+pub fn import_projects(
+    paths: &[PathBuf],
+    store: &dyn ProjectStore,
+) -> Vec<Result<ProjectInfo, ProjectError>> {
+    let mut projects = store.load_projects();
+    let listed = projects.len();
+    let results = paths
+        .iter()
+        .map(|path| add_imported_project(&mut projects, path))
+        .collect();
+    if projects.len() > listed {
+        store.set_projects(projects);
+    }
+    results
+}
+
+/// Add the project in the folder to `projects` unless it is already listed, returning its info.
+fn add_imported_project(
+    projects: &mut Vec<ProjectInfo>,
+    path: &Path,
+) -> Result<ProjectInfo, ProjectError> {
+    if !project_exists(path) {
+        return Err(ProjectError::NotFound(path.to_path_buf()));
+    }
+    if !scan::is_bevy_project(path) {
+        return Err(ProjectError::NotAProject {
+            path: path.to_path_buf(),
+            reason: "no Cargo.toml depending on bevy found".to_string(),
+        });
+    }
+
+    if let Some(listed) = projects
+        .iter()
+        .find(|listed| is_same_project(&listed.path, path))
+    {
+        return Ok(listed.clone());
+    }
+    // Never opened from the editor yet
+    let project = ProjectInfo::new(path);
+    projects.push(project.clone());
+    Ok(project)
+}
+
+/// Merge the projects pointing to the same folder, keeping the order of their first occurrence.
+///
+/// The folders are compared by their [`project_identity`], so `./game`, its absolute form and a
+/// symlink to it are the same project. The path of the first occurrence is kept, made absolute,
+/// as it is the one the user knows the project by.
+/// A merged project is pinned if any of its duplicates is, and keeps the latest opening time and
+/// the sum of the open counts.
+/// The environment variables of the first occurrence win over the ones of its duplicates, and so
+/// do its default workspace member, its icon and its collection if it has them. The tags of all
+/// the duplicates are kept.
+pub fn deduplicate_projects(projects: Vec<ProjectInfo>) -> Vec<ProjectInfo> {
+    let mut unique: Vec<(PathBuf, ProjectInfo)> = Vec::with_capacity(projects.len());
+    for mut project in projects {
+        let identity = project_identity(&project.path);
+        match unique
+            .iter_mut()
+            .find(|(existing, _)| *existing == identity)
+        {
+            Some((_, existing)) => {
+                existing.pinned |= project.pinned;
+                existing.last_opened = existing.last_opened.max(project.last_opened);
+                existing.open_count = existing.open_count.saturating_add(project.open_count);
+                for (key, value) in project.env {
+                    existing.env.entry(key).or_insert(value);
+                }
+                if existing.default_member.is_none() {
+                    existing.default_member = project.default_member;
+                }
+                if existing.icon.is_none() {
+                    existing.icon = project.icon;
+                }
+                for tag in &project.tags {
+                    existing.add_tag(tag);
+                }
+                if existing.collection.is_none() {
+                    existing.collection = project.collection;
+                }
+            }
+            None => {
+                if let Ok(absolute) = std::path::absolute(&project.path) {
+                    project.path = absolute;
+                }
+                unique.push((identity, project));
+            }
+        }
+    }
+    unique.into_iter().map(|(_, project)| project).collect()
+}
+
+/// The path identifying the folder, to compare project paths.
+///
+/// The symlinks are resolved, so every path to a folder has the same identity. The paths that
+/// can't be resolved, like missing folders and broken symlinks, are only made absolute.
+/// This should never be displayed, the user-facing path is the one of the [`ProjectInfo`].
+pub fn project_identity(path: &Path) -> PathBuf {
+    let canonical = fs::canonicalize(path)
+        .or_else(|_| std::path::absolute(path))
+        .unwrap_or_else(|_| path.to_path_buf());
+    // Windows canonicalizes to verbatim paths, which most tools don't understand
+    #[cfg(target_os = "windows")]
+    if let Some(stripped) = canonical
+        .to_str()
+        .and_then(|path| path.strip_prefix(r"\\?\"))
+    {
+        return PathBuf::from(stripped);
+    }
+    canonical
+}
+
+/// Whether both paths lead to the same folder, following the symlinks.
+pub fn is_same_project(path: &Path, other: &Path) -> bool {
+    path == other || project_identity(path) == project_identity(other)
+}
+
+/// Whether the project folder exists, a broken symlink being a missing project.
+pub fn project_exists(path: &Path) -> bool {
+    // Follows the symlinks, unlike `symlink_metadata`
+    path.is_dir()
+}
+
+/// The closest folder above `path` holding a `Cargo.toml`, if any.
+///
+/// Creating a project inside another one makes it part of the other's package or workspace,
+/// which is rarely intended. `path` itself isn't checked, it usually doesn't exist yet.
+pub fn enclosing_project(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.join("Cargo.toml").is_file())
+        .map(Path::to_path_buf)
+}
+
+/// Whether the folder doesn't exist or has no entries, so a project can be created or moved there.
+pub fn is_empty_or_missing(path: &Path) -> bool {
+    match fs::read_dir(path) {
+        Ok(mut entries) => entries.next().is_none(),
+        Err(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::tasks::block_on;
+
+    use super::*;
+
+    fn test_folder(name: &str) -> PathBuf {
+        let folder = std::env::temp_dir()
+            .join(format!("bevy_editor_project_{}", std::process::id()))
+            .join(name);
+        let _ = fs::remove_dir_all(&folder);
+        fs::create_dir_all(&folder).unwrap();
+        folder
+    }
+
+    #[test]
+    fn create_reports_invalid_locations() {
+        let folder = test_folder("create");
+        let result = block_on(create_new_project(
+            Templates::Blank,
+            folder.join("bad:name"),
+        ));
+        assert!(matches!(result, Err(ProjectError::NameInvalid(_))));
+
+        fs::write(folder.join("file.txt"), "taken").unwrap();
+        let result = block_on(create_new_project(Templates::Blank, folder.clone()));
+        assert!(matches!(result, Err(ProjectError::DirectoryNotEmpty(_))));
+    }
+
+    #[test]
+    fn report_the_creation_progress() {
+        let folder = test_folder("progress");
+        let template = folder.join("template");
+        fs::create_dir_all(template.join("src")).unwrap();
+        fs::write(template.join("Cargo.toml"), "name = \"{{package_name}}\"\n").unwrap();
+        fs::write(template.join("src/main.rs"), "fn main() {}\n").unwrap();
+        fs::write(template.join("README.md"), "# {{project_name}}\n").unwrap();
+        fs::write(template.join("notes.txt"), "not copied").unwrap();
+        fs::write(template.join(".bevyignore"), "notes.txt\n").unwrap();
+        fs::create_dir_all(template.join("target/debug")).unwrap();
+
+        let events = std::sync::Mutex::new(Vec::new());
+        let report = |event| events.lock().unwrap().push(event);
+        let created = folder.join("My Game");
+        block_on(create_project_files(
+            Templates::Custom(template),
+            created.clone(),
+            HashMap::new(),
+            false,
+            &RetryPolicy::NEVER,
+            &CancellationToken::new(),
+            &report,
+        ))
+        .unwrap();
+        assert_eq!(
+            events.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            vec![
+                ProgressEvent::Phase(CreationPhase::CopyingTemplate),
+                ProgressEvent::Skipped(2),
+                ProgressEvent::Phase(CreationPhase::SubstitutingPlaceholders),
+                ProgressEvent::Substituted(created.join("Cargo.toml")),
+                ProgressEvent::Substituted(created.join("README.md")),
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(created.join("Cargo.toml")).unwrap(),
+            "name = \"my_game\"\n"
+        );
+        assert!(!created.join("target").exists());
+        assert!(!created.join(".bevyignore").exists());
+
+        // The failure happens while copying, nothing is reported after it
+        let missing = Templates::Custom(folder.join("missing"));
+        assert!(
+            block_on(create_project_files(
+                missing,
+                folder.join("other"),
+                HashMap::new(),
+                false,
+                &RetryPolicy::NEVER,
+                &CancellationToken::new(),
+                &report
+            ))
+            .is_err()
+        );
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![ProgressEvent::Phase(CreationPhase::CopyingTemplate)]
+        );
+    }
+
+    #[test]
+    fn create_from_the_built_in_templates() {
+        let folder = test_folder("built_in");
+        // The templates folder is looked up from the working directory, the shipped one is used
+        let shipped = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../templates");
+        for template in Templates::ALL {
+            let name = template.path().unwrap().file_name().unwrap().to_owned();
+            let events = std::sync::Mutex::new(Vec::new());
+            let report = |event| events.lock().unwrap().push(event);
+            let created = folder.join(&name);
+            block_on(create_project_files(
+                Templates::Custom(shipped.join(&name)),
+                created.clone(),
+                HashMap::new(),
+                false,
+                &RetryPolicy::NEVER,
+                &CancellationToken::new(),
+                &report,
+            ))
+            .unwrap_or_else(|error| panic!("{template:?}: {error}"));
+
+            let events = events.into_inner().unwrap();
+            assert_eq!(
+                events.first(),
+                Some(&ProgressEvent::Phase(CreationPhase::CopyingTemplate)),
+                "{template:?}"
+            );
+            assert!(
+                events.contains(&ProgressEvent::Substituted(created.join("Cargo.toml"))),
+                "{template:?}"
+            );
+            let manifest = fs::read_to_string(created.join("Cargo.toml")).unwrap();
+            assert!(!manifest.contains("{{"), "{template:?}: {manifest}");
+            assert!(created.join("src/main.rs").is_file(), "{template:?}");
+            assert!(
+                !created.join(templates::MANIFEST_FILE).exists(),
+                "{template:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn cancel_and_clean_up_the_creation() {
+        let folder = test_folder("cancel");
+        let template = folder.join("template");
+        fs::create_dir_all(template.join("src")).unwrap();
+        fs::write(template.join("Cargo.toml"), "name = \"{{package_name}}\"\n").unwrap();
+        fs::write(template.join("src/main.rs"), "fn main() {}\n").unwrap();
+        let created = folder.join("game");
+        let create_cancelled_at = |phase| {
+            let cancel = CancellationToken::new();
+            let report = |event| {
+                if event == ProgressEvent::Phase(phase) {
+                    cancel.cancel();
+                }
+            };
+            block_on(create_project_files(
+                Templates::Custom(template.clone()),
+                created.clone(),
+                HashMap::new(),
+                false,
+                &RetryPolicy::NEVER,
+                &cancel,
+                &report,
+            ))
+        };
+
+        // Cancelled once the files are copied, they are removed with the created folder
+        let result = create_cancelled_at(CreationPhase::SubstitutingPlaceholders);
+        assert!(matches!(result, Err(ProjectError::Cancelled)));
+        assert!(!created.exists());
+
+        // Cancelled while copying, the empty folder picked beforehand is kept
+        fs::create_dir_all(&created).unwrap();
+        let result = create_cancelled_at(CreationPhase::CopyingTemplate);
+        assert!(matches!(result, Err(ProjectError::Cancelled)));
+        assert!(created.is_dir() && is_empty_or_missing(&created));
+    }
+
+    #[test]
+    fn run_the_post_create_command() {
+        let folder = test_folder("post_create");
+        let template = folder.join("template");
+        fs::create_dir_all(&template).unwrap();
+        let write_manifest = |command: &str| {
+            let manifest = format!("name = \"Codegen\"\n[post_create]\ncommand = {command}\n");
+            fs::write(template.join(templates::MANIFEST_FILE), manifest).unwrap();
+        };
+        let create = |name: &str, run_post_create: bool| {
+            let events = std::sync::Mutex::new(Vec::new());
+            let report = |event| events.lock().unwrap().push(event);
+            let result = block_on(create_project_files(
+                Templates::Custom(template.clone()),
+                folder.join(name),
+                HashMap::new(),
+                run_post_create,
+                &RetryPolicy::NEVER,
+                &CancellationToken::new(),
+                &report,
+            ));
+            (result, events.into_inner().unwrap())
+        };
+
+        write_manifest(r#"["cargo", "--version"]"#);
+        let (result, events) = create("skipped", false);
+        assert!(result.is_ok());
+        assert!(!events.contains(&ProgressEvent::Phase(CreationPhase::RunningPostCreate)));
+
+        let (result, events) = create("allowed", true);
+        assert!(result.is_ok());
+        assert!(events.iter().any(
+            |event| matches!(event, ProgressEvent::Output(line) if line.starts_with("cargo "))
+        ));
+        assert_eq!(
+            events.last(),
+            Some(&ProgressEvent::PostCreateExited(Some(0)))
+        );
+
+        // A failing command fails the creation, which removes the project
+        write_manifest(r#"["cargo", "--no-such-flag"]"#);
+        let (result, events) = create("failing", true);
+        assert!(matches!(result, Err(ProjectError::PostCreate(_))));
+        assert!(matches!(
+            events.last(),
+            Some(ProgressEvent::PostCreateExited(Some(code))) if *code != 0
+        ));
+        assert!(!folder.join("failing").exists());
+    }
+
+    #[test]
+    fn find_enclosing_projects() {
+        let folder = test_folder("enclosing");
+        fs::create_dir_all(folder.join("game/crates")).unwrap();
+        fs::write(folder.join("game/Cargo.toml"), "[workspace]\n").unwrap();
+
+        assert_eq!(
+            enclosing_project(&folder.join("game/crates/new")),
+            Some(folder.join("game"))
+        );
+        assert_eq!(
+            enclosing_project(&folder.join("game/new")),
+            Some(folder.join("game"))
+        );
+        assert_eq!(enclosing_project(&folder.join("game")), None);
+        assert_eq!(enclosing_project(&folder.join("other/new")), None);
+    }
+
+    #[test]
+    fn merge_duplicated_projects() {
+        let folder = test_folder("duplicates");
+        let opened = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(10);
+        let env = |vars: &[(&str, &str)]| {
+            vars.iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect()
+        };
+        let projects = vec![
+            ProjectInfo {
+                env: env(&[("RUST_LOG", "info")]),
+                tags: vec!["jam".to_string()],
+                ..ProjectInfo::new(folder.clone())
+            },
+            ProjectInfo {
+                pinned: true,
+                open_count: 3,
+                icon: Some("🚀".to_string()),
+                tags: vec!["prototype".to_string(), "jam".to_string()],
+                collection: Some("2024".to_string()),
+                ..ProjectInfo::new(folder.join("sub/.."))
+            },
+            ProjectInfo {
+                last_opened: opened,
+                env: env(&[("RUST_LOG", "debug"), ("ASSETS", "assets")]),
+                default_member: Some("client".to_string()),
+                open_count: 2,
+                ..ProjectInfo::new(folder.join("."))
+            },
+            ProjectInfo::new(folder.join("missing")),
+            ProjectInfo::new(folder.join("missing")),
+        ];
+        fs::create_dir_all(folder.join("sub")).unwrap();
+
+        let merged = deduplicate_projects(projects);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].path, folder);
+        assert!(merged[0].pinned);
+        assert_eq!(merged[0].last_opened, opened);
+        assert_eq!(merged[0].open_count, 5);
+        assert_eq!(merged[0].icon.as_deref(), Some("🚀"));
+        assert_eq!(
+            merged[0].env,
+            env(&[("ASSETS", "assets"), ("RUST_LOG", "info")])
+        );
+        assert_eq!(merged[0].default_member.as_deref(), Some("client"));
+        assert_eq!(merged[0].tags, ["jam", "prototype"]);
+        assert_eq!(merged[0].collection.as_deref(), Some("2024"));
+        assert_eq!(merged[1].path, folder.join("missing"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_projects_keep_their_path() {
+        let folder = test_folder("symlinks");
+        let real = folder.join("real");
+        let link = folder.join("link");
+        let broken = folder.join("broken");
+        fs::create_dir_all(&real).unwrap();
+        std::os::unix::fs::symlink(&real, &link).unwrap();
+        std::os::unix::fs::symlink(folder.join("gone"), &broken).unwrap();
+
+        assert!(is_same_project(&link, &real));
+        assert!(!is_same_project(&link, &broken));
+        assert!(project_exists(&link));
+        assert!(!project_exists(&broken));
+        assert_eq!(project_identity(&broken), broken);
+
+        let merged = deduplicate_projects(vec![
+            ProjectInfo::new(link.clone()),
+            ProjectInfo {
+                pinned: true,
+                ..ProjectInfo::new(real.clone())
+            },
+            ProjectInfo::new(broken.clone()),
+        ]);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].path, link);
+        assert!(merged[0].pinned);
+        assert_eq!(merged[1].path, broken);
+    }
+
+    #[test]
+    fn validate_env_var_names() {
+        for name in ["RUST_LOG", "BEVY_ASSET_ROOT", "lower_case"] {
+            assert!(validate_env_var_name(name).is_ok(), "{name}");
+        }
+        for name in ["", "A=B", "WITH SPACE", "TAB\t"] {
+            assert!(
+                matches!(
+                    validate_env_var_name(name),
+                    Err(ProjectError::EnvVarInvalid(_))
+                ),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn rename_delete_and_import_report_invalid_projects() {
+        let folder = test_folder("operations");
+        let store = MemoryProjectStore::default();
+        let missing = ProjectInfo::new(folder.join("missing"));
+        assert!(matches!(
+            rename_project(&missing, "renamed", &store),
+            Err(ProjectError::NotFound(_))
+        ));
+        assert!(matches!(
+            rename_project(&ProjectInfo::new(folder.clone()), "a/b", &store),
+            Err(ProjectError::NameInvalid(_))
+        ));
+
+        fs::write(folder.join("notes.txt"), "not a project").unwrap();
+        assert!(matches!(
+            delete_project(&ProjectInfo::new(folder.clone()), &store),
+            Err(ProjectError::NotAProject { .. })
+        ));
+        assert!(folder.join("notes.txt").exists());
+        assert!(matches!(
+            import_project(&folder, &store),
+            Err(ProjectError::NotAProject { .. })
+        ));
+        assert!(matches!(
+            import_project(&folder.join("missing"), &store),
+            Err(ProjectError::NotFound(_))
+        ));
+        let game = folder.join("game");
+        fs::create_dir_all(&game).unwrap();
+        fs::write(game.join("Cargo.toml"), "[dependencies]\nbevy = \"0.16\"\n").unwrap();
+        let results = import_projects(
+            &[folder.clone(), folder.join("missing"), game.clone()],
+            &store,
+        );
+        assert!(matches!(
+            results.as_slice(),
+            [
+                Err(ProjectError::NotAProject { .. }),
+                Err(ProjectError::NotFound(_)),
+                Ok(_)
+            ]
+        ));
+        // Only the store given is updated
+        assert_eq!(store.load_projects(), [ProjectInfo::new(game)]);
+        assert!(matches!(
+            run_project(&missing),
+            Err(ProjectError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn parse_project_icons() {
+        let folder = Path::new("/projects/game");
+        assert_eq!(
+            ProjectIcon::parse(" 🚀 ", folder),
+            Some(ProjectIcon::Text("🚀".to_string()))
+        );
+        assert_eq!(
+            ProjectIcon::parse("assets/icon.PNG", folder),
+            Some(ProjectIcon::Image(folder.join("assets/icon.PNG")))
+        );
+        assert_eq!(
+            ProjectIcon::parse("/icons/game.png", folder),
+            Some(ProjectIcon::Image(PathBuf::from("/icons/game.png")))
+        );
+        assert_eq!(ProjectIcon::parse("  ", folder), None);
+    }
+}
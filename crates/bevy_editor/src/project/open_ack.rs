@@ -0,0 +1,66 @@
+//! The acknowledgment of the editor to the launcher once a project it opened has started.
+//!
+//! When the launcher runs a project, it asks the editor to acknowledge the opening through the
+//! [`OPEN_ACK_VAR`] environment variable, holding the path of a file to write once started, see
+//! [`acknowledge_open`]. The file is at [`OPEN_ACK_FILE`] in the project folder, removed by
+//! [`run_project_at`](super::run_project_at) before running the project so that a previous run
+//! doesn't acknowledge it. Until the file is written the project is still building or starting,
+//! the launcher tells the failures apart by cargo exiting early.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The acknowledgment of a project, relative to the project root.
+pub const OPEN_ACK_FILE: &str = ".launcher/opened";
+
+/// The environment variable holding where the editor should write its acknowledgment, set by
+/// [`run_project_at`](super::run_project_at).
+pub const OPEN_ACK_VAR: &str = "BEVY_EDITOR_OPEN_ACK";
+
+/// The acknowledgment of the project at `path`, which may not exist yet.
+pub fn open_ack_path(path: &Path) -> PathBuf {
+    path.join(OPEN_ACK_FILE)
+}
+
+/// Where the launcher asked the editor to write its acknowledgment, from the [`OPEN_ACK_VAR`].
+///
+/// Like for [`open_target`](super::open_target), a relative path is resolved against the working
+/// directory, the project root when run by the launcher.
+pub fn open_ack_request() -> Option<PathBuf> {
+    let path = std::env::var_os(OPEN_ACK_VAR).filter(|path| !path.is_empty())?;
+    let path = PathBuf::from(path);
+    match std::env::current_dir() {
+        Ok(root) if path.is_relative() => Some(root.join(path)),
+        _ => Some(path),
+    }
+}
+
+/// Tell the launcher the project has started, when it asked for it.
+///
+/// Returns whether the acknowledgment was written, `false` when the project wasn't run by the
+/// launcher.
+pub fn acknowledge_open() -> io::Result<bool> {
+    let Some(path) = open_ack_request() else {
+        return Ok(false);
+    };
+    if let Some(folder) = path.parent() {
+        fs::create_dir_all(folder)?;
+    }
+    fs::write(&path, std::process::id().to_string())?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn acknowledge_in_the_project_folder() {
+        assert_eq!(
+            open_ack_path(Path::new("/projects/game")),
+            Path::new("/projects/game/.launcher/opened")
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Bundling what a bug report about a project needs into a folder, to attach to the report.
+//!
+//! A bundle holds:
+//! - `summary.txt`, with the versions of the application, Bevy and Rust, the OS and the error
+//!   met, if any.
+//! - `project.json`, the metadata of the project in the [`export`](super::export) format.
+//! - `Cargo.toml`, the manifest of the project.
+//! - `logs/`, the logs given in the [`ReportDetails`], like the recent output of the launcher.
+//! - `src/`, the sources of the project, only when the user opts in as they may be private.
+
+use std::{
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use super::{ProjectError, ProjectInfo, export::ExportedProject, scan};
+
+/// The folder the bug reports are written to, inside the Bevy Editor's data folder.
+pub const REPORTS_FOLDER: &str = "reports";
+
+/// What goes in a bug report bundle besides the files of the project.
+#[derive(Debug, Clone, Default)]
+pub struct ReportDetails {
+    /// The name and version of the application writing the report, like `Bevy Launcher 0.1.0`.
+    pub app_version: String,
+    /// The error met with the project, like the one opening it.
+    pub error: Option<String>,
+    /// The logs included in the bundle, by file name, like `launcher.log`.
+    pub logs: Vec<(String, String)>,
+    /// Whether the `src` folder of the project is included.
+    pub include_sources: bool,
+}
+
+/// Write the bug report bundle of the project in a new folder inside `folder`, returning the
+/// bundle folder.
+///
+/// The bundles are named after the project and the time they were written, so that they never
+/// replace each other.
+pub fn write_bug_report(
+    project: &ProjectInfo,
+    details: &ReportDetails,
+    folder: &Path,
+) -> Result<PathBuf, ProjectError> {
+    let name = project.name().unwrap_or_else(|| "project".to_string());
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let base = format!("{name}-{seconds}");
+    let bundle = (1..)
+        .map(|number| match number {
+            1 => folder.join(&base),
+            number => folder.join(format!("{base}-{number}")),
+        })
+        .find(|bundle| !bundle.exists())
+        .unwrap_or_else(|| folder.join(&base));
+    write_bundle(project, details, &bundle)
+        .map_err(|error| ProjectError::from_io(&bundle, error))?;
+    Ok(bundle)
+}
+
+fn write_bundle(project: &ProjectInfo, details: &ReportDetails, bundle: &Path) -> io::Result<()> {
+    fs::create_dir_all(bundle)?;
+    fs::write(bundle.join("summary.txt"), report_summary(project, details))?;
+    // The fields are strings, numbers and string maps, which always serialize
+    let metadata = serde_json::to_string_pretty(&ExportedProject::new(project)).unwrap_or_default();
+    fs::write(bundle.join("project.json"), metadata)?;
+
+    let manifest = project.path.join("Cargo.toml");
+    if manifest.is_file() {
+        fs::copy(&manifest, bundle.join("Cargo.toml"))?;
+    }
+    if !details.logs.is_empty() {
+        let logs = bundle.join("logs");
+        fs::create_dir_all(&logs)?;
+        for (file_name, content) in &details.logs {
+            fs::write(logs.join(file_name), content)?;
+        }
+    }
+    let sources = project.path.join("src");
+    if details.include_sources && sources.is_dir() {
+        copy_folder(&sources, &bundle.join("src"))?;
+    }
+    Ok(())
+}
+
+/// The content of the `summary.txt` of a bundle.
+fn report_summary(project: &ProjectInfo, details: &ReportDetails) -> String {
+    let mut summary = String::new();
+    let name = project.name().unwrap_or_default();
+    let bevy_version = scan::bevy_version(&project.path);
+    let _ = writeln!(summary, "Project: {name}");
+    let _ = writeln!(summary, "Path: {}", project.path.display());
+    let _ = writeln!(
+        summary,
+        "Bevy version: {}",
+        bevy_version.as_deref().unwrap_or("unknown")
+    );
+    let _ = writeln!(summary, "Application: {}", details.app_version);
+    let _ = writeln!(
+        summary,
+        "OS: {} {} ({})",
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        std::env::consts::FAMILY
+    );
+    let _ = writeln!(
+        summary,
+        "Rust: {}",
+        rustc_version().as_deref().unwrap_or("unavailable")
+    );
+    let _ = writeln!(
+        summary,
+        "Sources included: {}",
+        if details.include_sources { "yes" } else { "no" }
+    );
+    if let Some(error) = &details.error {
+        let _ = writeln!(summary, "\nError:\n{error}");
+    }
+    summary
+}
+
+/// The output of `rustc --version`, `None` when it can't be run.
+fn rustc_version() -> Option<String> {
+    let output = Command::new("rustc").arg("--version").output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Copy the folder and everything inside it to `target`.
+fn copy_folder(source: &Path, target: &Path) -> io::Result<()> {
+    fs::create_dir_all(target)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            copy_folder(&path, &target.join(entry.file_name()))?;
+        } else {
+            fs::copy(&path, target.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundle_a_bug_report() {
+        let root = std::env::temp_dir().join(format!("bevy_editor_report_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let path = root.join("game");
+        fs::create_dir_all(path.join("src/systems")).unwrap();
+        fs::write(path.join("Cargo.toml"), "[dependencies]\nbevy = \"0.16\"\n").unwrap();
+        fs::write(path.join("src/main.rs"), "fn main() {}\n").unwrap();
+        fs::write(path.join("src/systems/mod.rs"), "").unwrap();
+        let project = ProjectInfo::new(path);
+        let mut details = ReportDetails {
+            app_version: "Bevy Launcher 0.1.0".to_string(),
+            error: Some("error[E0308]: mismatched types".to_string()),
+            logs: vec![("launcher.log".to_string(), "INFO Opening".to_string())],
+            include_sources: false,
+        };
+
+        let bundle = write_bug_report(&project, &details, &root.join("reports")).unwrap();
+        let summary = fs::read_to_string(bundle.join("summary.txt")).unwrap();
+        assert!(summary.contains("Bevy version: 0.16"));
+        assert!(summary.contains("Bevy Launcher 0.1.0"));
+        assert!(summary.contains("mismatched types"));
+        assert!(bundle.join("project.json").is_file());
+        assert!(bundle.join("Cargo.toml").is_file());
+        assert_eq!(
+            fs::read_to_string(bundle.join("logs/launcher.log")).unwrap(),
+            "INFO Opening"
+        );
+        assert!(!bundle.join("src").exists(), "the sources are opt in");
+
+        details.include_sources = true;
+        let second = write_bug_report(&project, &details, &root.join("reports")).unwrap();
+        assert_ne!(second, bundle, "the bundles never replace each other");
+        let bundle = second;
+        assert!(bundle.join("src/main.rs").is_file());
+        assert!(bundle.join("src/systems/mod.rs").is_file());
+        let _ = fs::remove_dir_all(&root);
+    }
+}
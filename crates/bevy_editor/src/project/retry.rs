@@ -0,0 +1,206 @@
+//! Retrying the network operations that can fail transiently, like cloning a Git template, see
+//! [`RetryPolicy`].
+//!
+//! The delay between two attempts doubles each time, up to [`RetryPolicy::max_delay`], and the
+//! [`CancellationToken`] of the operation is checked while waiting, so a cancelled operation
+//! doesn't wait for the next attempt.
+
+use std::{io, thread, time::Duration};
+
+use super::CancellationToken;
+
+/// How many times a network operation is retried by default, after its first attempt.
+pub const DEFAULT_RETRIES: u32 = 3;
+/// How often the [`CancellationToken`] is checked while waiting for the next attempt.
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How a network operation is retried after a transient failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// How many times the operation is retried after its first attempt, 0 never retries.
+    pub retries: u32,
+    /// The delay before the first retry, doubled before each of the next ones.
+    pub initial_delay: Duration,
+    /// The longest delay between two attempts.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            retries: DEFAULT_RETRIES,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(16),
+        }
+    }
+}
+
+/// A failed attempt about to be retried, reported by [`RetryPolicy::run`].
+#[derive(Debug)]
+pub struct Retry<'a> {
+    /// The retry about to be made, starting at 1.
+    pub retry: u32,
+    /// How many retries the policy allows.
+    pub retries: u32,
+    /// How long until the retry.
+    pub delay: Duration,
+    /// Why the previous attempt failed.
+    pub error: &'a io::Error,
+}
+
+impl RetryPolicy {
+    /// A policy never retrying.
+    pub const NEVER: Self = Self {
+        retries: 0,
+        initial_delay: Duration::ZERO,
+        max_delay: Duration::ZERO,
+    };
+
+    /// The default delays, retrying `retries` times.
+    pub fn with_retries(retries: u32) -> Self {
+        Self {
+            retries,
+            ..Self::default()
+        }
+    }
+
+    /// The delay before the given retry, starting at 1.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.initial_delay
+            .saturating_mul(factor)
+            .min(self.max_delay)
+    }
+
+    /// Run the operation until it succeeds, fails with an error that isn't `transient`, or runs
+    /// out of retries, returning its last result.
+    ///
+    /// `on_retry` is told about every retry before waiting for it. Once `cancel` is cancelled,
+    /// the waiting stops with an [`io::ErrorKind::Interrupted`] error, an attempt already running
+    /// isn't stopped.
+    pub fn run<T>(
+        &self,
+        cancel: &CancellationToken,
+        mut operation: impl FnMut() -> io::Result<T>,
+        transient: impl Fn(&io::Error) -> bool,
+        mut on_retry: impl FnMut(Retry),
+    ) -> io::Result<T> {
+        let mut retry = 0;
+        loop {
+            let error = match operation() {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+            if retry >= self.retries || !transient(&error) || cancel.is_cancelled() {
+                return Err(error);
+            }
+            retry += 1;
+            let delay = self.delay(retry);
+            on_retry(Retry {
+                retry,
+                retries: self.retries,
+                delay,
+                error: &error,
+            });
+            wait(delay, cancel)?;
+        }
+    }
+}
+
+/// Sleep for the delay, failing early once `cancel` is cancelled.
+fn wait(delay: Duration, cancel: &CancellationToken) -> io::Result<()> {
+    let mut waited = Duration::ZERO;
+    while waited < delay {
+        if cancel.is_cancelled() {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "cancelled"));
+        }
+        let step = CANCEL_POLL_INTERVAL.min(delay - waited);
+        thread::sleep(step);
+        waited += step;
+    }
+    if cancel.is_cancelled() {
+        return Err(io::Error::new(io::ErrorKind::Interrupted, "cancelled"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    /// A policy retrying right away, for the tests not to wait.
+    fn immediate(retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            retries,
+            initial_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+        }
+    }
+
+    /// An operation failing transiently `failures` times, then succeeding.
+    fn flaky(failures: u32, attempts: &Cell<u32>) -> impl FnMut() -> io::Result<&'static str> {
+        move || {
+            attempts.set(attempts.get() + 1);
+            if attempts.get() <= failures {
+                Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "connection timed out",
+                ))
+            } else {
+                Ok("cloned")
+            }
+        }
+    }
+
+    #[test]
+    fn succeed_within_the_retries() {
+        let cancel = CancellationToken::new();
+        let attempts = Cell::new(0);
+        let mut retries = Vec::new();
+        let result = immediate(3).run(
+            &cancel,
+            flaky(2, &attempts),
+            |_| true,
+            |retry| retries.push(retry.retry),
+        );
+        assert_eq!(result.unwrap(), "cloned");
+        assert_eq!(attempts.get(), 3);
+        assert_eq!(retries, [1, 2]);
+
+        // Out of retries, the last error is returned
+        let attempts = Cell::new(0);
+        let result = immediate(1).run(&cancel, flaky(2, &attempts), |_| true, |_| {});
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::TimedOut);
+        assert_eq!(attempts.get(), 2);
+
+        // A permanent failure isn't retried
+        let attempts = Cell::new(0);
+        let result = immediate(3).run(&cancel, flaky(2, &attempts), |_| false, |_| {});
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+    }
+
+    #[test]
+    fn stop_waiting_once_cancelled() {
+        let cancel = CancellationToken::new();
+        let attempts = Cell::new(0);
+        let policy = RetryPolicy::with_retries(3);
+        let result = policy.run(&cancel, flaky(5, &attempts), |_| true, |_| cancel.cancel());
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::Interrupted);
+        assert_eq!(attempts.get(), 1);
+    }
+
+    #[test]
+    fn double_the_delays() {
+        let policy = RetryPolicy {
+            retries: 6,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(5),
+        };
+        let delays: Vec<u64> = (1..=4).map(|retry| policy.delay(retry).as_secs()).collect();
+        assert_eq!(delays, [1, 2, 4, 5]);
+        assert_eq!(policy.delay(40), Duration::from_secs(5));
+    }
+}
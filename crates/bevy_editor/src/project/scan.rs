@@ -0,0 +1,247 @@
+//! Module to find the Bevy projects in a folder tree.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use bevy::log::warn;
+
+use super::{CancellationToken, workspace};
+
+/// How deep [`scan_for_projects`] looks under the root by default.
+pub const DEFAULT_SCAN_DEPTH: usize = 4;
+
+/// The folders never worth looking into.
+const SKIPPED_FOLDERS: &[&str] = &["target", "node_modules"];
+
+/// Something [`scan_for_projects`] reports while walking the folder tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanProgress {
+    /// A folder is being looked into.
+    Visiting(PathBuf),
+    /// A Bevy project was found in the folder.
+    Found(PathBuf),
+}
+
+/// Whether the folder holds a Bevy project, meaning a `Cargo.toml` depending on `bevy`.
+///
+/// Workspaces declaring `bevy` in their `[workspace.dependencies]`, or with a member depending on
+/// it, are projects too, see [`workspace`](super::workspace) for their members.
+pub fn is_bevy_project(path: &Path) -> bool {
+    let Some(manifest) = read_manifest(path) else {
+        return false;
+    };
+    bevy_dependency(&manifest).is_some()
+        || (manifest.contains_key("workspace")
+            && member_manifests(path).any(|manifest| bevy_dependency(&manifest).is_some()))
+}
+
+/// The version requirement of the `bevy` dependency of the project, like `0.16` or `^0.15.3`.
+///
+/// Returns `None` for non-projects and for `bevy` dependencies without a version, like git ones.
+/// The version of a workspace is the one of its first member with a version, when it doesn't
+/// declare one in its `[workspace.dependencies]`.
+pub fn bevy_version(path: &Path) -> Option<String> {
+    let manifest = read_manifest(path)?;
+    if let Some(version) = bevy_dependency(&manifest).and_then(dependency_version) {
+        return Some(version);
+    }
+    if !manifest.contains_key("workspace") {
+        return None;
+    }
+    member_manifests(path)
+        .find_map(|manifest| bevy_dependency(&manifest).and_then(dependency_version))
+}
+
+/// The version requirement of a dependency, like `"0.16"` or `{ version = "0.16" }`.
+fn dependency_version(dependency: &toml::Value) -> Option<String> {
+    let version = match dependency {
+        toml::Value::String(version) => version,
+        toml::Value::Table(dependency) => dependency.get("version")?.as_str()?,
+        _ => return None,
+    };
+    Some(version.to_string())
+}
+
+/// The manifests of the members of the workspace at `path`, except the one of the root package.
+fn member_manifests(path: &Path) -> impl Iterator<Item = toml::Table> {
+    workspace::workspace_members(path)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(move |member| member.path != path)
+        .filter_map(|member| read_manifest(&member.path))
+}
+
+/// The `description` of the package of the project, if it has one.
+pub fn package_description(path: &Path) -> Option<String> {
+    let manifest = read_manifest(path)?;
+    let description = manifest.get("package")?.get("description")?.as_str()?;
+    Some(description.to_string())
+}
+
+/// Read and parse the `Cargo.toml` of the folder.
+pub(super) fn read_manifest(path: &Path) -> Option<toml::Table> {
+    let manifest = fs::read_to_string(path.join("Cargo.toml")).ok()?;
+    match manifest.parse::<toml::Table>() {
+        Ok(manifest) => Some(manifest),
+        Err(_) => {
+            warn!("Ignoring the malformed manifest in '{}'", path.display());
+            None
+        }
+    }
+}
+
+/// The `bevy` dependency of the manifest, from its `[dependencies]` or its `[workspace.dependencies]`.
+fn bevy_dependency(manifest: &toml::Table) -> Option<&toml::Value> {
+    let bevy = |dependencies: Option<&toml::Value>| dependencies?.as_table()?.get("bevy");
+    bevy(manifest.get("dependencies")).or_else(|| {
+        bevy(
+            manifest
+                .get("workspace")
+                .and_then(|workspace| workspace.get("dependencies")),
+        )
+    })
+}
+
+/// Walk the folders under `root`, up to `max_depth` levels deep, looking for Bevy projects.
+///
+/// Every visited folder and found project is reported to `progress` as it happens,
+/// the folders of the projects found aren't looked into.
+/// Hidden folders and build outputs are skipped.
+/// The walk stops early once `cancel` is cancelled, returning the projects found until then.
+pub fn scan_for_projects(
+    root: &Path,
+    max_depth: usize,
+    cancel: &CancellationToken,
+    mut progress: impl FnMut(ScanProgress),
+) -> io::Result<Vec<PathBuf>> {
+    if !root.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("'{}' isn't a folder", root.display()),
+        ));
+    }
+
+    let mut found = Vec::new();
+    let mut pending = vec![(root.to_path_buf(), 0)];
+    while let Some((folder, depth)) = pending.pop() {
+        if cancel.is_cancelled() {
+            break;
+        }
+        progress(ScanProgress::Visiting(folder.clone()));
+
+        if is_bevy_project(&folder) {
+            progress(ScanProgress::Found(folder.clone()));
+            found.push(folder);
+            continue;
+        }
+        if depth >= max_depth {
+            continue;
+        }
+
+        // Unreadable folders are skipped instead of failing the whole scan
+        let Ok(entries) = fs::read_dir(&folder) else {
+            continue;
+        };
+        let mut subfolders: Vec<PathBuf> = entries
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+            .filter(|entry| {
+                let name = entry.file_name();
+                let name = name.to_string_lossy();
+                !name.starts_with('.') && !SKIPPED_FOLDERS.contains(&name.as_ref())
+            })
+            .map(|entry| entry.path())
+            .collect();
+        // Reversed so the folders are popped, and reported, in alphabetical order
+        subfolders.sort_unstable_by(|a, b| b.cmp(a));
+        pending.extend(
+            subfolders
+                .into_iter()
+                .map(|subfolder| (subfolder, depth + 1)),
+        );
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_manifest(folder: &Path, manifest: &str) {
+        fs::create_dir_all(folder).unwrap();
+        fs::write(folder.join("Cargo.toml"), manifest).unwrap();
+    }
+
+    #[test]
+    fn find_bevy_projects() {
+        let root = std::env::temp_dir().join(format!("bevy_editor_scan_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write_manifest(
+            &root.join("game"),
+            "[package]\ndescription = \"A game\"\n\n[dependencies]\nbevy = \"0.16\"\n",
+        );
+        write_manifest(
+            &root.join("nested/workspace"),
+            "[workspace.dependencies]\nbevy = { version = \"0.16\" }\n",
+        );
+        // A virtual manifest whose member depends on Bevy, the member isn't a project of its own
+        write_manifest(
+            &root.join("prototypes"),
+            "[workspace]\nmembers = [\"client\"]\n",
+        );
+        write_manifest(
+            &root.join("prototypes/client"),
+            "[package]\nname = \"client\"\n\n[dependencies]\nbevy = \"0.15\"\n",
+        );
+        write_manifest(&root.join("tool"), "[dependencies]\nserde = \"1\"\n");
+        write_manifest(
+            &root.join("target/copy"),
+            "[dependencies]\nbevy = \"0.16\"\n",
+        );
+        write_manifest(
+            &root.join("a/b/c/d/deep"),
+            "[dependencies]\nbevy = \"0.16\"\n",
+        );
+
+        let mut reported = Vec::new();
+        let found = scan_for_projects(&root, 3, &CancellationToken::new(), |progress| {
+            if let ScanProgress::Found(path) = progress {
+                reported.push(path);
+            }
+        })
+        .unwrap();
+        assert_eq!(
+            found,
+            vec![
+                root.join("game"),
+                root.join("nested/workspace"),
+                root.join("prototypes")
+            ]
+        );
+        assert_eq!(reported, found);
+
+        assert_eq!(bevy_version(&root.join("game")).as_deref(), Some("0.16"));
+        assert_eq!(
+            bevy_version(&root.join("nested/workspace")).as_deref(),
+            Some("0.16")
+        );
+        assert_eq!(
+            bevy_version(&root.join("prototypes")).as_deref(),
+            Some("0.15")
+        );
+        assert_eq!(bevy_version(&root.join("tool")), None);
+        assert_eq!(
+            package_description(&root.join("game")).as_deref(),
+            Some("A game")
+        );
+        assert_eq!(package_description(&root.join("tool")), None);
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let cancelled = scan_for_projects(&root, 3, &cancel, |_| {}).unwrap();
+        assert!(cancelled.is_empty());
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,123 @@
+//! Where the project list is persisted, see [`ProjectStore`].
+
+use std::{io, sync::Mutex};
+
+use bevy::log::{error, warn};
+
+use super::{ProjectInfo, cache, deduplicate_projects};
+
+/// A storage of the project list.
+///
+/// The [`FileProjectStore`] is the one of the editor, shared with the launcher. Other stores can
+/// keep the list elsewhere, like the [`MemoryProjectStore`] of the tests.
+pub trait ProjectStore: Send + Sync {
+    /// Load the stored projects, their paths made absolute.
+    fn load(&self) -> io::Result<Vec<ProjectInfo>>;
+
+    /// Replace the stored projects.
+    fn save(&self, projects: Vec<ProjectInfo>) -> io::Result<()>;
+
+    /// Load the projects without duplicates, an empty list when the store can't be read.
+    fn load_projects(&self) -> Vec<ProjectInfo> {
+        match self.load() {
+            // Lists saved before the duplicates were merged may still hold some
+            Ok(projects) => deduplicate_projects(projects),
+            Err(error) => {
+                warn!("Failed to load the project list: {:?}", error);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Save the projects, merging the duplicated ones first, see [`deduplicate_projects`].
+    fn save_projects(&self, projects: Vec<ProjectInfo>) -> io::Result<()> {
+        self.save(deduplicate_projects(projects))
+    }
+
+    /// Like [`save_projects`](Self::save_projects), but logging the error instead of returning it.
+    fn set_projects(&self, projects: Vec<ProjectInfo>) {
+        if let Err(error) = self.save_projects(projects) {
+            error!("Unable to save project list: {:?}", error);
+        }
+    }
+}
+
+/// The project list stored in the `projects.ron` file of the [`data_dir`](super::data_dir).
+///
+/// The paths under the [`projects_root`](super::projects_root) are stored relative to it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FileProjectStore;
+
+impl ProjectStore for FileProjectStore {
+    fn load(&self) -> io::Result<Vec<ProjectInfo>> {
+        cache::load_projects()
+    }
+
+    fn save(&self, projects: Vec<ProjectInfo>) -> io::Result<()> {
+        cache::save_projects(projects)
+    }
+}
+
+/// A project list kept in memory, lost when dropped.
+#[derive(Debug, Default)]
+pub struct MemoryProjectStore(Mutex<Vec<ProjectInfo>>);
+
+impl MemoryProjectStore {
+    /// A store holding the given projects.
+    pub fn new(projects: Vec<ProjectInfo>) -> Self {
+        Self(Mutex::new(projects))
+    }
+}
+
+impl ProjectStore for MemoryProjectStore {
+    fn load(&self) -> io::Result<Vec<ProjectInfo>> {
+        self.0
+            .lock()
+            .map(|projects| projects.clone())
+            .map_err(|_| io::Error::other("the project list lock is poisoned"))
+    }
+
+    fn save(&self, projects: Vec<ProjectInfo>) -> io::Result<()> {
+        let mut stored = self
+            .0
+            .lock()
+            .map_err(|_| io::Error::other("the project list lock is poisoned"))?;
+        *stored = projects;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_and_load_from_memory() {
+        let store = MemoryProjectStore::default();
+        assert!(store.load_projects().is_empty());
+
+        store
+            .save_projects(
+                ["/projects/game", "/projects/tool", "/projects/game"]
+                    .map(|path| ProjectInfo {
+                        open_count: 1,
+                        ..ProjectInfo::new(path)
+                    })
+                    .to_vec(),
+            )
+            .unwrap();
+        let loaded = store.load_projects();
+        assert_eq!(
+            loaded,
+            [
+                ProjectInfo::new("/projects/game"),
+                ProjectInfo::new("/projects/tool")
+            ]
+        );
+        assert_eq!(loaded[0].open_count, 2, "the duplicates were merged");
+
+        let store: Box<dyn ProjectStore> = Box::new(store);
+        store.set_projects(Vec::new());
+        assert!(store.load_projects().is_empty());
+    }
+}
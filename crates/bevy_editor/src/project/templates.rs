@@ -1,44 +1,1047 @@
 //! Module to handle Bevy Editor's project templates.
+//!
+//! A template is a folder that gets copied to create a new project.
+//! It can contain a [`MANIFEST_FILE`] describing it, see [`TemplateManifest`], and an
+//! [`IGNORE_FILE`] listing the paths that aren't copied, see [`ignore`](super::ignore).
+//!
+//! The built-in templates are looked for in a few places, see [`templates_folder`]. Their folder
+//! can be missing or unreadable on locked-down systems, [`check_templates`] tells which templates
+//! can be used before offering them.
 
-use std::path::Path;
+use std::{
+    collections::HashMap,
+    ffi::OsStr,
+    io::{self, BufRead, BufReader, Read},
+    path::{Component, Path, PathBuf},
+    process::{Command, ExitStatus, Stdio},
+    sync::{OnceLock, mpsc},
+    time::{Duration, Instant, SystemTime},
+};
+
+use bevy::log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    CancellationToken, ProjectError,
+    assets::ASSETS_FOLDER,
+    ignore::{IGNORE_FILE, IgnorePatterns},
+    retry::{Retry, RetryPolicy},
+    toolchain::ToolchainRequirements,
+};
 
 /// The path to the folder containing the templates project
 const TEMPLATE_FOLDER_PATH: &str = "templates/";
 
+/// The folder of the built-in templates, resolved the first time it is needed.
+static TEMPLATES_FOLDER: OnceLock<PathBuf> = OnceLock::new();
+
 /// The names of the templates project
 const TEMPLATE_NAMES: &[&str] = &["blank_project", "getting_started"];
 
+/// The category of the templates without one.
+pub const DEFAULT_CATEGORY: &str = "Other";
+
+/// The category of the templates cloned from Git.
+pub const GIT_CATEGORY: &str = "Community";
+
+/// The name of the manifest file describing a template, at the root of the template folder.
+pub const MANIFEST_FILE: &str = "template.toml";
+
 /// The available projects template
-#[derive(Debug, Default, Clone, Copy)]
-#[allow(dead_code)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Templates {
     /// Template for a blank project
     #[default]
-    Blank = 0,
+    Blank,
     /// Template for a project with basic assets to get you started
-    GettingStarted = 1,
+    GettingStarted,
+    /// A template stored in the given folder
+    Custom(PathBuf),
+    /// A template cloned from the given Git repository
+    Git {
+        /// The URL of the repository
+        url: String,
+        /// Whether to start the project with a new history instead of the one of the repository
+        fresh_history: bool,
+    },
+}
+
+impl Templates {
+    /// All the built-in templates.
+    pub const ALL: [Templates; 2] = [Templates::Blank, Templates::GettingStarted];
+
+    /// The folder containing the template files, `None` for the templates cloned from Git.
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            Templates::Blank => Some(templates_folder().join(TEMPLATE_NAMES[0])),
+            Templates::GettingStarted => Some(templates_folder().join(TEMPLATE_NAMES[1])),
+            Templates::Custom(path) => Some(path.clone()),
+            Templates::Git { .. } => None,
+        }
+    }
+
+    /// Check that the folder of the template can be read, returning why the template can't be used
+    /// otherwise.
+    ///
+    /// Read-only folders are fine, templates are only ever read from. The manifest isn't checked, it
+    /// is reported when the template is picked, and the templates cloned from Git can't be checked
+    /// before cloning them.
+    pub fn check_folder(&self) -> Result<(), String> {
+        let Some(path) = self.path() else {
+            return Ok(());
+        };
+        match std::fs::metadata(&path) {
+            Ok(metadata) if !metadata.is_dir() => {
+                return Err(format!("'{}' isn't a folder", path.display()));
+            }
+            Ok(_) => {}
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Err(format!("the folder '{}' doesn't exist", path.display()));
+            }
+            Err(error) => {
+                return Err(format!(
+                    "the folder '{}' can't be read: {error}",
+                    path.display()
+                ));
+            }
+        }
+        std::fs::read_dir(&path)
+            .map(|_| ())
+            .map_err(|error| format!("the folder '{}' can't be read: {error}", path.display()))
+    }
+
+    /// The name of the template in diagnostics, before its manifest is known.
+    pub fn display_name(&self) -> String {
+        match self {
+            Templates::Git { url, .. } => url.clone(),
+            template => template
+                .path()
+                .and_then(|path| {
+                    path.file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                })
+                .unwrap_or_else(|| format!("{template:?}")),
+        }
+    }
+
+    /// Read and validate the [`TemplateManifest`] of the template.
+    ///
+    /// Templates without a manifest get a default one named after their folder.
+    /// The manifest of the templates cloned from Git isn't known before cloning them,
+    /// they get a default one named after their repository.
+    pub fn manifest(&self) -> io::Result<TemplateManifest> {
+        match self {
+            Templates::Git { url, .. } => {
+                let name = url
+                    .trim_end_matches('/')
+                    .trim_end_matches(".git")
+                    .rsplit(['/', ':'])
+                    .next()
+                    .filter(|name| !name.is_empty())
+                    .unwrap_or("Git repository")
+                    .to_string();
+                Ok(TemplateManifest {
+                    name,
+                    description: "A template cloned from a Git repository.".to_string(),
+                    category: Some(GIT_CATEGORY.to_string()),
+                    ..Default::default()
+                })
+            }
+            template => TemplateManifest::load(&template.path().unwrap_or_default()),
+        }
+    }
+}
+
+/// The folder the built-in templates are copied from.
+///
+/// It is the `templates` folder of the working directory, or the one next to the executable for the
+/// installed launchers, see [`resolve_templates_folder`]. It is resolved once, the first time.
+pub fn templates_folder() -> &'static Path {
+    TEMPLATES_FOLDER.get_or_init(|| {
+        let mut candidates = vec![PathBuf::from(TEMPLATE_FOLDER_PATH)];
+        if let Some(executable_folder) = std::env::current_exe()
+            .ok()
+            .and_then(|executable| executable.parent().map(Path::to_path_buf))
+        {
+            candidates.push(executable_folder.join(TEMPLATE_FOLDER_PATH));
+        }
+        let folder = resolve_templates_folder(&candidates);
+        info!("Using the templates of '{}'", folder.display());
+        folder
+    })
+}
+
+/// The first of the candidate folders containing a built-in template, or the first candidate
+/// when none does so that the diagnostics point to the expected place.
+fn resolve_templates_folder(candidates: &[PathBuf]) -> PathBuf {
+    candidates
+        .iter()
+        .find(|candidate| {
+            TEMPLATE_NAMES
+                .iter()
+                .any(|name| candidate.join(name).is_dir())
+        })
+        .or(candidates.first())
+        .cloned()
+        .unwrap_or_else(|| PathBuf::from(TEMPLATE_FOLDER_PATH))
+}
+
+/// Split the templates between the ones that can be used and the others, along with why they
+/// can't, see [`Templates::check_folder`].
+pub fn check_templates(templates: &[Templates]) -> (Vec<Templates>, Vec<(Templates, String)>) {
+    let mut usable = Vec::new();
+    let mut unusable = Vec::new();
+    for template in templates {
+        match template.check_folder() {
+            Ok(()) => usable.push(template.clone()),
+            Err(reason) => unusable.push((template.clone(), reason)),
+        }
+    }
+    (usable, unusable)
+}
+
+/// The content of a template [`MANIFEST_FILE`].
+///
+/// ```toml
+/// name = "Blank"
+/// description = "An empty project with the editor attached."
+/// icon = "file"
+/// category = "Starter"
+/// suggested_name = "my-bevy-app"
+///
+/// [[variables]]
+/// name = "author"
+/// description = "The name of the author of the project"
+/// default = "Anonymous"
+///
+/// [toolchain]
+/// edition = "2024"
+///
+/// [post_create]
+/// command = ["cargo", "run", "--bin", "codegen", "--", "{{package_name}}"]
+/// timeout_secs = 600
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateManifest {
+    /// The display name of the template.
+    pub name: String,
+    /// A short description of what the template contains.
+    #[serde(default)]
+    pub description: String,
+    /// An optional icon shown next to the template.
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// The category the template is listed under, see [`category`](Self::category).
+    #[serde(default)]
+    pub category: Option<String>,
+    /// The name suggested for the projects created from the template,
+    /// see [`suggested_project_name`](Self::suggested_project_name).
+    #[serde(default)]
+    pub suggested_name: Option<String>,
+    /// The placeholders that can be substituted in the template files.
+    #[serde(default)]
+    pub variables: Vec<TemplateVariable>,
+    /// The Rust toolchain needed to build the template.
+    #[serde(default)]
+    pub toolchain: ToolchainRequirements,
+    /// The command run in the project folder once the template files are written.
+    #[serde(default)]
+    pub post_create: Option<PostCreateHook>,
+    /// The assets the projects need, relative to their [`ASSETS_FOLDER`], checked along with the
+    /// ones loaded by their sources once they are created, see [`missing_assets`](super::assets::missing_assets).
+    #[serde(default)]
+    pub assets: Vec<String>,
+}
+
+/// The default [`PostCreateHook::timeout_secs`], 5 minutes.
+const DEFAULT_POST_CREATE_TIMEOUT_SECS: u64 = 300;
+
+/// A setup step declared by a [`TemplateManifest`], like downloading assets or generating code.
+///
+/// The command runs in the new project folder after the placeholders are filled in. As it can do
+/// anything, it only runs when the user allows it, see
+/// [`create_new_project_with_progress`](super::create_new_project_with_progress).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PostCreateHook {
+    /// The program and its arguments, run without a shell. The `{{name}}` placeholders of the
+    /// template variables are replaced in them.
+    pub command: Vec<String>,
+    /// How long the command may run before it is stopped and the creation fails, in seconds.
+    #[serde(default = "default_post_create_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_post_create_timeout() -> u64 {
+    DEFAULT_POST_CREATE_TIMEOUT_SECS
+}
+
+impl PostCreateHook {
+    /// The command with the placeholders of the given variables replaced.
+    pub fn resolved_command(&self, variables: &HashMap<String, String>) -> Vec<String> {
+        self.command
+            .iter()
+            .map(|arg| substitute(arg, variables).unwrap_or_else(|| arg.clone()))
+            .collect()
+    }
+
+    /// The command as typed in a shell, meant to be displayed before the user allows it.
+    pub fn command_line(&self, variables: &HashMap<String, String>) -> String {
+        self.resolved_command(variables)
+            .iter()
+            .map(|arg| {
+                if arg.is_empty() || arg.contains(char::is_whitespace) {
+                    format!("\"{arg}\"")
+                } else {
+                    arg.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self
+            .command
+            .first()
+            .is_none_or(|program| program.trim().is_empty())
+        {
+            return Err("the post-create command needs a program to run".to_string());
+        }
+        if self.timeout_secs == 0 {
+            return Err("the post-create timeout must be at least a second".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// A placeholder declared by a [`TemplateManifest`].
+///
+/// Variables without a default value are required, a value must be provided when creating the project.
+/// The `project_name`, `package_name` and `year` variables are always available and derived from the project.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TemplateVariable {
+    /// The name of the variable, used as `{{name}}` in the template files.
+    pub name: String,
+    /// What the variable is used for, shown when asking for its value.
+    #[serde(default)]
+    pub description: String,
+    /// The value used when none is provided.
+    #[serde(default)]
+    pub default: Option<String>,
+}
+
+impl TemplateManifest {
+    /// Load the manifest of the template stored in the given folder.
+    pub fn load(template_path: &Path) -> io::Result<Self> {
+        let manifest_path = template_path.join(MANIFEST_FILE);
+        if !manifest_path.exists() {
+            let name = template_path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("Template")
+                .to_string();
+            return Ok(Self {
+                name,
+                ..Default::default()
+            });
+        }
+
+        let content = std::fs::read_to_string(&manifest_path)?;
+        Self::parse(&content).map_err(|reason| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Invalid template manifest '{}': {reason}",
+                    manifest_path.display()
+                ),
+            )
+        })
+    }
+
+    /// Parse and validate the content of a manifest file.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let manifest: Self = toml::from_str(content).map_err(|error| error.to_string())?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// Resolve the value of every variable, from the provided values, the defaults and the derived ones.
+    pub fn resolve_variables(
+        &self,
+        project_path: &Path,
+        provided: &HashMap<String, String>,
+    ) -> Result<HashMap<String, String>, String> {
+        let project_name = project_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("project")
+            .to_string();
+        let mut package_name: String = project_name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        // Cargo rejects the package names starting with a digit, like for a `2d-game` folder
+        if package_name.starts_with(|c: char| c.is_ascii_digit()) {
+            package_name.insert_str(0, "project_");
+        }
+
+        let mut variables = HashMap::from([
+            ("project_name".to_string(), project_name),
+            ("package_name".to_string(), package_name),
+            ("year".to_string(), current_year().to_string()),
+        ]);
+        for variable in &self.variables {
+            let value = provided
+                .get(&variable.name)
+                .filter(|value| !value.is_empty())
+                .or(variable.default.as_ref())
+                .ok_or_else(|| format!("a value is required for '{}'", variable.name))?;
+            variables.insert(variable.name.clone(), value.clone());
+        }
+        Ok(variables)
+    }
+
+    /// The category the template is listed under, [`DEFAULT_CATEGORY`] unless the manifest declares one.
+    pub fn category(&self) -> &str {
+        self.category
+            .as_deref()
+            .filter(|category| !category.trim().is_empty())
+            .unwrap_or(DEFAULT_CATEGORY)
+    }
+
+    /// The name suggested for a new project, `my-<template name>` unless the manifest declares one.
+    pub fn suggested_project_name(&self) -> String {
+        if let Some(name) = &self.suggested_name {
+            return name.clone();
+        }
+        let mut name = String::from("my");
+        for word in self
+            .name
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+        {
+            name.push('-');
+            name.push_str(&word.to_lowercase());
+        }
+        name
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("the template name can't be empty".to_string());
+        }
+        if let Some(name) = &self.suggested_name
+            && (name.trim().is_empty() || name.contains(['/', '\\']))
+        {
+            return Err(format!("'{name}' isn't a valid project name suggestion"));
+        }
+        self.toolchain.validate()?;
+        if let Some(hook) = &self.post_create {
+            hook.validate()?;
+        }
+        for asset in &self.assets {
+            let path = Path::new(asset);
+            if asset.trim().is_empty()
+                || path.is_absolute()
+                || path.components().any(|part| part == Component::ParentDir)
+            {
+                return Err(format!("'{asset}' isn't a path inside the assets folder"));
+            }
+        }
+        for (index, variable) in self.variables.iter().enumerate() {
+            let valid_name = !variable.name.is_empty()
+                && variable
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !valid_name {
+                return Err(format!(
+                    "variable name '{}' must only contain letters, digits and underscores",
+                    variable.name
+                ));
+            }
+            if self.variables[..index]
+                .iter()
+                .any(|other| other.name == variable.name)
+            {
+                return Err(format!("variable '{}' is declared twice", variable.name));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Copy the template files to `to`, returning the amount of entries skipped by its [`IgnorePatterns`].
+///
+/// The copy stops with an [`io::ErrorKind::Interrupted`] error between two entries once `cancel` is
+/// cancelled, a Git clone can't be stopped midway. A Git clone failing transiently is retried as
+/// set by the `retry` policy, each retry told to `on_retry`.
+pub(super) async fn copy_template(
+    template: &Templates,
+    to: &Path,
+    retry: &RetryPolicy,
+    cancel: &CancellationToken,
+    on_retry: impl FnMut(Retry),
+) -> io::Result<usize> {
+    match template {
+        Templates::Git { url, fresh_history } => {
+            clone_git_template(url, to, *fresh_history, retry, cancel, on_retry)?;
+            Ok(0)
+        }
+        template => {
+            let from = template.path().unwrap_or_default();
+            let ignore = IgnorePatterns::load(&from)?;
+            let skipped = clone_directory(&from, to, Path::new(""), &ignore, cancel)?;
+            // The built-in templates load their assets from there, even when they have none yet
+            if matches!(template, Templates::Blank | Templates::GettingStarted) {
+                std::fs::create_dir_all(to.join(ASSETS_FOLDER))?;
+            }
+            if skipped > 0 {
+                info!("Skipped {skipped} ignored entries of the template");
+            }
+            Ok(skipped)
+        }
+    }
 }
 
-pub(super) async fn copy_template(template: Templates, to: &Path) -> std::io::Result<()> {
-    let template_path = Path::new(TEMPLATE_FOLDER_PATH).join(TEMPLATE_NAMES[template as usize]);
-    clone_directory(template_path, to)?;
+/// Make sure the URL looks like one Git can clone, like `https://host/repo.git` or
+/// `git@host:repo.git`, returning why it doesn't otherwise.
+///
+/// The repository itself is only known to exist once cloned.
+pub fn validate_git_url(url: &str) -> Result<(), String> {
+    let url = url.trim();
+    if url.is_empty() {
+        return Err("enter the URL of the repository".to_string());
+    }
+    if url.chars().any(char::is_whitespace) {
+        return Err("a URL can't contain spaces".to_string());
+    }
+    if let Some((scheme, rest)) = url.split_once("://") {
+        if !matches!(scheme, "https" | "http" | "ssh" | "git" | "file") {
+            return Err(format!("'{scheme}' isn't a protocol Git can clone from"));
+        }
+        let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+        if (scheme != "file" && host.is_empty()) || path.trim_matches('/').is_empty() {
+            return Err("the URL needs a host and the path of the repository".to_string());
+        }
+        return Ok(());
+    }
+    // The scp-like syntax of the SSH URLs, `user@host:path`
+    if let Some((host, path)) = url.split_once(':')
+        && host.contains('@')
+        && !host.starts_with('@')
+        && !path.is_empty()
+    {
+        return Ok(());
+    }
+    Err("use a URL like https://host/repository.git or git@host:repository.git".to_string())
+}
+
+/// The error output of Git telling about a network failure that may not happen again, lowercase.
+const TRANSIENT_GIT_ERRORS: &[&str] = &[
+    "could not resolve host",
+    "connection timed out",
+    "operation timed out",
+    "connection reset",
+    "connection refused",
+    "failed to connect",
+    "network is unreachable",
+    "temporary failure in name resolution",
+    "the remote end hung up unexpectedly",
+    "early eof",
+    "rpc failed",
+];
+
+/// Whether the failure of a Git command is worth retrying, unlike a missing repository or a
+/// rejected authentication.
+fn is_transient_git_error(error: &io::Error) -> bool {
+    if matches!(
+        error.kind(),
+        io::ErrorKind::TimedOut
+            | io::ErrorKind::ConnectionReset
+            | io::ErrorKind::ConnectionAborted
+            | io::ErrorKind::ConnectionRefused
+    ) {
+        return true;
+    }
+    let message = error.to_string().to_lowercase();
+    TRANSIENT_GIT_ERRORS
+        .iter()
+        .any(|transient| message.contains(transient))
+}
+
+/// Clone the repository at `url` into `to`, replacing its history with a single commit if `fresh_history` is set.
+///
+/// The clone is retried as set by the `retry` policy when it fails transiently.
+fn clone_git_template(
+    url: &str,
+    to: &Path,
+    fresh_history: bool,
+    retry: &RetryPolicy,
+    cancel: &CancellationToken,
+    mut on_retry: impl FnMut(Retry),
+) -> io::Result<()> {
+    let args: &[&str] = if fresh_history {
+        info!("Cloning '{url}' with a fresh history");
+        &["clone", "--depth", "1", "--"]
+    } else {
+        info!("Cloning '{url}' keeping its history");
+        &["clone", "--"]
+    };
+    retry.run(
+        cancel,
+        || run_git(None, args, &[OsStr::new(url), to.as_os_str()]),
+        is_transient_git_error,
+        |attempt| {
+            warn!(
+                "Cloning '{url}' failed, retrying in {}s ({}/{}): {}",
+                attempt.delay.as_secs_f32(),
+                attempt.retry,
+                attempt.retries,
+                attempt.error
+            );
+            // Git refuses to clone into the files left by the failed attempt
+            if to
+                .read_dir()
+                .is_ok_and(|mut entries| entries.next().is_some())
+                && let Err(error) = std::fs::remove_dir_all(to)
+            {
+                warn!("Couldn't remove the failed clone: {error}");
+            }
+            on_retry(attempt);
+        },
+    )?;
+
+    if fresh_history {
+        std::fs::remove_dir_all(to.join(".git"))?;
+        run_git(Some(to), &["init"], &[])?;
+        run_git(Some(to), &["add", "--all"], &[])?;
+        // Committing fails when Git has no identity configured, the repository is still usable
+        if let Err(error) = run_git(
+            Some(to),
+            &["commit", "--message", "Initial commit from template"],
+            &[],
+        ) {
+            warn!("Couldn't create the initial commit: {error}");
+        }
+    }
     Ok(())
 }
 
-fn clone_directory<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> std::io::Result<()> {
-    let from = from.as_ref();
-    let to = to.as_ref();
-    std::fs::create_dir_all(to)?;
-    for entry in std::fs::read_dir(from)? {
-        let entry = entry?;
-        let path = entry.path();
-        let file_name = path.file_name().unwrap();
-        let new_path = to.join(file_name);
+/// Run a git command, failing with its error output if it doesn't succeed.
+fn run_git(dir: Option<&Path>, args: &[&str], paths: &[&OsStr]) -> io::Result<()> {
+    let mut command = Command::new("git");
+    command.args(args).args(paths);
+    if let Some(dir) = dir {
+        command.current_dir(dir);
+    }
+    let output = command.output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(())
+}
+
+/// How often a running [`PostCreateHook`] is checked for output, exit, cancellation and timeout.
+const POST_CREATE_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Run the [`PostCreateHook`] in the project folder at `dir`, passing every line it prints to
+/// `output` as it comes, and returning how it exited.
+///
+/// The command is killed once `cancel` is cancelled, failing with [`ProjectError::Cancelled`], or
+/// when it runs longer than its timeout, failing with [`ProjectError::PostCreate`].
+pub(super) fn run_post_create(
+    hook: &PostCreateHook,
+    dir: &Path,
+    variables: &HashMap<String, String>,
+    cancel: &CancellationToken,
+    output: &impl Fn(String),
+) -> Result<ExitStatus, ProjectError> {
+    let command = hook.resolved_command(variables);
+    let Some((program, args)) = command.split_first() else {
+        return Err(ProjectError::PostCreate("no program to run".to_string()));
+    };
+    let mut child = Command::new(program)
+        .args(args)
+        .current_dir(dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| ProjectError::PostCreate(format!("couldn't run '{program}': {error}")))?;
+
+    // The pipes are read on their own threads, a full pipe would block the command otherwise
+    let (sender, lines) = mpsc::channel();
+    if let Some(stdout) = child.stdout.take() {
+        forward_lines(stdout, sender.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        forward_lines(stderr, sender);
+    }
+
+    let timeout = Duration::from_secs(hook.timeout_secs);
+    let started = Instant::now();
+    let status = loop {
+        lines.try_iter().for_each(output);
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        let error = if cancel.is_cancelled() {
+            ProjectError::Cancelled
+        } else if started.elapsed() > timeout {
+            ProjectError::PostCreate(format!(
+                "'{program}' didn't finish within {} seconds",
+                hook.timeout_secs
+            ))
+        } else {
+            std::thread::sleep(POST_CREATE_POLL_INTERVAL);
+            continue;
+        };
+        let _ = child.kill();
+        let _ = child.wait();
+        return Err(error);
+    };
+    // The last lines may still be in the pipes, unless a process started by the command keeps them
+    // open after it exited
+    while let Ok(line) = lines.recv_timeout(Duration::from_millis(500)) {
+        output(line);
+    }
+    Ok(status)
+}
+
+/// Send every line read from `reader` to `sender`, on a new thread.
+fn forward_lines(reader: impl Read + Send + 'static, sender: mpsc::Sender<String>) {
+    std::thread::spawn(move || {
+        for line in BufReader::new(reader).lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Replace the `{{name}}` placeholders of the given variables in every text file under `root`.
+///
+/// Binary files, detected as non UTF-8 content, are left untouched.
+/// Returns the files that were modified.
+pub(super) fn substitute_placeholders(
+    root: &Path,
+    variables: &HashMap<String, String>,
+) -> io::Result<Vec<PathBuf>> {
+    let mut modified = Vec::new();
+    for entry in std::fs::read_dir(root)? {
+        let path = entry?.path();
         if path.is_dir() {
-            clone_directory(path, new_path)?;
+            // The history of the templates cloned from Git must stay untouched
+            if path.file_name().is_some_and(|name| name == ".git") {
+                continue;
+            }
+            modified.extend(substitute_placeholders(&path, variables)?);
+            continue;
+        }
+        let Ok(content) = String::from_utf8(std::fs::read(&path)?) else {
+            continue;
+        };
+        if let Some(content) = substitute(&content, variables) {
+            std::fs::write(&path, content)?;
+            modified.push(path);
+        }
+    }
+    Ok(modified)
+}
+
+/// Replace the placeholders in the given content, returns `None` if there was nothing to replace.
+fn substitute(content: &str, variables: &HashMap<String, String>) -> Option<String> {
+    let mut result = content.to_string();
+    for (name, value) in variables {
+        let placeholder = format!("{{{{{name}}}}}");
+        if result.contains(&placeholder) {
+            result = result.replace(&placeholder, value);
+        }
+    }
+    (result != content).then_some(result)
+}
+
+/// The current year in the proleptic Gregorian calendar.
+fn current_year() -> i64 {
+    let days = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() / 86_400) as i64;
+    // Civil-from-days algorithm, see https://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let year = year_of_era + era * 400;
+    if month_index >= 10 { year + 1 } else { year }
+}
+
+/// Copy the `relative` folder of `from` to `to`, skipping the ignored entries.
+///
+/// Returns the amount of skipped entries, an ignored folder counting as a single one.
+fn clone_directory(
+    from: &Path,
+    to: &Path,
+    relative: &Path,
+    ignore: &IgnorePatterns,
+    cancel: &CancellationToken,
+) -> io::Result<usize> {
+    std::fs::create_dir_all(to.join(relative))?;
+    let mut skipped = 0;
+    for entry in std::fs::read_dir(from.join(relative))? {
+        if cancel.is_cancelled() {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "cancelled"));
+        }
+        let entry = entry?;
+        let file_name = entry.file_name();
+        // The manifest and the ignore file describe the template, they aren't part of the project
+        if relative.as_os_str().is_empty()
+            && (file_name == MANIFEST_FILE || file_name == IGNORE_FILE)
+        {
+            continue;
+        }
+        let entry_path = relative.join(&file_name);
+        let is_dir = entry.path().is_dir();
+        if ignore.is_ignored(&entry_path, is_dir) {
+            skipped += 1;
+            continue;
+        }
+        if is_dir {
+            skipped += clone_directory(from, to, &entry_path, ignore, cancel)?;
         } else {
-            std::fs::copy(path, new_path)?;
+            std::fs::copy(from.join(&entry_path), to.join(&entry_path))?;
         }
     }
-    Ok(())
+    Ok(skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid_manifest() {
+        let manifest = TemplateManifest::parse(
+            r#"
+            name = "Blank"
+            description = "An empty project"
+
+            [[variables]]
+            name = "author"
+            default = "Anonymous"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.name, "Blank");
+        assert_eq!(manifest.suggested_project_name(), "my-blank");
+        assert_eq!(manifest.category(), DEFAULT_CATEGORY);
+        assert_eq!(manifest.variables.len(), 1);
+        assert_eq!(manifest.variables[0].default.as_deref(), Some("Anonymous"));
+    }
+
+    #[test]
+    fn resolve_and_substitute_variables() {
+        let manifest = TemplateManifest::parse(
+            r#"
+            name = "Blank"
+
+            [[variables]]
+            name = "author"
+            default = "Anonymous"
+
+            [[variables]]
+            name = "license"
+            "#,
+        )
+        .unwrap();
+        let path = Path::new("/projects/My Game");
+
+        assert!(manifest.resolve_variables(path, &HashMap::new()).is_err());
+
+        let provided = HashMap::from([("license".to_string(), "MIT".to_string())]);
+        let variables = manifest.resolve_variables(path, &provided).unwrap();
+        assert_eq!(variables["project_name"], "My Game");
+        assert_eq!(variables["package_name"], "my_game");
+        assert_eq!(variables["author"], "Anonymous");
+        let variables = manifest
+            .resolve_variables(Path::new("/projects/2d-game"), &provided)
+            .unwrap();
+        assert_eq!(variables["package_name"], "project_2d_game");
+        let variables = manifest.resolve_variables(path, &provided).unwrap();
+
+        assert_eq!(
+            substitute("name = \"{{package_name}}\" # {{license}}", &variables).as_deref(),
+            Some("name = \"my_game\" # MIT")
+        );
+        assert_eq!(substitute("{{unknown}}", &variables), None);
+        assert!(current_year() >= 2024);
+    }
+
+    #[test]
+    fn git_templates_are_named_after_their_repository() {
+        let template = Templates::Git {
+            url: "https://github.com/bevyengine/bevy_github_ci_template.git".to_string(),
+            fresh_history: true,
+        };
+        assert_eq!(template.manifest().unwrap().name, "bevy_github_ci_template");
+        assert_eq!(
+            template.manifest().unwrap().suggested_project_name(),
+            "my-bevy-github-ci-template"
+        );
+        assert_eq!(template.manifest().unwrap().category(), GIT_CATEGORY);
+        assert_eq!(template.path(), None);
+    }
+
+    #[test]
+    fn find_the_usable_templates() {
+        let folder =
+            std::env::temp_dir().join(format!("bevy_editor_templates_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&folder);
+        let installed = folder.join("installed");
+        std::fs::create_dir_all(installed.join(TEMPLATE_NAMES[1])).unwrap();
+        std::fs::write(folder.join("file"), "not a template").unwrap();
+
+        let missing = folder.join("missing");
+        assert_eq!(
+            resolve_templates_folder(&[missing.clone(), installed.clone()]),
+            installed
+        );
+        assert_eq!(
+            resolve_templates_folder(std::slice::from_ref(&missing)),
+            missing,
+            "the expected folder when none has templates"
+        );
+
+        let templates = [
+            Templates::Custom(installed.join(TEMPLATE_NAMES[1])),
+            Templates::Custom(missing.clone()),
+            Templates::Custom(folder.join("file")),
+            Templates::Git {
+                url: "https://example.com/template.git".to_string(),
+                fresh_history: false,
+            },
+        ];
+        let (usable, unusable) = check_templates(&templates);
+        assert_eq!(usable, vec![templates[0].clone(), templates[3].clone()]);
+        assert_eq!(unusable.len(), 2);
+        assert!(unusable[0].1.contains("doesn't exist"), "{}", unusable[0].1);
+        assert!(
+            unusable[1].1.contains("isn't a folder"),
+            "{}",
+            unusable[1].1
+        );
+        assert_eq!(unusable[0].0.display_name(), "missing");
+        let _ = std::fs::remove_dir_all(&folder);
+    }
+
+    #[test]
+    fn validate_git_urls() {
+        for url in [
+            "https://github.com/bevyengine/bevy_github_ci_template.git",
+            " https://example.com/repo ",
+            "ssh://git@example.com/repo.git",
+            "git@github.com:bevyengine/bevy.git",
+            "file:///templates/repo",
+        ] {
+            assert_eq!(validate_git_url(url), Ok(()), "{url}");
+        }
+        for url in [
+            "",
+            "https://",
+            "https://example.com",
+            "https://example.com/my repo",
+            "ftp://example.com/repo",
+            "github.com/bevyengine/bevy",
+            "@host:repo",
+        ] {
+            assert!(validate_git_url(url).is_err(), "{url}");
+        }
+    }
+
+    #[test]
+    fn retry_only_the_network_failures() {
+        for message in [
+            "git clone failed: fatal: unable to access 'https://example.com/': Could not resolve host: example.com",
+            "git clone failed: error: RPC failed; curl 56 GnuTLS recv error\nfatal: early EOF",
+        ] {
+            assert!(
+                is_transient_git_error(&io::Error::other(message)),
+                "{message}"
+            );
+        }
+        assert!(is_transient_git_error(&io::ErrorKind::TimedOut.into()));
+        for message in [
+            "git clone failed: fatal: repository 'https://example.com/missing.git/' not found",
+            "git clone failed: fatal: Authentication failed for 'https://example.com/private.git/'",
+        ] {
+            assert!(
+                !is_transient_git_error(&io::Error::other(message)),
+                "{message}"
+            );
+        }
+    }
+
+    #[test]
+    fn reject_malformed_manifests() {
+        assert!(TemplateManifest::parse("description = \"no name\"").is_err());
+        assert!(TemplateManifest::parse("name = \"\"").is_err());
+        assert!(TemplateManifest::parse("name = \"A\"\n[toolchain]\nedition = \"2077\"").is_err());
+        assert!(TemplateManifest::parse("name = \"A\"\nunknown = 1").is_err());
+        assert!(TemplateManifest::parse("name = \"A\"\nsuggested_name = \"a/b\"").is_err());
+        assert!(
+            TemplateManifest::parse("name = \"A\"\n[[variables]]\nname = \"not valid\"").is_err()
+        );
+        assert!(
+            TemplateManifest::parse(
+                "name = \"A\"\n[[variables]]\nname = \"a\"\n[[variables]]\nname = \"a\""
+            )
+            .is_err()
+        );
+        assert!(TemplateManifest::parse("name = \"A\"\n[post_create]\ncommand = []").is_err());
+        assert!(
+            TemplateManifest::parse(
+                "name = \"A\"\n[post_create]\ncommand = [\"make\"]\ntimeout_secs = 0"
+            )
+            .is_err()
+        );
+        assert!(TemplateManifest::parse("name = \"A\"\nassets = [\"../secret.png\"]").is_err());
+        assert!(TemplateManifest::parse("name = \"A\"\nassets = [\"models/tile.glb\"]").is_ok());
+    }
+
+    #[test]
+    fn show_the_post_create_command() {
+        let manifest = TemplateManifest::parse(
+            r#"
+            name = "Codegen"
+
+            [post_create]
+            command = ["cargo", "run", "--", "{{package_name}}", "two words"]
+            "#,
+        )
+        .unwrap();
+        let hook = manifest.post_create.unwrap();
+        assert_eq!(hook.timeout_secs, DEFAULT_POST_CREATE_TIMEOUT_SECS);
+        let variables = HashMap::from([("package_name".to_string(), "my_game".to_string())]);
+        assert_eq!(
+            hook.command_line(&variables),
+            "cargo run -- my_game \"two words\""
+        );
+    }
 }
@@ -0,0 +1,47 @@
+//! The thumbnails of the projects, screenshots of the editor shown by the launcher.
+//!
+//! The thumbnail of a project is stored at [`THUMBNAIL_FILE`] in the project folder, the
+//! `.launcher` folder can be ignored by version control. When the launcher runs a project, it asks
+//! the editor to capture it through the [`THUMBNAIL_REQUEST_VAR`] environment variable, holding
+//! the path to write the screenshot to. The editor should capture its window once, after the
+//! project had a moment to load, see [`thumbnail_request`].
+
+use std::path::{Path, PathBuf};
+
+/// The thumbnail of a project, relative to the project root.
+pub const THUMBNAIL_FILE: &str = ".launcher/thumbnail.png";
+
+/// The environment variable holding where the editor should write the thumbnail of the project,
+/// set by [`run_project_at`](super::run_project_at).
+pub const THUMBNAIL_REQUEST_VAR: &str = "BEVY_EDITOR_CAPTURE_THUMBNAIL";
+
+/// The thumbnail of the project at `path`, which may not exist yet.
+pub fn thumbnail_path(path: &Path) -> PathBuf {
+    path.join(THUMBNAIL_FILE)
+}
+
+/// Where the launcher asked the editor to write the thumbnail, from the [`THUMBNAIL_REQUEST_VAR`].
+///
+/// Like for [`open_target`](super::open_target), a relative path is resolved against the working
+/// directory, the project root when run by the launcher. The parent folder may not exist yet.
+pub fn thumbnail_request() -> Option<PathBuf> {
+    let path = std::env::var_os(THUMBNAIL_REQUEST_VAR).filter(|path| !path.is_empty())?;
+    let path = PathBuf::from(path);
+    match std::env::current_dir() {
+        Ok(root) if path.is_relative() => Some(root.join(path)),
+        _ => Some(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumbnail_in_the_project_folder() {
+        assert_eq!(
+            thumbnail_path(Path::new("/projects/game")),
+            Path::new("/projects/game/.launcher/thumbnail.png")
+        );
+    }
+}
@@ -0,0 +1,461 @@
+//! Module to query the installed Rust toolchain and check it against the requirements of a template.
+//!
+//! It also reads the toolchain a project pins in its [`TOOLCHAIN_FILE`], see [`pinned_toolchain`].
+//! Rustup picks that toolchain by itself when cargo runs in the project, the launcher only makes
+//! sure nothing overrides it and reports which one is used.
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    str::FromStr,
+};
+
+use serde::Deserialize;
+
+/// A Rust version, like `1.85.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RustVersion {
+    /// The major version.
+    pub major: u32,
+    /// The minor version.
+    pub minor: u32,
+    /// The patch version.
+    pub patch: u32,
+}
+
+impl RustVersion {
+    /// Create a new [`RustVersion`].
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+}
+
+impl FromStr for RustVersion {
+    type Err = String;
+
+    /// Parse a version like `1.85` or `1.85.0`.
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("'{version}' isn't a valid Rust version");
+        let mut parts = version.trim().split('.').map(|part| part.parse::<u32>());
+        let major = parts.next().ok_or_else(invalid)?.map_err(|_| invalid())?;
+        let minor = parts.next().ok_or_else(invalid)?.map_err(|_| invalid())?;
+        let patch = parts.next().unwrap_or(Ok(0)).map_err(|_| invalid())?;
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(Self::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for RustVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The release channel of a Rust toolchain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolchainChannel {
+    /// A stable release.
+    Stable,
+    /// A beta release.
+    Beta,
+    /// A nightly release, with unstable features available.
+    Nightly,
+}
+
+/// The installed Rust toolchain, as reported by `rustc --version`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolchainInfo {
+    /// The version of `rustc`.
+    pub version: RustVersion,
+    /// The release channel of `rustc`.
+    pub channel: ToolchainChannel,
+}
+
+impl ToolchainInfo {
+    /// Query the installed toolchain, making sure both `rustc` and `cargo` are available.
+    pub fn query() -> io::Result<Self> {
+        let output = Command::new("cargo").arg("--version").output()?;
+        if !output.status.success() {
+            return Err(io::Error::other("cargo --version failed"));
+        }
+
+        let output = Command::new("rustc").arg("--version").output()?;
+        if !output.status.success() {
+            return Err(io::Error::other("rustc --version failed"));
+        }
+        Self::parse(&String::from_utf8_lossy(&output.stdout))
+            .map_err(|reason| io::Error::new(io::ErrorKind::InvalidData, reason))
+    }
+
+    /// Parse the output of `rustc --version`, like `rustc 1.85.0 (4d91de4e4 2025-02-17)`.
+    pub fn parse(output: &str) -> Result<Self, String> {
+        let version = output
+            .split_whitespace()
+            .nth(1)
+            .ok_or_else(|| format!("unexpected rustc version output '{}'", output.trim()))?;
+        let (number, tag) = version.split_once('-').unwrap_or((version, ""));
+        let channel = if tag.starts_with("nightly") || tag == "dev" {
+            ToolchainChannel::Nightly
+        } else if tag.starts_with("beta") {
+            ToolchainChannel::Beta
+        } else {
+            ToolchainChannel::Stable
+        };
+        Ok(Self {
+            version: number.parse()?,
+            channel,
+        })
+    }
+}
+
+/// The toolchain requirements declared in the `[toolchain]` table of a template manifest.
+///
+/// ```toml
+/// [toolchain]
+/// min_rust_version = "1.85"
+/// edition = "2024"
+/// nightly = false
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ToolchainRequirements {
+    /// The oldest `rustc` version able to build the template.
+    #[serde(default)]
+    pub min_rust_version: Option<String>,
+    /// The Rust edition used by the template, which requires a minimum `rustc` version.
+    #[serde(default)]
+    pub edition: Option<String>,
+    /// Whether the template uses unstable features, only available on nightly.
+    #[serde(default)]
+    pub nightly: bool,
+}
+
+impl ToolchainRequirements {
+    /// Make sure the requirements are well-formed.
+    pub(super) fn validate(&self) -> Result<(), String> {
+        if let Some(version) = &self.min_rust_version {
+            version.parse::<RustVersion>()?;
+        }
+        if let Some(edition) = &self.edition {
+            edition_rust_version(edition)?;
+        }
+        Ok(())
+    }
+
+    /// List the requirements the given toolchain doesn't satisfy, `None` meaning no toolchain was found.
+    pub fn unmet(&self, toolchain: Option<&ToolchainInfo>) -> Vec<String> {
+        let Some(toolchain) = toolchain else {
+            return vec!["No Rust toolchain found, install one from https://rustup.rs".to_string()];
+        };
+
+        let mut unmet = Vec::new();
+        if let Some(Ok(required)) = self
+            .min_rust_version
+            .as_deref()
+            .map(str::parse::<RustVersion>)
+            && toolchain.version < required
+        {
+            unmet.push(format!(
+                "Rust {required} or newer is required, {} is installed",
+                toolchain.version
+            ));
+        }
+        if let Some(edition) = &self.edition
+            && let Ok(required) = edition_rust_version(edition)
+            && toolchain.version < required
+        {
+            unmet.push(format!(
+                "The {edition} edition requires Rust {required} or newer, {} is installed",
+                toolchain.version
+            ));
+        }
+        if self.nightly && toolchain.channel != ToolchainChannel::Nightly {
+            unmet.push("A nightly toolchain is required".to_string());
+        }
+        unmet
+    }
+}
+
+/// The first Rust version supporting the given edition.
+fn edition_rust_version(edition: &str) -> Result<RustVersion, String> {
+    match edition {
+        "2015" => Ok(RustVersion::new(1, 0, 0)),
+        "2018" => Ok(RustVersion::new(1, 31, 0)),
+        "2021" => Ok(RustVersion::new(1, 56, 0)),
+        "2024" => Ok(RustVersion::new(1, 85, 0)),
+        _ => Err(format!("unknown Rust edition '{edition}'")),
+    }
+}
+
+/// The file pinning the toolchain of a project, read by rustup.
+pub const TOOLCHAIN_FILE: &str = "rust-toolchain.toml";
+/// The older name of the [`TOOLCHAIN_FILE`], used over it by rustup when both exist.
+const LEGACY_TOOLCHAIN_FILE: &str = "rust-toolchain";
+/// The environment variable overriding the toolchain rustup picks.
+pub const RUSTUP_TOOLCHAIN_VAR: &str = "RUSTUP_TOOLCHAIN";
+
+/// The toolchain pinned by a project, see [`pinned_toolchain`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PinnedToolchain {
+    /// The toolchain, like `stable`, `1.85` or `nightly-2025-06-01`.
+    pub channel: String,
+    /// The file pinning it.
+    pub file: PathBuf,
+}
+
+/// The `[toolchain]` table of a [`TOOLCHAIN_FILE`], only the channel is read.
+#[derive(Deserialize)]
+struct ToolchainFile {
+    toolchain: ToolchainFileSection,
+}
+
+#[derive(Deserialize)]
+struct ToolchainFileSection {
+    #[serde(default)]
+    channel: Option<String>,
+}
+
+/// Parse the channel out of a toolchain file, either a [`TOOLCHAIN_FILE`] or an older one with only
+/// the channel in it.
+///
+/// Returns `None` when the file only lists components or targets, for the default toolchain.
+pub fn parse_toolchain_file(content: &str) -> Result<Option<String>, String> {
+    let content = content.trim();
+    if !content.is_empty() && !content.contains(['\n', '=', '[']) {
+        validate_toolchain_channel(content)?;
+        return Ok(Some(content.to_string()));
+    }
+    let file: ToolchainFile = toml::from_str(content).map_err(|error| error.to_string())?;
+    match file.toolchain.channel {
+        Some(channel) => {
+            validate_toolchain_channel(&channel)?;
+            Ok(Some(channel))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Make sure the toolchain can be written in a [`TOOLCHAIN_FILE`] and is named like rustup names
+/// them.
+pub fn validate_toolchain_channel(channel: &str) -> Result<(), String> {
+    if channel.is_empty() {
+        return Err("the toolchain is empty".to_string());
+    }
+    if let Some(invalid) = channel
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_')))
+    {
+        return Err(format!(
+            "'{channel}' isn't a toolchain, it has a '{invalid}'"
+        ));
+    }
+    Ok(())
+}
+
+/// The toolchain pinned in the folder of the project, `None` when it doesn't pin one.
+///
+/// Only the project folder is read, though rustup also looks for the file in the parent folders.
+pub fn pinned_toolchain(project: &Path) -> Result<Option<PinnedToolchain>, String> {
+    for name in [LEGACY_TOOLCHAIN_FILE, TOOLCHAIN_FILE] {
+        let file = project.join(name);
+        let content = match fs::read_to_string(&file) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+            Err(error) => return Err(format!("'{name}' can't be read: {error}")),
+        };
+        let channel = parse_toolchain_file(&content)
+            .map_err(|reason| format!("'{name}' is invalid: {reason}"))?;
+        return Ok(channel.map(|channel| PinnedToolchain { channel, file }));
+    }
+    Ok(None)
+}
+
+/// Pin the toolchain of the project in a new [`TOOLCHAIN_FILE`], returning its path.
+///
+/// Fails with [`io::ErrorKind::AlreadyExists`] when the project already has a toolchain file.
+pub fn write_toolchain_file(project: &Path, channel: &str) -> io::Result<PathBuf> {
+    validate_toolchain_channel(channel)
+        .map_err(|reason| io::Error::new(io::ErrorKind::InvalidInput, reason))?;
+    let legacy = project.join(LEGACY_TOOLCHAIN_FILE);
+    if legacy.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("'{LEGACY_TOOLCHAIN_FILE}' already pins the toolchain"),
+        ));
+    }
+    let path = project.join(TOOLCHAIN_FILE);
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)?;
+    writeln!(file, "[toolchain]\nchannel = \"{channel}\"")?;
+    Ok(path)
+}
+
+/// The toolchains installed with rustup, with their host, like `stable-x86_64-unknown-linux-gnu`.
+pub fn installed_toolchains() -> io::Result<Vec<String>> {
+    let output = Command::new("rustup")
+        .args(["toolchain", "list"])
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other("rustup toolchain list failed"));
+    }
+    Ok(parse_toolchain_list(&String::from_utf8_lossy(
+        &output.stdout,
+    )))
+}
+
+/// Parse the output of `rustup toolchain list`, leaving out the `(default)` and `(active)` marks.
+pub fn parse_toolchain_list(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| line.split_whitespace().next())
+        .filter(|name| !name.is_empty() && *name != "no")
+        .map(str::to_string)
+        .collect()
+}
+
+/// Whether the toolchain, named as in a [`TOOLCHAIN_FILE`], is among the `installed` ones.
+///
+/// The installed toolchains are named with their host, `nightly` is installed as
+/// `nightly-x86_64-unknown-linux-gnu` while `nightly-2025-06-01-x86_64-unknown-linux-gnu` is
+/// another toolchain.
+pub fn is_toolchain_installed(channel: &str, installed: &[String]) -> bool {
+    installed.iter().any(|name| {
+        name == channel
+            || name
+                .strip_prefix(channel)
+                .and_then(|host| host.strip_prefix('-'))
+                .is_some_and(|host| !host.starts_with(|c: char| c.is_ascii_digit()))
+    })
+}
+
+/// Make cargo run in the project with the toolchain it pins, returning the toolchain used to
+/// report it, `None` when it is the default one.
+///
+/// Rustup sets [`RUSTUP_TOOLCHAIN_VAR`] for the processes it runs, like the launcher started with
+/// `cargo run`, which would override the pinned toolchain. The inherited variable is removed,
+/// unless the project sets it in its `env`, which then picks the toolchain.
+pub fn use_project_toolchain(
+    command: &mut Command,
+    project: &Path,
+    env: &BTreeMap<String, String>,
+) -> Option<String> {
+    if let Some(channel) = env.get(RUSTUP_TOOLCHAIN_VAR) {
+        return Some(format!("'{channel}', set by {RUSTUP_TOOLCHAIN_VAR}"));
+    }
+    let Ok(Some(pinned)) = pinned_toolchain(project) else {
+        return None;
+    };
+    command.env_remove(RUSTUP_TOOLCHAIN_VAR);
+    let file = pinned
+        .file
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy();
+    Some(format!("'{}', pinned by {file}", pinned.channel))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rustc_version() {
+        let stable = ToolchainInfo::parse("rustc 1.85.0 (4d91de4e4 2025-02-17)").unwrap();
+        assert_eq!(stable.version, RustVersion::new(1, 85, 0));
+        assert_eq!(stable.channel, ToolchainChannel::Stable);
+
+        let nightly = ToolchainInfo::parse("rustc 1.90.0-nightly (abc123 2025-06-01)").unwrap();
+        assert_eq!(nightly.channel, ToolchainChannel::Nightly);
+
+        assert!(ToolchainInfo::parse("command not found").is_err());
+        assert_eq!("1.85".parse(), Ok(RustVersion::new(1, 85, 0)));
+        assert!("1.x".parse::<RustVersion>().is_err());
+    }
+
+    #[test]
+    fn report_unmet_requirements() {
+        let requirements = ToolchainRequirements {
+            min_rust_version: Some("1.88".to_string()),
+            edition: Some("2024".to_string()),
+            nightly: true,
+        };
+        let old = ToolchainInfo {
+            version: RustVersion::new(1, 80, 1),
+            channel: ToolchainChannel::Stable,
+        };
+        assert_eq!(requirements.unmet(Some(&old)).len(), 3);
+        assert_eq!(requirements.unmet(None).len(), 1);
+
+        let recent = ToolchainInfo {
+            version: RustVersion::new(1, 90, 0),
+            channel: ToolchainChannel::Nightly,
+        };
+        assert!(requirements.unmet(Some(&recent)).is_empty());
+    }
+
+    #[test]
+    fn read_the_pinned_toolchain() {
+        assert_eq!(
+            parse_toolchain_file("[toolchain]\nchannel = \"1.85\"\ncomponents = [\"clippy\"]\n"),
+            Ok(Some("1.85".to_string()))
+        );
+        assert_eq!(
+            parse_toolchain_file("nightly-2025-06-01\n"),
+            Ok(Some("nightly-2025-06-01".to_string()))
+        );
+        assert_eq!(
+            parse_toolchain_file("[toolchain]\ntargets = [\"wasm32-unknown-unknown\"]"),
+            Ok(None)
+        );
+        assert!(parse_toolchain_file("[toolchain]\nchannel = \"1.85 beta\"").is_err());
+        assert!(parse_toolchain_file("channel = \"1.85\"").is_err());
+
+        let installed = parse_toolchain_list(
+            "stable-x86_64-unknown-linux-gnu (default)\n\
+             nightly-2025-06-01-x86_64-unknown-linux-gnu\n\
+             1.85.0-x86_64-unknown-linux-gnu (active)\n",
+        );
+        assert_eq!(installed.len(), 3);
+        assert!(is_toolchain_installed("stable", &installed));
+        assert!(is_toolchain_installed("1.85.0", &installed));
+        assert!(is_toolchain_installed("nightly-2025-06-01", &installed));
+        assert!(!is_toolchain_installed("nightly", &installed));
+        assert!(!is_toolchain_installed("1.85", &installed));
+        assert!(parse_toolchain_list("no installed toolchains\n").is_empty());
+
+        let project =
+            std::env::temp_dir().join(format!("bevy_editor_toolchain_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&project);
+        fs::create_dir_all(&project).unwrap();
+        assert_eq!(pinned_toolchain(&project), Ok(None));
+        let file = write_toolchain_file(&project, "1.88").unwrap();
+        assert_eq!(
+            pinned_toolchain(&project),
+            Ok(Some(PinnedToolchain {
+                channel: "1.88".to_string(),
+                file,
+            }))
+        );
+        assert_eq!(
+            write_toolchain_file(&project, "stable").unwrap_err().kind(),
+            io::ErrorKind::AlreadyExists
+        );
+        assert_eq!(
+            write_toolchain_file(&project, "").unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+        let _ = fs::remove_dir_all(&project);
+    }
+}
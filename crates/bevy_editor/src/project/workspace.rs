@@ -0,0 +1,222 @@
+//! Module to find the member crates of the projects that are cargo workspaces.
+
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use super::scan::read_manifest;
+
+/// A crate of a cargo workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceMember {
+    /// The name of the package, given to `cargo run -p`.
+    pub name: String,
+    /// The folder of the crate.
+    pub path: PathBuf,
+    /// Whether the crate has a binary that can be run, a `src/main.rs`, `src/bin` or `[[bin]]`.
+    pub is_binary: bool,
+}
+
+/// Whether the `Cargo.toml` of the folder declares a `[workspace]`.
+pub fn is_workspace(path: &Path) -> bool {
+    read_manifest(path).is_some_and(|manifest| manifest.contains_key("workspace"))
+}
+
+/// Whether the `Cargo.toml` of the folder is a virtual manifest, a `[workspace]` without a root package.
+pub fn is_virtual_manifest(path: &Path) -> bool {
+    read_manifest(path).is_some_and(|manifest| {
+        manifest.contains_key("workspace") && !manifest.contains_key("package")
+    })
+}
+
+/// The member crates of the workspace in the folder, sorted by name, `None` if it isn't a workspace.
+///
+/// The `members` may use `*` and `?` wildcards, like `crates/*`, and the `exclude`d folders are
+/// skipped. The root package, if any, is a member even when it isn't listed, like cargo does.
+/// The folders without a readable `Cargo.toml` naming their package are ignored.
+pub fn workspace_members(path: &Path) -> Option<Vec<WorkspaceMember>> {
+    let manifest = read_manifest(path)?;
+    let workspace = manifest.get("workspace")?;
+    let patterns = |key: &str| -> Vec<&str> {
+        workspace
+            .get(key)
+            .and_then(|value| value.as_array())
+            .map(|values| values.iter().filter_map(|value| value.as_str()).collect())
+            .unwrap_or_default()
+    };
+    let excluded: Vec<PathBuf> = patterns("exclude")
+        .into_iter()
+        .map(|exclude| path.join(exclude))
+        .collect();
+
+    let mut folders: Vec<PathBuf> = patterns("members")
+        .into_iter()
+        .flat_map(|pattern| expand_member_pattern(path, pattern))
+        .filter(|folder| !excluded.iter().any(|excluded| folder.starts_with(excluded)))
+        .collect();
+    if manifest.contains_key("package") {
+        folders.push(path.to_path_buf());
+    }
+
+    let mut members: Vec<WorkspaceMember> = Vec::new();
+    for folder in folders {
+        let Some(member) = read_member(&folder) else {
+            continue;
+        };
+        if !members.iter().any(|listed| listed.name == member.name) {
+            members.push(member);
+        }
+    }
+    members.sort_by(|a, b| a.name.cmp(&b.name));
+    Some(members)
+}
+
+/// The [`WorkspaceMember`] in the folder, if it holds a package.
+fn read_member(folder: &Path) -> Option<WorkspaceMember> {
+    let manifest = read_manifest(folder)?;
+    let name = manifest.get("package")?.get("name")?.as_str()?.to_string();
+    let declares_binaries = manifest
+        .get("bin")
+        .and_then(|bins| bins.as_array())
+        .is_some_and(|bins| !bins.is_empty());
+    let is_binary = declares_binaries
+        || folder.join("src/main.rs").is_file()
+        || folder.join("src/bin").is_dir();
+    Some(WorkspaceMember {
+        name,
+        path: folder.to_path_buf(),
+        is_binary,
+    })
+}
+
+/// The folders matching a `members` pattern of the workspace at `root`.
+fn expand_member_pattern(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut folders = vec![root.to_path_buf()];
+    for component in Path::new(pattern).components() {
+        let Component::Normal(component) = component else {
+            // `.` and `..` are kept as is, cargo allows members outside of the workspace folder
+            folders.iter_mut().for_each(|folder| folder.push(component));
+            continue;
+        };
+        let component = component.to_string_lossy();
+        if !component.contains(['*', '?']) {
+            folders
+                .iter_mut()
+                .for_each(|folder| folder.push(&*component));
+            continue;
+        }
+        folders = folders
+            .iter()
+            .filter_map(|folder| fs::read_dir(folder).ok())
+            .flat_map(|entries| entries.filter_map(Result::ok))
+            .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+            .filter(|entry| matches_wildcards(&entry.file_name().to_string_lossy(), &component))
+            .map(|entry| entry.path())
+            .collect();
+    }
+    folders.retain(|folder| folder.is_dir());
+    folders
+}
+
+/// Whether the name matches the pattern, `*` matching any characters and `?` a single one.
+fn matches_wildcards(name: &str, pattern: &str) -> bool {
+    let name: Vec<char> = name.chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    // Where the last `*` is in the pattern, and the name character it matched up to
+    let mut backtrack = None;
+    let (mut n, mut p) = (0, 0);
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some('?') => (n, p) = (n + 1, p + 1),
+            Some(c) if *c == name[n] => (n, p) = (n + 1, p + 1),
+            _ => match backtrack {
+                // Let the last `*` match one more character
+                Some((star, matched)) => {
+                    backtrack = Some((star, matched + 1));
+                    (n, p) = (matched + 1, star + 1);
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_manifest(folder: &Path, manifest: &str) {
+        fs::create_dir_all(folder).unwrap();
+        fs::write(folder.join("Cargo.toml"), manifest).unwrap();
+    }
+
+    #[test]
+    fn match_wildcards() {
+        assert!(matches_wildcards("game", "*"));
+        assert!(matches_wildcards("game_client", "game_*"));
+        assert!(matches_wildcards("a_b_c", "*_c"));
+        assert!(matches_wildcards("tool1", "tool?"));
+        assert!(!matches_wildcards("tool12", "tool?"));
+        assert!(!matches_wildcards("server", "game_*"));
+    }
+
+    #[test]
+    fn find_the_workspace_members() {
+        let root =
+            std::env::temp_dir().join(format!("bevy_editor_workspace_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write_manifest(
+            &root,
+            "[workspace]\nmembers = [\"crates/*\", \"tools/editor\"]\nexclude = [\"crates/old\"]\n",
+        );
+        write_manifest(
+            &root.join("crates/game"),
+            "[package]\nname = \"game\"\n\n[dependencies]\nbevy = \"0.16\"\n",
+        );
+        fs::create_dir_all(root.join("crates/game/src")).unwrap();
+        fs::write(root.join("crates/game/src/main.rs"), "fn main() {}").unwrap();
+        write_manifest(
+            &root.join("crates/physics"),
+            "[package]\nname = \"physics\"\n",
+        );
+        write_manifest(&root.join("crates/old"), "[package]\nname = \"old\"\n");
+        write_manifest(
+            &root.join("tools/editor"),
+            "[package]\nname = \"level_editor\"\n\n[[bin]]\nname = \"editor\"\npath = \"main.rs\"\n",
+        );
+        fs::create_dir_all(root.join("crates/notes")).unwrap();
+
+        assert!(is_workspace(&root));
+        assert!(is_virtual_manifest(&root));
+        let members = workspace_members(&root).unwrap();
+        let names: Vec<(&str, bool)> = members
+            .iter()
+            .map(|member| (member.name.as_str(), member.is_binary))
+            .collect();
+        assert_eq!(
+            names,
+            [("game", true), ("level_editor", true), ("physics", false)]
+        );
+        assert_eq!(members[0].path, root.join("crates/game"));
+
+        // The root package is a member of its own workspace
+        write_manifest(
+            &root,
+            "[package]\nname = \"launcher\"\n\n[workspace]\nmembers = [\"crates/game\"]\n",
+        );
+        assert!(!is_virtual_manifest(&root));
+        let members = workspace_members(&root).unwrap();
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[1].name, "launcher");
+        assert_eq!(members[1].path, root);
+
+        assert_eq!(workspace_members(&root.join("crates/game")), None);
+        let _ = fs::remove_dir_all(&root);
+    }
+}
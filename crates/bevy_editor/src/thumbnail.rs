@@ -0,0 +1,69 @@
+//! Capturing the thumbnail of the project when the launcher asks for it.
+//!
+//! See [`project::thumbnail`](crate::project::thumbnail) for the convention.
+
+use std::{fs, path::PathBuf, time::Duration};
+
+use bevy::{
+    prelude::*,
+    render::view::screenshot::{Screenshot, save_to_disk},
+};
+
+use crate::project::thumbnail::thumbnail_request;
+
+/// How long the editor runs before capturing the thumbnail, so the scene had time to load.
+const CAPTURE_DELAY: Duration = Duration::from_secs(5);
+
+pub(crate) struct ThumbnailCapturePlugin;
+
+impl Plugin for ThumbnailCapturePlugin {
+    fn build(&self, app: &mut App) {
+        if let Some(path) = thumbnail_request() {
+            app.insert_resource(ThumbnailRequest {
+                path,
+                timer: Timer::new(CAPTURE_DELAY, TimerMode::Once),
+            })
+            .add_systems(
+                Update,
+                capture_thumbnail.run_if(resource_exists::<ThumbnailRequest>),
+            );
+        }
+    }
+}
+
+/// Where to write the thumbnail, once the timer finishes.
+#[derive(Resource)]
+struct ThumbnailRequest {
+    path: PathBuf,
+    timer: Timer,
+}
+
+/// Take a screenshot of the primary window once the [`CAPTURE_DELAY`] is over.
+///
+/// The request is dropped after, a single thumbnail is captured per run.
+fn capture_thumbnail(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut request: ResMut<ThumbnailRequest>,
+) {
+    if !request.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    commands.remove_resource::<ThumbnailRequest>();
+    if let Some(folder) = request.path.parent()
+        && let Err(error) = fs::create_dir_all(folder)
+    {
+        warn!(
+            "Couldn't create the thumbnail folder '{}': {error}",
+            folder.display()
+        );
+        return;
+    }
+    info!(
+        "Capturing the project thumbnail to '{}'",
+        request.path.display()
+    );
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(request.path.clone()));
+}
@@ -1,7 +1,10 @@
 use bevy::prelude::*;
 
 use bevy_editor_styles::Theme;
-use bevy_footer_bar::{FooterBarNode, FooterBarPlugin, FooterBarSet};
+use bevy_footer_bar::{
+    FooterBarAppExt, FooterBarNode, FooterBarPlugin, FooterBarSet, FooterSlot, footer_version,
+    spawn_footer_info, spawn_footer_progress, spawn_footer_status,
+};
 use bevy_menu_bar::{MenuBarNode, MenuBarPlugin, MenuBarSet};
 use bevy_pane_layout::{PaneLayoutPlugin, PaneLayoutSet, RootPaneLayoutNode};
 use bevy_properties_pane::PropertiesPanePlugin;
@@ -25,7 +28,15 @@ impl Plugin for EditorUIPlugin {
                 FooterBarPlugin,
                 SceneTreePlugin,
                 PropertiesPanePlugin,
-            ));
+            ))
+            .add_footer_widget(FooterSlot::Left, 0, spawn_footer_status)
+            .add_footer_widget(FooterSlot::Left, 1, spawn_footer_progress)
+            .add_footer_widget(FooterSlot::Right, 0, spawn_footer_info)
+            .add_footer_widget(
+                FooterSlot::Right,
+                1,
+                footer_version(env!("CARGO_PKG_VERSION")),
+            );
     }
 }
 
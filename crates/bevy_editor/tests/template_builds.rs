@@ -0,0 +1,84 @@
+//! Make sure the projects generated from the built-in templates build, catching the templates
+//! broken by a Bevy or editor upgrade.
+//!
+//! The check builds the whole editor for every template, so it is ignored by default. Run it with:
+//!
+//! ```sh
+//! cargo test -p bevy_editor --test template_builds -- --ignored
+//! ```
+//!
+//! The generated projects depend on the `bevy_editor` of this repository instead of the published
+//! one, and share a target folder under `target/template_builds`. They are registered in a
+//! [`MemoryProjectStore`], the project list of the machine stays untouched. The custom and Git
+//! templates aren't checked, they don't ship with the editor.
+
+use std::{collections::HashMap, fs, path::Path, process::Command};
+
+use bevy::tasks::block_on;
+use bevy_editor::project::{
+    CancellationToken, MemoryProjectStore, RetryPolicy, create_new_project_with_progress,
+    templates::Templates,
+};
+
+/// The repository the templates depend on, replaced by the local crate.
+const EDITOR_REPOSITORY: &str = "https://github.com/bevyengine/bevy_editor_prototypes.git";
+
+#[test]
+#[ignore = "slow, builds the editor for every template"]
+fn generated_projects_build() {
+    let editor = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let workspace = editor.join("../..").canonicalize().unwrap();
+    // The built-in templates are looked up relative to the workspace
+    std::env::set_current_dir(&workspace).unwrap();
+
+    let root = std::env::temp_dir().join(format!(
+        "bevy_editor_template_builds_{}",
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(&root).unwrap();
+
+    let store = MemoryProjectStore::default();
+    let mut failures = Vec::new();
+    for template in Templates::ALL {
+        let path = root.join(format!("{template:?}").to_lowercase());
+        block_on(create_new_project_with_progress(
+            template.clone(),
+            path.clone(),
+            HashMap::new(),
+            false,
+            &RetryPolicy::default(),
+            &CancellationToken::new(),
+            &store,
+            |_| {},
+        ))
+        .unwrap_or_else(|error| panic!("Unable to create a project from {template:?}: {error}"));
+
+        let manifest = path.join("Cargo.toml");
+        let mut content = fs::read_to_string(&manifest).unwrap();
+        content.push_str(&format!(
+            "\n[patch.\"{EDITOR_REPOSITORY}\"]\nbevy_editor = {{ path = {:?} }}\n",
+            editor.canonicalize().unwrap()
+        ));
+        fs::write(&manifest, content).unwrap();
+
+        // The output is inherited, so cargo reports its progress and errors as it goes
+        eprintln!("Checking the project generated from {template:?}");
+        let status = Command::new("cargo")
+            .arg("check")
+            .current_dir(&path)
+            .env("CARGO_TARGET_DIR", workspace.join("target/template_builds"))
+            .status()
+            .expect("Unable to run cargo");
+        if !status.success() {
+            failures.push(format!("{template:?} ({status})"));
+        }
+    }
+
+    let _ = fs::remove_dir_all(&root);
+    assert!(
+        failures.is_empty(),
+        "The projects generated from these templates don't build: {}",
+        failures.join(", ")
+    );
+}
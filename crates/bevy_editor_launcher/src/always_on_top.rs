@@ -0,0 +1,121 @@
+//! Keeping the launcher window above the other windows, see
+//! [`LauncherSettings::always_on_top`].
+//!
+//! The setting is applied to the window as soon as it changes. Where the windowing system doesn't
+//! let applications do it, like on Wayland, the option is explained instead of offered.
+
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowLevel},
+};
+use bevy_editor_styles::{
+    Theme,
+    checkbox::{CheckboxChanged, spawn_checkbox},
+};
+
+use crate::settings::LauncherSettings;
+
+/// Plugin applying [`LauncherSettings::always_on_top`] to the primary window.
+pub struct AlwaysOnTopPlugin;
+
+impl Plugin for AlwaysOnTopPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            apply_always_on_top.run_if(resource_changed::<LauncherSettings>),
+        );
+    }
+}
+
+/// Why the windows can't be kept on top, `None` where they can.
+fn unsupported_reason(desktop: bool, wayland: bool) -> Option<&'static str> {
+    if !desktop {
+        Some("this platform has no windows to keep on top")
+    } else if wayland {
+        Some(
+            "Wayland doesn't let applications keep their windows on top, use the window menu instead",
+        )
+    } else {
+        None
+    }
+}
+
+/// Why the launcher window can't be kept on top on this platform, `None` when it can.
+fn always_on_top_unsupported() -> Option<&'static str> {
+    let desktop = cfg!(any(
+        target_os = "windows",
+        target_os = "macos",
+        target_os = "linux",
+        target_os = "freebsd",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_os = "dragonfly",
+    ));
+    // Winit picks Wayland over X11 when both are available
+    let wayland = !cfg!(any(target_os = "windows", target_os = "macos"))
+        && std::env::var_os("WAYLAND_DISPLAY").is_some_and(|display| !display.is_empty());
+    unsupported_reason(desktop, wayland)
+}
+
+/// Raise the primary window above the others or put it back among them, as set in the settings.
+fn apply_always_on_top(
+    mut window: Single<&mut Window, With<PrimaryWindow>>,
+    settings: Res<LauncherSettings>,
+) {
+    let level = if settings.always_on_top && always_on_top_unsupported().is_none() {
+        WindowLevel::AlwaysOnTop
+    } else {
+        WindowLevel::Normal
+    };
+    if window.window_level != level {
+        window.window_level = level;
+    }
+}
+
+/// Spawn the checkbox of [`LauncherSettings::always_on_top`], applied as soon as it is toggled, or
+/// the reason it isn't available.
+pub(crate) fn spawn_always_on_top_option<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    settings: &LauncherSettings,
+) -> EntityCommands<'a> {
+    if let Some(reason) = always_on_top_unsupported() {
+        return commands.spawn((
+            Text::new(format!("The launcher can't be kept on top: {reason}.")),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(theme.text.low_priority),
+        ));
+    }
+    let mut checkbox = spawn_checkbox(
+        commands,
+        theme,
+        "Keep the launcher on top of other windows",
+        settings.always_on_top,
+    );
+    checkbox.observe(
+        |trigger: On<CheckboxChanged>, mut settings: ResMut<LauncherSettings>| {
+            settings.always_on_top = trigger.event().0;
+        },
+    );
+    checkbox
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explain_the_unsupported_platforms() {
+        assert_eq!(unsupported_reason(true, false), None);
+        assert!(
+            unsupported_reason(true, true)
+                .unwrap()
+                .starts_with("Wayland")
+        );
+        assert!(unsupported_reason(false, false).is_some());
+    }
+}
@@ -0,0 +1,97 @@
+//! Removing the ANSI escape sequences, like the colors of cargo, which `Text` would show as is.
+
+/// Where an [`AnsiStripper`] is in the escape sequences.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum AnsiState {
+    /// In plain text.
+    #[default]
+    Text,
+    /// After an ESC character.
+    Escape,
+    /// In a control sequence, like a color, ended by a character in the `@` to `~` range.
+    ControlSequence,
+    /// In an operating system command, like a hyperlink, ended by BEL or ESC \.
+    Command,
+    /// After an ESC character in an operating system command.
+    CommandEscape,
+}
+
+/// Removes the ANSI escape sequences from a stream of text.
+///
+/// The state is kept between the chunks, so the sequences split across them are removed too.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AnsiStripper {
+    state: AnsiState,
+}
+
+impl AnsiStripper {
+    /// The chunk without its escape sequences, including the end of the one the last chunk started.
+    pub fn strip(&mut self, chunk: &str) -> String {
+        let mut stripped = String::with_capacity(chunk.len());
+        for c in chunk.chars() {
+            self.state = match (self.state, c) {
+                (AnsiState::Text, '\u{1b}') => AnsiState::Escape,
+                (AnsiState::Text, c) => {
+                    stripped.push(c);
+                    AnsiState::Text
+                }
+                (AnsiState::Escape, '[') => AnsiState::ControlSequence,
+                (AnsiState::Escape, ']') => AnsiState::Command,
+                (AnsiState::Command, '\u{1b}') => AnsiState::CommandEscape,
+                // The end of the sequences, the other escapes are a single character long
+                (AnsiState::Escape, _)
+                | (AnsiState::ControlSequence, '@'..='~')
+                | (AnsiState::Command, '\u{7}')
+                | (AnsiState::CommandEscape, '\\') => AnsiState::Text,
+                (state @ (AnsiState::ControlSequence | AnsiState::Command), _) => state,
+                (AnsiState::CommandEscape, _) => AnsiState::Command,
+            };
+        }
+        stripped
+    }
+}
+
+/// The text without its ANSI escape sequences.
+pub(crate) fn strip_ansi(text: &str) -> String {
+    AnsiStripper::default().strip(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_colored_cargo_output() {
+        let output = "\u{1b}[1m\u{1b}[32m   Compiling\u{1b}[0m game v0.1.0 (/projects/game)\n\
+                      \u{1b}[0m\u{1b}[1m\u{1b}[38;5;9merror[E0425]\u{1b}[0m\u{1b}[0m\u{1b}[1m: \
+                      cannot find value `x`\u{1b}[0m\n\
+                      \u{1b}[1m\u{1b}[32m    Finished\u{1b}[0m `dev` profile [unoptimized] in 1.2s";
+        assert_eq!(
+            strip_ansi(output),
+            "   Compiling game v0.1.0 (/projects/game)\n\
+             error[E0425]: cannot find value `x`\n    \
+             Finished `dev` profile [unoptimized] in 1.2s"
+        );
+        assert_eq!(
+            strip_ansi("see \u{1b}]8;;https://bevy.org\u{7}the docs\u{1b}]8;;\u{1b}\\"),
+            "see the docs"
+        );
+        assert_eq!(strip_ansi("plain [text]"), "plain [text]");
+    }
+
+    #[test]
+    fn strip_sequences_split_across_chunks() {
+        let mut stripper = AnsiStripper::default();
+        let chunks = [
+            "\u{1b}",
+            "[1;3",
+            "2m   Compiling\u{1b}[",
+            "0m game",
+            "\u{1b}]8;;url\u{1b}",
+            "\\",
+        ];
+        let stripped: String = chunks.iter().map(|chunk| stripper.strip(chunk)).collect();
+        assert_eq!(stripped, "   Compiling game");
+        assert_eq!(stripper.strip("done"), "done");
+    }
+}
@@ -0,0 +1,190 @@
+//! Saving the [`ProjectInfoList`] periodically while it has unsaved edits, and when the app exits.
+//!
+//! Most edits save the list right away, this catches the ones kept in memory so a crash of a
+//! long running launcher doesn't lose them. A failed save keeps the list dirty, so it is saved
+//! again on the next interval, unless the failure is permanent and waits for a retry or a new edit.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    ProjectInfoList, ProjectListStore,
+    save_status::{FailureKind, RetrySaveEvent, SaveFailures, SaveTarget, report_save},
+};
+
+/// How often the [`ProjectInfoList`] is saved while it has unsaved edits.
+const AUTO_SAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Plugin saving the [`ProjectInfoList`] when it changed.
+pub struct AutoSavePlugin;
+
+impl Plugin for AutoSavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProjectListAutoSave>()
+            .init_resource::<SaveFailures>()
+            .add_event::<RetrySaveEvent>()
+            .add_systems(
+                Last,
+                (auto_save_project_list, flush_project_list_on_exit).chain(),
+            );
+    }
+}
+
+/// Whether the [`ProjectInfoList`] changed since it was last saved, and when to save it next.
+#[derive(Resource, Debug)]
+struct ProjectListAutoSave {
+    dirty: bool,
+    /// Whether the last save failed permanently, the list is only saved again when retried or
+    /// changed again.
+    paused: bool,
+    timer: Timer,
+}
+
+impl Default for ProjectListAutoSave {
+    fn default() -> Self {
+        Self {
+            dirty: false,
+            paused: false,
+            timer: Timer::new(AUTO_SAVE_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+impl ProjectListAutoSave {
+    /// Record whether the list `changed`, and tell if it is time to save it, right away when
+    /// `retry` is set.
+    ///
+    /// The result of the save must be given to [`saved`](Self::saved) once this returns `true`.
+    fn tick(&mut self, changed: bool, retry: bool, delta: Duration) -> bool {
+        self.dirty |= changed;
+        self.paused &= !changed;
+        let due = self.timer.tick(delta).just_finished();
+        self.dirty && (retry || (due && !self.paused))
+    }
+
+    /// Record the result of a save, given the kind of its failure if it failed.
+    fn saved(&mut self, failure: Option<FailureKind>) {
+        self.dirty = failure.is_some();
+        self.paused = failure == Some(FailureKind::Permanent);
+    }
+}
+
+fn auto_save_project_list(
+    project_list: Res<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    mut auto_save: ResMut<ProjectListAutoSave>,
+    mut failures: ResMut<SaveFailures>,
+    mut retry: EventReader<RetrySaveEvent>,
+    time: Res<Time>,
+) {
+    // The list loaded at startup is already saved
+    let changed = project_list.is_changed() && !project_list.is_added();
+    let retry = retry.read().count() > 0;
+    if auto_save.tick(changed, retry, time.delta()) {
+        debug!("Auto-saving the project list");
+        let result = store.save(project_list.0.clone());
+        auto_save.saved(report_save(&mut failures, SaveTarget::ProjectList, &result));
+    }
+}
+
+/// Save the unsaved edits without waiting for the next auto-save when the app is about to exit.
+fn flush_project_list_on_exit(
+    mut exit: EventReader<AppExit>,
+    project_list: Res<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    mut auto_save: ResMut<ProjectListAutoSave>,
+    mut failures: ResMut<SaveFailures>,
+) {
+    if exit.is_empty() {
+        return;
+    }
+    exit.clear();
+
+    if auto_save.dirty {
+        info!("Saving the project list before exiting");
+        let result = store.save(project_list.0.clone());
+        auto_save.saved(report_save(&mut failures, SaveTarget::ProjectList, &result));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use bevy_editor::project::{MemoryProjectStore, ProjectInfo};
+
+    use super::*;
+
+    #[test]
+    fn save_only_when_dirty() {
+        let mut auto_save = ProjectListAutoSave::default();
+        let step = AUTO_SAVE_INTERVAL / 2;
+        assert!(!auto_save.tick(false, false, step));
+        assert!(!auto_save.tick(false, false, step), "nothing to save");
+
+        assert!(!auto_save.tick(true, false, step));
+        assert!(
+            auto_save.tick(false, false, step),
+            "saved on the next interval"
+        );
+        auto_save.saved(None);
+        assert!(
+            !auto_save.tick(false, false, AUTO_SAVE_INTERVAL),
+            "already saved"
+        );
+        assert!(!auto_save.tick(false, true, step), "nothing to retry");
+    }
+
+    #[test]
+    fn save_again_after_a_failure() {
+        let mut auto_save = ProjectListAutoSave::default();
+        assert!(auto_save.tick(true, false, AUTO_SAVE_INTERVAL));
+        auto_save.saved(Some(FailureKind::Transient));
+        assert!(
+            auto_save.tick(false, false, AUTO_SAVE_INTERVAL),
+            "saved again on the next interval"
+        );
+
+        auto_save.saved(Some(FailureKind::Permanent));
+        assert!(!auto_save.tick(false, false, AUTO_SAVE_INTERVAL), "paused");
+        assert!(auto_save.tick(false, true, Duration::ZERO), "retried");
+        auto_save.saved(Some(FailureKind::Permanent));
+        assert!(
+            auto_save.tick(true, false, AUTO_SAVE_INTERVAL),
+            "changed again"
+        );
+        auto_save.saved(None);
+        assert!(!auto_save.tick(false, false, AUTO_SAVE_INTERVAL));
+    }
+
+    #[test]
+    fn flush_to_the_store_on_exit() {
+        let project = ProjectInfo {
+            pinned: true,
+            ..ProjectInfo::new("/projects/game")
+        };
+        let mut app = App::new();
+        app.insert_resource(ProjectListStore(Arc::new(MemoryProjectStore::default())))
+            .insert_resource(ProjectInfoList(vec![project.clone()]))
+            .init_resource::<ProjectListAutoSave>()
+            .init_resource::<SaveFailures>()
+            .add_event::<AppExit>()
+            .add_systems(Update, flush_project_list_on_exit);
+
+        app.world_mut().write_event(AppExit::Success);
+        app.update();
+        assert!(
+            app.world().resource::<ProjectListStore>().load().is_empty(),
+            "nothing to save"
+        );
+
+        app.world_mut().resource_mut::<ProjectListAutoSave>().dirty = true;
+        app.world_mut().write_event(AppExit::Success);
+        app.update();
+        let saved = app.world().resource::<ProjectListStore>().load();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].path, project.path);
+        assert!(!app.world().resource::<ProjectListAutoSave>().dirty);
+    }
+}
@@ -0,0 +1,790 @@
+//! Badges on the project nodes warning about the health of the projects, and marking the ones
+//! opened frequently.
+//!
+//! The badges are computed in the background when a node is spawned and when the list is refreshed,
+//! then cached on the node. Clicking a warning badge runs the action fixing it.
+//!
+//! The Bevy versions of the projects are compared to the one of the [`LauncherSettings`], or to the
+//! newest one among the projects, so the prototypes left behind stand out.
+//!
+//! Which badges are shown, and in what order, is picked in the badge settings opened from the top
+//! bar and stored in [`LauncherSettings::badges`]. The nodes are updated as soon as it changes.
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use bevy_editor::project::scan;
+use bevy_editor_styles::{
+    Theme,
+    busy::BusyActions,
+    checkbox::{CheckboxChanged, spawn_checkbox},
+    chip::{Chip, ChipClicked, ChipRole},
+    dialog::{close_dialog, spawn_dialog_button, spawn_dialog_root},
+    tooltip::Tooltip,
+};
+
+use crate::{
+    ProjectInfoList, ProjectListStore,
+    bevy_version::show_bevy_version_dialog,
+    refresh::{REFRESH_ACTION, RefreshProjectsEvent},
+    settings::{BadgeKind, LauncherSettings},
+    terminal::open_terminal_for_node,
+    toast::{ToastLevel, push_toast},
+    ui::ProjectNode,
+};
+
+/// The open count from which a project gets the [`ProjectBadge::FrequentlyOpened`] badge.
+pub(crate) const FREQUENTLY_OPENED: u32 = 10;
+
+/// Plugin computing the [`ProjectBadges`] of the [`ProjectNode`]s and displaying them.
+pub struct BadgesPlugin;
+
+impl Plugin for BadgesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NewestBevyVersion>().add_systems(
+            Update,
+            (
+                detect_newest_bevy_version,
+                start_badge_tasks,
+                poll_badge_tasks,
+                update_badge_rows,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// The Bevy version the projects are compared to, and how far behind they can be.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct VersionCheck {
+    /// The current version, like `0.16`.
+    current: String,
+    /// How many minor versions a project can be behind before it is outdated.
+    allowed_gap: u64,
+}
+
+impl VersionCheck {
+    /// The check set by the settings, `None` when the projects aren't compared.
+    fn new(settings: &LauncherSettings, newest: &NewestBevyVersion) -> Option<Self> {
+        let current = match &settings.current_bevy_version {
+            Some(current) => current.clone(),
+            None if settings.detect_bevy_version => newest.version.clone()?,
+            None => return None,
+        };
+        Some(Self {
+            current,
+            allowed_gap: settings.outdated_bevy_gap,
+        })
+    }
+}
+
+/// The newest Bevy version among the projects, found in the background when the list changes.
+#[derive(Resource, Default)]
+struct NewestBevyVersion {
+    version: Option<String>,
+    task: Option<Task<Option<String>>>,
+}
+
+/// A health warning about a project, or a mark of how it is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ProjectBadge {
+    /// The project folder doesn't exist anymore.
+    Missing,
+    /// The folder has no `Cargo.toml`, or one that doesn't depend on Bevy.
+    NotAProject,
+    /// The project depends on a Bevy version too far behind the current one.
+    OutdatedBevy {
+        /// The version requirement of the project.
+        version: String,
+        /// The version it was compared to.
+        current: String,
+    },
+    /// The git repository of the project has uncommitted changes.
+    UncommittedChanges,
+    /// The project was opened at least [`FREQUENTLY_OPENED`] times, the count is stored.
+    FrequentlyOpened(u32),
+}
+
+impl BadgeKind {
+    /// All the badges, in their default order, the warnings first.
+    pub const ALL: [BadgeKind; 5] = [
+        BadgeKind::Missing,
+        BadgeKind::NotAProject,
+        BadgeKind::OutdatedBevy,
+        BadgeKind::UncommittedChanges,
+        BadgeKind::FrequentlyOpened,
+    ];
+
+    /// The name of the badge, in the badge settings.
+    pub fn label(self) -> &'static str {
+        match self {
+            BadgeKind::Missing => "Missing folder",
+            BadgeKind::NotAProject => "Not a Bevy project",
+            BadgeKind::OutdatedBevy => "Outdated Bevy version",
+            BadgeKind::UncommittedChanges => "Uncommitted changes",
+            BadgeKind::FrequentlyOpened => "Frequently opened",
+        }
+    }
+}
+
+impl ProjectBadge {
+    /// The kind of the badge, as shown or hidden by the settings.
+    fn kind(&self) -> BadgeKind {
+        match self {
+            ProjectBadge::Missing => BadgeKind::Missing,
+            ProjectBadge::NotAProject => BadgeKind::NotAProject,
+            ProjectBadge::OutdatedBevy { .. } => BadgeKind::OutdatedBevy,
+            ProjectBadge::UncommittedChanges => BadgeKind::UncommittedChanges,
+            ProjectBadge::FrequentlyOpened(_) => BadgeKind::FrequentlyOpened,
+        }
+    }
+
+    /// The short text of the badge.
+    fn label(&self) -> &'static str {
+        match self {
+            ProjectBadge::Missing => "Missing",
+            ProjectBadge::NotAProject => "Invalid",
+            ProjectBadge::OutdatedBevy { .. } => "Outdated",
+            ProjectBadge::UncommittedChanges => "Changes",
+            ProjectBadge::FrequentlyOpened(_) => "Frequent",
+        }
+    }
+
+    /// The explanation shown when hovering the badge, with the action of a click.
+    fn tooltip(&self) -> String {
+        match self {
+            ProjectBadge::Missing => {
+                "The project folder doesn't exist anymore, click to remove it from the list"
+                    .to_string()
+            }
+            ProjectBadge::NotAProject => {
+                "No valid Cargo.toml depending on Bevy, click to remove it from the list"
+                    .to_string()
+            }
+            ProjectBadge::OutdatedBevy { version, current } => {
+                format!("Uses Bevy {version}, behind {current}, click to upgrade it")
+            }
+            ProjectBadge::UncommittedChanges => {
+                "The git repository has uncommitted changes, click to open a terminal".to_string()
+            }
+            ProjectBadge::FrequentlyOpened(count) => {
+                format!("Opened {count} times from the launcher")
+            }
+        }
+    }
+
+    /// The color of the badge, by severity.
+    fn role(&self) -> ChipRole {
+        match self {
+            ProjectBadge::Missing | ProjectBadge::NotAProject => ChipRole::Error,
+            ProjectBadge::OutdatedBevy { .. } => ChipRole::Warning,
+            ProjectBadge::UncommittedChanges => ChipRole::Info,
+            ProjectBadge::FrequentlyOpened(_) => ChipRole::Success,
+        }
+    }
+}
+
+/// The badges of a [`ProjectNode`], cached until the next refresh.
+#[derive(Component, Debug, Default)]
+struct ProjectBadges(Vec<ProjectBadge>);
+
+/// The background computation of the [`ProjectBadges`] of a [`ProjectNode`].
+#[derive(Component)]
+struct BadgeTask(Task<Vec<ProjectBadge>>);
+
+/// The row of a [`ProjectNode`] holding its badges.
+#[derive(Component)]
+struct BadgeRow;
+
+/// A badge displayed in a [`BadgeRow`].
+#[derive(Component)]
+struct Badge(ProjectBadge);
+
+/// Compute the badges of the project in the given folder, opened `open_count` times.
+///
+/// The Bevy version is compared as set by the `version_check`, `None` to skip the comparison.
+/// A missing or invalid project only gets the badge saying so.
+fn project_badges(
+    path: &Path,
+    version_check: Option<&VersionCheck>,
+    open_count: u32,
+) -> Vec<ProjectBadge> {
+    if !path.is_dir() {
+        return vec![ProjectBadge::Missing];
+    }
+    if !scan::is_bevy_project(path) {
+        return vec![ProjectBadge::NotAProject];
+    }
+
+    let mut badges = Vec::new();
+    if let Some(check) = version_check
+        && let Some(version) = scan::bevy_version(path)
+        && is_outdated(&version, &check.current, check.allowed_gap)
+    {
+        badges.push(ProjectBadge::OutdatedBevy {
+            version,
+            current: check.current.clone(),
+        });
+    }
+    if has_uncommitted_changes(path) {
+        badges.push(ProjectBadge::UncommittedChanges);
+    }
+    if open_count >= FREQUENTLY_OPENED {
+        badges.push(ProjectBadge::FrequentlyOpened(open_count));
+    }
+    badges
+}
+
+/// The badges shown by the settings, in their order.
+fn shown_badges<'a>(badges: &'a [ProjectBadge], shown: &[BadgeKind]) -> Vec<&'a ProjectBadge> {
+    shown
+        .iter()
+        .enumerate()
+        // A kind listed twice in a hand edited file is only shown once
+        .filter(|(index, kind)| !shown[..*index].contains(*kind))
+        .flat_map(|(_, kind)| badges.iter().filter(move |badge| badge.kind() == *kind))
+        .collect()
+}
+
+/// Show or hide the badge in the settings, a badge shown again goes last.
+fn set_badge_shown(shown_badges: &mut Vec<BadgeKind>, kind: BadgeKind, shown: bool) {
+    shown_badges.retain(|shown| *shown != kind);
+    if shown {
+        shown_badges.push(kind);
+    }
+}
+
+/// Move the shown badge by `step` places in the settings, staying within the shown ones.
+fn move_badge(shown_badges: &mut [BadgeKind], kind: BadgeKind, step: isize) {
+    let Some(index) = shown_badges.iter().position(|shown| *shown == kind) else {
+        return;
+    };
+    let Some(target) = index.checked_add_signed(step) else {
+        return;
+    };
+    if target < shown_badges.len() {
+        shown_badges.swap(index, target);
+    }
+}
+
+/// How many times the project at the path was opened.
+fn open_count(project_list: &ProjectInfoList, path: &Path) -> u32 {
+    project_list
+        .0
+        .iter()
+        .find(|project| project.path == path)
+        .map_or(0, |project| project.open_count)
+}
+
+/// The major and minor numbers of the version requirement, `None` when it isn't a version.
+fn major_minor(version: &str) -> Option<(u64, u64)> {
+    let mut numbers = version
+        .trim_start_matches(['^', '~', '=', '>', '<', ' '])
+        .split('.');
+    let major = numbers.next()?.trim().parse().ok()?;
+    let minor = numbers
+        .next()
+        .map_or(Some(0), |minor| minor.trim().parse().ok())?;
+    Some((major, minor))
+}
+
+/// Whether the version requirement is more than `allowed_gap` minor versions behind `current`.
+///
+/// Only the major and minor numbers are compared, Bevy breaks its API on every minor release. An
+/// older major version is always outdated.
+fn is_outdated(version: &str, current: &str, allowed_gap: u64) -> bool {
+    match (major_minor(version), major_minor(current)) {
+        (Some((major, minor)), Some((current_major, current_minor))) => {
+            major < current_major || (major == current_major && current_minor > minor + allowed_gap)
+        }
+        _ => false,
+    }
+}
+
+/// The newest of the version requirements, ignoring the ones that aren't versions.
+fn newest_version(versions: impl IntoIterator<Item = String>) -> Option<String> {
+    versions
+        .into_iter()
+        .filter_map(|version| Some((major_minor(&version)?, version)))
+        .max_by_key(|(numbers, _)| *numbers)
+        .map(|(_, version)| version)
+}
+
+/// Find the [`NewestBevyVersion`] again when the project list changes or is refreshed.
+fn detect_newest_bevy_version(
+    mut newest: ResMut<NewestBevyVersion>,
+    mut refresh_events: EventReader<RefreshProjectsEvent>,
+    project_list: Res<ProjectInfoList>,
+    settings: Res<LauncherSettings>,
+) {
+    let refresh = refresh_events.read().count() > 0;
+    if settings.detect_bevy_version && (refresh || project_list.is_changed()) {
+        let paths: Vec<PathBuf> = project_list
+            .0
+            .iter()
+            .map(|project| project.path.clone())
+            .collect();
+        // Replacing the task of an older list cancels it
+        newest.task = Some(IoTaskPool::get().spawn(async move {
+            newest_version(paths.iter().filter_map(|path| scan::bevy_version(path)))
+        }));
+    }
+    let Some(task) = &mut newest.task else {
+        return;
+    };
+    if let Some(version) = block_on(future::poll_once(task)) {
+        newest.task = None;
+        // Only a change recomputes the badges
+        if newest.version != version {
+            newest.version = version;
+        }
+    }
+}
+
+/// Whether the folder is a git repository with uncommitted changes.
+///
+/// Folders that aren't repositories, or where git can't be run, have none.
+fn has_uncommitted_changes(path: &Path) -> bool {
+    if !path.join(".git").exists() {
+        return false;
+    }
+    Command::new("git")
+        .arg("status")
+        .arg("--porcelain")
+        .current_dir(path)
+        .output()
+        .is_ok_and(|output| output.status.success() && !output.stdout.is_empty())
+}
+
+/// Compute the badges of the new [`ProjectNode`]s, and of all of them on refresh
+/// or when the [`VersionCheck`] changes.
+fn start_badge_tasks(
+    mut commands: Commands,
+    mut refresh_events: EventReader<RefreshProjectsEvent>,
+    added: Query<(Entity, &ProjectNode), Added<ProjectNode>>,
+    nodes: Query<(Entity, &ProjectNode)>,
+    settings: Res<LauncherSettings>,
+    newest: Res<NewestBevyVersion>,
+    project_list: Res<ProjectInfoList>,
+    mut busy: ResMut<BusyActions>,
+    mut last_check: Local<Option<VersionCheck>>,
+) {
+    let version_check = VersionCheck::new(&settings, &newest);
+    let check_changed = *last_check != version_check;
+    last_check.clone_from(&version_check);
+    let refresh = refresh_events.read().count() > 0 || check_changed;
+
+    let nodes: Vec<(Entity, PathBuf)> = if refresh {
+        nodes
+            .iter()
+            .map(|(entity, node)| (entity, node.0.clone()))
+            .collect()
+    } else {
+        added
+            .iter()
+            .map(|(entity, node)| (entity, node.0.clone()))
+            .collect()
+    };
+    for (entity, path) in nodes {
+        let version_check = version_check.clone();
+        let open_count = open_count(&project_list, &path);
+        let task = IoTaskPool::get()
+            .spawn(async move { project_badges(&path, version_check.as_ref(), open_count) });
+        // The node may have been removed by the same refresh, which finishes its busy task
+        commands.entity(entity).try_insert(BadgeTask(task));
+        if refresh {
+            busy.start(REFRESH_ACTION, entity);
+        }
+    }
+}
+
+/// Compute the badges of the given [`ProjectNode`] again, after its project was modified.
+pub(crate) fn refresh_project_badges(
+    In(project_entity): In<Entity>,
+    mut commands: Commands,
+    nodes: Query<&ProjectNode>,
+    settings: Res<LauncherSettings>,
+    newest: Res<NewestBevyVersion>,
+    project_list: Res<ProjectInfoList>,
+) {
+    let Ok(ProjectNode(path)) = nodes.get(project_entity) else {
+        return;
+    };
+    let path = path.clone();
+    let version_check = VersionCheck::new(&settings, &newest);
+    let open_count = open_count(&project_list, &path);
+    let task = IoTaskPool::get()
+        .spawn(async move { project_badges(&path, version_check.as_ref(), open_count) });
+    commands.entity(project_entity).try_insert(BadgeTask(task));
+}
+
+/// Store the computed badges on their [`ProjectNode`], when they changed.
+fn poll_badge_tasks(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut BadgeTask, Option<&ProjectBadges>)>,
+    mut busy: ResMut<BusyActions>,
+) {
+    for (entity, mut task, cached) in &mut tasks {
+        let Some(badges) = block_on(future::poll_once(&mut task.0)) else {
+            continue;
+        };
+        commands.entity(entity).remove::<BadgeTask>();
+        busy.finish(REFRESH_ACTION, entity);
+        if cached.is_some_and(|cached| cached.0 == badges) {
+            continue;
+        }
+        commands.entity(entity).insert(ProjectBadges(badges));
+    }
+}
+
+/// Rebuild the [`BadgeRow`] of the [`ProjectNode`]s whose badges changed, and of all of them when
+/// the [`LauncherSettings::badges`] change.
+fn update_badge_rows(
+    mut commands: Commands,
+    nodes: Query<(Entity, Ref<ProjectBadges>, Option<&Children>)>,
+    rows: Query<(), With<BadgeRow>>,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+    mut last_shown: Local<Vec<BadgeKind>>,
+) {
+    let settings_changed = *last_shown != settings.badges;
+    if settings_changed {
+        last_shown.clone_from(&settings.badges);
+    }
+    for (entity, badges, children) in &nodes {
+        if !settings_changed && !badges.is_changed() {
+            continue;
+        }
+        for child in children.into_iter().flat_map(|children| children.iter()) {
+            if rows.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+        let shown = shown_badges(&badges.0, &settings.badges);
+        if shown.is_empty() {
+            continue;
+        }
+        let row = commands
+            .spawn((
+                BadgeRow,
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(8.0),
+                    right: Val::Px(8.0),
+                    display: Display::Flex,
+                    column_gap: Val::Px(4.0),
+                    ..default()
+                },
+                ChildOf(entity),
+            ))
+            .id();
+        for badge in shown {
+            spawn_badge(&mut commands, &theme, entity, badge).insert(ChildOf(row));
+        }
+    }
+}
+
+fn spawn_badge<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    project_entity: Entity,
+    badge: &ProjectBadge,
+) -> EntityCommands<'a> {
+    let mut chip = Chip::new(badge.label()).role(badge.role());
+    // Like the rest of the node, the frequently opened badge opens the project
+    if !matches!(badge, ProjectBadge::FrequentlyOpened(_)) {
+        chip = chip.clickable();
+    }
+    let mut badge_ec = chip.spawn(commands, theme);
+    badge_ec
+        .insert((
+            Badge(badge.clone()),
+            Tooltip::new(badge.tooltip()).with_max_width(200.0),
+        ))
+        .observe(
+            move |trigger: On<ChipClicked>, mut commands: Commands, badges: Query<&Badge>| {
+                let Ok(Badge(badge)) = badges.get(trigger.target()) else {
+                    return;
+                };
+                match badge {
+                    ProjectBadge::Missing | ProjectBadge::NotAProject => {
+                        commands.run_system_cached_with(remove_project_node, project_entity);
+                    }
+                    ProjectBadge::OutdatedBevy { .. } => {
+                        commands.run_system_cached_with(show_bevy_version_dialog, project_entity);
+                    }
+                    ProjectBadge::UncommittedChanges => {
+                        commands.run_system_cached_with(open_terminal_for_node, project_entity);
+                    }
+                    ProjectBadge::FrequentlyOpened(_) => {}
+                }
+            },
+        );
+    badge_ec
+}
+
+/// Remove the project of the given [`ProjectNode`] from the list.
+fn remove_project_node(
+    In(project_entity): In<Entity>,
+    mut commands: Commands,
+    query_nodes: Query<&ProjectNode>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+) {
+    let Ok(ProjectNode(path)) = query_nodes.get(project_entity) else {
+        return;
+    };
+    project_list.0.retain(|project| &project.path != path);
+    store.set(project_list.0.clone());
+    push_toast(
+        &mut commands,
+        ToastLevel::Info,
+        format!("Removed '{}' from the list", path.display()),
+    );
+    commands.entity(project_entity).despawn();
+}
+
+/// The list of the badge settings, in its dialog.
+#[derive(Component)]
+struct BadgeSettingsList;
+
+/// Open the dialog picking the badges shown on the project nodes and their order.
+pub(crate) fn show_badge_settings(
+    mut commands: Commands,
+    lists: Query<(), With<BadgeSettingsList>>,
+    theme: Res<Theme>,
+) {
+    if !lists.is_empty() {
+        return;
+    }
+
+    let dialog = spawn_dialog_root(&mut commands);
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(380.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    commands.spawn((
+        Text::new("Badges"),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+    commands.spawn((
+        Text::new("The badges shown on the projects, from left to right"),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(panel),
+    ));
+    let list = commands
+        .spawn((
+            BadgeSettingsList,
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    commands.run_system_cached_with(fill_badge_settings, list);
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(&mut commands, &theme, "Done", theme.button.hover_color)
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                close_dialog(&mut commands, dialog, true);
+            },
+        );
+}
+
+/// List the badges in the [`BadgeSettingsList`], the shown ones first in their order.
+fn fill_badge_settings(
+    In(list): In<Entity>,
+    mut commands: Commands,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+) {
+    commands.entity(list).despawn_related::<Children>();
+    let hidden = BadgeKind::ALL
+        .into_iter()
+        .filter(|kind| !settings.badges.contains(kind));
+    for kind in settings.badges.iter().copied().chain(hidden) {
+        let shown = settings.badges.contains(&kind);
+        let row = commands
+            .spawn((
+                Node {
+                    display: Display::Flex,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(4.0),
+                    ..default()
+                },
+                ChildOf(list),
+            ))
+            .id();
+        spawn_checkbox(&mut commands, &theme, kind.label(), shown)
+            .insert((
+                Node {
+                    flex_grow: 1.0,
+                    ..default()
+                },
+                ChildOf(row),
+            ))
+            .observe(
+                move |trigger: On<CheckboxChanged>,
+                      mut commands: Commands,
+                      mut settings: ResMut<LauncherSettings>| {
+                    set_badge_shown(&mut settings.badges, kind, trigger.event().0);
+                    commands.run_system_cached_with(fill_badge_settings, list);
+                },
+            );
+        if !shown {
+            continue;
+        }
+        for (label, step) in [("Up", -1), ("Down", 1)] {
+            spawn_dialog_button(
+                &mut commands,
+                &theme,
+                label,
+                theme.button.background_color.0,
+            )
+            .insert(ChildOf(row))
+            .observe(
+                move |_trigger: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      mut settings: ResMut<LauncherSettings>| {
+                    move_badge(&mut settings.badges, kind, step);
+                    commands.run_system_cached_with(fill_badge_settings, list);
+                },
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn compare_bevy_versions() {
+        assert!(is_outdated("0.15", "0.16", 0));
+        assert!(!is_outdated("0.15", "0.16", 1), "within the allowed gap");
+        assert!(is_outdated("^0.14.2", "0.16", 1));
+        assert!(!is_outdated("0.16.1", "0.16", 0));
+        assert!(!is_outdated("=0.17.0-dev", "0.16", 0));
+        assert!(!is_outdated("*", "0.16", 0));
+        assert!(is_outdated("0.16", "1.0", 5), "an older major version");
+
+        let versions = ["0.14", "*", "=0.16.1", "^0.15"].map(String::from);
+        assert_eq!(newest_version(versions).as_deref(), Some("=0.16.1"));
+        assert_eq!(newest_version(["*".to_string()]), None);
+    }
+
+    #[test]
+    fn compute_the_badges() {
+        let root =
+            std::env::temp_dir().join(format!("bevy_launcher_badges_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("tool")).unwrap();
+        fs::write(
+            root.join("tool/Cargo.toml"),
+            "[dependencies]\nserde = \"1\"\n",
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("game")).unwrap();
+        fs::write(
+            root.join("game/Cargo.toml"),
+            "[dependencies]\nbevy = \"0.15\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            project_badges(&root.join("missing"), None, 0),
+            vec![ProjectBadge::Missing]
+        );
+        assert_eq!(
+            project_badges(&root.join("tool"), None, FREQUENTLY_OPENED),
+            vec![ProjectBadge::NotAProject]
+        );
+        let check = VersionCheck {
+            current: "0.17".to_string(),
+            allowed_gap: 1,
+        };
+        assert_eq!(
+            project_badges(&root.join("game"), Some(&check), 0),
+            vec![ProjectBadge::OutdatedBevy {
+                version: "0.15".to_string(),
+                current: "0.17".to_string(),
+            }]
+        );
+        assert!(project_badges(&root.join("game"), None, FREQUENTLY_OPENED - 1).is_empty());
+        assert_eq!(
+            project_badges(&root.join("game"), None, 25),
+            vec![ProjectBadge::FrequentlyOpened(25)]
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn pick_the_shown_badges() {
+        let badges = [
+            ProjectBadge::UncommittedChanges,
+            ProjectBadge::FrequentlyOpened(12),
+        ];
+        assert_eq!(
+            shown_badges(&badges, &BadgeKind::ALL),
+            vec![&badges[0], &badges[1]]
+        );
+
+        let mut shown = BadgeKind::ALL.to_vec();
+        move_badge(&mut shown, BadgeKind::FrequentlyOpened, -1);
+        assert_eq!(shown_badges(&badges, &shown), vec![&badges[1], &badges[0]]);
+        move_badge(&mut shown, BadgeKind::Missing, -1);
+        assert_eq!(shown[0], BadgeKind::Missing, "already the first");
+
+        set_badge_shown(&mut shown, BadgeKind::UncommittedChanges, false);
+        assert_eq!(shown_badges(&badges, &shown), vec![&badges[1]]);
+        set_badge_shown(&mut shown, BadgeKind::UncommittedChanges, true);
+        assert_eq!(shown.last(), Some(&BadgeKind::UncommittedChanges));
+
+        let duplicated = [BadgeKind::FrequentlyOpened, BadgeKind::FrequentlyOpened];
+        assert_eq!(shown_badges(&badges, &duplicated), vec![&badges[1]]);
+    }
+}
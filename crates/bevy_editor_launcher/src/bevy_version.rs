@@ -0,0 +1,299 @@
+//! Changing the Bevy version a project depends on, from the context menu of its [`ProjectNode`].
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use bevy_editor::project::{
+    ProjectError,
+    bevy_version::{set_bevy_version, update_bevy_lockfile, validate_bevy_version},
+    scan,
+};
+use bevy_editor_styles::{
+    Theme,
+    checkbox::{CheckboxChanged, spawn_checkbox},
+    dialog::{close_dialog, spawn_dialog_button, spawn_dialog_root},
+    dropdown::{Dropdown, spawn_dropdown},
+    text_input::{TextInputSubmit, TextInputValue, spawn_text_input},
+};
+
+use crate::{
+    badges::refresh_project_badges,
+    settings::LauncherSettings,
+    task_limit::TaskSlots,
+    toast::{ToastLevel, push_toast},
+    ui::ProjectNode,
+};
+
+/// The versions offered in the dropdown, after the [`LauncherSettings::current_bevy_version`].
+const SUGGESTED_VERSIONS: &[&str] = &["0.16", "0.15", "0.14"];
+
+/// Plugin applying the Bevy version changes in the background.
+pub struct BevyVersionPlugin;
+
+impl Plugin for BevyVersionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, poll_bevy_version_tasks);
+    }
+}
+
+/// The dialog choosing the new Bevy version of a [`ProjectNode`].
+#[derive(Component)]
+struct BevyVersionDialog {
+    /// The node of the project.
+    node: Entity,
+    /// The dropdown of the [`SUGGESTED_VERSIONS`].
+    versions: Entity,
+    /// The input of a version that isn't suggested, used when not empty.
+    custom: Entity,
+    /// Whether to run `cargo update -p bevy` after editing the manifest.
+    update_lockfile: bool,
+}
+
+/// The background change of the Bevy version of a [`ProjectNode`], on the node.
+#[derive(Component)]
+struct BevyVersionTask(Task<Result<String, ProjectError>>);
+
+/// The versions offered in the dropdown, starting with the current Bevy version if set.
+fn suggested_versions(current_version: Option<&str>) -> Vec<String> {
+    current_version
+        .into_iter()
+        .chain(SUGGESTED_VERSIONS.iter().copied())
+        .fold(Vec::new(), |mut versions, version| {
+            if !versions.iter().any(|listed| listed == version) {
+                versions.push(version.to_string());
+            }
+            versions
+        })
+}
+
+/// Open the dialog changing the Bevy version of the given [`ProjectNode`].
+pub(crate) fn show_bevy_version_dialog(
+    In(project_entity): In<Entity>,
+    mut commands: Commands,
+    nodes: Query<&ProjectNode>,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+) {
+    let Ok(ProjectNode(path)) = nodes.get(project_entity) else {
+        return;
+    };
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let current = match scan::bevy_version(path) {
+        Some(version) => format!("Currently depends on Bevy {version}"),
+        None => "The current Bevy version is unknown".to_string(),
+    };
+
+    let dialog = spawn_dialog_root(&mut commands);
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(420.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(format!("Change the Bevy version of {name}")),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+    commands.spawn((
+        Text::new(current),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(panel),
+    ));
+
+    let versions = spawn_dropdown(
+        &mut commands,
+        &theme,
+        suggested_versions(settings.current_bevy_version.as_deref()),
+        0,
+    )
+    .insert(ChildOf(panel))
+    .id();
+    let custom = spawn_text_input(&mut commands, &theme, "", "Or another version, like 0.16.1")
+        .insert(ChildOf(panel))
+        .observe(
+            move |_trigger: On<TextInputSubmit>, mut commands: Commands| {
+                commands.run_system_cached_with(apply_bevy_version, dialog);
+            },
+        )
+        .id();
+    spawn_checkbox(&mut commands, &theme, "Run cargo update -p bevy", true)
+        .insert(ChildOf(panel))
+        .observe(
+            move |trigger: On<CheckboxChanged>, mut dialogs: Query<&mut BevyVersionDialog>| {
+                if let Ok(mut dialog) = dialogs.get_mut(dialog) {
+                    dialog.update_lockfile = trigger.event().0;
+                }
+            },
+        );
+    commands.entity(dialog).insert(BevyVersionDialog {
+        node: project_entity,
+        versions,
+        custom,
+        update_lockfile: true,
+    });
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Cancel",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            close_dialog(&mut commands, dialog, false);
+        },
+    );
+    spawn_dialog_button(&mut commands, &theme, "Change", theme.button.hover_color)
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                commands.run_system_cached_with(apply_bevy_version, dialog);
+            },
+        );
+}
+
+/// Validate the version chosen in the [`BevyVersionDialog`] and start changing it in the background.
+///
+/// The dialog stays open when the version is invalid.
+fn apply_bevy_version(
+    In(dialog): In<Entity>,
+    mut commands: Commands,
+    dialogs: Query<&BevyVersionDialog>,
+    dropdowns: Query<&Dropdown>,
+    inputs: Query<&TextInputValue>,
+    nodes: Query<&ProjectNode>,
+    slots: Res<TaskSlots>,
+) {
+    let Ok(dialog_state) = dialogs.get(dialog) else {
+        return;
+    };
+    let custom = inputs
+        .get(dialog_state.custom)
+        .map(|value| value.0.trim().to_string())
+        .unwrap_or_default();
+    let version = if custom.is_empty() {
+        dropdowns
+            .get(dialog_state.versions)
+            .map(|dropdown| dropdown.selected_label().to_string())
+            .unwrap_or_default()
+    } else {
+        custom
+    };
+    if let Err(error) = validate_bevy_version(&version) {
+        push_toast(&mut commands, ToastLevel::Error, error.to_string());
+        return;
+    }
+    let Ok(ProjectNode(path)) = nodes.get(dialog_state.node) else {
+        close_dialog(&mut commands, dialog, false);
+        return;
+    };
+
+    let path = path.clone();
+    let update_lockfile = dialog_state.update_lockfile;
+    info!(
+        "Changing the Bevy version of '{}' to {version}",
+        path.display()
+    );
+    let task = IoTaskPool::get().spawn(slots.run(async move {
+        set_bevy_version(&path, &version)?;
+        if update_lockfile {
+            let report = update_bevy_lockfile(&path)?;
+            if !report.is_empty() {
+                info!("{report}");
+            }
+        }
+        Ok(version)
+    }));
+    commands
+        .entity(dialog_state.node)
+        .try_insert(BevyVersionTask(task));
+    close_dialog(&mut commands, dialog, true);
+}
+
+/// Report the result of the version changes and update the badges of their projects.
+fn poll_bevy_version_tasks(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &ProjectNode, &mut BevyVersionTask)>,
+) {
+    for (entity, ProjectNode(path), mut task) in &mut tasks {
+        let Some(result) = block_on(future::poll_once(&mut task.0)) else {
+            continue;
+        };
+        commands.entity(entity).remove::<BevyVersionTask>();
+        match result {
+            Ok(version) => {
+                info!("'{}' now depends on Bevy {version}", path.display());
+                push_toast(
+                    &mut commands,
+                    ToastLevel::Success,
+                    format!("Updated '{}' to Bevy {version}", path.display()),
+                );
+            }
+            Err(error @ ProjectError::ManualEditRequired { .. }) => {
+                warn!("Unable to change the Bevy version: {error}");
+                push_toast(&mut commands, ToastLevel::Warning, error.to_string());
+            }
+            Err(error) => {
+                error!("Unable to change the Bevy version: {error}");
+                push_toast(
+                    &mut commands,
+                    ToastLevel::Error,
+                    format!("Unable to change the Bevy version: {error}"),
+                );
+            }
+        }
+        // The manifest may have been edited even if cargo update failed
+        commands.run_system_cached_with(refresh_project_badges, entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggest_the_current_version_first() {
+        assert_eq!(suggested_versions(None), ["0.16", "0.15", "0.14"]);
+        assert_eq!(
+            suggested_versions(Some("0.17")),
+            ["0.17", "0.16", "0.15", "0.14"]
+        );
+        assert_eq!(suggested_versions(Some("0.15")), ["0.15", "0.16", "0.14"]);
+    }
+}
@@ -0,0 +1,309 @@
+//! Bundling the diagnostics of a project for a bug report, see [`bevy_editor::project::report`].
+//!
+//! A bundle is offered from the context menu of the project nodes, and in one click from the issues
+//! found by the health check. It includes the last error met opening the project, the recent lines
+//! of the [`LogConsole`] and the creation log of the project, but the sources only when the user
+//! opts in. The bundles are written to the `reports` folder of the Bevy Editor's data folder, and
+//! revealed once written.
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    process::{Child, Command},
+};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use bevy_editor::project::{
+    ProjectError, data_dir,
+    report::{REPORTS_FOLDER, ReportDetails, write_bug_report},
+};
+use bevy_editor_styles::{
+    Theme,
+    checkbox::{CheckboxChanged, spawn_checkbox},
+    dialog::{close_dialog, spawn_dialog_button, spawn_dialog_root},
+};
+
+use crate::{
+    ProjectInfoList,
+    ansi::strip_ansi,
+    creation_progress::creation_log_path,
+    log_console::{LogConsole, LogLine},
+    toast::{ToastLevel, push_toast},
+    ui::ProjectNode,
+};
+
+/// Plugin writing the bug report bundles in the background.
+pub struct BugReportPlugin;
+
+impl Plugin for BugReportPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProjectErrors>()
+            .add_systems(Update, poll_bug_report_tasks);
+    }
+}
+
+/// The last error met with each project, like opening it, included in its bug report.
+#[derive(Resource, Default)]
+pub(crate) struct ProjectErrors(pub HashMap<PathBuf, String>);
+
+/// The dialog confirming what goes in the bug report of a project.
+#[derive(Component)]
+struct BugReportDialog {
+    path: PathBuf,
+    error: Option<String>,
+    /// Whether the sources of the project are included.
+    include_sources: bool,
+}
+
+/// The background writing of a bug report bundle, returning the bundle folder.
+#[derive(Component)]
+struct BugReportTask(Task<Result<PathBuf, ProjectError>>);
+
+/// The content of the log file of the [`LogConsole`] lines, without their colors.
+fn console_log<'a>(lines: impl Iterator<Item = &'a LogLine>) -> String {
+    lines
+        .map(|line| format!("{} {}\n", line.level, strip_ansi(&line.message)))
+        .collect()
+}
+
+/// Open the bug report dialog of the project of the given [`ProjectNode`].
+pub(crate) fn report_project_node(
+    In(project_entity): In<Entity>,
+    mut commands: Commands,
+    nodes: Query<&ProjectNode>,
+) {
+    let Ok(ProjectNode(path)) = nodes.get(project_entity) else {
+        return;
+    };
+    commands.run_system_cached_with(show_bug_report_dialog, (path.clone(), None));
+}
+
+/// Open the dialog writing the bug report of the project at the path, about the given error or the
+/// last one met with it.
+pub(crate) fn show_bug_report_dialog(
+    In((path, error)): In<(PathBuf, Option<String>)>,
+    mut commands: Commands,
+    errors: Res<ProjectErrors>,
+    theme: Res<Theme>,
+) {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let error = error.or_else(|| errors.0.get(&path).cloned());
+
+    let dialog = spawn_dialog_root(&mut commands);
+    commands.entity(dialog).insert(BugReportDialog {
+        path,
+        error,
+        include_sources: false,
+    });
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(420.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(format!("Bug report of {name}")),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+    commands.spawn((
+        Text::new(
+            "The report bundles the Cargo.toml of the project, its Bevy version, the last error, \
+             the recent logs of the launcher, the launcher version and the OS, in a folder to \
+             attach to the bug report.",
+        ),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(panel),
+    ));
+    spawn_checkbox(
+        &mut commands,
+        &theme,
+        "Include the source code of the project",
+        false,
+    )
+    .insert(ChildOf(panel))
+    .observe(
+        move |trigger: On<CheckboxChanged>, mut dialogs: Query<&mut BugReportDialog>| {
+            if let Ok(mut dialog) = dialogs.get_mut(dialog) {
+                dialog.include_sources = trigger.event().0;
+            }
+        },
+    );
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Cancel",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            close_dialog(&mut commands, dialog, false);
+        },
+    );
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Create report",
+        theme.button.hover_color,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached_with(start_bug_report, dialog);
+        },
+    );
+}
+
+/// Start writing the bug report configured in the [`BugReportDialog`], closing it.
+fn start_bug_report(
+    In(dialog): In<Entity>,
+    mut commands: Commands,
+    dialogs: Query<&BugReportDialog>,
+    project_list: Res<ProjectInfoList>,
+    console: Res<LogConsole>,
+) {
+    let Ok(report) = dialogs.get(dialog) else {
+        return;
+    };
+    close_dialog(&mut commands, dialog, true);
+    let Some(project) = project_list
+        .0
+        .iter()
+        .find(|project| project.path == report.path)
+        .cloned()
+    else {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!(
+                "'{}' isn't in the project list anymore",
+                report.path.display()
+            ),
+        );
+        return;
+    };
+
+    let mut details = ReportDetails {
+        app_version: format!("Bevy Editor Launcher {}", env!("CARGO_PKG_VERSION")),
+        error: report.error.clone(),
+        logs: vec![("launcher.log".to_string(), console_log(console.lines()))],
+        include_sources: report.include_sources,
+    };
+    let creation_log = creation_log_path(&project.path);
+    let task = IoTaskPool::get().spawn(async move {
+        if let Ok(log) = fs::read_to_string(&creation_log) {
+            details.logs.push(("create.log".to_string(), log));
+        }
+        write_bug_report(&project, &details, &data_dir().join(REPORTS_FOLDER))
+    });
+    commands.spawn(BugReportTask(task));
+}
+
+/// Tell the user where the finished bug reports were written, and reveal them.
+fn poll_bug_report_tasks(mut commands: Commands, mut tasks: Query<(Entity, &mut BugReportTask)>) {
+    for (entity, mut task) in &mut tasks {
+        let Some(result) = block_on(future::poll_once(&mut task.0)) else {
+            continue;
+        };
+        commands.entity(entity).despawn();
+        match result {
+            Ok(bundle) => {
+                push_toast(
+                    &mut commands,
+                    ToastLevel::Success,
+                    format!("Wrote the bug report to '{}'", bundle.display()),
+                );
+                if let Err(error) = reveal_folder(&bundle) {
+                    warn!("Couldn't reveal '{}': {error}", bundle.display());
+                }
+            }
+            Err(error) => push_toast(
+                &mut commands,
+                ToastLevel::Error,
+                format!("Couldn't write the bug report: {error}"),
+            ),
+        }
+    }
+}
+
+/// Show the folder in the file manager of the platform.
+#[cfg(target_os = "windows")]
+pub(crate) fn reveal_folder(path: &Path) -> io::Result<Child> {
+    Command::new("explorer").arg(path).spawn()
+}
+
+/// Show the folder in the file manager of the platform.
+#[cfg(target_os = "macos")]
+pub(crate) fn reveal_folder(path: &Path) -> io::Result<Child> {
+    Command::new("open").arg(path).spawn()
+}
+
+/// Show the folder in the file manager of the platform.
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub(crate) fn reveal_folder(path: &Path) -> io::Result<Child> {
+    Command::new("xdg-open").arg(path).spawn()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::log::Level;
+
+    use super::*;
+
+    #[test]
+    fn write_the_console_log() {
+        let lines = [
+            LogLine {
+                level: Level::INFO,
+                message: "Opening 'game'".to_string(),
+            },
+            LogLine {
+                level: Level::ERROR,
+                message: "\u{1b}[31merror\u{1b}[0m: could not compile".to_string(),
+            },
+        ];
+        assert_eq!(
+            console_log(lines.iter()),
+            "INFO Opening 'game'\nERROR error: could not compile\n"
+        );
+    }
+}
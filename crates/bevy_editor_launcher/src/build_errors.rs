@@ -0,0 +1,121 @@
+//! Extracting the errors reported by cargo and rustc from their output, for a compact summary.
+//!
+//! The output may be colored, the ANSI escape sequences are ignored.
+
+use crate::ansi::strip_ansi;
+
+/// An error reported in a build output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct BuildError {
+    /// The error code, like `E0308`.
+    pub code: Option<String>,
+    /// The first line of the message, like `mismatched types`.
+    pub message: String,
+    /// Where the error is, like `src/main.rs:3:5`, from the `-->` line following it.
+    pub location: Option<String>,
+}
+
+impl BuildError {
+    /// A one line description of the error, like `E0308: mismatched types (src/main.rs:3:5)`.
+    pub fn summary(&self) -> String {
+        let mut summary = match &self.code {
+            Some(code) => format!("{code}: {}", self.message),
+            None => self.message.clone(),
+        };
+        if let Some(location) = &self.location {
+            summary.push_str(&format!(" ({location})"));
+        }
+        summary
+    }
+}
+
+/// The errors reported in the output, in the order they appear.
+///
+/// The `error: could not compile` and `error: aborting due to` conclusions of cargo are skipped,
+/// they only repeat that the errors above happened.
+pub(crate) fn build_errors(output: &str) -> Vec<BuildError> {
+    let output = strip_ansi(output);
+    let mut errors: Vec<BuildError> = Vec::new();
+    // Whether the lines following the last error may hold its location
+    let mut in_error = false;
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(error) = parse_error_line(line) {
+            in_error = true;
+            errors.push(error);
+        } else if in_error && let Some(location) = line.strip_prefix("--> ") {
+            in_error = false;
+            if let Some(error) = errors.last_mut() {
+                error.location = Some(location.trim().to_string());
+            }
+        } else if line.starts_with("error") || line.starts_with("warning") {
+            in_error = false;
+        }
+    }
+    errors
+}
+
+/// The error starting on the line, like `error[E0308]: mismatched types` or `error: linking failed`.
+fn parse_error_line(line: &str) -> Option<BuildError> {
+    let rest = line.strip_prefix("error")?;
+    let (code, message) = match rest.strip_prefix('[') {
+        Some(rest) => {
+            let (code, message) = rest.split_once("]:")?;
+            (Some(code.to_string()), message)
+        }
+        None => (None, rest.strip_prefix(':')?),
+    };
+    let message = message.trim();
+    if message.is_empty()
+        || message.starts_with("could not compile")
+        || message.starts_with("aborting due to")
+    {
+        return None;
+    }
+    Some(BuildError {
+        code,
+        message: message.to_string(),
+        location: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_the_errors() {
+        let output = "\
+\u{1b}[0m\u{1b}[1m\u{1b}[32m   Compiling\u{1b}[0m game v0.1.0
+\u{1b}[1m\u{1b}[31merror[E0308]\u{1b}[0m\u{1b}[1m: mismatched types\u{1b}[0m
+ \u{1b}[1m\u{1b}[34m--> \u{1b}[0msrc/main.rs:3:18
+  |
+3 |     let x: u32 = \"text\";
+warning: unused variable: `y`
+ --> src/main.rs:4:9
+error: cannot find macro `prinln` in this scope
+error: could not compile `game` (bin \"game\") due to 2 previous errors
+error: aborting due to 2 previous errors
+";
+        assert_eq!(
+            build_errors(output),
+            [
+                BuildError {
+                    code: Some("E0308".to_string()),
+                    message: "mismatched types".to_string(),
+                    location: Some("src/main.rs:3:18".to_string()),
+                },
+                BuildError {
+                    code: None,
+                    message: "cannot find macro `prinln` in this scope".to_string(),
+                    location: None,
+                },
+            ]
+        );
+        assert_eq!(
+            build_errors(output)[0].summary(),
+            "E0308: mismatched types (src/main.rs:3:18)"
+        );
+        assert!(build_errors("Finished `dev` profile in 2.3s\nerrors: 0").is_empty());
+    }
+}
@@ -0,0 +1,225 @@
+//! Cleaning the build artifacts of projects to reclaim disk space, for one project or a selection.
+//!
+//! The cleaning runs `cargo clean` in the background, after a confirmation, and reports the freed
+//! space in the footer bar and the log.
+
+use std::path::{Path, PathBuf};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use bevy_editor::project::{ProjectError, clean::clean_target};
+use bevy_editor_styles::{
+    Theme,
+    busy::BusyActions,
+    dialog::{ConfirmationDialog, DialogResult},
+};
+use bevy_footer_bar::FooterBarStatus;
+
+use crate::{
+    confirmations::{Confirmation, offer_dont_ask_again},
+    details::{format_size, refresh_details_sizes},
+    settings::LauncherSettings,
+    task_limit::TaskSlots,
+    toast::{ToastLevel, push_toast},
+    ui::ProjectNode,
+};
+
+/// The [`BusyAction`](bevy_editor_styles::busy::BusyAction) of the buttons starting a cleaning.
+pub(crate) const CLEAN_ACTION: &str = "clean";
+/// The amount of project names listed in the confirmation.
+const LISTED_NAMES: usize = 5;
+
+/// Plugin reporting the results of the [`CleanTask`]s.
+pub struct CleanPlugin;
+
+impl Plugin for CleanPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, poll_clean_tasks);
+    }
+}
+
+/// The background cleaning of projects, with the result of each one.
+#[derive(Component)]
+struct CleanTask(Task<Vec<(PathBuf, Result<u64, ProjectError>)>>);
+
+/// The name of the project folder, for the messages.
+fn folder_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// "1 project" or "`count` projects".
+fn project_count(count: usize) -> String {
+    match count {
+        1 => "1 project".to_string(),
+        count => format!("{count} projects"),
+    }
+}
+
+/// The summary of a cleaning, like `Freed 1.2 GB by cleaning 3 projects`, and how serious it is.
+fn clean_summary(results: &[(PathBuf, Result<u64, ProjectError>)]) -> (ToastLevel, String) {
+    let freed: u64 = results
+        .iter()
+        .filter_map(|(_, result)| result.as_ref().ok())
+        .sum();
+    let failed: Vec<String> = results
+        .iter()
+        .filter(|(_, result)| result.is_err())
+        .map(|(path, _)| folder_name(path))
+        .collect();
+    let cleaned = results.len() - failed.len();
+    let summary = format!(
+        "Freed {} by cleaning {}",
+        format_size(freed),
+        project_count(cleaned)
+    );
+    if failed.is_empty() {
+        (ToastLevel::Success, summary)
+    } else {
+        (
+            ToastLevel::Warning,
+            format!("{summary}, couldn't clean {}", failed.join(", ")),
+        )
+    }
+}
+
+/// Ask for a confirmation before cleaning the build artifacts of the projects at the given paths.
+pub(crate) fn confirm_clean_projects(
+    In(paths): In<Vec<PathBuf>>,
+    mut commands: Commands,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+) {
+    let count = paths.len();
+    if count == 0 {
+        return;
+    }
+    let mut names: Vec<String> = paths
+        .iter()
+        .take(LISTED_NAMES)
+        .map(|path| folder_name(path))
+        .collect();
+    if count > LISTED_NAMES {
+        names.push(format!("and {} more", count - LISTED_NAMES));
+    }
+    let dialog = ConfirmationDialog::new(
+        format!("Clean {}?", project_count(count)),
+        format!(
+            "Their target folders will be deleted, the next builds start from scratch:\n- {}",
+            names.join("\n- ")
+        ),
+    );
+    offer_dont_ask_again(dialog, Confirmation::CleanProjects, &settings)
+        .confirm_label("Clean")
+        .spawn(&mut commands, &theme)
+        .observe(move |trigger: On<DialogResult>, mut commands: Commands| {
+            if trigger.event().0 {
+                commands.run_system_cached_with(start_clean, paths.clone());
+            }
+        });
+}
+
+/// Ask for a confirmation before cleaning the project of the given [`ProjectNode`].
+pub(crate) fn clean_project_node(
+    In(project_entity): In<Entity>,
+    mut commands: Commands,
+    nodes: Query<&ProjectNode>,
+) {
+    if let Ok(ProjectNode(path)) = nodes.get(project_entity) {
+        commands.run_system_cached_with(confirm_clean_projects, vec![path.clone()]);
+    }
+}
+
+/// Start cleaning the projects in the background, one after the other.
+fn start_clean(
+    In(paths): In<Vec<PathBuf>>,
+    mut commands: Commands,
+    mut busy: ResMut<BusyActions>,
+    mut footer_status: Query<&mut Text, With<FooterBarStatus>>,
+    slots: Res<TaskSlots>,
+) {
+    info!("Cleaning {}", project_count(paths.len()));
+    for mut status in &mut footer_status {
+        status.0 = format!("Cleaning {}...", project_count(paths.len()));
+    }
+    let task = IoTaskPool::get().spawn(slots.run(async move {
+        paths
+            .into_iter()
+            .map(|path| {
+                let result = clean_target(&path);
+                (path, result)
+            })
+            .collect()
+    }));
+    let entity = commands.spawn(CleanTask(task)).id();
+    busy.start(CLEAN_ACTION, entity);
+}
+
+/// Report the finished [`CleanTask`]s, and update the sizes shown in the details of the projects.
+fn poll_clean_tasks(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut CleanTask)>,
+    mut footer_status: Query<&mut Text, With<FooterBarStatus>>,
+) {
+    for (entity, mut task) in &mut tasks {
+        let Some(results) = block_on(future::poll_once(&mut task.0)) else {
+            continue;
+        };
+        // Despawning the task finishes its busy action
+        commands.entity(entity).despawn();
+
+        for (path, result) in &results {
+            match result {
+                Ok(freed) => info!("Freed {} in '{}'", format_size(*freed), path.display()),
+                Err(error) => warn!("Failed to clean '{}': {error}", path.display()),
+            }
+        }
+        let (level, summary) = clean_summary(&results);
+        for mut status in &mut footer_status {
+            status.0.clone_from(&summary);
+        }
+        push_toast(&mut commands, level, summary);
+        commands.run_system_cached_with(
+            refresh_details_sizes,
+            results.into_iter().map(|(path, _)| path).collect(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summarize_the_cleaning() {
+        let results = vec![
+            (PathBuf::from("/projects/game"), Ok(3 * 1024 * 1024)),
+            (PathBuf::from("/projects/tool"), Ok(0)),
+        ];
+        assert_eq!(
+            clean_summary(&results),
+            (
+                ToastLevel::Success,
+                "Freed 3.0 MB by cleaning 2 projects".to_string()
+            )
+        );
+
+        let results = vec![
+            (PathBuf::from("/projects/game"), Ok(512)),
+            (
+                PathBuf::from("/projects/gone"),
+                Err(ProjectError::NotFound(PathBuf::from("/projects/gone"))),
+            ),
+        ];
+        assert_eq!(
+            clean_summary(&results),
+            (
+                ToastLevel::Warning,
+                "Freed 512 B by cleaning 1 project, couldn't clean gone".to_string()
+            )
+        );
+    }
+}
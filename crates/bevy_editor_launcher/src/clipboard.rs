@@ -0,0 +1,61 @@
+//! Copying the path or the name of a project to the clipboard.
+
+use bevy::prelude::*;
+use bevy_clipboard::BevyClipboard;
+
+use crate::{
+    toast::{ToastLevel, push_toast},
+    ui::ProjectNode,
+};
+
+/// What to copy from a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CopiedText {
+    /// The full path of the project folder
+    Path,
+    /// The name of the project folder
+    Name,
+}
+
+/// Copy the path or the name of the project of the given [`ProjectNode`] to the clipboard.
+///
+/// A toast tells whether it worked, the clipboard isn't available in every environment.
+pub(crate) fn copy_project_text(
+    In((project_entity, copied)): In<(Entity, CopiedText)>,
+    mut commands: Commands,
+    query_nodes: Query<&ProjectNode>,
+    clipboard: Option<ResMut<BevyClipboard>>,
+) {
+    let Ok(ProjectNode(path)) = query_nodes.get(project_entity) else {
+        return;
+    };
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let (text, description) = match copied {
+        CopiedText::Path => (path.display().to_string(), "path"),
+        CopiedText::Name => (name.clone(), "name"),
+    };
+
+    let Some(mut clipboard) = clipboard else {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            "The clipboard isn't available",
+        );
+        return;
+    };
+    match clipboard.set_text(text) {
+        Ok(()) => push_toast(
+            &mut commands,
+            ToastLevel::Success,
+            format!("Copied the {description} of '{name}'"),
+        ),
+        Err(error) => push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!("Couldn't copy the {description} of '{name}': {error}"),
+        ),
+    }
+}
@@ -0,0 +1,403 @@
+//! The collections of the project list, each grouping the [`ProjectNode`]s of its projects under a
+//! header.
+//!
+//! The header of a group collapses it, the projects sliding out of view unless the animations are
+//! reduced. The collapsed collections are kept in the [`LauncherSettings`], by name. The projects
+//! that aren't in any collection are listed before the groups.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bevy::prelude::*;
+use bevy_editor_styles::Theme;
+
+use crate::{
+    ProjectInfoList,
+    focus::{Activate, Focusable},
+    quick_access::{collapse_label, eased_height, step_collapse},
+    settings::LauncherSettings,
+    sort::sort_project_nodes,
+    ui::{ProjectList, ProjectNode},
+};
+
+/// Plugin grouping the [`ProjectNode`]s by collection and animating the collapsed groups.
+pub struct CollectionGroupsPlugin;
+
+impl Plugin for CollectionGroupsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                group_project_nodes.before(sort_project_nodes),
+                animate_collection_collapse,
+            ),
+        );
+    }
+}
+
+/// The header and the [`ProjectNode`]s of a collection, in the [`ProjectList`].
+#[derive(Component)]
+pub(crate) struct CollectionGroup {
+    name: String,
+    /// The node the [`ProjectNode`]s of the collection are spawned into.
+    content: Entity,
+}
+
+/// The part of a [`CollectionGroup`] below its header, its height animated when collapsing.
+#[derive(Component)]
+struct CollectionBody {
+    collection: String,
+    /// The node of the projects, measured to animate the height.
+    content: Entity,
+    /// From 0 when collapsed to 1 when expanded.
+    progress: f32,
+}
+
+/// The text of a group header telling what a click does.
+#[derive(Component)]
+struct CollectionCollapseLabel(String);
+
+/// Collapse the group of the collection, or expand it if it already is.
+fn toggle_collection(In(collection): In<String>, mut settings: ResMut<LauncherSettings>) {
+    if !settings.collapsed_collections.remove(&collection) {
+        settings.collapsed_collections.insert(collection);
+    }
+}
+
+/// Spawn the [`CollectionGroup`] of the collection, returning it and the node of its projects.
+fn spawn_collection_group(
+    commands: &mut Commands,
+    theme: &Theme,
+    settings: &LauncherSettings,
+    name: String,
+) -> (Entity, Entity) {
+    let collapsed = settings.collapsed_collections.contains(&name);
+    let group = commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            ..default()
+        })
+        .id();
+    let clicked = name.clone();
+    let activated = name.clone();
+    commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                column_gap: Val::Px(8.0),
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(4.0)),
+                ..default()
+            },
+            Focusable(1),
+            ChildOf(group),
+            children![
+                (
+                    Text::new(name.clone()),
+                    TextFont {
+                        font: theme.text.font.clone(),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(theme.text.text_color),
+                    Pickable::IGNORE,
+                ),
+                (
+                    CollectionCollapseLabel(name.clone()),
+                    Text::new(collapse_label(collapsed)),
+                    TextFont {
+                        font: theme.text.font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(theme.text.low_priority),
+                    Pickable::IGNORE,
+                ),
+            ],
+        ))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                commands.run_system_cached_with(toggle_collection, clicked.clone());
+            },
+        )
+        .observe(move |_trigger: On<Activate>, mut commands: Commands| {
+            commands.run_system_cached_with(toggle_collection, activated.clone());
+        });
+    let body = commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: if collapsed { Val::Px(0.0) } else { Val::Auto },
+                display: if collapsed {
+                    Display::None
+                } else {
+                    Display::Flex
+                },
+                flex_direction: FlexDirection::Column,
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            ChildOf(group),
+        ))
+        .id();
+    // Not shrinking with the body, so its height is the one of the expanded group
+    let content = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                flex_wrap: FlexWrap::Wrap,
+                flex_shrink: 0.0,
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            ChildOf(body),
+        ))
+        .id();
+    commands.entity(body).insert(CollectionBody {
+        collection: name.clone(),
+        content,
+        progress: if collapsed { 0.0 } else { 1.0 },
+    });
+    commands
+        .entity(group)
+        .insert(CollectionGroup { name, content });
+    (group, content)
+}
+
+/// Move the [`ProjectNode`]s to the [`CollectionGroup`] of their collection when the nodes or the
+/// projects change, spawning the missing groups and despawning the empty ones.
+///
+/// The groups are in the order of their names, after the projects without a collection. The other
+/// children of the [`ProjectList`], like the new project button, stay last.
+#[expect(clippy::too_many_arguments)]
+fn group_project_nodes(
+    mut commands: Commands,
+    project_list: Res<ProjectInfoList>,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+    list: Single<(Entity, &Children), With<ProjectList>>,
+    added: Query<(), Added<ProjectNode>>,
+    nodes: Query<(Entity, &ProjectNode, &ChildOf)>,
+    groups: Query<(Entity, &CollectionGroup)>,
+) {
+    if added.is_empty() && !project_list.is_changed() {
+        return;
+    }
+    let (list, list_children) = *list;
+    let mut groups_by_name: BTreeMap<String, (Entity, Entity)> = groups
+        .iter()
+        .map(|(group, collection)| (collection.name.clone(), (group, collection.content)))
+        .collect();
+    let mut shown = BTreeSet::new();
+    let mut ungrouped = Vec::new();
+    for (node, ProjectNode(path), ChildOf(parent)) in &nodes {
+        let collection = project_list
+            .0
+            .iter()
+            .find(|project| project.path == *path)
+            .and_then(|project| project.collection.clone());
+        let Some(collection) = collection else {
+            ungrouped.push(node);
+            continue;
+        };
+        let (_, content) = *groups_by_name.entry(collection.clone()).or_insert_with(|| {
+            spawn_collection_group(&mut commands, &theme, &settings, collection.clone())
+        });
+        if *parent != content {
+            commands.entity(node).insert(ChildOf(content));
+        }
+        shown.insert(collection);
+    }
+
+    let mut order = ungrouped;
+    order.extend(
+        groups_by_name
+            .iter()
+            .filter(|(name, _)| shown.contains(*name))
+            .map(|(_, (group, _))| *group),
+    );
+    order.extend(
+        list_children
+            .iter()
+            .filter(|child| !nodes.contains(*child) && !groups.contains(*child)),
+    );
+    if !order.iter().copied().eq(list_children.iter()) {
+        commands.entity(list).replace_children(&order);
+    }
+    // After the projects leaving them are moved to the list
+    for (name, (group, _)) in &groups_by_name {
+        if !shown.contains(name) {
+            commands.entity(*group).despawn();
+        }
+    }
+}
+
+/// Move the [`CollectionBody`]s toward the collapsed state of their collection in the settings,
+/// instantly when the animations are reduced.
+///
+/// The height of a body follows the measured height of its projects, so the scroll box of the
+/// [`ProjectList`] follows it every frame. The expanded bodies go back to an automatic height, for
+/// the projects added to them, and the collapsed ones aren't displayed so their projects can't be
+/// focused.
+fn animate_collection_collapse(
+    mut bodies: Query<(&mut CollectionBody, &mut Node)>,
+    contents: Query<&ComputedNode>,
+    mut labels: Query<(&CollectionCollapseLabel, &mut Text)>,
+    settings: Res<LauncherSettings>,
+    time: Res<Time>,
+) {
+    if settings.is_changed() {
+        for (label, mut text) in &mut labels {
+            let collapsed = collapse_label(settings.collapsed_collections.contains(&label.0));
+            if text.0 != collapsed {
+                text.0 = collapsed.to_string();
+            }
+        }
+    }
+    for (mut body, mut node) in &mut bodies {
+        let target = if settings.collapsed_collections.contains(&body.collection) {
+            0.0
+        } else {
+            1.0
+        };
+        if body.progress == target {
+            continue;
+        }
+        body.progress = step_collapse(body.progress, target, &settings, time.delta_secs());
+        // The projects of a body that wasn't displayed are measured from the next frame on
+        let content_height = contents
+            .get(body.content)
+            .map(|content| content.size().y * content.inverse_scale_factor())
+            .unwrap_or_default();
+        node.height = if body.progress == 1.0 {
+            Val::Auto
+        } else {
+            Val::Px(content_height * eased_height(body.progress))
+        };
+        node.display = if body.progress > 0.0 {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use bevy_editor::project::ProjectInfo;
+
+    use super::*;
+
+    #[test]
+    fn group_the_projects_by_collection() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_resource::<Theme>()
+            .init_resource::<LauncherSettings>()
+            .insert_resource(ProjectInfoList(vec![
+                ProjectInfo {
+                    collection: Some("Games".to_string()),
+                    ..ProjectInfo::new("/projects/runner")
+                },
+                ProjectInfo::new("/projects/tool"),
+                ProjectInfo {
+                    collection: Some("Games".to_string()),
+                    ..ProjectInfo::new("/projects/puzzle")
+                },
+            ]))
+            .add_systems(Update, group_project_nodes);
+        let list = app.world_mut().spawn(ProjectList).id();
+        let nodes = ["/projects/runner", "/projects/tool", "/projects/puzzle"].map(|path| {
+            app.world_mut()
+                .spawn((ProjectNode(PathBuf::from(path)), ChildOf(list)))
+                .id()
+        });
+        let button = app.world_mut().spawn((Node::default(), ChildOf(list))).id();
+        app.update();
+
+        let world = app.world_mut();
+        let (group, content) = world
+            .query::<(Entity, &CollectionGroup)>()
+            .single(world)
+            .map(|(group, collection)| (group, collection.content))
+            .unwrap();
+        let children = |world: &World, entity: Entity| -> Vec<Entity> {
+            world.get::<Children>(entity).unwrap().to_vec()
+        };
+        assert_eq!(children(world, list), [nodes[1], group, button]);
+        assert_eq!(children(world, content), [nodes[0], nodes[2]]);
+
+        // Taking the last projects out of the collection despawns its group
+        for project in &mut world.resource_mut::<ProjectInfoList>().0 {
+            project.collection = None;
+        }
+        app.update();
+        let world = app.world();
+        assert!(world.get_entity(group).is_err());
+        assert_eq!(children(world, list)[3], button);
+    }
+
+    #[test]
+    fn animate_the_collapsed_groups() {
+        let mut app = App::new();
+        app.insert_resource(LauncherSettings {
+            collapse_animation_ms: 100,
+            ..default()
+        })
+        .init_resource::<Time>()
+        .add_systems(Update, animate_collection_collapse);
+        let content = app
+            .world_mut()
+            .spawn(ComputedNode {
+                size: Vec2::new(500.0, 400.0),
+                inverse_scale_factor: 1.0,
+                ..default()
+            })
+            .id();
+        let body = app
+            .world_mut()
+            .spawn((
+                CollectionBody {
+                    collection: "Games".to_string(),
+                    content,
+                    progress: 1.0,
+                },
+                Node::default(),
+            ))
+            .id();
+        let frame = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(50));
+            app.update();
+            let world = app.world();
+            let node = world.get::<Node>(body).unwrap();
+            (
+                world.get::<CollectionBody>(body).unwrap().progress,
+                node.height,
+                node.display,
+            )
+        };
+
+        app.world_mut()
+            .resource_mut::<LauncherSettings>()
+            .collapsed_collections
+            .insert("Games".to_string());
+        assert_eq!(
+            frame(&mut app),
+            (0.5, Val::Px(400.0 * eased_height(0.5)), Display::Flex)
+        );
+        assert_eq!(frame(&mut app), (0.0, Val::Px(0.0), Display::None));
+
+        app.world_mut()
+            .resource_mut::<LauncherSettings>()
+            .collapsed_collections
+            .clear();
+        assert_eq!(
+            frame(&mut app),
+            (0.5, Val::Px(400.0 * eased_height(0.5)), Display::Flex)
+        );
+        assert_eq!(frame(&mut app), (1.0, Val::Auto, Display::Flex));
+    }
+}
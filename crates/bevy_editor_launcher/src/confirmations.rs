@@ -0,0 +1,260 @@
+//! Skipping the confirmations the user asked not to show again.
+//!
+//! The [`Confirmation`]s offering a "Don't ask again" checkbox are remembered in the
+//! [`LauncherSettings::suppressed_confirmations`] as soon as they are confirmed, so they are saved
+//! like any other setting, even when confirming exits the launcher. The settings are copied to the
+//! [`SuppressedConfirmations`] the dialogs look their key up in. The ones deleting files only offer
+//! it when [`LauncherSettings::suppress_file_deleting_confirmations`] is set. The settings dialog
+//! asks all of them again, see [`spawn_reset_confirmations_row`].
+
+use bevy::prelude::*;
+use bevy_editor_styles::{
+    Theme,
+    dialog::{
+        ConfirmationDialog, ConfirmationSuppressed, SuppressedConfirmations, spawn_dialog_button,
+    },
+};
+
+use crate::{
+    settings::LauncherSettings,
+    toast::{ToastLevel, push_toast},
+};
+
+/// The confirmations that can be skipped, each remembered under its own key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Confirmation {
+    /// Creating a project from a template whose toolchain requirements aren't met.
+    CreateWithoutToolchain,
+    /// Creating a project inside the folder of another one.
+    CreateInsideProject,
+    /// Creating a project from a Git template while the network seems unreachable.
+    CreateOffline,
+    /// Reopening the projects of the last session at startup.
+    RestoreSession,
+    /// Deleting the target folders of projects.
+    CleanProjects,
+    /// Deleting the folders of projects.
+    DeleteProjects,
+    /// Quitting while edits couldn't be saved or tasks are running.
+    QuitWithPendingWork,
+}
+
+impl Confirmation {
+    /// The key the confirmation is stored under in the settings.
+    pub fn key(self) -> &'static str {
+        match self {
+            Confirmation::CreateWithoutToolchain => "create_without_toolchain",
+            Confirmation::CreateInsideProject => "create_inside_project",
+            Confirmation::CreateOffline => "create_offline",
+            Confirmation::RestoreSession => "restore_session",
+            Confirmation::CleanProjects => "clean_projects",
+            Confirmation::DeleteProjects => "delete_projects",
+            Confirmation::QuitWithPendingWork => "quit_with_pending_work",
+        }
+    }
+
+    /// Whether confirming deletes files from the disk.
+    pub fn deletes_files(self) -> bool {
+        matches!(
+            self,
+            Confirmation::CleanProjects | Confirmation::DeleteProjects
+        )
+    }
+}
+
+/// Plugin keeping the [`SuppressedConfirmations`] in sync with the [`LauncherSettings`].
+pub struct ConfirmationsPlugin;
+
+impl Plugin for ConfirmationsPlugin {
+    fn build(&self, app: &mut App) {
+        // Before the dialogs spawned at startup look their key up in `Update`
+        app.add_systems(PreUpdate, sync_suppressed_confirmations)
+            .add_observer(remember_suppressed_confirmation);
+    }
+}
+
+/// Offer not to ask the confirmation again, unless it deletes files and the settings don't allow it.
+pub(crate) fn offer_dont_ask_again(
+    dialog: ConfirmationDialog,
+    confirmation: Confirmation,
+    settings: &LauncherSettings,
+) -> ConfirmationDialog {
+    if confirmation.deletes_files() && !settings.suppress_file_deleting_confirmations {
+        dialog
+    } else {
+        dialog.dont_ask_again(confirmation.key())
+    }
+}
+
+/// Describe how many confirmations are skipped.
+fn skipped_confirmations_text(count: usize) -> String {
+    match count {
+        0 => "Every confirmation is asked.".to_string(),
+        1 => "1 confirmation is skipped.".to_string(),
+        count => format!("{count} confirmations are skipped."),
+    }
+}
+
+/// Spawn how many confirmations are skipped, with the button asking all of them again.
+pub(crate) fn spawn_reset_confirmations_row<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    settings: &LauncherSettings,
+) -> EntityCommands<'a> {
+    let row = commands
+        .spawn(Node {
+            display: Display::Flex,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        })
+        .id();
+    let text = commands
+        .spawn((
+            Node {
+                flex_grow: 1.0,
+                ..default()
+            },
+            Text::new(skipped_confirmations_text(
+                settings.suppressed_confirmations.len(),
+            )),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(theme.text.low_priority),
+            ChildOf(row),
+        ))
+        .id();
+    spawn_dialog_button(
+        commands,
+        theme,
+        "Ask again",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(row))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached_with(ask_confirmations_again, text);
+        },
+    );
+    commands.entity(row)
+}
+
+/// Ask all the skipped confirmations again, the [`SuppressedConfirmations`] following the settings.
+fn ask_confirmations_again(
+    In(text): In<Entity>,
+    mut commands: Commands,
+    mut settings: ResMut<LauncherSettings>,
+    mut texts: Query<&mut Text>,
+) {
+    if settings.suppressed_confirmations.is_empty() {
+        return;
+    }
+    settings.suppressed_confirmations.clear();
+    if let Ok(mut text) = texts.get_mut(text) {
+        text.0 = skipped_confirmations_text(0);
+    }
+    push_toast(
+        &mut commands,
+        ToastLevel::Info,
+        "All the confirmations will be asked again",
+    );
+}
+
+/// Add the confirmation the user asked not to show again to the settings, in the frame it is
+/// confirmed.
+fn remember_suppressed_confirmation(
+    trigger: On<ConfirmationSuppressed>,
+    mut settings: ResMut<LauncherSettings>,
+) {
+    let key = &trigger.event().0;
+    if !settings.suppressed_confirmations.contains(key) {
+        settings.suppressed_confirmations.insert(key.clone());
+    }
+}
+
+/// Copy the [`LauncherSettings::suppressed_confirmations`] to the [`SuppressedConfirmations`].
+fn sync_suppressed_confirmations(
+    settings: Res<LauncherSettings>,
+    mut suppressed: ResMut<SuppressedConfirmations>,
+) {
+    if settings.suppressed_confirmations != suppressed.0 {
+        suppressed.0.clone_from(&settings.suppressed_confirmations);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::settings::{SettingsFile, SettingsPlugin};
+
+    fn confirmations_app(settings_file: &std::path::Path) -> App {
+        let settings = fs::read_to_string(settings_file)
+            .ok()
+            .and_then(|settings| ron::de::from_str::<LauncherSettings>(&settings).ok())
+            .unwrap_or_default();
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<AppExit>()
+            .insert_resource(SettingsFile(settings_file.to_path_buf()))
+            .insert_resource(settings)
+            .init_resource::<SuppressedConfirmations>()
+            .add_plugins((SettingsPlugin, ConfirmationsPlugin));
+        app
+    }
+
+    #[test]
+    fn remember_the_suppressed_confirmations_across_exits() {
+        let folder = std::env::temp_dir().join(format!(
+            "bevy_editor_launcher_confirmations_{}",
+            std::process::id()
+        ));
+        let settings_file = folder.join("settings.ron");
+        let _ = fs::remove_dir_all(&folder);
+
+        let mut app = confirmations_app(&settings_file);
+        app.update();
+        // Confirming the quit dialog exits in the same frame, while another setting changed
+        let dialog = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .resource_mut::<LauncherSettings>()
+            .open_after_creation = false;
+        app.world_mut().trigger_targets(
+            ConfirmationSuppressed(Confirmation::QuitWithPendingWork.key().to_string()),
+            dialog,
+        );
+        app.world_mut().write_event(AppExit::Success);
+        app.update();
+
+        let mut reloaded = confirmations_app(&settings_file);
+        reloaded.update();
+        let suppressed = reloaded.world().resource::<SuppressedConfirmations>();
+        assert!(suppressed.contains(Confirmation::QuitWithPendingWork.key()));
+        assert!(
+            !reloaded
+                .world()
+                .resource::<LauncherSettings>()
+                .open_after_creation
+        );
+
+        // Emptying the list in the settings asks for all the confirmations again
+        reloaded
+            .world_mut()
+            .resource_mut::<LauncherSettings>()
+            .suppressed_confirmations
+            .clear();
+        reloaded.update();
+        assert!(
+            reloaded
+                .world()
+                .resource::<SuppressedConfirmations>()
+                .0
+                .is_empty()
+        );
+        let _ = fs::remove_dir_all(&folder);
+    }
+}
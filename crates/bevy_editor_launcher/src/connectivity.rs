@@ -0,0 +1,156 @@
+//! Finding out whether the network is reachable, to disable the features needing it while offline.
+//!
+//! A background probe opens a connection to well known hosts every [`PROBE_INTERVAL`], the
+//! [`Connectivity`] resource holds its latest result. The Git templates are disabled while offline,
+//! see [`NewProjectWindow`](crate::new_project::NewProjectWindow), and a toast tells when the
+//! connection is lost or back.
+
+use std::{
+    net::{TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+
+use crate::{
+    settings::LauncherSettings,
+    toast::{ToastLevel, push_toast},
+};
+
+/// The hosts the probe connects to, reaching any of them means being online.
+const PROBE_ADDRESSES: &[&str] = &["index.crates.io:443", "github.com:443"];
+/// How long the probe waits for a connection to a host.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+/// How often the network is probed.
+const PROBE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Plugin probing the network and keeping the [`Connectivity`] up to date.
+pub struct ConnectivityPlugin;
+
+impl Plugin for ConnectivityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Connectivity>()
+            .init_resource::<ConnectivityProbe>()
+            .add_systems(Update, (probe_connectivity, report_connectivity).chain());
+    }
+}
+
+/// Whether the network was reachable at the latest probe.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum Connectivity {
+    /// No probe finished yet, or the probe is disabled by the settings.
+    #[default]
+    Unknown,
+    /// A host could be reached.
+    Online,
+    /// None of the hosts could be reached.
+    Offline,
+}
+
+impl Connectivity {
+    /// Whether the network is known to be unreachable.
+    pub fn is_offline(self) -> bool {
+        self == Connectivity::Offline
+    }
+}
+
+/// The running probe, and the time until the next one.
+#[derive(Resource)]
+struct ConnectivityProbe {
+    timer: Timer,
+    task: Option<Task<bool>>,
+}
+
+impl Default for ConnectivityProbe {
+    fn default() -> Self {
+        // Finished right away, so the first probe starts with the launcher
+        let mut timer = Timer::new(PROBE_INTERVAL, TimerMode::Repeating);
+        timer.set_elapsed(PROBE_INTERVAL);
+        Self { timer, task: None }
+    }
+}
+
+/// Whether a connection can be opened to any of the addresses, resolving their host names first.
+fn probe(addresses: &[&str]) -> bool {
+    addresses.iter().any(|address| {
+        address.to_socket_addrs().is_ok_and(|mut resolved| {
+            resolved.any(|socket| TcpStream::connect_timeout(&socket, PROBE_TIMEOUT).is_ok())
+        })
+    })
+}
+
+/// Start a probe every [`PROBE_INTERVAL`], and store its result in the [`Connectivity`].
+fn probe_connectivity(
+    mut probe_state: ResMut<ConnectivityProbe>,
+    mut connectivity: ResMut<Connectivity>,
+    settings: Res<LauncherSettings>,
+    time: Res<Time>,
+) {
+    if !settings.check_connectivity {
+        probe_state.task = None;
+        connectivity.set_if_neq(Connectivity::Unknown);
+        return;
+    }
+
+    if let Some(task) = &mut probe_state.task {
+        let Some(online) = block_on(future::poll_once(task)) else {
+            return;
+        };
+        probe_state.task = None;
+        connectivity.set_if_neq(if online {
+            Connectivity::Online
+        } else {
+            Connectivity::Offline
+        });
+    }
+    // The timer is paused while a probe runs, as the system returns early
+    if probe_state.timer.tick(time.delta()).just_finished() {
+        probe_state.task = Some(IoTaskPool::get().spawn(async { probe(PROBE_ADDRESSES) }));
+    }
+}
+
+/// Tell the user when the connection is lost, and when it is back.
+fn report_connectivity(
+    mut commands: Commands,
+    connectivity: Res<Connectivity>,
+    mut previous: Local<Connectivity>,
+) {
+    if !connectivity.is_changed() {
+        return;
+    }
+    match (*previous, *connectivity) {
+        (Connectivity::Online | Connectivity::Unknown, Connectivity::Offline) => push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            "No network connection, the Git templates are disabled",
+        ),
+        (Connectivity::Offline, Connectivity::Online) => push_toast(
+            &mut commands,
+            ToastLevel::Info,
+            "Back online, the Git templates are available again",
+        ),
+        _ => {}
+    }
+    *previous = *connectivity;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+
+    use super::*;
+
+    #[test]
+    fn probe_the_addresses() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let reachable = listener.local_addr().unwrap().to_string();
+        assert!(probe(&[reachable.as_str()]));
+
+        drop(listener);
+        assert!(!probe(&[reachable.as_str()]), "nothing listens anymore");
+        assert!(!probe(&[]));
+    }
+}
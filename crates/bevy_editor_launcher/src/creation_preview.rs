@@ -0,0 +1,423 @@
+//! The last step of the new project window, reviewing what the creation will do before it starts.
+//!
+//! The [`CreationPreview`] dialog lists the folder the project is created in, the template, the
+//! package name, what happens to the Git history and the values of the template placeholders. The
+//! folder can be fixed in place, the rest of the review follows it as it is typed. The project is
+//! only created once confirmed, cancelling goes back to the new project window.
+//!
+//! A template with a post-create command shows it, and the command only runs when the user ticks
+//! the box allowing it, as it can do anything a program can.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use bevy::prelude::*;
+use bevy_editor::project::{
+    enclosing_project, is_empty_or_missing,
+    templates::{TemplateManifest, Templates},
+    validate_project_name,
+};
+use bevy_editor_styles::{
+    Theme,
+    checkbox::{CheckboxChanged, spawn_checkbox},
+    dialog::{close_dialog, spawn_dialog_button, spawn_dialog_root},
+    text_input::{TextInputSubmit, TextInputValidator, TextInputValue, spawn_text_input},
+};
+
+use crate::{
+    new_project::create_project_at,
+    toast::{ToastLevel, push_toast},
+};
+
+/// Plugin keeping the [`CreationPreview`] in sync with its folder.
+pub struct CreationPreviewPlugin;
+
+impl Plugin for CreationPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_creation_previews);
+    }
+}
+
+/// The dialog reviewing the project configured in a new project window.
+#[derive(Component)]
+struct CreationPreview {
+    /// The new project window the project is configured in.
+    window: Entity,
+    template: Templates,
+    manifest: TemplateManifest,
+    /// The values entered for the template variables.
+    variables: HashMap<String, String>,
+    /// The input of the folder the project is created in.
+    path_input: Entity,
+    /// The node listing the [`preview_lines`].
+    summary: Entity,
+    /// Whether the user allowed the post-create command of the template to run.
+    run_post_create: bool,
+}
+
+/// Check the project can be created in the folder, returning why otherwise.
+fn check_project_path(path: &Path) -> Result<(), String> {
+    if !path.is_absolute() {
+        return Err("Enter the full path of the folder".to_string());
+    }
+    let Some(name) = path.file_name() else {
+        return Err("Enter the folder of the project".to_string());
+    };
+    validate_project_name(&name.to_string_lossy()).map_err(|error| error.to_string())?;
+    // Like the creation, which fills an empty folder
+    if !is_empty_or_missing(path) {
+        return Err(format!(
+            "'{}' already exists and isn't empty",
+            path.display()
+        ));
+    }
+    Ok(())
+}
+
+/// The labels and values reviewed before creating the project in the folder.
+fn preview_lines(
+    template: &Templates,
+    manifest: &TemplateManifest,
+    path: &Path,
+    variables: &HashMap<String, String>,
+) -> Vec<(String, String)> {
+    // Already resolved without errors by the new project window, the folder doesn't change that
+    let resolved = manifest
+        .resolve_variables(path, variables)
+        .unwrap_or_default();
+    let value = |name: &str| resolved.get(name).cloned().unwrap_or_default();
+
+    let mut lines = vec![
+        ("Folder".to_string(), path.display().to_string()),
+        (
+            "Template".to_string(),
+            match template {
+                Templates::Git { url, .. } => url.clone(),
+                _ => manifest.name.clone(),
+            },
+        ),
+        ("Package name".to_string(), value("package_name")),
+        (
+            "Git history".to_string(),
+            match template {
+                Templates::Git {
+                    fresh_history: true,
+                    ..
+                } => "A new history".to_string(),
+                Templates::Git {
+                    fresh_history: false,
+                    ..
+                } => "The history of the repository".to_string(),
+                _ => "None, the folder isn't a Git repository".to_string(),
+            },
+        ),
+    ];
+    if let Some(enclosing) = enclosing_project(path) {
+        lines.push(("Nested in".to_string(), enclosing.display().to_string()));
+    }
+    lines.extend(
+        manifest
+            .variables
+            .iter()
+            .map(|variable| (variable.name.clone(), value(&variable.name))),
+    );
+    if let Some(hook) = &manifest.post_create {
+        lines.push((
+            "Post-create command".to_string(),
+            hook.command_line(&resolved),
+        ));
+    }
+    lines
+}
+
+/// Open the [`CreationPreview`] of the project configured in the new project window, to be
+/// created in the given folder.
+pub(crate) fn show_creation_preview(
+    In((window, path, template, variables)): In<(
+        Entity,
+        PathBuf,
+        Templates,
+        HashMap<String, String>,
+    )>,
+    mut commands: Commands,
+    theme: Res<Theme>,
+) {
+    let manifest = match template.manifest() {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            push_toast(&mut commands, ToastLevel::Error, error.to_string());
+            return;
+        }
+    };
+
+    let dialog = spawn_dialog_root(&mut commands);
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(480.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    commands.spawn((
+        Text::new("Review the new project"),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+
+    let path_input = spawn_text_input(
+        &mut commands,
+        &theme,
+        path.display().to_string(),
+        "The folder of the project",
+    )
+    .insert((
+        TextInputValidator::new(|text| check_project_path(Path::new(text.trim()))),
+        ChildOf(panel),
+    ))
+    .observe(
+        move |_trigger: On<TextInputSubmit>, mut commands: Commands| {
+            commands.run_system_cached_with(confirm_creation, dialog);
+        },
+    )
+    .id();
+    let summary = commands
+        .spawn((
+            Node {
+                display: Display::Grid,
+                grid_template_columns: vec![GridTrack::auto(), GridTrack::flex(1.0)],
+                column_gap: Val::Px(12.0),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    if manifest.post_create.is_some() {
+        commands.spawn((
+            Text::new(
+                "The template runs the command above in the project folder once its files are \
+                 written. Only allow it for templates you trust.",
+            ),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(theme.text.low_priority),
+            ChildOf(panel),
+        ));
+        spawn_checkbox(&mut commands, &theme, "Run the post-create command", false)
+            .insert(ChildOf(panel))
+            .observe(
+                move |trigger: On<CheckboxChanged>, mut previews: Query<&mut CreationPreview>| {
+                    if let Ok(mut preview) = previews.get_mut(dialog) {
+                        preview.run_post_create = trigger.event().0;
+                    }
+                },
+            );
+    }
+    commands.entity(dialog).insert(CreationPreview {
+        window,
+        template,
+        manifest,
+        variables,
+        path_input,
+        summary,
+        run_post_create: false,
+    });
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Back",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            close_dialog(&mut commands, dialog, false);
+        },
+    );
+    spawn_dialog_button(&mut commands, &theme, "Create", theme.button.hover_color)
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                commands.run_system_cached_with(confirm_creation, dialog);
+            },
+        );
+}
+
+/// List the [`preview_lines`] of the [`CreationPreview`]s whose folder was just spawned or edited.
+fn update_creation_previews(
+    mut commands: Commands,
+    previews: Query<&CreationPreview>,
+    inputs: Query<&TextInputValue, Changed<TextInputValue>>,
+    theme: Res<Theme>,
+) {
+    for preview in &previews {
+        let Ok(input) = inputs.get(preview.path_input) else {
+            continue;
+        };
+        let path = PathBuf::from(input.0.trim());
+        commands
+            .entity(preview.summary)
+            .despawn_related::<Children>();
+        for (label, value) in preview_lines(
+            &preview.template,
+            &preview.manifest,
+            &path,
+            &preview.variables,
+        ) {
+            for (text, color) in [
+                (label, theme.text.low_priority),
+                (value, theme.text.text_color),
+            ] {
+                commands.spawn((
+                    Text::new(text),
+                    TextFont {
+                        font: theme.text.font.clone(),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                    ChildOf(preview.summary),
+                ));
+            }
+        }
+    }
+}
+
+/// Create the project reviewed in the [`CreationPreview`] in its folder, closing the preview.
+///
+/// The preview stays open when the folder can't be used.
+fn confirm_creation(
+    In(dialog): In<Entity>,
+    mut commands: Commands,
+    previews: Query<&CreationPreview>,
+    inputs: Query<&TextInputValue>,
+) {
+    let Ok(preview) = previews.get(dialog) else {
+        return;
+    };
+    let Ok(input) = inputs.get(preview.path_input) else {
+        return;
+    };
+    let path = PathBuf::from(input.0.trim());
+    if let Err(reason) = check_project_path(&path) {
+        push_toast(&mut commands, ToastLevel::Warning, reason);
+        return;
+    }
+    commands.run_system_cached_with(
+        create_project_at,
+        (preview.window, path, preview.run_post_create),
+    );
+    close_dialog(&mut commands, dialog, true);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use bevy_editor::project::templates::TemplateVariable;
+
+    use super::*;
+
+    #[test]
+    fn review_what_the_creation_will_do() {
+        let folder = std::env::temp_dir().join(format!(
+            "bevy_editor_launcher_preview_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&folder).unwrap();
+
+        let path = folder.join("My Game");
+        assert_eq!(check_project_path(&path), Ok(()));
+        assert!(check_project_path(Path::new("relative/game")).is_err());
+        fs::create_dir_all(&path).unwrap();
+        assert_eq!(check_project_path(&path), Ok(()), "the folder is empty");
+        fs::write(path.join("notes.txt"), "").unwrap();
+        assert!(
+            check_project_path(&path).is_err(),
+            "the folder has files already"
+        );
+        fs::remove_dir_all(&path).unwrap();
+
+        let manifest = TemplateManifest {
+            name: "Blank".to_string(),
+            variables: vec![TemplateVariable {
+                name: "author".to_string(),
+                default: Some("Anonymous".to_string()),
+                ..default()
+            }],
+            ..default()
+        };
+        let lines = preview_lines(&Templates::Blank, &manifest, &path, &HashMap::new());
+        let value = |label: &str| {
+            lines
+                .iter()
+                .find(|(line, _)| line == label)
+                .map(|(_, value)| value.as_str())
+        };
+        assert_eq!(value("Template"), Some("Blank"));
+        assert_eq!(value("Package name"), Some("my_game"));
+        assert_eq!(
+            value("Git history"),
+            Some("None, the folder isn't a Git repository")
+        );
+        assert_eq!(value("author"), Some("Anonymous"));
+        assert_eq!(value("Nested in"), None);
+        assert_eq!(value("Post-create command"), None);
+
+        let git = Templates::Git {
+            url: "https://example.com/game.git".to_string(),
+            fresh_history: false,
+        };
+        let lines = preview_lines(&git, &manifest, &path, &HashMap::new());
+        assert!(lines.contains(&(
+            "Template".to_string(),
+            "https://example.com/game.git".to_string()
+        )));
+        assert!(lines.contains(&(
+            "Git history".to_string(),
+            "The history of the repository".to_string()
+        )));
+
+        let manifest = TemplateManifest::parse(
+            "name = \"Codegen\"\n[post_create]\ncommand = [\"make\", \"{{package_name}}\"]",
+        )
+        .unwrap();
+        let lines = preview_lines(&Templates::Blank, &manifest, &path, &HashMap::new());
+        assert!(lines.contains(&(
+            "Post-create command".to_string(),
+            "make my_game".to_string()
+        )));
+
+        fs::remove_dir_all(&folder).unwrap();
+    }
+}
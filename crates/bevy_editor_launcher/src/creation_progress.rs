@@ -0,0 +1,539 @@
+//! Displaying the progress of the [`CreateProjectTask`]s, in the footer bar and in an overlay.
+//!
+//! The overlay scrolls through the retained log lines and follows the newest ones, at the bottom or
+//! at the top with [`LauncherSettings::creation_log_newest_first`], unless scrolled away from them.
+
+use std::{
+    collections::VecDeque,
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use bevy::{log::Level, prelude::*};
+use bevy_editor::project::{CreationPhase, ProgressEvent, data_dir};
+use bevy_editor_styles::{
+    Theme,
+    checkbox::{CheckboxChanged, spawn_checkbox},
+    dialog::{close_dialog, spawn_dialog_button, spawn_dialog_root},
+};
+use bevy_footer_bar::{FooterBarProgress, FooterBarStatus};
+use bevy_scroll_box::{ScrollBox, ScrollEdge, spawn_scroll_box};
+
+use crate::{
+    CreateProjectTask,
+    ansi::AnsiStripper,
+    log_console::LogConsoleInput,
+    settings::{LauncherSettings, ProgressDisplay},
+};
+
+/// Plugin displaying the progress of the project creations.
+pub struct CreationProgressPlugin;
+
+impl Plugin for CreationProgressPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                receive_creation_progress,
+                spawn_creation_overlays,
+                (update_footer_progress, update_creation_overlays),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// The folder of the creation log files, inside the Bevy Editor's data folder.
+const LOGS_FOLDER: &str = "logs";
+/// The height of the log of a [`CreationOverlay`], in pixels.
+const OVERLAY_LOG_HEIGHT: f32 = 180.0;
+
+/// The log lines of a [`CreateProjectTask`], from the [`ProgressEvent`]s it reported.
+///
+/// Only the last [`max_lines`](Self::new) lines are kept in memory when set, the full log can be
+/// written to a file as it goes instead.
+#[derive(Component, Debug, Default)]
+pub(crate) struct ProjectCreationLogs {
+    /// The retained lines, the oldest first.
+    lines: VecDeque<String>,
+    /// The number of lines kept in memory, `None` to keep all of them.
+    max_lines: Option<usize>,
+    /// The file receiving every line, if any.
+    file: Option<File>,
+    /// Removes the colors of the lines, which may come from cargo.
+    ansi: AnsiStripper,
+}
+
+impl ProjectCreationLogs {
+    /// Logs keeping the last `max_lines` in memory, and writing all of them to `file` if set.
+    ///
+    /// The logs are only kept in memory when the file can't be created.
+    pub(crate) fn new(max_lines: Option<usize>, file: Option<&Path>) -> Self {
+        let file = file.and_then(|path| match create_log_file(path) {
+            Ok(file) => Some(file),
+            Err(error) => {
+                warn!(
+                    "Unable to create the creation log file '{}': {error}",
+                    path.display()
+                );
+                None
+            }
+        });
+        Self {
+            lines: VecDeque::new(),
+            max_lines,
+            file,
+            ansi: AnsiStripper::default(),
+        }
+    }
+
+    /// Add a line without its escape sequences, evicting the oldest ones past the limit.
+    pub(crate) fn push(&mut self, line: String) {
+        let line = self.ansi.strip(&line);
+        if let Some(file) = &mut self.file
+            && let Err(error) = writeln!(file, "{line}")
+        {
+            warn!("Unable to write to the creation log file: {error}");
+            self.file = None;
+        }
+        self.lines.push_back(line);
+        if let Some(max_lines) = self.max_lines {
+            while self.lines.len() > max_lines {
+                self.lines.pop_front();
+            }
+        }
+    }
+
+    /// The retained lines as a text, the newest at the top when `newest_first` is set.
+    fn text(&self, newest_first: bool) -> String {
+        let lines: Vec<&str> = if newest_first {
+            self.lines.iter().rev().map(String::as_str).collect()
+        } else {
+            self.lines.iter().map(String::as_str).collect()
+        };
+        lines.join("\n")
+    }
+}
+
+/// The log file of the creation of the project at the given path, in the Bevy Editor's data folder.
+pub(crate) fn creation_log_path(project_path: &Path) -> PathBuf {
+    let name = project_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "project".to_string());
+    data_dir()
+        .join(LOGS_FOLDER)
+        .join(format!("create_{name}.log"))
+}
+
+/// Create the log file, replacing the log of a previous creation at the same path.
+fn create_log_file(path: &Path) -> io::Result<File> {
+    if let Some(folder) = path.parent() {
+        fs::create_dir_all(folder)?;
+    }
+    File::create(path)
+}
+
+/// A dialog showing the progress of the given [`CreateProjectTask`], it can be hidden at any time.
+#[derive(Component)]
+struct CreationOverlay {
+    task: Entity,
+}
+
+/// The text of a [`CreationOverlay`] describing the current phase.
+#[derive(Component)]
+struct CreationOverlayPhase;
+
+/// The text of a [`CreationOverlay`] showing the retained [`ProjectCreationLogs`] lines.
+///
+/// The lines follow the [`LauncherSettings::creation_log_newest_first`] order, growing down or up.
+#[derive(Component)]
+struct CreationOverlayLog;
+
+/// The [`ScrollBox`] of the [`CreationOverlayLog`], kept on the newest lines while it is on them.
+#[derive(Component)]
+struct CreationOverlayLogBox;
+
+/// The filled part of the progress bar of a [`CreationOverlay`].
+#[derive(Component)]
+struct CreationOverlayFill;
+
+impl CreateProjectTask {
+    /// The name of the project being created.
+    pub(crate) fn project_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "project".to_string())
+    }
+
+    /// A sentence describing the current phase.
+    fn phase_description(&self) -> String {
+        match self.phase {
+            Some(phase) => format!(
+                "Creating '{}': {} ({}/{})",
+                self.project_name(),
+                phase.description(),
+                phase.index() + 1,
+                CreationPhase::COUNT
+            ),
+            None => format!("Creating '{}'", self.project_name()),
+        }
+    }
+
+    /// The fraction of the creation done.
+    fn progress(&self) -> f32 {
+        self.phase.map_or(0.0, CreationPhase::progress)
+    }
+}
+
+/// Store the latest phase reported by each task, and log the other progress events.
+fn receive_creation_progress(
+    mut tasks: Query<(&mut CreateProjectTask, &mut ProjectCreationLogs)>,
+    mut console: ResMut<LogConsoleInput>,
+) {
+    for (mut task, mut logs) in &mut tasks {
+        let events: Vec<ProgressEvent> = match task.progress.lock() {
+            Ok(progress) => progress.try_iter().collect(),
+            Err(_) => continue,
+        };
+        for event in events {
+            let line = match event {
+                ProgressEvent::Phase(phase) => {
+                    task.phase = Some(phase);
+                    format!("{}...", phase.description())
+                }
+                ProgressEvent::Skipped(count) => {
+                    format!("Skipped {count} ignored entries of the template")
+                }
+                ProgressEvent::Substituted(file) => format!(
+                    "Filled in '{}'",
+                    file.strip_prefix(&task.path).unwrap_or(&file).display()
+                ),
+                ProgressEvent::Output(line) => {
+                    // Summarized and searched with the rest of the console
+                    console.push(Level::INFO, line.clone());
+                    line
+                }
+                ProgressEvent::PostCreateExited(Some(code)) => {
+                    format!("The template setup exited with code {code}")
+                }
+                ProgressEvent::PostCreateExited(None) => {
+                    "The template setup was stopped by a signal".to_string()
+                }
+                ProgressEvent::MissingAsset(asset) => {
+                    format!("Warning: the project loads 'assets/{asset}', which doesn't exist")
+                }
+                ProgressEvent::Retrying {
+                    retry,
+                    retries,
+                    error,
+                } => format!("Retrying ({retry}/{retries}) after: {error}"),
+                ProgressEvent::Finished(path) => format!("Created '{}'", path.display()),
+            };
+            logs.push(line);
+        }
+    }
+}
+
+/// Show the progress of the first running task in the footer bar, going back to idle once all are done.
+fn update_footer_progress(
+    tasks: Query<&CreateProjectTask>,
+    mut removed_tasks: RemovedComponents<CreateProjectTask>,
+    mut footer_progress: Query<&mut FooterBarProgress>,
+    mut footer_status: Query<&mut Text, With<FooterBarStatus>>,
+) {
+    let finished = removed_tasks.read().count() > 0;
+    let Some(task) = tasks.iter().next() else {
+        if finished {
+            for mut progress in &mut footer_progress {
+                progress.0 = None;
+            }
+            for mut status in &mut footer_status {
+                status.0.clear();
+            }
+        }
+        return;
+    };
+
+    let mut description = task.phase_description();
+    let others = tasks.iter().count() - 1;
+    if others > 0 {
+        description.push_str(&format!(" and {others} more"));
+    }
+    for mut progress in &mut footer_progress {
+        progress.set_if_neq(FooterBarProgress(Some(task.progress())));
+    }
+    for mut status in &mut footer_status {
+        if status.0 != description {
+            status.0.clone_from(&description);
+        }
+    }
+}
+
+/// Spawn a [`CreationOverlay`] for the new tasks, unless the progress is only shown in the footer bar.
+fn spawn_creation_overlays(
+    mut commands: Commands,
+    tasks: Query<(Entity, &CreateProjectTask), Added<CreateProjectTask>>,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+) {
+    if settings.creation_progress != ProgressDisplay::Overlay {
+        return;
+    }
+
+    for (task_entity, task) in &tasks {
+        let root = spawn_dialog_root(&mut commands);
+        commands
+            .entity(root)
+            .insert(CreationOverlay { task: task_entity });
+
+        let panel = commands
+            .spawn((
+                Node {
+                    width: Val::Px(400.0),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(12.0),
+                    padding: UiRect::all(Val::Px(16.0)),
+                    ..default()
+                },
+                theme.pane.area_background_color,
+                theme.general.border_radius,
+                ChildOf(root),
+            ))
+            .id();
+        commands.spawn((
+            Text::new(format!("Creating '{}'", task.project_name())),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(theme.text.text_color),
+            ChildOf(panel),
+        ));
+        commands.spawn((
+            CreationOverlayPhase,
+            Text::new(task.phase_description()),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(theme.text.low_priority),
+            ChildOf(panel),
+        ));
+        let log = commands
+            .spawn((
+                Node {
+                    height: Val::Px(OVERLAY_LOG_HEIGHT),
+                    ..default()
+                },
+                ChildOf(panel),
+            ))
+            .id();
+        let theme_ref: &Theme = &theme;
+        spawn_scroll_box(
+            &mut commands,
+            &theme,
+            Overflow::scroll_y(),
+            Some(move |commands: &mut Commands, scroll_content: Entity| {
+                commands.spawn((
+                    CreationOverlayLog,
+                    Text::default(),
+                    TextFont {
+                        font: theme_ref.text.font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(theme_ref.text.low_priority),
+                    ChildOf(scroll_content),
+                ));
+            }),
+        )
+        .insert((CreationOverlayLogBox, ChildOf(log)));
+        commands.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(6.0),
+                ..default()
+            },
+            theme.button.background_color,
+            theme.button.border_radius,
+            ChildOf(panel),
+            children![(
+                CreationOverlayFill,
+                Node {
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(theme.text.high_priority),
+                theme.button.border_radius,
+            )],
+        ));
+
+        let buttons = commands
+            .spawn((
+                Node {
+                    display: Display::Flex,
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ChildOf(panel),
+            ))
+            .id();
+        spawn_checkbox(
+            &mut commands,
+            &theme,
+            "Newest first",
+            settings.creation_log_newest_first,
+        )
+        .insert(ChildOf(buttons))
+        .observe(
+            |trigger: On<CheckboxChanged>, mut settings: ResMut<LauncherSettings>| {
+                settings.creation_log_newest_first = trigger.event().0;
+            },
+        );
+        let actions = commands
+            .spawn((
+                Node {
+                    display: Display::Flex,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                },
+                ChildOf(buttons),
+            ))
+            .id();
+        spawn_dialog_button(
+            &mut commands,
+            &theme,
+            "Cancel",
+            theme.button.background_color.0,
+        )
+        .insert(ChildOf(actions))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, tasks: Query<&CreateProjectTask>| {
+                // The overlay closes once the task reports it stopped
+                if let Ok(task) = tasks.get(task_entity) {
+                    task.cancel.cancel();
+                }
+            },
+        );
+        // Hiding the overlay doesn't stop the creation, its progress stays visible in the footer bar
+        spawn_dialog_button(
+            &mut commands,
+            &theme,
+            "Hide",
+            theme.button.background_color.0,
+        )
+        .insert(ChildOf(actions))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                close_dialog(&mut commands, root, false);
+            },
+        );
+    }
+}
+
+/// Keep the [`CreationOverlay`]s up to date, closing them when their task is done.
+#[expect(clippy::too_many_arguments)]
+fn update_creation_overlays(
+    mut commands: Commands,
+    overlays: Query<(Entity, &CreationOverlay)>,
+    tasks: Query<(&CreateProjectTask, Ref<ProjectCreationLogs>)>,
+    children: Query<&Children>,
+    mut phases: Query<&mut Text, (With<CreationOverlayPhase>, Without<CreationOverlayLog>)>,
+    mut logs: Query<&mut Text, With<CreationOverlayLog>>,
+    mut log_boxes: Query<&mut ScrollBox, With<CreationOverlayLogBox>>,
+    mut fills: Query<&mut Node, With<CreationOverlayFill>>,
+    settings: Res<LauncherSettings>,
+) {
+    let newest = if settings.creation_log_newest_first {
+        ScrollEdge::Top
+    } else {
+        ScrollEdge::Bottom
+    };
+    for (overlay_entity, overlay) in &overlays {
+        let Ok((task, task_logs)) = tasks.get(overlay.task) else {
+            close_dialog(&mut commands, overlay_entity, true);
+            continue;
+        };
+        let settings_changed = settings.is_changed();
+        let log_changed = task_logs.is_changed() || settings_changed;
+        for descendant in children.iter_descendants(overlay_entity) {
+            if let Ok(mut text) = phases.get_mut(descendant) {
+                let description = task.phase_description();
+                if text.0 != description {
+                    text.0 = description;
+                }
+            }
+            if log_changed && let Ok(mut text) = logs.get_mut(descendant) {
+                let log = task_logs.text(settings.creation_log_newest_first);
+                if text.0 != log {
+                    text.0 = log;
+                }
+            }
+            if log_changed && let Ok(mut log_box) = log_boxes.get_mut(descendant) {
+                // Reading older lines isn't interrupted, changing the order jumps to the newest
+                if settings_changed || log_box.is_at_edge(newest) {
+                    log_box.scroll_to_edge(newest);
+                }
+            }
+            if let Ok(mut fill) = fills.get_mut(descendant) {
+                let width = Val::Percent(task.progress() * 100.0);
+                if fill.width != width {
+                    fill.width = width;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_the_last_lines_and_write_all_of_them() {
+        let path = std::env::temp_dir()
+            .join(format!("bevy_editor_creation_logs_{}", std::process::id()))
+            .join("create_game.log");
+        let mut logs = ProjectCreationLogs::new(Some(3), Some(&path));
+        for index in 0..5 {
+            logs.push(format!("line {index}"));
+        }
+        assert_eq!(logs.lines, ["line 2", "line 3", "line 4"]);
+        drop(logs);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "line 0\nline 1\nline 2\nline 3\nline 4\n"
+        );
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+
+        let mut unbounded = ProjectCreationLogs::default();
+        for index in 0..5 {
+            unbounded.push(format!("line {index}"));
+        }
+        assert_eq!(unbounded.lines.len(), 5);
+
+        unbounded.push("\u{1b}[1m\u{1b}[32m   Compiling\u{1b}[0m game v0.1.0".to_string());
+        assert_eq!(
+            unbounded.lines.back().map(String::as_str),
+            Some("   Compiling game v0.1.0")
+        );
+    }
+
+    #[test]
+    fn show_the_lines_in_either_order() {
+        let mut logs = ProjectCreationLogs::default();
+        for index in 0..4 {
+            logs.push(format!("line {index}"));
+        }
+        assert_eq!(logs.text(false), "line 0\nline 1\nline 2\nline 3");
+        assert_eq!(logs.text(true), "line 3\nline 2\nline 1\nline 0");
+    }
+}
@@ -0,0 +1,110 @@
+//! Showing the Bevy Editor's data folder, where the project list, the settings, the creation logs
+//! and the bug reports are written, to inspect them when something goes wrong.
+//!
+//! The folder is resolved with [`data_dir`], like for every file the launcher writes there, and
+//! shown in the settings dialog with buttons revealing it in the file manager and copying its path.
+
+use std::fs;
+
+use bevy::prelude::*;
+use bevy_clipboard::BevyClipboard;
+use bevy_editor::project::data_dir;
+use bevy_editor_styles::{Theme, dialog::spawn_dialog_button};
+
+use crate::{
+    bug_report::reveal_folder,
+    toast::{ToastLevel, push_toast},
+};
+
+/// Spawn the path of the data folder, with the buttons revealing it and copying it.
+pub(crate) fn spawn_data_folder_row<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+) -> EntityCommands<'a> {
+    let row = commands
+        .spawn(Node {
+            display: Display::Flex,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        })
+        .id();
+    commands.spawn((
+        Node {
+            flex_grow: 1.0,
+            ..default()
+        },
+        Text::new(format!("Data folder: {}", data_dir().display())),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(row),
+    ));
+    spawn_dialog_button(
+        commands,
+        theme,
+        "Copy path",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(row))
+    .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
+        commands.run_system_cached(copy_data_folder_path);
+    });
+    spawn_dialog_button(commands, theme, "Open", theme.button.background_color.0)
+        .insert(ChildOf(row))
+        .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached(open_data_folder);
+        });
+    commands.entity(row)
+}
+
+/// Reveal the data folder in the file manager, creating it first if nothing was written there yet.
+fn open_data_folder(mut commands: Commands) {
+    let folder = data_dir();
+    if let Err(error) = fs::create_dir_all(&folder) {
+        push_toast(
+            &mut commands,
+            ToastLevel::Error,
+            format!("Couldn't create '{}': {error}", folder.display()),
+        );
+        return;
+    }
+    info!("Revealing the data folder '{}'", folder.display());
+    if let Err(error) = reveal_folder(&folder) {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!(
+                "Couldn't open the file manager, the data folder is '{}': {error}",
+                folder.display()
+            ),
+        );
+    }
+}
+
+/// Copy the path of the data folder to the clipboard.
+fn copy_data_folder_path(mut commands: Commands, clipboard: Option<ResMut<BevyClipboard>>) {
+    let Some(mut clipboard) = clipboard else {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            "The clipboard isn't available",
+        );
+        return;
+    };
+    match clipboard.set_text(data_dir().display().to_string()) {
+        Ok(()) => push_toast(
+            &mut commands,
+            ToastLevel::Success,
+            "Copied the path of the data folder",
+        ),
+        Err(error) => push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!("Couldn't copy the path of the data folder: {error}"),
+        ),
+    }
+}
@@ -0,0 +1,980 @@
+//! A side panel with all the details of a project, opened from its context menu.
+//!
+//! The manifest and git details are read in the background when the panel opens, the size on disk
+//! and the size of the build artifacts in a separate task as it can take a while for projects with
+//! a large `target` folder. The environment variables and the icon of the project are edited in the
+//! panel too.
+//! A project not pinning its Rust toolchain can have it pinned from the panel, in a
+//! [`TOOLCHAIN_FILE`](bevy_editor::project::toolchain::TOOLCHAIN_FILE).
+//! The panel is resized by dragging its left edge, its width is kept in the settings.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::SystemTime,
+};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use bevy_editor::project::{
+    ProjectError,
+    clean::{folder_size, target_folder},
+    scan,
+    toolchain::{
+        PinnedToolchain, installed_toolchains, is_toolchain_installed, pinned_toolchain,
+        write_toolchain_file,
+    },
+    validate_env_var_name,
+};
+use bevy_editor_styles::{
+    Theme,
+    busy::BusyAction,
+    dialog::spawn_dialog_button,
+    splitter::{Splitter, SplitterEdge, SplitterResized, spawn_splitter},
+    text_input::{TextInputSubmit, spawn_text_input},
+};
+use bevy_scroll_box::{ScrollShadows, spawn_scroll_box};
+
+use crate::{
+    ProjectInfoList, ProjectListStore,
+    clean::{CLEAN_ACTION, confirm_clean_projects},
+    clipboard::{CopiedText, copy_project_text},
+    open::OpenProjectEvent,
+    settings::LauncherSettings,
+    terminal::open_terminal_for_node,
+    toast::{ToastLevel, push_toast},
+    ui::ProjectNode,
+};
+
+/// The smallest width the details panel can be resized to, in pixels.
+const PANEL_MIN_WIDTH: f32 = 260.0;
+/// The largest width the details panel can be resized to, in pixels.
+const PANEL_MAX_WIDTH: f32 = 720.0;
+
+/// Plugin filling the [`DetailsPanel`] once its details are computed.
+pub struct DetailsPlugin;
+
+impl Plugin for DetailsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                close_details_of_removed_projects,
+                poll_details_tasks,
+                poll_size_tasks,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// The details of a project that are read from its folder.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct ProjectDetails {
+    /// The `description` of the package.
+    description: Option<String>,
+    /// The version requirement of the `bevy` dependency.
+    bevy_version: Option<String>,
+    /// The state of the git repository, `None` when the project isn't one.
+    git: Option<GitStatus>,
+    /// When the folder was created, on the platforms recording it.
+    created: Option<SystemTime>,
+    /// The Rust toolchain the project builds with.
+    toolchain: ToolchainDetails,
+}
+
+/// The Rust toolchain pinned by a project.
+#[derive(Debug, Clone, Default, PartialEq)]
+enum ToolchainDetails {
+    /// The project builds with the default toolchain.
+    #[default]
+    Default,
+    /// The project pins a toolchain, `installed` is `None` when rustup can't tell.
+    Pinned {
+        toolchain: PinnedToolchain,
+        installed: Option<bool>,
+    },
+    /// The toolchain file can't be read, the reason is stored.
+    Invalid(String),
+}
+
+/// The state of the git repository of a project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct GitStatus {
+    /// The checked out branch, `HEAD` when detached.
+    branch: String,
+    /// The amount of changed and untracked files.
+    changes: usize,
+}
+
+/// The panel showing the details of the project of a [`ProjectNode`].
+#[derive(Component)]
+struct DetailsPanel {
+    /// The node the panel was opened from.
+    node: Entity,
+}
+
+/// The reading of the [`ProjectDetails`] of a [`DetailsPanel`].
+#[derive(Component)]
+struct DetailsTask(Task<ProjectDetails>);
+
+/// The computation of the size on disk, and of the build artifacts, of a [`DetailsPanel`].
+#[derive(Component)]
+struct SizeTask(Task<(u64, u64)>);
+
+/// The node the detail rows of a [`DetailsPanel`] are spawned into.
+#[derive(Component)]
+struct DetailsRows;
+
+/// The text showing the size on disk of the project.
+#[derive(Component)]
+struct SizeValue;
+
+/// The text showing the size of the [`target_folder`] of the project.
+#[derive(Component)]
+struct TargetSizeValue;
+
+/// The node listing the environment variables of the project at the given path.
+#[derive(Component)]
+struct EnvRows(PathBuf);
+
+fn project_details(path: &Path) -> ProjectDetails {
+    ProjectDetails {
+        description: scan::package_description(path),
+        bevy_version: scan::bevy_version(path),
+        git: git_status(path),
+        created: fs::metadata(path)
+            .and_then(|metadata| metadata.created())
+            .ok(),
+        toolchain: toolchain_details(path),
+    }
+}
+
+/// The toolchain pinned by the project, checked to be installed.
+fn toolchain_details(path: &Path) -> ToolchainDetails {
+    match pinned_toolchain(path) {
+        Ok(Some(toolchain)) => {
+            let installed = installed_toolchains()
+                .ok()
+                .map(|installed| is_toolchain_installed(&toolchain.channel, &installed));
+            ToolchainDetails::Pinned {
+                toolchain,
+                installed,
+            }
+        }
+        Ok(None) => ToolchainDetails::Default,
+        Err(reason) => ToolchainDetails::Invalid(reason),
+    }
+}
+
+/// The branch and the amount of changes of the repository, `None` if git can't tell.
+fn git_status(path: &Path) -> Option<GitStatus> {
+    if !path.join(".git").exists() {
+        return None;
+    }
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(path)
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+    let branch = git(&["rev-parse", "--abbrev-ref", "HEAD"])?;
+    let status = git(&["status", "--porcelain"])?;
+    Some(GitStatus {
+        branch: branch.trim().to_string(),
+        changes: status.lines().count(),
+    })
+}
+
+/// The sizes of the project folder and of its [`target_folder`], in bytes.
+fn project_sizes(path: &Path) -> (u64, u64) {
+    (folder_size(path), folder_size(&target_folder(path)))
+}
+
+/// The name and value of an environment variable typed as `KEY=value`.
+fn parse_env_assignment(text: &str) -> Result<(String, String), ProjectError> {
+    let Some((key, value)) = text.split_once('=') else {
+        return Err(ProjectError::EnvVarInvalid(text.trim().to_string()));
+    };
+    let key = key.trim();
+    validate_env_var_name(key)?;
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// The size in the largest unit keeping it above 1, like `12.3 MB`.
+pub(crate) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+/// How many times a project was opened, like `12 times`.
+fn format_open_count(count: u32) -> String {
+    match count {
+        0 => "Never".to_string(),
+        1 => "Once".to_string(),
+        count => format!("{count} times"),
+    }
+}
+
+/// How long ago `time` was, like `3 days ago`.
+fn format_age(time: SystemTime, now: SystemTime) -> String {
+    let Ok(elapsed) = now.duration_since(time) else {
+        return "Just now".to_string();
+    };
+    let minutes = elapsed.as_secs() / 60;
+    let (amount, unit) = match minutes {
+        0 => return "Just now".to_string(),
+        1..60 => (minutes, "minute"),
+        60..1440 => (minutes / 60, "hour"),
+        1440..43200 => (minutes / 1440, "day"),
+        43200..525600 => (minutes / 43200, "month"),
+        _ => (minutes / 525600, "year"),
+    };
+    if amount == 1 {
+        format!("1 {unit} ago")
+    } else {
+        format!("{amount} {unit}s ago")
+    }
+}
+
+fn spawn_detail_row<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    rows: Entity,
+    label: &str,
+    value: impl Into<String>,
+) -> EntityCommands<'a> {
+    let row = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            ChildOf(rows),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(label),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(row),
+    ));
+    commands.spawn((
+        Text::new(value),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(row),
+    ))
+}
+
+/// Open the [`DetailsPanel`] of the given [`ProjectNode`], replacing the open one.
+pub(crate) fn show_project_details(
+    In(project_entity): In<Entity>,
+    mut commands: Commands,
+    nodes: Query<&ProjectNode>,
+    panels: Query<Entity, With<DetailsPanel>>,
+    project_list: Res<ProjectInfoList>,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+) {
+    let Ok(ProjectNode(path)) = nodes.get(project_entity) else {
+        return;
+    };
+    for panel in &panels {
+        commands.entity(panel).despawn();
+    }
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let listed = project_list.0.iter().find(|project| project.path == *path);
+    let last_opened = listed
+        .map(|project| project.last_opened)
+        .filter(|opened| *opened > SystemTime::UNIX_EPOCH);
+    let open_count = listed.map_or(0, |project| project.open_count);
+    let icon = listed
+        .and_then(|project| project.icon.clone())
+        .unwrap_or_default();
+
+    let task_path = path.clone();
+    let details = IoTaskPool::get().spawn(async move { project_details(&task_path) });
+    let size_path = path.clone();
+    let size = IoTaskPool::get().spawn(async move { project_sizes(&size_path) });
+
+    let panel = commands
+        .spawn((
+            DetailsPanel {
+                node: project_entity,
+            },
+            DetailsTask(details),
+            SizeTask(size),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                right: Val::Px(0.0),
+                width: Val::Px(
+                    settings
+                        .details_panel_width
+                        .clamp(PANEL_MIN_WIDTH, PANEL_MAX_WIDTH),
+                ),
+                height: Val::Percent(100.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(12.0)),
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BoxShadow::new(
+                theme.general.elevation_color,
+                Val::Px(-2.0),
+                Val::ZERO,
+                Val::ZERO,
+                Val::Px(10.0),
+            ),
+            theme.pane.area_background_color,
+            GlobalZIndex(4),
+        ))
+        .id();
+
+    let header = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(name),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(theme.text.high_priority),
+        ChildOf(header),
+    ));
+    spawn_dialog_button(&mut commands, &theme, "×", theme.button.background_color.0)
+        .insert(ChildOf(header))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                commands.entity(panel).despawn();
+            },
+        );
+
+    let content = commands
+        .spawn((
+            Node {
+                flex_grow: 1.0,
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    let path = path.clone();
+    let theme_ref: &Theme = &theme;
+    spawn_scroll_box(
+        &mut commands,
+        &theme,
+        Overflow::scroll_y(),
+        Some(move |commands: &mut Commands, scroll_content: Entity| {
+            let rows = commands
+                .spawn((
+                    DetailsRows,
+                    Node {
+                        width: Val::Percent(100.0),
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    ChildOf(scroll_content),
+                ))
+                .id();
+            spawn_detail_row(
+                commands,
+                theme_ref,
+                rows,
+                "Path",
+                path.display().to_string(),
+            );
+            spawn_detail_row(
+                commands,
+                theme_ref,
+                rows,
+                "Last opened",
+                last_opened.map_or("Never".to_string(), |opened| {
+                    format_age(opened, SystemTime::now())
+                }),
+            );
+            spawn_detail_row(
+                commands,
+                theme_ref,
+                rows,
+                "Opened",
+                format_open_count(open_count),
+            );
+            spawn_detail_row(commands, theme_ref, rows, "Size on disk", "Computing...")
+                .insert(SizeValue);
+            spawn_detail_row(commands, theme_ref, rows, "Build artifacts", "Computing...")
+                .insert(TargetSizeValue);
+
+            let icon_rows = commands
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.0),
+                        margin: UiRect::top(Val::Px(10.0)),
+                        ..default()
+                    },
+                    ChildOf(scroll_content),
+                ))
+                .id();
+            commands.spawn((
+                Text::new("Icon, shown next to the name"),
+                TextFont {
+                    font: theme_ref.text.font.clone(),
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(theme_ref.text.low_priority),
+                ChildOf(icon_rows),
+            ));
+            let icon_path = path.clone();
+            spawn_text_input(
+                commands,
+                theme_ref,
+                icon,
+                "An emoji or an image path, Enter to set",
+            )
+            .insert(ChildOf(icon_rows))
+            .observe(
+                move |trigger: On<TextInputSubmit>, mut commands: Commands| {
+                    let icon = trigger.event().0.trim();
+                    let icon = (!icon.is_empty()).then(|| icon.to_string());
+                    commands.run_system_cached_with(set_project_icon, (icon_path.clone(), icon));
+                },
+            );
+
+            let env_rows = commands
+                .spawn((
+                    EnvRows(path.clone()),
+                    Node {
+                        width: Val::Percent(100.0),
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.0),
+                        margin: UiRect::top(Val::Px(10.0)),
+                        ..default()
+                    },
+                    ChildOf(scroll_content),
+                ))
+                .id();
+            commands.run_system_cached_with(fill_env_rows, env_rows);
+        }),
+    )
+    .insert((ScrollShadows, ChildOf(content)));
+
+    let actions = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(8.0),
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(&mut commands, &theme, "Open", theme.button.hover_color)
+        .insert(ChildOf(actions))
+        .observe(
+            move |_trigger: On<Pointer<Click>>,
+                  nodes: Query<&ProjectNode>,
+                  mut events: EventWriter<OpenProjectEvent>| {
+                if let Ok(ProjectNode(path)) = nodes.get(project_entity) {
+                    events.write(OpenProjectEvent {
+                        path: path.clone(),
+                        behavior: None,
+                        with: None,
+                        target: None,
+                    });
+                }
+            },
+        );
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Open terminal",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(actions))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached_with(open_terminal_for_node, project_entity);
+        },
+    );
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Copy path",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(actions))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached_with(copy_project_text, (project_entity, CopiedText::Path));
+        },
+    );
+    let clean_path = path.clone();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Clean...",
+        theme.button.background_color.0,
+    )
+    .insert((BusyAction(CLEAN_ACTION), ChildOf(actions)))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached_with(confirm_clean_projects, vec![clean_path.clone()]);
+        },
+    );
+
+    spawn_splitter(
+        &mut commands,
+        &theme,
+        Splitter::new(SplitterEdge::Left, PANEL_MIN_WIDTH, PANEL_MAX_WIDTH),
+    )
+    .insert(ChildOf(panel))
+    .observe(
+        |trigger: On<SplitterResized>, mut settings: ResMut<LauncherSettings>| {
+            settings.details_panel_width = trigger.event().0;
+        },
+    );
+}
+
+/// Fill the [`EnvRows`] with the environment variables of their project, and an input adding new ones.
+fn fill_env_rows(
+    In(env_rows): In<Entity>,
+    mut commands: Commands,
+    rows: Query<&EnvRows>,
+    project_list: Res<ProjectInfoList>,
+    theme: Res<Theme>,
+) {
+    let Ok(EnvRows(path)) = rows.get(env_rows) else {
+        return;
+    };
+    let env = project_list
+        .0
+        .iter()
+        .find(|project| project.path == *path)
+        .map(|project| project.env.clone())
+        .unwrap_or_default();
+    commands.entity(env_rows).despawn_related::<Children>();
+
+    commands.spawn((
+        Text::new("Environment, applied when running the project"),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(env_rows),
+    ));
+    if env.is_empty() {
+        commands.spawn((
+            Text::new("No variables"),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(theme.text.text_color),
+            ChildOf(env_rows),
+        ));
+    }
+    for (key, value) in env {
+        let row = commands
+            .spawn((
+                Node {
+                    display: Display::Flex,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                },
+                ChildOf(env_rows),
+            ))
+            .id();
+        commands.spawn((
+            Node {
+                flex_grow: 1.0,
+                ..default()
+            },
+            Text::new(format!("{key}={value}")),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(theme.text.text_color),
+            ChildOf(row),
+        ));
+        let path = path.clone();
+        spawn_dialog_button(&mut commands, &theme, "×", theme.button.background_color.0)
+            .insert(ChildOf(row))
+            .observe(
+                move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                    commands.run_system_cached_with(
+                        set_project_env_var,
+                        (path.clone(), key.clone(), None),
+                    );
+                },
+            );
+    }
+
+    let path = path.clone();
+    spawn_text_input(&mut commands, &theme, "", "KEY=value, Enter to add")
+        .insert(ChildOf(env_rows))
+        .observe(
+            move |trigger: On<TextInputSubmit>, mut commands: Commands| {
+                let (key, value) = match parse_env_assignment(&trigger.event().0) {
+                    Ok(assignment) => assignment,
+                    Err(error) => {
+                        push_toast(&mut commands, ToastLevel::Warning, error.to_string());
+                        return;
+                    }
+                };
+                commands
+                    .run_system_cached_with(set_project_env_var, (path.clone(), key, Some(value)));
+            },
+        );
+}
+
+/// Set or remove an environment variable of the project at the given path, saving the project list.
+fn set_project_env_var(
+    In((path, key, value)): In<(PathBuf, String, Option<String>)>,
+    mut commands: Commands,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    rows: Query<(Entity, &EnvRows)>,
+) {
+    let Some(project) = project_list.0.iter_mut().find(|p| p.path == path) else {
+        return;
+    };
+    match value {
+        Some(value) => project.env.insert(key, value),
+        None => project.env.remove(&key),
+    };
+    store.set(project_list.0.clone());
+    for (env_rows, EnvRows(rows_path)) in &rows {
+        if *rows_path == path {
+            commands.run_system_cached_with(fill_env_rows, env_rows);
+        }
+    }
+}
+
+/// Set the icon of the project at the given path, `None` for the generated one, saving the project
+/// list.
+fn set_project_icon(
+    In((path, icon)): In<(PathBuf, Option<String>)>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+) {
+    let Some(project) = project_list.0.iter_mut().find(|p| p.path == path) else {
+        return;
+    };
+    if project.icon == icon {
+        return;
+    }
+    project.icon = icon;
+    store.set(project_list.0.clone());
+}
+
+/// Close the panels of the projects whose node was removed.
+fn close_details_of_removed_projects(
+    mut commands: Commands,
+    panels: Query<(Entity, &DetailsPanel)>,
+    nodes: Query<(), With<ProjectNode>>,
+) {
+    for (entity, panel) in &panels {
+        if !nodes.contains(panel.node) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Add the rows of the computed [`ProjectDetails`] to their panel.
+fn poll_details_tasks(
+    mut commands: Commands,
+    mut panels: Query<(Entity, &DetailsPanel, &mut DetailsTask)>,
+    children: Query<&Children>,
+    rows: Query<(), With<DetailsRows>>,
+    theme: Res<Theme>,
+) {
+    for (panel, DetailsPanel { node }, mut task) in &mut panels {
+        let Some(details) = block_on(future::poll_once(&mut task.0)) else {
+            continue;
+        };
+        commands.entity(panel).remove::<DetailsTask>();
+        let Some(rows) = children
+            .iter_descendants(panel)
+            .find(|descendant| rows.contains(*descendant))
+        else {
+            continue;
+        };
+
+        let row = |commands: &mut Commands, label: &str, value: String| {
+            spawn_detail_row(commands, &theme, rows, label, value);
+        };
+        row(
+            &mut commands,
+            "Description",
+            details
+                .description
+                .unwrap_or_else(|| "No description".to_string()),
+        );
+        row(
+            &mut commands,
+            "Bevy version",
+            details
+                .bevy_version
+                .unwrap_or_else(|| "Unknown".to_string()),
+        );
+        match details.git {
+            Some(git) => {
+                row(&mut commands, "Git branch", git.branch);
+                let status = match git.changes {
+                    0 => "Clean".to_string(),
+                    1 => "1 uncommitted change".to_string(),
+                    changes => format!("{changes} uncommitted changes"),
+                };
+                row(&mut commands, "Git status", status);
+            }
+            None => row(&mut commands, "Git", "Not a repository".to_string()),
+        }
+        if let Some(created) = details.created {
+            row(
+                &mut commands,
+                "Created",
+                format_age(created, SystemTime::now()),
+            );
+        }
+        match details.toolchain {
+            ToolchainDetails::Default => {
+                row(
+                    &mut commands,
+                    "Rust toolchain",
+                    "Default, not pinned".to_string(),
+                );
+                let node = *node;
+                spawn_text_input(
+                    &mut commands,
+                    &theme,
+                    "",
+                    "A toolchain like stable or 1.85, Enter to pin it",
+                )
+                .insert(ChildOf(rows))
+                .observe(
+                    move |trigger: On<TextInputSubmit>, mut commands: Commands| {
+                        let channel = trigger.event().0.trim().to_string();
+                        commands.run_system_cached_with(pin_project_toolchain, (node, channel));
+                    },
+                );
+            }
+            ToolchainDetails::Pinned {
+                toolchain,
+                installed,
+            } => {
+                let file = toolchain.file.file_name().unwrap_or_default();
+                row(
+                    &mut commands,
+                    "Rust toolchain",
+                    format!(
+                        "{}, pinned by {}",
+                        toolchain.channel,
+                        file.to_string_lossy()
+                    ),
+                );
+                if installed == Some(false) {
+                    commands.spawn((
+                        Text::new(format!(
+                            "Not installed, install it with `rustup toolchain install {}`",
+                            toolchain.channel
+                        )),
+                        TextFont {
+                            font: theme.text.font.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(theme.status.warning),
+                        ChildOf(rows),
+                    ));
+                }
+            }
+            ToolchainDetails::Invalid(reason) => {
+                spawn_detail_row(&mut commands, &theme, rows, "Rust toolchain", reason)
+                    .insert(TextColor(theme.status.warning));
+            }
+        }
+    }
+}
+
+/// Pin the toolchain of the project of the [`ProjectNode`] in a new toolchain file, then show its
+/// details again.
+fn pin_project_toolchain(
+    In((node, channel)): In<(Entity, String)>,
+    mut commands: Commands,
+    nodes: Query<&ProjectNode>,
+) {
+    let Ok(ProjectNode(path)) = nodes.get(node) else {
+        return;
+    };
+    match write_toolchain_file(path, &channel) {
+        Ok(file) => {
+            info!(
+                "Pinned the Rust toolchain '{channel}' in '{}'",
+                file.display()
+            );
+            push_toast(
+                &mut commands,
+                ToastLevel::Success,
+                format!("Pinned the Rust toolchain '{channel}'"),
+            );
+            commands.run_system_cached_with(show_project_details, node);
+        }
+        Err(error) => push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!("Couldn't pin the Rust toolchain: {error}"),
+        ),
+    }
+}
+
+/// Compute the sizes of the open [`DetailsPanel`]s of the projects again, after they were cleaned.
+pub(crate) fn refresh_details_sizes(
+    In(paths): In<Vec<PathBuf>>,
+    mut commands: Commands,
+    panels: Query<(Entity, &DetailsPanel)>,
+    nodes: Query<&ProjectNode>,
+) {
+    for (entity, panel) in &panels {
+        let Ok(ProjectNode(path)) = nodes.get(panel.node) else {
+            continue;
+        };
+        if paths.contains(path) {
+            let path = path.clone();
+            let task = IoTaskPool::get().spawn(async move { project_sizes(&path) });
+            commands.entity(entity).insert(SizeTask(task));
+        }
+    }
+}
+
+/// Show the computed sizes in their panel.
+fn poll_size_tasks(
+    mut commands: Commands,
+    mut panels: Query<(Entity, &mut SizeTask)>,
+    children: Query<&Children>,
+    values: Query<(Has<SizeValue>, Has<TargetSizeValue>)>,
+    mut texts: Query<&mut Text>,
+) {
+    for (panel, mut task) in &mut panels {
+        let Some((size, target_size)) = block_on(future::poll_once(&mut task.0)) else {
+            continue;
+        };
+        commands.entity(panel).remove::<SizeTask>();
+        for descendant in children.iter_descendants(panel) {
+            let value = match values.get(descendant) {
+                Ok((true, _)) => format_size(size),
+                Ok((_, true)) if target_size == 0 => "None".to_string(),
+                Ok((_, true)) => format_size(target_size),
+                _ => continue,
+            };
+            if let Ok(mut text) = texts.get_mut(descendant) {
+                text.0 = value;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn format_sizes_ages_and_counts() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(5 * 1024 * 1024 * 1024), "5.0 GB");
+
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(100 * 24 * 3600);
+        let ago = |secs| format_age(now - Duration::from_secs(secs), now);
+        assert_eq!(ago(30), "Just now");
+        assert_eq!(ago(60), "1 minute ago");
+        assert_eq!(ago(3 * 3600), "3 hours ago");
+        assert_eq!(ago(2 * 24 * 3600), "2 days ago");
+        assert_eq!(ago(65 * 24 * 3600), "2 months ago");
+        assert_eq!(format_age(now + Duration::from_secs(60), now), "Just now");
+
+        assert_eq!(format_open_count(0), "Never");
+        assert_eq!(format_open_count(1), "Once");
+        assert_eq!(format_open_count(12), "12 times");
+    }
+
+    #[test]
+    fn parse_env_assignments() {
+        assert_eq!(
+            parse_env_assignment(" RUST_LOG =debug,wgpu=warn").unwrap(),
+            ("RUST_LOG".to_string(), "debug,wgpu=warn".to_string())
+        );
+        assert_eq!(
+            parse_env_assignment("EMPTY=").unwrap(),
+            ("EMPTY".to_string(), String::new())
+        );
+        for text in ["RUST_LOG", "=debug", "  =debug", "WITH SPACE=1"] {
+            assert!(
+                matches!(
+                    parse_env_assignment(text),
+                    Err(ProjectError::EnvVarInvalid(_))
+                ),
+                "{text}"
+            );
+        }
+    }
+}
@@ -0,0 +1,337 @@
+//! A short setup shown on the first launch, when there is no settings file yet.
+//!
+//! It asks for the folder the projects are created in and the theme of the launcher, then writes
+//! the initial [`LauncherSettings`]. Skipping it writes the default ones, so it is only shown once.
+//! The same dialog changes both choices later, along with keeping the launcher on top and asking
+//! the skipped confirmations again, and shows where the launcher writes its files, see
+//! [`data_folder`](crate::data_folder). It is opened from the "Settings" button of the top bar or
+//! with the `--settings` flag of the launcher, see
+//! [`StartView::Settings`](crate::StartView::Settings).
+
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy_editor::project::set_projects_root;
+use bevy_editor_styles::{
+    Theme, ThemeMode,
+    dialog::{DialogResult, close_dialog, spawn_dialog_button, spawn_dialog_root},
+    dropdown::{Dropdown, spawn_dropdown},
+};
+
+use crate::{
+    LauncherConfig,
+    always_on_top::spawn_always_on_top_option,
+    confirmations::spawn_reset_confirmations_row,
+    data_folder::spawn_data_folder_row,
+    settings::{LauncherSettings, SettingsFile, save_settings_to},
+    toast::{ToastLevel, push_toast},
+    ui,
+};
+
+/// The themes offered by the wizard, in the order of their dropdown options.
+const THEMES: [(ThemeMode, &str); 3] = [
+    (ThemeMode::Dark, "Dark"),
+    (ThemeMode::Light, "Light"),
+    (ThemeMode::HighContrast, "High contrast"),
+];
+
+/// Plugin showing the [`FirstRunWizard`] when the launcher starts without a settings file.
+pub struct FirstRunPlugin;
+
+impl Plugin for FirstRunPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, show_first_run_wizard.after(ui::setup));
+    }
+}
+
+/// The dialog of the first run setup, or of the settings it asks for.
+#[derive(Component)]
+struct FirstRunWizard {
+    /// Whether the dialog is the first run setup, writing the settings file even when skipped.
+    first_run: bool,
+    /// The chosen projects folder, `None` to pick one for each project.
+    folder: Option<PathBuf>,
+    /// The text showing the [`folder`](Self::folder).
+    folder_text: Entity,
+    /// The dropdown of the [`THEMES`].
+    theme: Entity,
+}
+
+/// The description of the projects folder of the [`FirstRunWizard`].
+fn folder_label(folder: Option<&PathBuf>) -> String {
+    match folder {
+        Some(folder) => folder.display().to_string(),
+        None => "Not set, picked for each new project".to_string(),
+    }
+}
+
+/// The theme chosen in the dropdown of the [`FirstRunWizard`].
+fn theme_of_option(option: usize) -> ThemeMode {
+    THEMES
+        .get(option)
+        .map_or_else(ThemeMode::default, |(mode, _)| *mode)
+}
+
+fn show_first_run_wizard(mut commands: Commands, settings_file: Res<SettingsFile>) {
+    if settings_file.0.exists() {
+        return;
+    }
+    info!(
+        "No settings found at '{}', showing the first run setup",
+        settings_file.0.display()
+    );
+    commands.run_system_cached_with(show_setup_dialog, true);
+}
+
+/// Open the [`FirstRunWizard`], as the first run setup or to change its choices.
+pub(crate) fn show_setup_dialog(
+    In(first_run): In<bool>,
+    mut commands: Commands,
+    settings: Res<LauncherSettings>,
+    config: Res<LauncherConfig>,
+    theme: Res<Theme>,
+) {
+    let (title, description, skip_label, confirm_label) = if first_run {
+        (
+            "Welcome to the Bevy Editor",
+            "Two choices before you start, both can be changed later in the settings.",
+            "Skip",
+            "Get started",
+        )
+    } else {
+        (
+            "Launcher settings",
+            "Where the projects are created and how the launcher looks.",
+            "Cancel",
+            "Save",
+        )
+    };
+
+    let dialog = spawn_dialog_root(&mut commands);
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(440.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    let spawn_text = |commands: &mut Commands, content: &str, size: f32, color: Color| {
+        commands.spawn((
+            Text::new(content),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: size,
+                ..default()
+            },
+            TextColor(color),
+            ChildOf(panel),
+        ));
+    };
+    spawn_text(&mut commands, title, 18.0, theme.text.text_color);
+    spawn_text(&mut commands, description, 14.0, theme.text.low_priority);
+
+    spawn_text(
+        &mut commands,
+        "Where should your projects live?",
+        14.0,
+        theme.text.text_color,
+    );
+    let folder = config.project_root.clone();
+    let folder_row = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    let folder_text = commands
+        .spawn((
+            Node {
+                flex_grow: 1.0,
+                ..default()
+            },
+            Text::new(folder_label(folder.as_ref())),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 13.0,
+                ..default()
+            },
+            TextColor(theme.text.low_priority),
+            ChildOf(folder_row),
+        ))
+        .id();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Choose...",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(folder_row))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached_with(choose_projects_folder, dialog);
+        },
+    );
+
+    spawn_text(&mut commands, "Theme", 14.0, theme.text.text_color);
+    let selected = THEMES
+        .iter()
+        .position(|(mode, _)| *mode == settings.theme)
+        .unwrap_or_default();
+    let theme_dropdown = spawn_dropdown(
+        &mut commands,
+        &theme,
+        THEMES.iter().map(|(_, label)| label.to_string()).collect(),
+        selected,
+    )
+    .insert(ChildOf(panel))
+    .id();
+    spawn_text(
+        &mut commands,
+        "The theme is applied the next time the launcher starts.",
+        12.0,
+        theme.text.low_priority,
+    );
+    if !first_run {
+        // Applied right away, cancelling the dialog doesn't undo it
+        spawn_always_on_top_option(&mut commands, &theme, &settings).insert(ChildOf(panel));
+        spawn_reset_confirmations_row(&mut commands, &theme, &settings).insert(ChildOf(panel));
+        spawn_data_folder_row(&mut commands, &theme).insert(ChildOf(panel));
+    }
+
+    commands
+        .entity(dialog)
+        .insert(FirstRunWizard {
+            first_run,
+            folder,
+            folder_text,
+            theme: theme_dropdown,
+        })
+        .observe(finish_first_run);
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        skip_label,
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            close_dialog(&mut commands, dialog, false);
+        },
+    );
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        confirm_label,
+        theme.button.hover_color,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            close_dialog(&mut commands, dialog, true);
+        },
+    );
+}
+
+/// Pick the projects folder of the [`FirstRunWizard`].
+fn choose_projects_folder(
+    In(dialog): In<Entity>,
+    mut wizards: Query<&mut FirstRunWizard>,
+    mut texts: Query<&mut Text>,
+) {
+    let Ok(mut wizard) = wizards.get_mut(dialog) else {
+        return;
+    };
+    let mut picker = rfd::FileDialog::new();
+    if let Some(folder) = &wizard.folder {
+        picker = picker.set_directory(folder);
+    }
+    let Some(folder) = picker.pick_folder() else {
+        return;
+    };
+    if let Ok(mut text) = texts.get_mut(wizard.folder_text) {
+        text.0 = folder_label(Some(&folder));
+    }
+    wizard.folder = Some(folder);
+}
+
+/// Apply the choices of the [`FirstRunWizard`] when confirmed, and write the settings, either way
+/// for the first run setup.
+fn finish_first_run(
+    trigger: On<DialogResult>,
+    mut commands: Commands,
+    wizards: Query<&FirstRunWizard>,
+    dropdowns: Query<&Dropdown>,
+    mut settings: ResMut<LauncherSettings>,
+    mut config: ResMut<LauncherConfig>,
+    settings_file: Res<SettingsFile>,
+) {
+    let Ok(wizard) = wizards.get(trigger.target()) else {
+        return;
+    };
+    if !trigger.event().0 && !wizard.first_run {
+        return;
+    }
+    if trigger.event().0 {
+        if let Some(folder) = &wizard.folder {
+            settings.default_project_root = Some(folder.clone());
+            config.project_root = Some(folder.clone());
+            set_projects_root(folder.clone());
+        }
+        if let Ok(dropdown) = dropdowns.get(wizard.theme) {
+            settings.theme = theme_of_option(dropdown.selected);
+        }
+    }
+
+    match save_settings_to(&settings_file.0, &settings) {
+        Ok(()) => info!("Saved the settings to '{}'", settings_file.0.display()),
+        Err(error) => {
+            warn!("Unable to save the settings: {error}");
+            let message = if wizard.first_run {
+                "The settings couldn't be saved, the setup will be shown again"
+            } else {
+                "The settings couldn't be saved"
+            };
+            push_toast(&mut commands, ToastLevel::Warning, message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_the_theme_options() {
+        assert_eq!(theme_of_option(0), ThemeMode::Dark);
+        assert_eq!(theme_of_option(1), ThemeMode::Light);
+        assert_eq!(theme_of_option(2), ThemeMode::HighContrast);
+        assert_eq!(theme_of_option(5), ThemeMode::Dark);
+        assert_eq!(folder_label(Some(&PathBuf::from("/projects"))), "/projects");
+    }
+}
@@ -0,0 +1,118 @@
+//! Keyboard focus handling for the launcher.
+//!
+//! Tab/Shift+Tab traversal is provided by Bevy's [`TabNavigationPlugin`], this module makes sure
+//! hidden or disabled controls are skipped, draws a focus ring around the focused control and
+//! turns <kbd>Enter</kbd> into an [`Activate`] event on it.
+
+use bevy::{
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::{
+        FocusedInput, InputDispatchPlugin, InputFocus, InputFocusVisible,
+        tab_navigation::{TabIndex, TabNavigationPlugin},
+    },
+    prelude::*,
+    ui::InteractionDisabled,
+};
+use bevy_editor_styles::Theme;
+
+/// Plugin handling keyboard focus traversal and activation.
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<InputDispatchPlugin>() {
+            app.add_plugins(InputDispatchPlugin);
+        }
+        if !app.is_plugin_added::<TabNavigationPlugin>() {
+            app.add_plugins(TabNavigationPlugin);
+        }
+        app.add_systems(Update, (sync_tab_indices, update_focus_ring).chain())
+            .add_observer(activate_on_enter);
+    }
+}
+
+/// Makes a control reachable with Tab/Shift+Tab.
+///
+/// Controls are visited in increasing order, controls sharing the same order are visited in
+/// hierarchy order. The [`TabIndex`] of the entity is managed from this component, so that
+/// hidden and disabled controls are skipped.
+#[derive(Component, Clone, Copy, Default)]
+#[require(TabIndex)]
+pub struct Focusable(pub i32);
+
+/// Triggered on the focused control when the user presses <kbd>Enter</kbd>.
+#[derive(EntityEvent, Clone, Copy)]
+pub struct Activate;
+
+/// Marks the entity currently drawing the focus ring.
+#[derive(Component)]
+struct FocusRing;
+
+fn sync_tab_indices(
+    mut query: Query<(
+        &Focusable,
+        &mut TabIndex,
+        &InheritedVisibility,
+        &ComputedNode,
+        Has<InteractionDisabled>,
+    )>,
+) {
+    for (focusable, mut tab_index, visibility, computed, disabled) in &mut query {
+        let reachable = visibility.get() && !computed.is_empty() && !disabled;
+        let index = if reachable { focusable.0 } else { -1 };
+        if tab_index.0 != index {
+            tab_index.0 = index;
+        }
+    }
+}
+
+/// Outline the focused control while the focus is visible.
+pub(crate) fn update_focus_ring(
+    mut commands: Commands,
+    focus: Res<InputFocus>,
+    focus_visible: Res<InputFocusVisible>,
+    rings: Query<Entity, With<FocusRing>>,
+    theme: Res<Theme>,
+) {
+    if !focus.is_changed() && !focus_visible.is_changed() {
+        return;
+    }
+
+    for entity in &rings {
+        commands.entity(entity).remove::<(FocusRing, Outline)>();
+    }
+
+    if focus_visible.0
+        && let Some(focused) = focus.0
+        && let Ok(mut entity) = commands.get_entity(focused)
+    {
+        entity.insert((
+            FocusRing,
+            Outline {
+                width: theme.general.focus_ring_width,
+                offset: Val::Px(2.0),
+                color: theme.general.focus_ring_color,
+            },
+        ));
+    }
+}
+
+fn activate_on_enter(
+    mut trigger: On<FocusedInput<KeyboardInput>>,
+    mut commands: Commands,
+    focusables: Query<(), (With<Focusable>, Without<InteractionDisabled>)>,
+) {
+    let input = &trigger.event().input;
+    if input.state != ButtonState::Pressed
+        || input.repeat
+        || !matches!(input.key_code, KeyCode::Enter | KeyCode::NumpadEnter)
+    {
+        return;
+    }
+
+    let target = trigger.target();
+    if focusables.contains(target) {
+        trigger.propagate(false);
+        commands.trigger_targets(Activate, target);
+    }
+}
@@ -0,0 +1,498 @@
+//! A folder browser picking the location of a new project, instead of the dialog of the OS.
+//!
+//! The [`FolderBrowser`] dialog shows the path of the current folder as a breadcrumb, whose
+//! segments go back up to them, and lists its subfolders to go down into. The hidden folders
+//! aren't listed, and the ones that can't be read are greyed out. A subfolder can be created in the
+//! current folder, which is then entered. Choosing the current folder triggers a [`FolderPicked`]
+//! on the dialog.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use bevy::prelude::*;
+use bevy_editor::project::validate_project_name;
+use bevy_editor_styles::{
+    Theme,
+    dialog::{close_dialog, spawn_dialog_button, spawn_dialog_root},
+    text_input::{TextInputSubmit, TextInputValue, spawn_text_input},
+    tooltip::Tooltip,
+};
+use bevy_scroll_box::{ScrollShadows, spawn_scroll_box};
+
+use crate::{
+    focus::{Activate, Focusable},
+    toast::{ToastLevel, push_toast},
+};
+
+/// Plugin listing the current folder of the [`FolderBrowser`]s.
+pub struct FolderBrowserPlugin;
+
+impl Plugin for FolderBrowserPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_folder_browsers);
+    }
+}
+
+/// The dialog browsing the folders, see [`spawn_folder_browser`].
+#[derive(Component)]
+pub(crate) struct FolderBrowser {
+    /// The folder shown, and picked when choosing it.
+    current: PathBuf,
+    /// The node of the breadcrumb of the current folder.
+    breadcrumb: Entity,
+    /// The node listing the subfolders of the current folder.
+    list: Entity,
+    /// The input of the name of the subfolder to create.
+    new_folder: Entity,
+}
+
+/// Triggered on the [`FolderBrowser`] dialog when its current folder is chosen, before it closes.
+#[derive(EntityEvent, Clone)]
+pub(crate) struct FolderPicked(pub PathBuf);
+
+/// A folder listed by a [`FolderBrowser`].
+#[derive(Debug, PartialEq, Eq)]
+struct Subfolder {
+    path: PathBuf,
+    name: String,
+    /// Whether the folder can be listed, the ones that can't aren't entered.
+    readable: bool,
+}
+
+/// The segments of the breadcrumb of the folder, from the root, with the folder each goes to.
+fn breadcrumb(folder: &Path) -> Vec<(String, PathBuf)> {
+    let mut segments: Vec<(String, PathBuf)> = folder
+        .ancestors()
+        .map(|ancestor| {
+            let label = match ancestor.file_name() {
+                Some(name) => name.to_string_lossy().into_owned(),
+                // The root, like `/` or `C:\`
+                None => ancestor.display().to_string(),
+            };
+            (label, ancestor.to_path_buf())
+        })
+        .filter(|(label, _)| !label.is_empty())
+        .collect();
+    segments.reverse();
+    segments
+}
+
+/// The subfolders of the folder sorted by name, without the hidden ones.
+fn list_subfolders(folder: &Path) -> io::Result<Vec<Subfolder>> {
+    let mut subfolders: Vec<Subfolder> = fs::read_dir(folder)?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name.starts_with('.') {
+                return None;
+            }
+            let path = entry.path();
+            Some(Subfolder {
+                readable: fs::read_dir(&path).is_ok(),
+                path,
+                name,
+            })
+        })
+        .collect();
+    subfolders.sort_by_key(|subfolder| subfolder.name.to_lowercase());
+    Ok(subfolders)
+}
+
+/// The closest folder at or above the path that exists, to start browsing from.
+fn existing_folder(path: &Path) -> PathBuf {
+    path.ancestors()
+        .find(|ancestor| ancestor.is_dir())
+        .map(Path::to_path_buf)
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_default()
+}
+
+/// Spawn a [`FolderBrowser`] starting in the folder, or the closest existing one above it.
+///
+/// Observe [`FolderPicked`] on the returned dialog to get the chosen folder.
+pub(crate) fn spawn_folder_browser<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    title: impl Into<String>,
+    start: &Path,
+) -> EntityCommands<'a> {
+    let dialog = spawn_dialog_root(commands);
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(520.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(title),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+    let breadcrumb = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                flex_wrap: FlexWrap::Wrap,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(2.0),
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+
+    let list_box = commands
+        .spawn((
+            Node {
+                height: Val::Px(260.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    let mut list = Entity::PLACEHOLDER;
+    spawn_scroll_box(
+        commands,
+        theme,
+        Overflow::scroll_y(),
+        Some(|commands: &mut Commands, content: Entity| {
+            list = commands
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(2.0),
+                        ..default()
+                    },
+                    ChildOf(content),
+                ))
+                .id();
+        }),
+    )
+    .insert((ScrollShadows, ChildOf(list_box)));
+
+    let new_folder_row = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    let new_folder_input = commands
+        .spawn((
+            Node {
+                flex_grow: 1.0,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            ChildOf(new_folder_row),
+        ))
+        .id();
+    let new_folder = spawn_text_input(commands, theme, "", "New folder name")
+        .insert(ChildOf(new_folder_input))
+        .observe(
+            move |_trigger: On<TextInputSubmit>, mut commands: Commands| {
+                commands.run_system_cached_with(create_subfolder, dialog);
+            },
+        )
+        .id();
+    spawn_dialog_button(
+        commands,
+        theme,
+        "Create folder",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(new_folder_row))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached_with(create_subfolder, dialog);
+        },
+    );
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(commands, theme, "Cancel", theme.button.background_color.0)
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                close_dialog(&mut commands, dialog, false);
+            },
+        );
+    spawn_dialog_button(
+        commands,
+        theme,
+        "Choose this folder",
+        theme.button.hover_color,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>,
+              mut commands: Commands,
+              browsers: Query<&FolderBrowser>| {
+            let Ok(browser) = browsers.get(dialog) else {
+                return;
+            };
+            commands.trigger_targets(FolderPicked(browser.current.clone()), dialog);
+            close_dialog(&mut commands, dialog, true);
+        },
+    );
+
+    commands.entity(dialog).insert(FolderBrowser {
+        current: existing_folder(start),
+        breadcrumb,
+        list,
+        new_folder,
+    });
+    commands.entity(dialog)
+}
+
+/// Enter the folder in the [`FolderBrowser`].
+fn enter_folder(
+    In((dialog, folder)): In<(Entity, PathBuf)>,
+    mut browsers: Query<&mut FolderBrowser>,
+) {
+    if let Ok(mut browser) = browsers.get_mut(dialog) {
+        browser.current = folder;
+    }
+}
+
+/// Spawn a clickable entry of a [`FolderBrowser`], entering the folder once clicked or activated.
+fn spawn_folder_entry<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    dialog: Entity,
+    label: String,
+    folder: PathBuf,
+) -> EntityCommands<'a> {
+    let activate_folder = folder.clone();
+    let mut entry = commands.spawn((
+        Node {
+            padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        theme.button.border_radius,
+        Focusable(0),
+        children![(
+            Text::new(label),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(theme.text.text_color),
+            Pickable::IGNORE,
+        )],
+    ));
+    entry
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                commands.run_system_cached_with(enter_folder, (dialog, folder.clone()));
+            },
+        )
+        .observe(move |_trigger: On<Activate>, mut commands: Commands| {
+            commands.run_system_cached_with(enter_folder, (dialog, activate_folder.clone()));
+        })
+        .observe(
+            |trigger: On<Pointer<Over>>,
+             theme: Res<Theme>,
+             mut backgrounds: Query<&mut BackgroundColor>| {
+                if let Ok(mut background) = backgrounds.get_mut(trigger.target()) {
+                    background.0 = theme.button.hover_color;
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Out>>, mut backgrounds: Query<&mut BackgroundColor>| {
+                if let Ok(mut background) = backgrounds.get_mut(trigger.target()) {
+                    background.0 = Color::NONE;
+                }
+            },
+        );
+    entry
+}
+
+/// Show the breadcrumb and the subfolders of the current folder of the [`FolderBrowser`]s that
+/// were just spawned or moved to another folder.
+fn update_folder_browsers(
+    mut commands: Commands,
+    browsers: Query<(Entity, &FolderBrowser), Changed<FolderBrowser>>,
+    theme: Res<Theme>,
+) {
+    for (dialog, browser) in &browsers {
+        commands
+            .entity(browser.breadcrumb)
+            .despawn_related::<Children>();
+        for (index, (label, folder)) in breadcrumb(&browser.current).into_iter().enumerate() {
+            if index > 0 {
+                commands.spawn((
+                    Text::new("›"),
+                    TextFont {
+                        font: theme.text.font.clone(),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(theme.text.low_priority),
+                    ChildOf(browser.breadcrumb),
+                ));
+            }
+            spawn_folder_entry(&mut commands, &theme, dialog, label, folder)
+                .insert(ChildOf(browser.breadcrumb));
+        }
+
+        commands.entity(browser.list).despawn_related::<Children>();
+        let message = match list_subfolders(&browser.current) {
+            Ok(subfolders) if subfolders.is_empty() => Some("No subfolders".to_string()),
+            Ok(subfolders) => {
+                for subfolder in subfolders {
+                    if subfolder.readable {
+                        spawn_folder_entry(
+                            &mut commands,
+                            &theme,
+                            dialog,
+                            subfolder.name,
+                            subfolder.path,
+                        )
+                        .insert(ChildOf(browser.list));
+                        continue;
+                    }
+                    commands.spawn((
+                        Node {
+                            padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
+                            ..default()
+                        },
+                        Tooltip::new("No permission to open this folder"),
+                        ChildOf(browser.list),
+                        children![(
+                            Text::new(subfolder.name),
+                            TextFont {
+                                font: theme.text.font.clone(),
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(theme.text.low_priority),
+                            Pickable::IGNORE,
+                        )],
+                    ));
+                }
+                None
+            }
+            Err(error) => Some(format!("Couldn't read the folder: {error}")),
+        };
+        if let Some(message) = message {
+            commands.spawn((
+                Text::new(message),
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(theme.text.low_priority),
+                ChildOf(browser.list),
+            ));
+        }
+    }
+}
+
+/// Create the subfolder named in the [`FolderBrowser`] in its current folder, and enter it.
+fn create_subfolder(
+    In(dialog): In<Entity>,
+    mut commands: Commands,
+    mut browsers: Query<&mut FolderBrowser>,
+    inputs: Query<&TextInputValue>,
+) {
+    let Ok(mut browser) = browsers.get_mut(dialog) else {
+        return;
+    };
+    let Ok(input) = inputs.get(browser.new_folder) else {
+        return;
+    };
+    let name = input.0.trim();
+    if let Err(error) = validate_project_name(name) {
+        push_toast(&mut commands, ToastLevel::Warning, error.to_string());
+        return;
+    }
+    let folder = browser.current.join(name);
+    match fs::create_dir(&folder) {
+        Ok(()) => browser.current = folder,
+        Err(error) => push_toast(
+            &mut commands,
+            ToastLevel::Error,
+            format!("Couldn't create '{}': {error}", folder.display()),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn browse_the_folders() {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_editor_launcher_browser_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        for folder in ["games", "Assets", ".git", "games/space"] {
+            fs::create_dir_all(root.join(folder)).unwrap();
+        }
+        fs::write(root.join("notes.txt"), "").unwrap();
+
+        let subfolders = list_subfolders(&root).unwrap();
+        let names: Vec<&str> = subfolders
+            .iter()
+            .map(|subfolder| subfolder.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            ["Assets", "games"],
+            "sorted, without the hidden ones"
+        );
+        assert!(subfolders.iter().all(|subfolder| subfolder.readable));
+
+        let segments = breadcrumb(&root.join("games/space"));
+        let (last, _) = &segments[segments.len() - 1];
+        assert_eq!(last, "space");
+        assert_eq!(segments[segments.len() - 2].1, root.join("games"));
+        assert_eq!(segments[0].1, root.ancestors().last().unwrap());
+
+        assert_eq!(
+            existing_folder(&root.join("games/missing/deeper")),
+            root.join("games")
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,301 @@
+//! Running the project management of the launcher without a window or a renderer, for CI and
+//! servers, see [`HeadlessLauncherPlugin`].
+
+use std::{collections::HashMap, path::PathBuf};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use bevy_editor::project::{
+    CancellationToken, ProjectError, ProjectInfo, RetryPolicy, create_new_project_with_progress,
+    import_project, import_projects, is_same_project,
+    scan::{DEFAULT_SCAN_DEPTH, scan_for_projects},
+    templates::{Templates, validate_git_url},
+};
+
+use crate::{ProjectInfoList, ProjectListStore, insert_launcher_resources};
+
+/// The flag creating a project, followed by its folder and optionally [`TEMPLATE_FLAG`].
+const CREATE_FLAG: &str = "--create-project";
+/// The option of [`CREATE_FLAG`] picking the template, followed by its name, folder or Git URL.
+const TEMPLATE_FLAG: &str = "--template";
+/// The flag adding an existing project to the project list, followed by its folder.
+const IMPORT_FLAG: &str = "--import-project";
+/// The flag scanning a folder tree for projects to add to the project list, followed by the folder.
+const SCAN_FLAG: &str = "--scan-projects";
+
+/// Plugin running a [`HeadlessCommand`] with the project list of the launcher, then exiting.
+///
+/// Unlike the [`LauncherPlugin`](crate::LauncherPlugin), it needs neither a window nor a
+/// renderer: add it with the [`MinimalPlugins`] instead of the `DefaultPlugins`, their runner
+/// given a wait with `ScheduleRunnerPlugin::run_loop` so that it doesn't spin while the command
+/// runs in the background. The [`LauncherSettings`](crate::LauncherSettings) and the
+/// [`ProjectListStore`] are loaded the same way, but no UI, theme or styles are set up, so none of
+/// their systems run.
+///
+/// Available headless:
+/// - creating a project from a template, [`HeadlessCommand::Create`], skipping the post-create
+///   command of the template as no one is there to allow it
+/// - adding an existing project to the project list, [`HeadlessCommand::Import`]
+/// - scanning a folder tree for projects to add, [`HeadlessCommand::Scan`]
+///
+/// Everything else needs the UI, like opening, renaming or cleaning the projects, the details
+/// panel, the thumbnails and the project icons. Exporting the project list and checking its health
+/// don't need Bevy at all, see the `--export-projects` and `--check-projects` flags of the
+/// launcher.
+pub struct HeadlessLauncherPlugin {
+    /// The templates that can be created from, see
+    /// [`LauncherPlugin::templates`](crate::LauncherPlugin::templates).
+    pub templates: Vec<Templates>,
+    /// The folder the paths of the projects inside it are stored relative to, see
+    /// [`LauncherPlugin::project_root`](crate::LauncherPlugin::project_root).
+    pub project_root: Option<PathBuf>,
+}
+
+impl Default for HeadlessLauncherPlugin {
+    fn default() -> Self {
+        Self {
+            templates: Templates::ALL.to_vec(),
+            project_root: None,
+        }
+    }
+}
+
+impl Plugin for HeadlessLauncherPlugin {
+    fn build(&self, app: &mut App) {
+        insert_launcher_resources(app, &self.templates, self.project_root.clone());
+        app.add_systems(Startup, start_headless_command)
+            .add_systems(Update, poll_headless_task);
+    }
+}
+
+/// What the [`HeadlessLauncherPlugin`] does, inserted as a resource before running the app.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub enum HeadlessCommand {
+    /// Create a project from the template in the folder, and add it to the project list.
+    Create {
+        /// The folder the project is created in, which mustn't exist or be empty.
+        path: PathBuf,
+        /// The template the project is created from.
+        template: Templates,
+    },
+    /// Add the existing project in the folder to the project list.
+    Import(PathBuf),
+    /// Scan the folder tree for projects, and add the ones found to the project list.
+    Scan(PathBuf),
+}
+
+impl HeadlessCommand {
+    /// The command given by the command line arguments, without the program name, `None` when they
+    /// don't ask for one.
+    ///
+    /// The template of `--create-project` is `blank`, `getting-started`, a Git URL or the folder
+    /// of a custom template, `blank` by default.
+    pub fn from_args(args: &[String]) -> Result<Option<Self>, String> {
+        let Some(flag) = args.first() else {
+            return Ok(None);
+        };
+        if ![CREATE_FLAG, IMPORT_FLAG, SCAN_FLAG].contains(&flag.as_str()) {
+            return Ok(None);
+        }
+        let Some(path) = args.get(1).filter(|path| !path.starts_with("--")) else {
+            return Err(format!("{flag} needs a folder"));
+        };
+        let path = PathBuf::from(path);
+        Ok(Some(match flag.as_str() {
+            CREATE_FLAG => {
+                let template = match &args[2..] {
+                    [] => Templates::Blank,
+                    [option, template] if option == TEMPLATE_FLAG => parse_template(template),
+                    _ => {
+                        return Err(format!(
+                            "{CREATE_FLAG} only takes {TEMPLATE_FLAG} <TEMPLATE>"
+                        ));
+                    }
+                };
+                HeadlessCommand::Create { path, template }
+            }
+            IMPORT_FLAG => HeadlessCommand::Import(path),
+            _ => HeadlessCommand::Scan(path),
+        }))
+    }
+}
+
+/// The template named on the command line.
+fn parse_template(name: &str) -> Templates {
+    match name {
+        "blank" => Templates::Blank,
+        "getting-started" => Templates::GettingStarted,
+        url if validate_git_url(url).is_ok() => Templates::Git {
+            url: url.to_string(),
+            fresh_history: true,
+        },
+        folder => Templates::Custom(PathBuf::from(folder)),
+    }
+}
+
+/// The background run of the [`HeadlessCommand`], returning the projects it added to the
+/// [`ProjectListStore`], to add to the list in memory.
+#[derive(Resource)]
+struct HeadlessTask(Task<Result<Vec<ProjectInfo>, ProjectError>>);
+
+fn start_headless_command(
+    mut commands: Commands,
+    command: Option<Res<HeadlessCommand>>,
+    store: Res<ProjectListStore>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Some(command) = command else {
+        warn!("No headless command to run");
+        exit.write(AppExit::Success);
+        return;
+    };
+    let command = command.clone();
+    let store = store.0.clone();
+    info!("Running {command:?}");
+    let task = IoTaskPool::get().spawn(async move {
+        match command {
+            HeadlessCommand::Create { path, template } => create_new_project_with_progress(
+                template,
+                path,
+                HashMap::new(),
+                false,
+                &RetryPolicy::default(),
+                &CancellationToken::new(),
+                &*store,
+                |_| {},
+            )
+            .await
+            .map(|project| vec![project]),
+            HeadlessCommand::Import(path) => {
+                import_project(&path, &*store).map(|project| vec![project])
+            }
+            HeadlessCommand::Scan(root) => {
+                let found = scan_for_projects(
+                    &root,
+                    DEFAULT_SCAN_DEPTH,
+                    &CancellationToken::new(),
+                    |_| {},
+                )?;
+                // A project that can't be added doesn't prevent adding the others
+                Ok(import_projects(&found, &*store)
+                    .into_iter()
+                    .filter_map(|result| {
+                        result
+                            .inspect_err(|error| warn!("Couldn't add the project: {error}"))
+                            .ok()
+                    })
+                    .collect())
+            }
+        }
+    });
+    commands.insert_resource(HeadlessTask(task));
+}
+
+/// Add the projects of the finished [`HeadlessTask`] to the project list in memory, then exit.
+fn poll_headless_task(
+    mut commands: Commands,
+    task: Option<ResMut<HeadlessTask>>,
+    mut project_list: ResMut<ProjectInfoList>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Some(mut task) = task else {
+        return;
+    };
+    let Some(result) = block_on(future::poll_once(&mut task.0)) else {
+        return;
+    };
+    commands.remove_resource::<HeadlessTask>();
+    match result {
+        Ok(projects) => {
+            let added = add_new_projects(&mut project_list.0, projects);
+            for project in &added {
+                info!("Added '{}' to the project list", project.path.display());
+            }
+            info!("Added {} projects", added.len());
+            exit.write(AppExit::Success);
+        }
+        Err(error) => {
+            error!("{error}");
+            exit.write(AppExit::error());
+        }
+    }
+}
+
+/// Add the projects that aren't in the list yet to it, returning them.
+fn add_new_projects(list: &mut Vec<ProjectInfo>, projects: Vec<ProjectInfo>) -> Vec<ProjectInfo> {
+    let mut added = Vec::new();
+    for project in projects {
+        if list
+            .iter()
+            .any(|listed| is_same_project(&listed.path, &project.path))
+        {
+            continue;
+        }
+        list.push(project.clone());
+        added.push(project);
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn read_the_headless_command() {
+        assert_eq!(HeadlessCommand::from_args(&args(&[])), Ok(None));
+        assert_eq!(
+            HeadlessCommand::from_args(&args(&["--export-projects"])),
+            Ok(None)
+        );
+        assert_eq!(
+            HeadlessCommand::from_args(&args(&["--create-project", "/games/space"])),
+            Ok(Some(HeadlessCommand::Create {
+                path: PathBuf::from("/games/space"),
+                template: Templates::Blank,
+            }))
+        );
+        assert_eq!(
+            HeadlessCommand::from_args(&args(&[
+                "--create-project",
+                "/games/space",
+                "--template",
+                "https://example.com/template.git",
+            ])),
+            Ok(Some(HeadlessCommand::Create {
+                path: PathBuf::from("/games/space"),
+                template: Templates::Git {
+                    url: "https://example.com/template.git".to_string(),
+                    fresh_history: true,
+                },
+            }))
+        );
+        assert_eq!(
+            HeadlessCommand::from_args(&args(&[
+                "--create-project",
+                "/games/space",
+                "--template",
+                "/templates/shooter",
+            ])),
+            Ok(Some(HeadlessCommand::Create {
+                path: PathBuf::from("/games/space"),
+                template: Templates::Custom(PathBuf::from("/templates/shooter")),
+            }))
+        );
+        assert_eq!(
+            HeadlessCommand::from_args(&args(&["--scan-projects", "/games"])),
+            Ok(Some(HeadlessCommand::Scan(PathBuf::from("/games"))))
+        );
+        assert!(HeadlessCommand::from_args(&args(&["--import-project"])).is_err());
+        assert!(
+            HeadlessCommand::from_args(&args(&["--create-project", "/games/space", "--force"]))
+                .is_err()
+        );
+    }
+}
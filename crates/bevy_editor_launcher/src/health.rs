@@ -0,0 +1,552 @@
+//! Checking the health of the whole project list, listing the projects that can't be opened anymore.
+//!
+//! The check runs in the background, see [`bevy_editor::project::health`] for what is checked, and
+//! its issues are listed in a dialog where they can be filtered, fixed, and saved as a report.
+
+use std::{fs, iter, path::PathBuf};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use bevy_editor::project::{
+    CancellationToken, ProjectError,
+    health::{DEFAULT_CHECK_PARALLELISM, HealthIssue, check_projects, health_report},
+};
+use bevy_editor_styles::{
+    Theme,
+    busy::BusyActions,
+    checkbox::{CheckboxChanged, spawn_checkbox},
+    dialog::{DialogResult, close_dialog, spawn_dialog_button, spawn_dialog_root},
+    text_input::{TextInputValue, spawn_text_input},
+};
+use bevy_scroll_box::{ScrollShadows, spawn_scroll_box};
+
+use crate::{
+    ProjectInfoList, ProjectListStore,
+    bug_report::show_bug_report_dialog,
+    settings::LauncherSettings,
+    task_limit::{TaskSlot, TaskSlots},
+    terminal::open_terminal_with_toast,
+    toast::{ToastLevel, push_toast},
+    ui::ProjectNode,
+};
+
+/// The [`BusyAction`](bevy_editor_styles::busy::BusyAction) of the buttons starting a health check.
+pub(crate) const HEALTH_ACTION: &str = "health_check";
+
+/// Plugin checking the health of the project list, see [`start_health_check`].
+pub struct HealthPlugin;
+
+impl Plugin for HealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (poll_health_check, filter_health_issues).chain());
+    }
+}
+
+/// The result of a [`HealthCheck`] task.
+type HealthResult = Result<Vec<HealthIssue>, ProjectError>;
+
+/// The health check of the project list, stored on the dialog showing its issues.
+///
+/// Closing the dialog cancels the check.
+#[derive(Component)]
+struct HealthCheck {
+    /// The check itself, `None` once it is done
+    task: Option<Task<HealthResult>>,
+    /// Cancels the running check
+    cancel: CancellationToken,
+    /// The number of projects checked
+    checked: usize,
+    /// The issues found, minus the ones fixed from the dialog
+    issues: Vec<HealthIssue>,
+}
+
+impl HealthCheck {
+    /// A sentence describing the progress of the check.
+    fn description(&self) -> String {
+        if self.task.is_some() {
+            format!("Checking {} projects...", self.checked)
+        } else if self.issues.is_empty() {
+            format!("All {} projects are fine", self.checked)
+        } else {
+            format!(
+                "Found {} issues among {} projects",
+                self.issues.len(),
+                self.checked
+            )
+        }
+    }
+}
+
+/// The text of the health dialog describing the progress.
+#[derive(Component)]
+struct HealthStatus;
+
+/// The input filtering the [`HealthIssueRow`]s.
+#[derive(Component)]
+struct HealthFilter;
+
+/// The node the [`HealthIssueRow`]s are listed in.
+#[derive(Component)]
+struct HealthIssues;
+
+/// An issue found by the check, with a button fixing it.
+#[derive(Component)]
+struct HealthIssueRow {
+    /// The project with the issue
+    path: PathBuf,
+    /// The lowercase text of the row, matched by the filter
+    text: String,
+}
+
+/// Open the health dialog and check the project list, without `cargo check` at first.
+///
+/// Only one check runs at a time.
+pub(crate) fn start_health_check(
+    mut commands: Commands,
+    checks: Query<(), With<HealthCheck>>,
+    theme: Res<Theme>,
+) {
+    if !checks.is_empty() {
+        return;
+    }
+
+    let dialog = spawn_dialog_root(&mut commands);
+    commands
+        .entity(dialog)
+        .observe(|trigger: On<DialogResult>, checks: Query<&HealthCheck>| {
+            if let Ok(check) = checks.get(trigger.target()) {
+                check.cancel.cancel();
+            }
+        });
+
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(600.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    commands.spawn((
+        Text::new("Check projects"),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+    commands.spawn((
+        HealthStatus,
+        Text::default(),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(panel),
+    ));
+    spawn_checkbox(
+        &mut commands,
+        &theme,
+        "Also run cargo check, slow for the projects never built",
+        false,
+    )
+    .insert(ChildOf(panel))
+    .observe(
+        move |trigger: On<CheckboxChanged>, mut commands: Commands| {
+            commands.run_system_cached_with(run_health_check, (dialog, trigger.event().0));
+        },
+    );
+    spawn_text_input(&mut commands, &theme, "", "Filter the issues")
+        .insert((HealthFilter, ChildOf(panel)));
+
+    let issues = commands
+        .spawn((
+            Node {
+                height: Val::Px(280.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_scroll_box(
+        &mut commands,
+        &theme,
+        Overflow::scroll_y(),
+        Some(|commands: &mut Commands, content: Entity| {
+            commands.spawn((
+                HealthIssues,
+                Node {
+                    width: Val::Percent(100.0),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                ChildOf(content),
+            ));
+        }),
+    )
+    .insert((ScrollShadows, ChildOf(issues)));
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Save report",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached_with(save_health_report, dialog);
+        },
+    );
+    spawn_dialog_button(&mut commands, &theme, "Close", theme.button.hover_color)
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                close_dialog(&mut commands, dialog, false);
+            },
+        );
+
+    commands.run_system_cached_with(run_health_check, (dialog, false));
+}
+
+/// (Re)start the check of the dialog, cancelling the running one and clearing its issues.
+fn run_health_check(
+    In((dialog, cargo_check)): In<(Entity, bool)>,
+    mut commands: Commands,
+    checks: Query<&HealthCheck>,
+    project_list: Res<ProjectInfoList>,
+    rows: Query<Entity, With<HealthIssueRow>>,
+    children: Query<&Children>,
+    slots: Res<TaskSlots>,
+    mut busy: ResMut<BusyActions>,
+) {
+    if let Ok(check) = checks.get(dialog) {
+        check.cancel.cancel();
+    }
+    for descendant in children.iter_descendants(dialog) {
+        if rows.contains(descendant) {
+            commands.entity(descendant).despawn();
+        }
+    }
+
+    let paths: Vec<PathBuf> = project_list
+        .0
+        .iter()
+        .map(|project| project.path.clone())
+        .collect();
+    let checked = paths.len();
+    let cancel = CancellationToken::new();
+    let task_cancel = cancel.clone();
+    let slots = slots.clone();
+    let task = IoTaskPool::get().spawn(async move {
+        // Each project checked at once holds a slot, the free ones are taken without waiting
+        let first = slots.acquire().await;
+        let extra = DEFAULT_CHECK_PARALLELISM.min(paths.len()).saturating_sub(1);
+        let held: Vec<TaskSlot> = iter::once(first)
+            .chain(iter::from_fn(|| slots.try_acquire()).take(extra))
+            .collect();
+        check_projects(&paths, held.len(), cargo_check, &task_cancel)
+    });
+    info!("Checking the health of {checked} projects");
+
+    busy.start(HEALTH_ACTION, dialog);
+    commands.entity(dialog).insert(HealthCheck {
+        task: Some(task),
+        cancel,
+        checked,
+        issues: Vec::new(),
+    });
+}
+
+/// Handle the result of the checks when done, listing their issues.
+fn poll_health_check(
+    mut commands: Commands,
+    mut checks: Query<(Entity, &mut HealthCheck)>,
+    issue_lists: Query<(), With<HealthIssues>>,
+    children: Query<&Children>,
+    mut statuses: Query<&mut Text, With<HealthStatus>>,
+    mut busy: ResMut<BusyActions>,
+    theme: Res<Theme>,
+) {
+    for (dialog, mut check) in &mut checks {
+        if let Some(task) = &mut check.task {
+            if let Some(result) = block_on(future::poll_once(task)) {
+                check.task = None;
+                busy.finish(HEALTH_ACTION, dialog);
+                match result {
+                    Ok(issues) => check.issues = issues,
+                    // Cancelled checks are replaced by a new one, or their dialog is closed
+                    Err(ProjectError::Cancelled) => {}
+                    Err(error) => push_toast(
+                        &mut commands,
+                        ToastLevel::Error,
+                        format!("Failed to check the projects: {error}"),
+                    ),
+                }
+                if let Some(list) = children
+                    .iter_descendants(dialog)
+                    .find(|descendant| issue_lists.contains(*descendant))
+                {
+                    for issue in &check.issues {
+                        spawn_health_issue(&mut commands, &theme, dialog, list, issue);
+                    }
+                }
+            }
+        }
+
+        let description = check.description();
+        for descendant in children.iter_descendants(dialog) {
+            if let Ok(mut status) = statuses.get_mut(descendant)
+                && status.0 != description
+            {
+                status.0 = description.clone();
+            }
+        }
+    }
+}
+
+/// Spawn the row of an issue in the [`HealthIssues`], with the button fixing it, and the one
+/// reporting it for the projects that can't be opened.
+fn spawn_health_issue(
+    commands: &mut Commands,
+    theme: &Theme,
+    dialog: Entity,
+    list: Entity,
+    issue: &HealthIssue,
+) {
+    let text = format!("{}\n{}", issue.path.display(), issue.error);
+    let row = commands
+        .spawn((
+            HealthIssueRow {
+                path: issue.path.clone(),
+                text: text.to_lowercase(),
+            },
+            Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                column_gap: Val::Px(8.0),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                ..default()
+            },
+            ChildOf(list),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(text),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        Node {
+            flex_shrink: 1.0,
+            ..default()
+        },
+        ChildOf(row),
+    ));
+
+    let path = issue.path.clone();
+    if issue.is_missing() {
+        spawn_dialog_button(commands, theme, "Remove", theme.button.background_color.0)
+            .insert(ChildOf(row))
+            .observe(
+                move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                    commands.run_system_cached_with(remove_unhealthy_project, (dialog, row));
+                },
+            );
+    } else {
+        let buttons = commands
+            .spawn((
+                Node {
+                    display: Display::Flex,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                },
+                ChildOf(row),
+            ))
+            .id();
+        let error = issue.error.to_string();
+        spawn_dialog_button(commands, theme, "Report", theme.button.background_color.0)
+            .insert(ChildOf(buttons))
+            .observe({
+                let path = path.clone();
+                move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                    commands.run_system_cached_with(
+                        show_bug_report_dialog,
+                        (path.clone(), Some(error.clone())),
+                    );
+                }
+            });
+        spawn_dialog_button(
+            commands,
+            theme,
+            "Open terminal",
+            theme.button.background_color.0,
+        )
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>,
+                  mut commands: Commands,
+                  settings: Res<LauncherSettings>| {
+                open_terminal_with_toast(&mut commands, &path, &settings);
+            },
+        );
+    }
+}
+
+/// Hide the [`HealthIssueRow`]s not matching the [`HealthFilter`], ignoring the case.
+fn filter_health_issues(
+    filters: Query<&TextInputValue, With<HealthFilter>>,
+    mut rows: Query<(&HealthIssueRow, &mut Node)>,
+) {
+    // A single health dialog is open at a time
+    let Some(filter) = filters.iter().next() else {
+        return;
+    };
+    let filter = filter.0.trim().to_lowercase();
+    for (row, mut node) in &mut rows {
+        let display = if row.text.contains(&filter) {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+}
+
+/// Remove the project of the issue row from the project list, along with its row.
+fn remove_unhealthy_project(
+    In((dialog, row)): In<(Entity, Entity)>,
+    mut commands: Commands,
+    mut checks: Query<&mut HealthCheck>,
+    rows: Query<&HealthIssueRow>,
+    project_nodes: Query<(Entity, &ProjectNode)>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+) {
+    let Ok(HealthIssueRow { path, .. }) = rows.get(row) else {
+        return;
+    };
+    project_list.0.retain(|project| &project.path != path);
+    store.set(project_list.0.clone());
+    for (entity, ProjectNode(node_path)) in &project_nodes {
+        if node_path == path {
+            commands.entity(entity).despawn();
+        }
+    }
+    if let Ok(mut check) = checks.get_mut(dialog) {
+        check.issues.retain(|issue| &issue.path != path);
+    }
+    push_toast(
+        &mut commands,
+        ToastLevel::Info,
+        format!("Removed '{}' from the list", path.display()),
+    );
+    commands.entity(row).despawn();
+}
+
+/// Pick a file and write the report of the check of the dialog to it.
+fn save_health_report(In(dialog): In<Entity>, mut commands: Commands, checks: Query<&HealthCheck>) {
+    let Ok(check) = checks.get(dialog) else {
+        return;
+    };
+    if check.task.is_some() {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            "The check is still running",
+        );
+        return;
+    }
+    let Some(file) = rfd::FileDialog::new()
+        .set_file_name("project_health.txt")
+        .save_file()
+    else {
+        return;
+    };
+    match fs::write(&file, health_report(&check.issues, check.checked)) {
+        Ok(()) => push_toast(
+            &mut commands,
+            ToastLevel::Info,
+            format!("Saved the report to '{}'", file.display()),
+        ),
+        Err(error) => push_toast(
+            &mut commands,
+            ToastLevel::Error,
+            format!("Failed to save the report: {error}"),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_the_issues() {
+        let mut app = App::new();
+        app.add_systems(Update, filter_health_issues);
+        let filter = app
+            .world_mut()
+            .spawn((HealthFilter, TextInputValue(" Missing ".to_string())))
+            .id();
+        let row = |text: &str| HealthIssueRow {
+            path: PathBuf::from("/projects/game"),
+            text: text.to_string(),
+        };
+        let missing = app
+            .world_mut()
+            .spawn((
+                row("'/projects/game' doesn't exist (missing)"),
+                Node::default(),
+            ))
+            .id();
+        let cargo = app
+            .world_mut()
+            .spawn((row("cargo failed: error[e0425]"), Node::default()))
+            .id();
+
+        app.update();
+        let display = |app: &App, entity| app.world().get::<Node>(entity).unwrap().display;
+        assert_eq!(display(&app, missing), Display::Flex);
+        assert_eq!(display(&app, cargo), Display::None);
+
+        app.world_mut()
+            .get_mut::<TextInputValue>(filter)
+            .unwrap()
+            .0
+            .clear();
+        app.update();
+        assert_eq!(display(&app, cargo), Display::Flex);
+    }
+}
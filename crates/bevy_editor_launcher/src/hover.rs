@@ -0,0 +1,73 @@
+//! The elevation of the project cards while they are hovered.
+
+use bevy::prelude::*;
+use bevy_editor_styles::Theme;
+
+use crate::settings::LauncherSettings;
+
+/// How long the elevation takes to appear or disappear, in seconds.
+const ELEVATION_DURATION: f32 = 0.12;
+/// How far the shadow of a fully raised card reaches, in pixels.
+const ELEVATION_BLUR: f32 = 16.0;
+/// How far the shadow of a fully raised card is offset down, in pixels.
+const ELEVATION_OFFSET: f32 = 4.0;
+
+/// Plugin animating the [`CardElevation`] of the hovered cards.
+pub struct HoverPlugin;
+
+impl Plugin for HoverPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, animate_card_elevation);
+    }
+}
+
+/// Raises the card with a shadow while its [`Interaction`] is hovered or pressed.
+#[derive(Component, Default)]
+#[require(Interaction, BoxShadow)]
+pub(crate) struct CardElevation {
+    /// From 0 when resting to 1 when fully raised.
+    progress: f32,
+}
+
+/// Move the [`CardElevation`]s toward their target, instantly when the animations are reduced.
+fn animate_card_elevation(
+    mut cards: Query<(&Interaction, &mut CardElevation, &mut BoxShadow)>,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+    time: Res<Time>,
+) {
+    for (interaction, mut elevation, mut shadow) in &mut cards {
+        let target = match interaction {
+            Interaction::Hovered | Interaction::Pressed => 1.0,
+            Interaction::None => 0.0,
+        };
+        if elevation.progress == target && !theme.is_changed() {
+            continue;
+        }
+        elevation.progress = if settings.reduce_motion {
+            target
+        } else {
+            let step = time.delta_secs() / ELEVATION_DURATION;
+            if target > elevation.progress {
+                (elevation.progress + step).min(target)
+            } else {
+                (elevation.progress - step).max(target)
+            }
+        };
+
+        *shadow = if elevation.progress > 0.0 {
+            BoxShadow::new(
+                theme
+                    .general
+                    .elevation_color
+                    .with_alpha(theme.general.elevation_color.alpha() * elevation.progress),
+                Val::Px(0.0),
+                Val::Px(ELEVATION_OFFSET * elevation.progress),
+                Val::Px(0.0),
+                Val::Px(ELEVATION_BLUR * elevation.progress),
+            )
+        } else {
+            BoxShadow::default()
+        };
+    }
+}
@@ -0,0 +1,586 @@
+//! The keyboard shortcuts of the launcher, mapping named actions to key combinations.
+//!
+//! The defaults can be overridden in [`LauncherSettings::keybindings`], with combinations like
+//! `"Ctrl+Shift+N"`, `"F5"` or `"/"`. <kbd>Ctrl</kbd> also matches <kbd>Cmd</kbd> on macOS.
+//! The conflicting and invalid combinations are reported when the settings are loaded.
+//!
+//! The keys acting on the focused project node, reached with <kbd>Tab</kbd>, follow the file
+//! managers:
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | <kbd>Enter</kbd> | Open the project, not configurable |
+//! | <kbd>Space</kbd> | Open its context menu, [`LauncherAction::ShowContextMenu`] |
+//! | <kbd>F2</kbd> | Rename it, [`LauncherAction::RenameProject`] |
+//!
+//! They only apply while a project node has the focus, never while typing in a text field.
+
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use bevy::{input_focus::InputFocus, prelude::*};
+use bevy_context_menu::OpenContextMenu;
+use bevy_editor_styles::dialog::ModalStack;
+use bevy_text_editing::EditableTextLine;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    new_project::open_new_project_window, refresh::RefreshProjectsEvent,
+    rename::show_rename_dialog, settings::LauncherSettings, ui::ProjectNode,
+};
+
+/// Plugin building the [`Keybindings`] from the [`LauncherSettings`] and running the global shortcuts.
+pub struct KeybindingsPlugin;
+
+impl Plugin for KeybindingsPlugin {
+    fn build(&self, app: &mut App) {
+        // Built right away, so the hints spawned at startup show the configured shortcuts
+        let overrides = app
+            .world()
+            .get_resource::<LauncherSettings>()
+            .map(|settings| settings.keybindings.clone())
+            .unwrap_or_default();
+        app.insert_resource(Keybindings::load(&overrides))
+            .add_systems(
+                PreUpdate,
+                update_keybindings.run_if(resource_changed::<LauncherSettings>),
+            )
+            .add_systems(Update, (run_global_shortcuts, run_project_shortcuts));
+    }
+}
+
+/// An action of the launcher that can be bound to key combinations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum LauncherAction {
+    /// Open the new project window.
+    NewProject,
+    /// Focus the search box.
+    FocusSearch,
+    /// Reload the project list from disk.
+    Refresh,
+    /// Show or hide the log console.
+    ToggleLogConsole,
+    /// Open the context menu of the focused project.
+    ShowContextMenu,
+    /// Rename the focused project.
+    RenameProject,
+}
+
+impl LauncherAction {
+    /// All the actions, in the order they win conflicts.
+    pub const ALL: [LauncherAction; 6] = [
+        LauncherAction::NewProject,
+        LauncherAction::FocusSearch,
+        LauncherAction::Refresh,
+        LauncherAction::ToggleLogConsole,
+        LauncherAction::ShowContextMenu,
+        LauncherAction::RenameProject,
+    ];
+
+    /// The combinations the action is bound to when the settings don't override them.
+    pub fn default_combos(self) -> Vec<KeyCombo> {
+        match self {
+            LauncherAction::NewProject => vec![KeyCombo::ctrl(KeyCode::KeyN)],
+            LauncherAction::FocusSearch => {
+                vec![KeyCombo::new(KeyCode::Slash), KeyCombo::ctrl(KeyCode::KeyF)]
+            }
+            LauncherAction::Refresh => {
+                vec![KeyCombo::new(KeyCode::F5), KeyCombo::ctrl(KeyCode::KeyR)]
+            }
+            LauncherAction::ToggleLogConsole => vec![KeyCombo::new(KeyCode::F12)],
+            LauncherAction::ShowContextMenu => vec![KeyCombo::new(KeyCode::Space)],
+            LauncherAction::RenameProject => vec![KeyCombo::new(KeyCode::F2)],
+        }
+    }
+}
+
+/// A key pressed with a set of modifiers, like <kbd>Ctrl</kbd> + <kbd>N</kbd>.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    /// The key completing the combination.
+    pub key: KeyCode,
+    /// Whether <kbd>Ctrl</kbd>, or <kbd>Cmd</kbd>, is held.
+    pub ctrl: bool,
+    /// Whether <kbd>Shift</kbd> is held.
+    pub shift: bool,
+    /// Whether <kbd>Alt</kbd> is held.
+    pub alt: bool,
+}
+
+/// How <kbd>Ctrl</kbd> is displayed, the platform shortcuts use <kbd>Cmd</kbd> on macOS.
+const CTRL_LABEL: &str = if cfg!(target_os = "macos") {
+    "Cmd"
+} else {
+    "Ctrl"
+};
+
+/// The names of the keys that can be bound, as written in the settings.
+const KEY_NAMES: &[(&str, KeyCode)] = &[
+    ("A", KeyCode::KeyA),
+    ("B", KeyCode::KeyB),
+    ("C", KeyCode::KeyC),
+    ("D", KeyCode::KeyD),
+    ("E", KeyCode::KeyE),
+    ("F", KeyCode::KeyF),
+    ("G", KeyCode::KeyG),
+    ("H", KeyCode::KeyH),
+    ("I", KeyCode::KeyI),
+    ("J", KeyCode::KeyJ),
+    ("K", KeyCode::KeyK),
+    ("L", KeyCode::KeyL),
+    ("M", KeyCode::KeyM),
+    ("N", KeyCode::KeyN),
+    ("O", KeyCode::KeyO),
+    ("P", KeyCode::KeyP),
+    ("Q", KeyCode::KeyQ),
+    ("R", KeyCode::KeyR),
+    ("S", KeyCode::KeyS),
+    ("T", KeyCode::KeyT),
+    ("U", KeyCode::KeyU),
+    ("V", KeyCode::KeyV),
+    ("W", KeyCode::KeyW),
+    ("X", KeyCode::KeyX),
+    ("Y", KeyCode::KeyY),
+    ("Z", KeyCode::KeyZ),
+    ("0", KeyCode::Digit0),
+    ("1", KeyCode::Digit1),
+    ("2", KeyCode::Digit2),
+    ("3", KeyCode::Digit3),
+    ("4", KeyCode::Digit4),
+    ("5", KeyCode::Digit5),
+    ("6", KeyCode::Digit6),
+    ("7", KeyCode::Digit7),
+    ("8", KeyCode::Digit8),
+    ("9", KeyCode::Digit9),
+    ("F1", KeyCode::F1),
+    ("F2", KeyCode::F2),
+    ("F3", KeyCode::F3),
+    ("F4", KeyCode::F4),
+    ("F5", KeyCode::F5),
+    ("F6", KeyCode::F6),
+    ("F7", KeyCode::F7),
+    ("F8", KeyCode::F8),
+    ("F9", KeyCode::F9),
+    ("F10", KeyCode::F10),
+    ("F11", KeyCode::F11),
+    ("F12", KeyCode::F12),
+    ("/", KeyCode::Slash),
+    ("\\", KeyCode::Backslash),
+    (",", KeyCode::Comma),
+    (".", KeyCode::Period),
+    (";", KeyCode::Semicolon),
+    ("-", KeyCode::Minus),
+    ("=", KeyCode::Equal),
+    ("`", KeyCode::Backquote),
+    ("Space", KeyCode::Space),
+    ("Enter", KeyCode::Enter),
+    ("Escape", KeyCode::Escape),
+    ("Tab", KeyCode::Tab),
+    ("Backspace", KeyCode::Backspace),
+    ("Delete", KeyCode::Delete),
+    ("Insert", KeyCode::Insert),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+    ("Up", KeyCode::ArrowUp),
+    ("Down", KeyCode::ArrowDown),
+    ("Left", KeyCode::ArrowLeft),
+    ("Right", KeyCode::ArrowRight),
+];
+
+impl KeyCombo {
+    /// The key without modifiers.
+    pub const fn new(key: KeyCode) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    /// The key with <kbd>Ctrl</kbd>, or <kbd>Cmd</kbd>.
+    pub const fn ctrl(key: KeyCode) -> Self {
+        Self {
+            ctrl: true,
+            ..Self::new(key)
+        }
+    }
+
+    /// Whether <kbd>Ctrl</kbd>, <kbd>Shift</kbd> or <kbd>Alt</kbd> is part of the combination.
+    pub fn has_modifier(&self) -> bool {
+        self.ctrl || self.shift || self.alt
+    }
+
+    /// Whether the key was just pressed with exactly the modifiers of the combination.
+    pub fn just_pressed(&self, keys: &ButtonInput<KeyCode>) -> bool {
+        keys.just_pressed(self.key)
+            && self.ctrl
+                == keys.any_pressed([
+                    KeyCode::ControlLeft,
+                    KeyCode::ControlRight,
+                    KeyCode::SuperLeft,
+                    KeyCode::SuperRight,
+                ])
+            && self.shift == keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+            && self.alt == keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight])
+    }
+}
+
+impl FromStr for KeyCombo {
+    type Err = String;
+
+    /// Parse a combination like `Ctrl+Shift+N`, the modifiers and key names are case insensitive.
+    fn from_str(combo: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| format!("'{combo}' isn't a valid key combination: {reason}");
+        let (modifiers, key) = combo.rsplit_once('+').unwrap_or(("", combo));
+        let key = KEY_NAMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key.trim()))
+            .map(|(_, key)| *key)
+            .ok_or_else(|| invalid("unknown key"))?;
+
+        let mut parsed = Self::new(key);
+        for modifier in modifiers
+            .split('+')
+            .filter(|modifier| !modifier.trim().is_empty())
+        {
+            let flag = match modifier.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" | "cmd" | "super" => &mut parsed.ctrl,
+                "shift" => &mut parsed.shift,
+                "alt" | "option" => &mut parsed.alt,
+                _ => return Err(invalid("unknown modifier")),
+            };
+            if *flag {
+                return Err(invalid("repeated modifier"));
+            }
+            *flag = true;
+        }
+        Ok(parsed)
+    }
+}
+
+impl fmt::Display for KeyCombo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "{CTRL_LABEL}+")?;
+        }
+        if self.shift {
+            f.write_str("Shift+")?;
+        }
+        if self.alt {
+            f.write_str("Alt+")?;
+        }
+        match KEY_NAMES.iter().find(|(_, key)| *key == self.key) {
+            Some((name, _)) => f.write_str(name),
+            None => write!(f, "{:?}", self.key),
+        }
+    }
+}
+
+/// The key combinations bound to each [`LauncherAction`], consulted by the input systems.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct Keybindings(BTreeMap<LauncherAction, Vec<KeyCombo>>);
+
+impl Default for Keybindings {
+    fn default() -> Self {
+        Self(
+            LauncherAction::ALL
+                .into_iter()
+                .map(|action| (action, action.default_combos()))
+                .collect(),
+        )
+    }
+}
+
+impl Keybindings {
+    /// The default bindings with the overrides of the settings applied, the problems found logged.
+    pub fn load(overrides: &BTreeMap<LauncherAction, Vec<String>>) -> Self {
+        let (keybindings, problems) = Self::from_overrides(overrides);
+        for problem in problems {
+            warn!("{problem}");
+        }
+        keybindings
+    }
+
+    /// The default bindings with the overrides applied, returned with the problems found.
+    ///
+    /// An action whose combinations can't all be parsed keeps its defaults. A combination bound
+    /// to several actions only triggers the first one of [`LauncherAction::ALL`].
+    pub fn from_overrides(
+        overrides: &BTreeMap<LauncherAction, Vec<String>>,
+    ) -> (Self, Vec<String>) {
+        let mut problems = Vec::new();
+        let mut bindings = Self::default().0;
+        for (action, combos) in overrides {
+            match combos
+                .iter()
+                .map(|combo| combo.parse())
+                .collect::<Result<Vec<KeyCombo>, _>>()
+            {
+                Ok(combos) => {
+                    bindings.insert(*action, combos);
+                }
+                Err(error) => problems.push(format!(
+                    "{error}, keeping the default shortcuts of {action:?}"
+                )),
+            }
+        }
+
+        let mut bound: Vec<(KeyCombo, LauncherAction)> = Vec::new();
+        for action in LauncherAction::ALL {
+            let Some(combos) = bindings.get_mut(&action) else {
+                continue;
+            };
+            combos.retain(
+                |combo| match bound.iter().find(|(listed, _)| listed == combo) {
+                    Some((_, owner)) if *owner == action => false,
+                    Some((_, owner)) => {
+                        problems.push(format!(
+                            "'{combo}' is bound to {owner:?} and {action:?}, only {owner:?} uses it"
+                        ));
+                        false
+                    }
+                    None => {
+                        bound.push((*combo, action));
+                        true
+                    }
+                },
+            );
+        }
+        (Self(bindings), problems)
+    }
+
+    /// The combinations bound to the action.
+    pub fn combos(&self, action: LauncherAction) -> &[KeyCombo] {
+        self.0.get(&action).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Whether one of the combinations of the action was just pressed.
+    pub fn just_pressed(&self, action: LauncherAction, keys: &ButtonInput<KeyCode>) -> bool {
+        self.combos(action)
+            .iter()
+            .any(|combo| combo.just_pressed(keys))
+    }
+
+    /// Whether one of the combinations of the action holding a modifier was just pressed.
+    pub fn just_pressed_with_modifier(
+        &self,
+        action: LauncherAction,
+        keys: &ButtonInput<KeyCode>,
+    ) -> bool {
+        self.combos(action)
+            .iter()
+            .any(|combo| combo.has_modifier() && combo.just_pressed(keys))
+    }
+
+    /// The combinations of the action for the hints of the UI, like `/ or Ctrl+F`.
+    pub fn label(&self, action: LauncherAction) -> String {
+        let combos: Vec<_> = self
+            .combos(action)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        combos.join(" or ")
+    }
+}
+
+/// Rebuild the [`Keybindings`] when the overrides of the settings change.
+fn update_keybindings(settings: Res<LauncherSettings>, mut keybindings: ResMut<Keybindings>) {
+    let updated = Keybindings::from_overrides(&settings.keybindings).0;
+    if *keybindings != updated {
+        *keybindings = Keybindings::load(&settings.keybindings);
+    }
+}
+
+/// Run the shortcuts that aren't tied to a part of the UI.
+///
+/// While typing in a text field or in a dialog, the keys without a modifier are left to them.
+fn run_global_shortcuts(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    keybindings: Res<Keybindings>,
+    focus: Res<InputFocus>,
+    modals: Res<ModalStack>,
+    text_fields: Query<(), With<EditableTextLine>>,
+    mut refresh_events: EventWriter<RefreshProjectsEvent>,
+) {
+    let keys_taken =
+        !modals.is_empty() || focus.0.is_some_and(|focused| text_fields.contains(focused));
+    let just_pressed = |action| {
+        if keys_taken {
+            keybindings.just_pressed_with_modifier(action, &keys)
+        } else {
+            keybindings.just_pressed(action, &keys)
+        }
+    };
+    if just_pressed(LauncherAction::NewProject) {
+        commands.run_system_cached(open_new_project_window);
+    }
+    if just_pressed(LauncherAction::Refresh) {
+        refresh_events.write(RefreshProjectsEvent);
+    }
+}
+
+/// Run the shortcuts acting on the focused [`ProjectNode`].
+///
+/// While typing, the text field has the focus instead, so the keys reach it alone.
+fn run_project_shortcuts(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    keybindings: Res<Keybindings>,
+    focus: Res<InputFocus>,
+    modals: Res<ModalStack>,
+    nodes: Query<(&ComputedNode, &UiGlobalTransform), With<ProjectNode>>,
+) {
+    let Some(focused) = focus.0 else {
+        return;
+    };
+    let Ok((computed, transform)) = nodes.get(focused) else {
+        return;
+    };
+    // A dialog opened over the project list takes the keys
+    if !modals.is_empty() {
+        return;
+    }
+    if keybindings.just_pressed(LauncherAction::ShowContextMenu, &keys) {
+        // Opened at the center of the node, where a click would be
+        let position = transform.translation * computed.inverse_scale_factor();
+        commands.trigger_targets(OpenContextMenu(position), focused);
+    }
+    if keybindings.just_pressed(LauncherAction::RenameProject, &keys) {
+        commands.run_system_cached_with(show_rename_dialog, focused);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_key_combos() {
+        assert_eq!("F5".parse(), Ok(KeyCombo::new(KeyCode::F5)));
+        assert_eq!("ctrl+n".parse(), Ok(KeyCombo::ctrl(KeyCode::KeyN)));
+        assert_eq!(
+            "Cmd + Shift + N".parse(),
+            Ok(KeyCombo {
+                shift: true,
+                ..KeyCombo::ctrl(KeyCode::KeyN)
+            })
+        );
+        assert!("Ctrl+Ctrl+N".parse::<KeyCombo>().is_err());
+        assert!("Hyper+N".parse::<KeyCombo>().is_err());
+        assert!("Ctrl+".parse::<KeyCombo>().is_err());
+        assert!("".parse::<KeyCombo>().is_err());
+        assert_eq!(
+            KeyCombo {
+                alt: true,
+                ..KeyCombo::ctrl(KeyCode::Slash)
+            }
+            .to_string(),
+            format!("{CTRL_LABEL}+Alt+/")
+        );
+    }
+
+    #[test]
+    fn report_conflicting_and_invalid_bindings() {
+        let overrides = BTreeMap::from([
+            (LauncherAction::Refresh, vec!["Ctrl+N".to_string()]),
+            (
+                LauncherAction::ToggleLogConsole,
+                vec!["Ctrl+Nope".to_string()],
+            ),
+            (LauncherAction::FocusSearch, vec![]),
+        ]);
+        let (keybindings, problems) = Keybindings::from_overrides(&overrides);
+        assert_eq!(problems.len(), 2, "{problems:?}");
+        assert_eq!(
+            keybindings.combos(LauncherAction::NewProject),
+            [KeyCombo::ctrl(KeyCode::KeyN)]
+        );
+        assert!(keybindings.combos(LauncherAction::Refresh).is_empty());
+        assert!(keybindings.combos(LauncherAction::FocusSearch).is_empty());
+        assert_eq!(
+            keybindings.combos(LauncherAction::ToggleLogConsole),
+            [KeyCombo::new(KeyCode::F12)]
+        );
+        assert!(Keybindings::from_overrides(&BTreeMap::new()).1.is_empty());
+    }
+
+    #[test]
+    fn match_the_modifiers_exactly() {
+        let keybindings = Keybindings::default();
+        let mut keys = ButtonInput::default();
+        keys.press(KeyCode::ControlLeft);
+        keys.press(KeyCode::KeyF);
+        assert!(keybindings.just_pressed(LauncherAction::FocusSearch, &keys));
+        assert!(!keybindings.just_pressed(LauncherAction::NewProject, &keys));
+
+        keys.reset_all();
+        keys.press(KeyCode::ShiftLeft);
+        keys.press(KeyCode::F5);
+        assert!(!keybindings.just_pressed(LauncherAction::Refresh, &keys));
+        assert_eq!(
+            keybindings.label(LauncherAction::FocusSearch),
+            format!("/ or {CTRL_LABEL}+F")
+        );
+    }
+
+    #[test]
+    fn act_on_the_project_with_the_file_manager_keys() {
+        let keybindings = Keybindings::default();
+        let mut keys = ButtonInput::default();
+        keys.press(KeyCode::Space);
+        assert!(keybindings.just_pressed(LauncherAction::ShowContextMenu, &keys));
+        assert!(!keybindings.just_pressed(LauncherAction::RenameProject, &keys));
+
+        keys.reset_all();
+        keys.press(KeyCode::F2);
+        assert!(keybindings.just_pressed(LauncherAction::RenameProject, &keys));
+
+        // Configurable like the other shortcuts
+        let overrides =
+            BTreeMap::from([(LauncherAction::RenameProject, vec!["Shift+F6".to_string()])]);
+        let (keybindings, problems) = Keybindings::from_overrides(&overrides);
+        assert!(problems.is_empty(), "{problems:?}");
+        assert_eq!(keybindings.label(LauncherAction::RenameProject), "Shift+F6");
+    }
+
+    #[test]
+    fn leave_the_plain_keys_to_the_text_fields() {
+        let overrides = BTreeMap::from([(
+            LauncherAction::Refresh,
+            vec!["R".to_string(), "Ctrl+R".to_string()],
+        )]);
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(Keybindings::from_overrides(&overrides).0)
+            .init_resource::<InputFocus>()
+            .init_resource::<ModalStack>()
+            .add_event::<RefreshProjectsEvent>()
+            .add_systems(Update, run_global_shortcuts);
+        let text_field = app.world_mut().spawn(EditableTextLine::new("")).id();
+        app.world_mut().resource_mut::<InputFocus>().set(text_field);
+        let refreshes = |app: &App| app.world().resource::<Events<RefreshProjectsEvent>>().len();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyR);
+        app.update();
+        assert_eq!(refreshes(&app), 0, "R is typed in the text field");
+
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.reset_all();
+        keys.press(KeyCode::ControlLeft);
+        keys.press(KeyCode::KeyR);
+        app.update();
+        assert_eq!(refreshes(&app), 1);
+
+        app.world_mut().resource_mut::<InputFocus>().clear();
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.reset_all();
+        keys.press(KeyCode::KeyR);
+        app.update();
+        assert_eq!(refreshes(&app), 2);
+    }
+}
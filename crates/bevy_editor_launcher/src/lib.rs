@@ -0,0 +1,524 @@
+//! The launcher for the Bevy Editor.
+//!
+//! The launcher provide a bunch of functionalities to manage your projects.
+//! Everything is packaged in the [`LauncherPlugin`], so it can be embedded in any Bevy app.
+//! Without a window or a renderer, like on CI, the [`HeadlessLauncherPlugin`] runs the project
+//! management alone, see it for what is available.
+
+use std::{
+    collections::HashMap,
+    io,
+    path::PathBuf,
+    sync::{
+        Arc, Mutex,
+        mpsc::{self, Receiver},
+    },
+};
+
+use bevy::{
+    ecs::schedule::common_conditions::any_with_component, feathers::cursor::CursorIconPlugin,
+    prelude::*,
+};
+
+use always_on_top::AlwaysOnTopPlugin;
+use auto_save::AutoSavePlugin;
+use badges::BadgesPlugin;
+use bevy_clipboard::ClipboardPlugin;
+use bevy_context_menu::ContextMenuPlugin;
+use bevy_editor::project::{
+    CancellationToken, CreationPhase, FileProjectStore, ProgressEvent, ProjectError, ProjectInfo,
+    ProjectStore, RetryPolicy, create_new_project_with_progress, projects_root, set_projects_root,
+    templates::{Templates, check_templates, templates_folder},
+};
+use bevy_editor_styles::{StylesPlugin, Theme, ThemeMode};
+use bevy_footer_bar::{
+    FooterBarAppExt, FooterBarPlugin, FooterBarSet, FooterSlot, footer_version, spawn_footer_info,
+    spawn_footer_progress, spawn_footer_status,
+};
+use bevy_scroll_box::ScrollBoxPlugin;
+use bevy_text_editing::EditableTextLinePlugin;
+use bevy_version::BevyVersionPlugin;
+use bug_report::BugReportPlugin;
+use clean::CleanPlugin;
+use collection_groups::CollectionGroupsPlugin;
+use confirmations::ConfirmationsPlugin;
+use connectivity::ConnectivityPlugin;
+use creation_preview::CreationPreviewPlugin;
+use creation_progress::{CreationProgressPlugin, ProjectCreationLogs};
+use details::DetailsPlugin;
+use first_run::FirstRunPlugin;
+use focus::FocusPlugin;
+use folder_browser::FolderBrowserPlugin;
+use health::HealthPlugin;
+use hover::HoverPlugin;
+use keybindings::KeybindingsPlugin;
+use log_console::LogConsolePlugin;
+use log_search::LogSearchPlugin;
+use new_project::{NewProjectPlugin, UnusableTemplates};
+use open::OpenProjectPlugin;
+use project_icons::ProjectIconsPlugin;
+use quick_access::QuickAccessPlugin;
+use refresh::RefreshPlugin;
+use save_status::SaveStatusPlugin;
+use scan::ScanPlugin;
+use search::SearchPlugin;
+use selection::SelectionPlugin;
+use session::SessionPlugin;
+use settings::SettingsPlugin;
+use sort::SortPlugin;
+use start_view::StartViewPlugin;
+use tags::TagsPlugin;
+use task_completion::{BackgroundTask, TaskChannel};
+use task_limit::{TaskLimitPlugin, TaskSlots};
+use thumbnails::ThumbnailsPlugin;
+use toast::{ToastLevel, ToastPlugin, push_toast};
+use tray::TrayPlugin;
+use ui::{NewProjectButton, ProjectList};
+use whats_new::WhatsNewPlugin;
+use window_state::WindowStatePlugin;
+use workspace::WorkspacePlugin;
+
+pub use headless::{HeadlessCommand, HeadlessLauncherPlugin};
+pub use keybindings::{KeyCombo, Keybindings, LauncherAction};
+pub use log_console::{LogConsole, LogLine, log_console_layer};
+pub use open::{OpenOutcome, OpenProjectAck, OpenProjectEvent};
+pub use open_with::open_with_tool;
+pub use refresh::RefreshProjectsEvent;
+pub use settings::{
+    BadgeKind, LauncherSettings, OpenBehavior, OpenWith, ProgressDisplay, ProjectSort,
+    SettingsFile, TaskCompletion, WindowState, load_settings, save_settings, save_settings_to,
+};
+pub use start_view::StartView;
+pub use terminal::open_terminal;
+pub use tray::TrayAvailable;
+pub use window_state::apply_window_size;
+
+mod always_on_top;
+mod ansi;
+mod auto_save;
+mod badges;
+mod bevy_version;
+mod bug_report;
+mod build_errors;
+mod clean;
+mod clipboard;
+mod collection_groups;
+mod confirmations;
+mod connectivity;
+mod creation_preview;
+mod creation_progress;
+mod data_folder;
+mod details;
+mod first_run;
+mod focus;
+mod folder_browser;
+mod headless;
+mod health;
+mod hover;
+mod keybindings;
+mod log_console;
+mod log_search;
+mod new_project;
+mod open;
+mod open_with;
+mod project_icons;
+mod quick_access;
+mod quit;
+mod refresh;
+mod rename;
+mod save_status;
+mod scan;
+mod search;
+mod selection;
+mod session;
+mod settings;
+mod sort;
+mod start_view;
+mod tags;
+mod task_completion;
+mod task_limit;
+mod terminal;
+mod thumbnails;
+mod toast;
+mod tray;
+mod ui;
+mod whats_new;
+mod window_state;
+mod workspace;
+
+/// Plugin adding the launcher UI and the project management to the app.
+///
+/// The [`LauncherSettings`] are loaded from disk unless the resource already exists, and so is
+/// the project list unless a [`ProjectListStore`] is already inserted.
+/// Their [`theme`](LauncherSettings::theme) is used unless a [`ThemeMode`] is already inserted.
+pub struct LauncherPlugin {
+    /// The templates offered when creating a new project.
+    pub templates: Vec<Templates>,
+    /// The folder suggested when picking the location of a new project, instead of the
+    /// [`default_project_root`](LauncherSettings::default_project_root) of the settings.
+    ///
+    /// The paths of the projects inside it are stored relative to it, see [`set_projects_root`].
+    pub project_root: Option<PathBuf>,
+}
+
+impl Default for LauncherPlugin {
+    fn default() -> Self {
+        Self {
+            templates: Templates::ALL.to_vec(),
+            project_root: None,
+        }
+    }
+}
+
+impl Plugin for LauncherPlugin {
+    fn build(&self, app: &mut App) {
+        // The theme is built when the styles are added, it needs the settings first
+        let unusable_templates =
+            insert_launcher_resources(app, &self.templates, self.project_root.clone());
+        let theme = app.world().resource::<LauncherSettings>().theme;
+        if !app.world().contains_resource::<ThemeMode>() {
+            app.insert_resource(theme);
+        }
+        if !app.is_plugin_added::<StylesPlugin>() {
+            app.add_plugins(StylesPlugin);
+        }
+        if !app.is_plugin_added::<FooterBarPlugin>() {
+            app.add_plugins(FooterBarPlugin);
+        }
+        if !app.is_plugin_added::<ScrollBoxPlugin>() {
+            app.add_plugins(ScrollBoxPlugin);
+        }
+        if !app.is_plugin_added::<ContextMenuPlugin>() {
+            app.add_plugins(ContextMenuPlugin);
+        }
+        if !app.is_plugin_added::<ClipboardPlugin>() {
+            app.add_plugins(ClipboardPlugin);
+        }
+        if !app.is_plugin_added::<EditableTextLinePlugin>() {
+            app.add_plugins(EditableTextLinePlugin);
+        }
+        // Shows the `EntityCursor` of the hovered splitters and menus, the editor gets it from
+        // `FeathersPlugin`
+        if !app.is_plugin_added::<CursorIconPlugin>() {
+            app.add_plugins(CursorIconPlugin);
+        }
+
+        app.add_plugins((
+            ToastPlugin,
+            FocusPlugin,
+            SettingsPlugin,
+            WindowStatePlugin,
+            OpenProjectPlugin,
+            NewProjectPlugin,
+            SearchPlugin,
+            CreationProgressPlugin,
+            RefreshPlugin,
+            ScanPlugin,
+            QuickAccessPlugin,
+            TrayPlugin,
+            BadgesPlugin,
+            LogConsolePlugin,
+            HoverPlugin,
+        ))
+        .add_plugins((
+            SelectionPlugin,
+            SessionPlugin,
+            DetailsPlugin,
+            BevyVersionPlugin,
+            KeybindingsPlugin,
+            FirstRunPlugin,
+            AutoSavePlugin,
+            SaveStatusPlugin,
+            WorkspacePlugin,
+            CleanPlugin,
+            SortPlugin,
+            ConfirmationsPlugin,
+            ThumbnailsPlugin,
+            ConnectivityPlugin,
+            HealthPlugin,
+        ))
+        // The tuples above are full
+        .add_plugins((
+            TaskLimitPlugin,
+            WhatsNewPlugin,
+            ProjectIconsPlugin,
+            CreationPreviewPlugin,
+            BugReportPlugin,
+            FolderBrowserPlugin,
+            StartViewPlugin,
+            AlwaysOnTopPlugin,
+            LogSearchPlugin,
+            TagsPlugin,
+            CollectionGroupsPlugin,
+        ))
+        .insert_resource(UnusableTemplates(unusable_templates))
+        .init_resource::<TaskChannel<CreationResult>>()
+        .add_systems(Startup, ui::setup)
+        .add_systems(
+            Update,
+            poll_create_project_task.run_if(any_with_component::<CreateProjectTask>),
+        )
+        .configure_sets(Startup, FooterBarSet.after(ui::setup))
+        // The open, scan and clean tasks report to the status, `task_limit` to the info
+        .add_footer_widget(FooterSlot::Left, 0, spawn_footer_status)
+        .add_footer_widget(FooterSlot::Left, 1, spawn_footer_progress)
+        .add_footer_widget(FooterSlot::Right, 0, spawn_footer_info)
+        .add_footer_widget(
+            FooterSlot::Right,
+            1,
+            footer_version(env!("CARGO_PKG_VERSION")),
+        );
+    }
+}
+
+/// Insert the resources shared by the [`LauncherPlugin`] and the [`HeadlessLauncherPlugin`]: the
+/// [`LauncherSettings`], the [`ProjectListStore`], the project list and the [`LauncherConfig`],
+/// returning the templates that can't be used.
+fn insert_launcher_resources(
+    app: &mut App,
+    templates: &[Templates],
+    project_root: Option<PathBuf>,
+) -> Vec<(Templates, String)> {
+    if !app.world().contains_resource::<LauncherSettings>() {
+        app.insert_resource(load_settings());
+    }
+    let project_root = project_root.or_else(|| {
+        app.world()
+            .resource::<LauncherSettings>()
+            .default_project_root
+            .clone()
+    });
+    if !app.world().contains_resource::<ProjectListStore>() {
+        app.insert_resource(ProjectListStore(Arc::new(FileProjectStore)));
+    }
+    let projects = app.world().resource::<ProjectListStore>().load();
+
+    // Templates that can't be read would only fail once picked
+    let (templates, unusable_templates) = check_templates(templates);
+    for (template, reason) in &unusable_templates {
+        warn!(
+            "The template '{}' can't be used and isn't offered: {reason}",
+            template.display_name()
+        );
+    }
+    if unusable_templates
+        .iter()
+        .any(|(template, _)| Templates::ALL.contains(template))
+    {
+        warn!(
+            "The built-in templates are expected in '{}', next to the launcher or in its working directory",
+            templates_folder().display()
+        );
+    }
+
+    if let Some(project_root) = &project_root
+        && projects_root().as_ref() != Some(project_root)
+    {
+        set_projects_root(project_root.clone());
+    }
+
+    app.insert_resource(LauncherConfig {
+        templates,
+        project_root,
+    })
+    .insert_resource(ProjectInfoList(projects));
+    unusable_templates
+}
+
+/// The configuration of the [`LauncherPlugin`].
+#[derive(Resource, Debug, Clone)]
+pub struct LauncherConfig {
+    /// The templates offered when creating a new project, the ones of the [`LauncherPlugin`]
+    /// whose folder can be read.
+    pub templates: Vec<Templates>,
+    /// The folder suggested when picking the location of a new project.
+    pub project_root: Option<PathBuf>,
+}
+
+/// The result of a [`CreateProjectTask`].
+type CreationResult = Result<ProjectInfo, ProjectError>;
+
+/// The Task that creates a new project
+#[derive(Component)]
+#[require(ProjectCreationLogs)]
+struct CreateProjectTask {
+    task: BackgroundTask<CreationResult>,
+    /// The folder the project is created in
+    path: PathBuf,
+    /// The progress reported by the task, see [`create_new_project_with_progress`]
+    progress: Mutex<Receiver<ProgressEvent>>,
+    /// The latest phase reported by the task
+    phase: Option<CreationPhase>,
+    /// Cancels the creation, which removes the files it wrote
+    cancel: CancellationToken,
+    /// Whether to open the project once it has been created
+    open_after_creation: bool,
+}
+
+/// Check on the status of the [`CreateProjectTask`] and handle the result when done
+///
+/// The results are polled from the tasks or received from the [`TaskChannel`], depending on the
+/// [`TaskCompletion`] they were spawned with.
+fn poll_create_project_task(
+    mut commands: Commands,
+    mut task_query: Query<(Entity, &mut CreateProjectTask)>,
+    channel: Res<TaskChannel<CreationResult>>,
+    project_list_entity: Single<Entity, With<ProjectList>>,
+    new_project_button: Single<Entity, With<NewProjectButton>>,
+    theme: Res<Theme>,
+    mut project_list: ResMut<ProjectInfoList>,
+    mut open_events: EventWriter<OpenProjectEvent>,
+) {
+    let mut finished: Vec<(Entity, CreationResult)> = task_query
+        .iter_mut()
+        .filter_map(|(entity, mut task)| task.task.poll().map(|result| (entity, result)))
+        .collect();
+    finished.extend(channel.drain());
+    for (task_entity, result) in finished {
+        // The results sent by the tasks cancelled meanwhile are dropped
+        let Ok((_, task)) = task_query.get(task_entity) else {
+            continue;
+        };
+        let open_after_creation = task.open_after_creation;
+        commands.entity(task_entity).despawn();
+        match result {
+            Ok(project_info) => {
+                // The task added it to the store, only the list in memory is left
+                project_list.0.push(project_info.clone());
+                // Add new project node Ui element
+                ui::append_project_node(
+                    &mut commands,
+                    *project_list_entity,
+                    *new_project_button,
+                    &theme,
+                    &project_info,
+                );
+
+                if open_after_creation {
+                    open_events.write(OpenProjectEvent {
+                        path: project_info.path,
+                        behavior: None,
+                        with: None,
+                        target: None,
+                    });
+                }
+            }
+            Err(ProjectError::Cancelled) => {
+                info!("Cancelled the creation of '{}'", task.path.display());
+                // The files written by the creation were removed by it
+                push_toast(
+                    &mut commands,
+                    ToastLevel::Info,
+                    format!("Cancelled the creation of '{}'", task.project_name()),
+                );
+            }
+            Err(error) => {
+                error!("Failed to create new project: {:?}", error);
+                let (level, message) = match &error {
+                    ProjectError::NameInvalid(name) => (
+                        ToastLevel::Warning,
+                        format!("'{name}' can't be used as a project name"),
+                    ),
+                    ProjectError::DirectoryNotEmpty(path) => (
+                        ToastLevel::Warning,
+                        format!("'{}' isn't empty, pick another location", path.display()),
+                    ),
+                    ProjectError::NotWritable { path, .. } => (
+                        ToastLevel::Error,
+                        format!("No permission to write to '{}'", path.display()),
+                    ),
+                    ProjectError::Template(reason) => (
+                        ToastLevel::Error,
+                        format!("The template can't be used: {reason}"),
+                    ),
+                    error => (
+                        ToastLevel::Error,
+                        format!("Failed to create new project: {error}"),
+                    ),
+                };
+                push_toast(&mut commands, level, message);
+            }
+        }
+    }
+}
+
+/// Spawn a new [`CreateProjectTask`] to create a new project, its progress recorded to the `logs`
+#[expect(clippy::too_many_arguments)]
+fn spawn_create_new_project_task(
+    commands: &mut Commands,
+    completion: TaskCompletion,
+    channel: &TaskChannel<CreationResult>,
+    slots: &TaskSlots,
+    template: Templates,
+    path: PathBuf,
+    variables: HashMap<String, String>,
+    run_post_create: bool,
+    retry: RetryPolicy,
+    open_after_creation: bool,
+    logs: ProjectCreationLogs,
+    store: Arc<dyn ProjectStore>,
+) {
+    let (sender, progress) = mpsc::channel();
+    let task_path = path.clone();
+    let cancel = CancellationToken::new();
+    let task_cancel = cancel.clone();
+    let entity = commands.spawn_empty().id();
+    let slot = slots.acquire();
+    let task = BackgroundTask::spawn(completion, entity, channel, async move {
+        let _slot = slot.await;
+        create_new_project_with_progress(
+            template,
+            task_path,
+            variables,
+            run_post_create,
+            &retry,
+            &task_cancel,
+            &*store,
+            move |event| {
+                // The receiver is gone once the task entity is despawned, nothing to report to then
+                let _ = sender.send(event);
+            },
+        )
+        .await
+    });
+    commands.entity(entity).insert((
+        CreateProjectTask {
+            task,
+            path,
+            progress: Mutex::new(progress),
+            phase: None,
+            cancel,
+            open_after_creation,
+        },
+        logs,
+    ));
+}
+
+#[derive(Resource)]
+struct ProjectInfoList(Vec<ProjectInfo>);
+
+/// The [`ProjectStore`] the project list of the launcher is loaded from and saved to.
+///
+/// Insert it before the [`LauncherPlugin`] to keep the list elsewhere than in the
+/// [`FileProjectStore`] of the editor, like in a
+/// [`MemoryProjectStore`](bevy_editor::project::MemoryProjectStore) in tests. The operations of
+/// the editor on the projects, like creating, renaming or deleting them, update it too.
+#[derive(Resource)]
+pub struct ProjectListStore(pub Arc<dyn ProjectStore>);
+
+impl ProjectListStore {
+    /// Load the project list, an empty one when the store can't be read.
+    pub fn load(&self) -> Vec<ProjectInfo> {
+        self.0.load_projects()
+    }
+
+    /// Save the project list, merging the duplicated projects first.
+    pub fn save(&self, projects: Vec<ProjectInfo>) -> io::Result<()> {
+        self.0.save_projects(projects)
+    }
+
+    /// Like [`save`](Self::save), but logging the error instead of returning it.
+    pub fn set(&self, projects: Vec<ProjectInfo>) {
+        self.0.set_projects(projects);
+    }
+}
@@ -0,0 +1,548 @@
+//! An in-app console showing the log output of the launcher, toggled with the
+//! [`LauncherAction::ToggleLogConsole`] shortcut, <kbd>F12</kbd> by default.
+//!
+//! The log events are captured by the [`log_console_layer`], which must be set as the
+//! [`LogPlugin::custom_layer`](bevy::log::LogPlugin::custom_layer) of the app.
+//! Without it, the console only shows the output of the commands run by the launcher, like the
+//! ones setting up a new project, written to the [`LogConsoleInput`].
+//!
+//! The errors reported by cargo in the captured lines are summarized above the log, clicking one
+//! scrolls to the line reporting it.
+//!
+//! The lines are clipped to a single row, hovering one shows its full content wrapped in a
+//! [`Tooltip`], as does hovering an error of the summary. The full content is searched with the
+//! find bar of the console, see [`log_search`](crate::log_search).
+
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::{
+        Mutex,
+        mpsc::{self, Receiver, Sender},
+    },
+};
+
+use bevy::{
+    log::{
+        BoxedLayer, Level,
+        tracing::{
+            self, Subscriber,
+            field::{Field, Visit},
+        },
+        tracing_subscriber::{Layer, layer::Context},
+    },
+    prelude::*,
+};
+use bevy_editor_styles::{Theme, dialog::spawn_dialog_button, tooltip::Tooltip};
+use bevy_scroll_box::{ScrollBox, ScrollJumpButtons, ScrollShadows, spawn_scroll_box};
+
+use crate::{
+    ansi::strip_ansi,
+    build_errors::build_errors,
+    focus::Focusable,
+    keybindings::{Keybindings, LauncherAction},
+    log_search::{open_log_search, spawn_log_search_bar},
+};
+
+/// The maximum amount of lines kept by the [`LogConsole`], the oldest ones are dropped first.
+const MAX_CONSOLE_LINES: usize = 500;
+/// The maximum amount of errors listed in the [`LogConsoleSummary`], the oldest ones are dropped first.
+const MAX_SUMMARY_ERRORS: usize = 8;
+/// The width past which the full content of a line wraps in its [`Tooltip`], in pixels.
+const LINE_TOOLTIP_WIDTH: f32 = 480.0;
+
+/// Plugin displaying the log lines captured by the [`log_console_layer`].
+pub struct LogConsolePlugin;
+
+impl Plugin for LogConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LogConsole>()
+            .init_resource::<LogConsoleInput>()
+            .add_systems(Startup, spawn_log_console)
+            .add_systems(
+                Update,
+                (
+                    (receive_log_lines, update_error_summary).chain(),
+                    toggle_log_console,
+                ),
+            );
+    }
+}
+
+/// A log event captured for the console.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    /// The level the event was logged at.
+    pub level: Level,
+    /// The message of the event.
+    pub message: String,
+}
+
+/// The last [`MAX_CONSOLE_LINES`] captured log lines, oldest first.
+#[derive(Resource, Debug, Default)]
+pub struct LogConsole {
+    lines: VecDeque<LogLine>,
+}
+
+impl LogConsole {
+    /// The captured lines, oldest first.
+    pub fn lines(&self) -> impl Iterator<Item = &LogLine> {
+        self.lines.iter()
+    }
+
+    /// Add a line, dropping the oldest one when the console is full.
+    fn push(&mut self, line: LogLine) {
+        if self.lines.len() == MAX_CONSOLE_LINES {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(line);
+    }
+}
+
+/// The lines written to the console without going through the log, not displayed yet.
+///
+/// The output of the commands goes there, so its build errors are summarized and it can be searched
+/// without filling the log of the launcher.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct LogConsoleInput(Vec<LogLine>);
+
+impl LogConsoleInput {
+    /// Write a line to the console.
+    pub fn push(&mut self, level: Level, message: impl Into<String>) {
+        self.0.push(LogLine {
+            level,
+            message: message.into(),
+        });
+    }
+}
+
+/// The log lines sent by the [`log_console_layer`], not displayed yet.
+#[derive(Resource)]
+struct LogCapture(Mutex<Receiver<LogLine>>);
+
+/// A tracing layer sending the log events to the [`LogConsole`].
+struct CaptureLayer {
+    sender: Sender<LogLine>,
+}
+
+impl<S: Subscriber> Layer<S> for CaptureLayer {
+    fn on_event(&self, event: &tracing::Event<'_>, _context: Context<'_, S>) {
+        let mut visitor = MessageVisitor(None);
+        event.record(&mut visitor);
+        if let Some(message) = visitor.0 {
+            // The receiver is gone once the app exits, nothing to log to then
+            let _ = self.sender.send(LogLine {
+                level: *event.metadata().level(),
+                message,
+            });
+        }
+    }
+}
+
+/// Extracts the message of a log event.
+struct MessageVisitor(Option<String>);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+/// The custom [`LogPlugin`](bevy::log::LogPlugin) layer capturing the log events for the console.
+///
+/// ```no_run
+/// # use bevy::{log::LogPlugin, prelude::*};
+/// # use bevy_editor_launcher::log_console_layer;
+/// App::new().add_plugins(DefaultPlugins.set(LogPlugin {
+///     custom_layer: log_console_layer,
+///     ..default()
+/// }));
+/// ```
+pub fn log_console_layer(app: &mut App) -> Option<BoxedLayer> {
+    let (sender, receiver) = mpsc::channel();
+    app.insert_resource(LogCapture(Mutex::new(receiver)));
+    Some(Box::new(CaptureLayer { sender }))
+}
+
+/// The panel of the console, hidden until toggled.
+#[derive(Component)]
+struct LogConsolePanel;
+
+/// The node the lines of the console are spawned into, newest first.
+#[derive(Component)]
+pub(crate) struct LogConsoleLines;
+
+/// A line of the [`LogConsoleLines`], with its full content.
+#[derive(Component)]
+pub(crate) struct LogConsoleLine(pub(crate) String);
+
+/// The summary of the build errors found in the lines, hidden while there are none.
+#[derive(Component)]
+struct LogConsoleSummary;
+
+/// The title of the [`LogConsoleSummary`], with the amount of errors.
+#[derive(Component)]
+struct LogConsoleSummaryTitle;
+
+/// An error listed in the [`LogConsoleSummary`], found in the given line of the [`LogConsoleLines`].
+#[derive(Component)]
+struct SummaryEntry {
+    line: Entity,
+}
+
+fn spawn_log_console(mut commands: Commands, theme: Res<Theme>, keybindings: Res<Keybindings>) {
+    let shortcuts = keybindings.label(LauncherAction::ToggleLogConsole);
+    let title = if shortcuts.is_empty() {
+        "Console".to_string()
+    } else {
+        format!("Console ({shortcuts} to hide)")
+    };
+    let panel = commands
+        .spawn((
+            LogConsolePanel,
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(0.0),
+                left: Val::Px(0.0),
+                width: Val::Percent(100.0),
+                height: Val::Percent(35.0),
+                display: Display::None,
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.0)),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            GlobalZIndex(5),
+        ))
+        .id();
+    let header = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(title),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 13.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(header),
+    ));
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Find...",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(header))
+    .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
+        commands.run_system_cached(open_log_search);
+    });
+    spawn_log_search_bar(&mut commands, &theme).insert(ChildOf(panel));
+    commands.spawn((
+        LogConsoleSummary,
+        Node {
+            display: Display::None,
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(6.0)),
+            row_gap: Val::Px(2.0),
+            ..default()
+        },
+        theme.button.background_color,
+        theme.button.border_radius,
+        ChildOf(panel),
+        children![(
+            LogConsoleSummaryTitle,
+            Text::default(),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(theme.status.error),
+        )],
+    ));
+    let lines = commands
+        .spawn((
+            Node {
+                flex_grow: 1.0,
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_scroll_box(
+        &mut commands,
+        &theme,
+        Overflow::scroll_y(),
+        Some(|commands: &mut Commands, content: Entity| {
+            commands.spawn((
+                LogConsoleLines,
+                Node {
+                    width: Val::Percent(100.0),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                ChildOf(content),
+            ));
+        }),
+    )
+    // The lines aren't focusable, the box is so that Home and End reach it
+    .insert((
+        ScrollShadows,
+        ScrollJumpButtons,
+        Focusable(2),
+        ChildOf(lines),
+    ));
+}
+
+/// The first row of a log line, with the amount of rows hidden after it.
+fn first_row(text: &str) -> String {
+    let mut rows = text.lines();
+    let first = rows.next().unwrap_or_default();
+    match rows.count() {
+        0 => first.to_string(),
+        1 => format!("{first} (+1 line)"),
+        hidden => format!("{first} (+{hidden} lines)"),
+    }
+}
+
+fn level_color(level: Level, theme: &Theme) -> Color {
+    match level {
+        Level::ERROR => theme.status.error,
+        Level::WARN => theme.status.warning,
+        Level::INFO => theme.text.text_color,
+        _ => theme.text.low_priority,
+    }
+}
+
+/// Move the captured log lines and the [`LogConsoleInput`] to the [`LogConsole`] and display them,
+/// adding their build errors to the [`LogConsoleSummary`].
+fn receive_log_lines(
+    mut commands: Commands,
+    capture: Option<Res<LogCapture>>,
+    mut input: ResMut<LogConsoleInput>,
+    mut console: ResMut<LogConsole>,
+    lines: Single<(Entity, Option<&Children>), With<LogConsoleLines>>,
+    summary: Single<Entity, With<LogConsoleSummary>>,
+    theme: Res<Theme>,
+) {
+    let mut received: Vec<LogLine> = capture
+        .and_then(|capture| {
+            capture
+                .0
+                .lock()
+                .ok()
+                .map(|receiver| receiver.try_iter().collect())
+        })
+        .unwrap_or_default();
+    received.append(&mut input.0);
+    if received.is_empty() {
+        return;
+    }
+
+    let (lines_entity, existing) = *lines;
+    // The newest lines come first, so they are visible without scrolling
+    let mut spawned = Vec::with_capacity(received.len());
+    let mut entries = Vec::new();
+    for line in received.iter().rev().take(MAX_CONSOLE_LINES) {
+        let message = strip_ansi(&line.message);
+        let text = format!("{} {message}", line.level);
+        // Clipped to a single row, the tooltip shows the rest
+        let line_entity = commands
+            .spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    overflow: Overflow::clip_x(),
+                    ..default()
+                },
+                LogConsoleLine(text.clone()),
+                // Highlighted by the search
+                BackgroundColor(Color::NONE),
+                Tooltip::new(text.clone()).with_max_width(LINE_TOOLTIP_WIDTH),
+                children![(
+                    Text::new(first_row(&text)),
+                    TextFont {
+                        font: theme.text.font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(level_color(line.level, &theme)),
+                    TextLayout::new_with_no_wrap(),
+                    Pickable::IGNORE,
+                )],
+            ))
+            .id();
+        spawned.push(line_entity);
+        // The newest errors come first too, the lines are already reversed
+        for error in build_errors(&message).into_iter().rev() {
+            entries.push(
+                commands
+                    .spawn((
+                        SummaryEntry { line: line_entity },
+                        Text::new(error.summary()),
+                        Tooltip::new(text.clone()).with_max_width(LINE_TOOLTIP_WIDTH),
+                        TextFont {
+                            font: theme.text.font.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(theme.text.text_color),
+                    ))
+                    .observe(
+                        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                            commands.run_system_cached_with(scroll_to_log_line, line_entity);
+                        },
+                    )
+                    .id(),
+            );
+        }
+    }
+    // After the title of the summary
+    commands.entity(*summary).insert_children(1, &entries);
+    let kept = MAX_CONSOLE_LINES - spawned.len();
+    for old in existing
+        .into_iter()
+        .flat_map(|children| children.iter())
+        .skip(kept)
+    {
+        commands.entity(old).despawn();
+    }
+    commands.entity(lines_entity).insert_children(0, &spawned);
+
+    for line in received {
+        console.push(line);
+    }
+}
+
+/// Drop the [`SummaryEntry`]s of the dropped lines and the oldest ones past [`MAX_SUMMARY_ERRORS`],
+/// and show the amount of errors in the [`LogConsoleSummary`].
+fn update_error_summary(
+    mut commands: Commands,
+    summary: Single<(&mut Node, &Children), With<LogConsoleSummary>>,
+    entries: Query<&SummaryEntry>,
+    lines: Query<(), With<LogConsoleLines>>,
+    parents: Query<&ChildOf>,
+    mut title: Single<&mut Text, With<LogConsoleSummaryTitle>>,
+) {
+    let (mut node, children) = summary.into_inner();
+    let mut count = 0;
+    for child in children.iter() {
+        let Ok(entry) = entries.get(child) else {
+            continue;
+        };
+        let line_shown = parents
+            .get(entry.line)
+            .is_ok_and(|child_of| lines.contains(child_of.parent()));
+        if line_shown && count < MAX_SUMMARY_ERRORS {
+            count += 1;
+        } else {
+            commands.entity(child).despawn();
+        }
+    }
+
+    let text = match count {
+        1 => "1 build error".to_string(),
+        count => format!("{count} build errors, newest first"),
+    };
+    if title.0 != text {
+        title.0 = text;
+    }
+    let display = if count == 0 {
+        Display::None
+    } else {
+        Display::Flex
+    };
+    if node.display != display {
+        node.display = display;
+    }
+}
+
+/// Scroll the console to the given line of the [`LogConsoleLines`].
+pub(crate) fn scroll_to_log_line(
+    In(line): In<Entity>,
+    lines: Single<(Entity, &Children), With<LogConsoleLines>>,
+    nodes: Query<&ComputedNode>,
+    parents: Query<&ChildOf>,
+    mut scroll_boxes: Query<(&mut ScrollBox, &ComputedNode)>,
+) {
+    let (lines_entity, children) = *lines;
+    if !children.contains(&line) {
+        return;
+    }
+    // The lines are stacked in a column, the line starts after the ones above it
+    let offset: f32 = children
+        .iter()
+        .take_while(|child| *child != line)
+        .filter_map(|child| nodes.get(child).ok())
+        .map(|node| node.size().y * node.inverse_scale_factor())
+        .sum();
+    let Some(box_entity) = parents
+        .iter_ancestors(lines_entity)
+        .find(|ancestor| scroll_boxes.contains(*ancestor))
+    else {
+        return;
+    };
+    let Ok((mut scroll_box, box_node)) = scroll_boxes.get_mut(box_entity) else {
+        return;
+    };
+    let content_height = nodes
+        .get(lines_entity)
+        .map_or(0.0, |node| node.size().y * node.inverse_scale_factor());
+    let box_height = box_node.size().y * box_node.inverse_scale_factor();
+    scroll_box.scroll_to(offset.min((content_height - box_height).max(0.0)));
+}
+
+fn toggle_log_console(
+    keys: Res<ButtonInput<KeyCode>>,
+    keybindings: Res<Keybindings>,
+    mut panel: Single<&mut Node, With<LogConsolePanel>>,
+) {
+    if keybindings.just_pressed(LauncherAction::ToggleLogConsole, &keys) {
+        panel.display = if panel.display == Display::None {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn console_drops_oldest_lines() {
+        let mut console = LogConsole::default();
+        for index in 0..MAX_CONSOLE_LINES + 2 {
+            console.push(LogLine {
+                level: Level::INFO,
+                message: index.to_string(),
+            });
+        }
+        assert_eq!(console.lines().count(), MAX_CONSOLE_LINES);
+        assert_eq!(console.lines().next().unwrap().message, "2");
+    }
+
+    #[test]
+    fn show_the_first_row_of_lines() {
+        assert_eq!(first_row("INFO Scanning"), "INFO Scanning");
+        assert_eq!(
+            first_row("ERROR error[E0425]: x\n --> src/main.rs:2:5"),
+            "ERROR error[E0425]: x (+1 line)"
+        );
+        assert_eq!(first_row("WARN a\nb\nc"), "WARN a (+2 lines)");
+    }
+}
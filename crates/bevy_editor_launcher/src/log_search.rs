@@ -0,0 +1,380 @@
+//! Finding text in the lines of the log console, to go through a long failing build.
+//!
+//! The find bar is opened from the header of the console. The whole lines are searched, including
+//! the rows clipped after the first one, and the lines with a match are highlighted, the one of the
+//! current match more. Stepping through the matches scrolls to their line like the error summary
+//! does. Closing the bar clears the highlights.
+
+use std::ops::Range;
+
+use bevy::{input_focus::InputFocus, prelude::*};
+use bevy_editor_styles::{
+    Theme,
+    checkbox::{CheckboxChanged, spawn_checkbox},
+    dialog::spawn_dialog_button,
+    text_input::{TextInputCancel, TextInputSubmit, TextInputValue, spawn_text_input},
+};
+use bevy_text_editing::{CharPosition, EditableTextLine, RenderWidget};
+
+use crate::log_console::{LogConsoleLine, LogConsoleLines, scroll_to_log_line};
+
+/// The width of the input of the [`LogSearchBar`], in pixels.
+const SEARCH_INPUT_WIDTH: f32 = 240.0;
+/// How transparent the highlight of the lines with a match is, the current one is opaque.
+const MATCH_HIGHLIGHT_ALPHA: f32 = 0.35;
+
+/// Plugin finding the [`LogSearch`] in the lines of the log console.
+pub struct LogSearchPlugin;
+
+impl Plugin for LogSearchPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LogSearch>()
+            .init_resource::<LogMatches>()
+            .add_systems(
+                Update,
+                (read_search_query, find_log_matches, highlight_log_matches).chain(),
+            );
+    }
+}
+
+/// What is searched in the log console.
+#[derive(Resource, Debug, Default)]
+struct LogSearch {
+    /// Whether the [`LogSearchBar`] is open, the lines are only highlighted while it is.
+    open: bool,
+    /// The searched text.
+    query: String,
+    /// Whether the case of the lines must match the one of the query.
+    case_sensitive: bool,
+}
+
+/// The matches of the [`LogSearch`], in the order of the lines and within them.
+#[derive(Resource, Debug, Default)]
+struct LogMatches {
+    /// The line of each match, a line appears once per match in it.
+    lines: Vec<Entity>,
+    /// The index of the current match in `lines`.
+    current: usize,
+}
+
+impl LogMatches {
+    /// The line of the current match and how many matches of that line come before it.
+    fn current_match(&self) -> Option<(Entity, usize)> {
+        let line = *self.lines.get(self.current)?;
+        let before = self.lines[..self.current]
+            .iter()
+            .filter(|other| **other == line)
+            .count();
+        Some((line, before))
+    }
+}
+
+/// The find bar above the lines of the console, hidden until opened.
+#[derive(Component)]
+struct LogSearchBar;
+
+/// The input of the [`LogSearchBar`].
+#[derive(Component)]
+struct LogSearchInput;
+
+/// The text showing which match is the current one, and how many there are.
+#[derive(Component)]
+struct LogSearchCount;
+
+/// The byte ranges of the occurrences of `query` in `text`, without overlaps.
+///
+/// The case is ignored unless `case_sensitive` is set. An empty query matches nothing.
+fn find_matches(text: &str, query: &str, case_sensitive: bool) -> Vec<Range<usize>> {
+    let mut matches = Vec::new();
+    if query.is_empty() {
+        return matches;
+    }
+    let mut from = 0;
+    while let Some(found) = text[from..].char_indices().find_map(|(offset, _)| {
+        let start = from + offset;
+        match_end(text, start, query, case_sensitive).map(|end| start..end)
+    }) {
+        from = found.end;
+        matches.push(found);
+    }
+    matches
+}
+
+/// The end of the occurrence of `query` at `start` in `text`, `None` when it doesn't occur there.
+fn match_end(text: &str, start: usize, query: &str, case_sensitive: bool) -> Option<usize> {
+    let mut rest = text[start..].char_indices();
+    let mut end = start;
+    for query_char in query.chars() {
+        let (offset, text_char) = rest.next()?;
+        let same = if case_sensitive {
+            text_char == query_char
+        } else {
+            text_char.to_lowercase().eq(query_char.to_lowercase())
+        };
+        if !same {
+            return None;
+        }
+        end = start + offset + text_char.len_utf8();
+    }
+    Some(end)
+}
+
+/// Which match is the current one out of how many, like `3 of 12`.
+fn format_match_count(current: usize, count: usize) -> String {
+    match count {
+        0 => "No matches".to_string(),
+        count => format!("{} of {count}", current + 1),
+    }
+}
+
+/// Spawn the [`LogSearchBar`], hidden until [`open_log_search`] shows it.
+pub(crate) fn spawn_log_search_bar<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+) -> EntityCommands<'a> {
+    let bar = commands
+        .spawn((
+            LogSearchBar,
+            Node {
+                display: Display::None,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+        ))
+        .id();
+    // The input stretches to the width of its column
+    let input_column = commands
+        .spawn((
+            Node {
+                width: Val::Px(SEARCH_INPUT_WIDTH),
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            ChildOf(bar),
+        ))
+        .id();
+    spawn_text_input(
+        commands,
+        theme,
+        "",
+        "Find in the log, Enter for the next match",
+    )
+    .insert((LogSearchInput, ChildOf(input_column)))
+    .observe(|_trigger: On<TextInputSubmit>, mut commands: Commands| {
+        commands.run_system_cached_with(step_log_match, true);
+    })
+    .observe(|_trigger: On<TextInputCancel>, mut commands: Commands| {
+        commands.run_system_cached(close_log_search);
+    });
+    spawn_dialog_button(commands, theme, "↑", theme.button.background_color.0)
+        .insert(ChildOf(bar))
+        .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached_with(step_log_match, false);
+        });
+    spawn_dialog_button(commands, theme, "↓", theme.button.background_color.0)
+        .insert(ChildOf(bar))
+        .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached_with(step_log_match, true);
+        });
+    spawn_checkbox(commands, theme, "Match case", false)
+        .insert(ChildOf(bar))
+        .observe(
+            |trigger: On<CheckboxChanged>, mut search: ResMut<LogSearch>| {
+                search.case_sensitive = trigger.event().0;
+            },
+        );
+    commands.spawn((
+        LogSearchCount,
+        Node {
+            flex_grow: 1.0,
+            ..default()
+        },
+        Text::default(),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(bar),
+    ));
+    spawn_dialog_button(commands, theme, "×", theme.button.background_color.0)
+        .insert(ChildOf(bar))
+        .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached(close_log_search);
+        });
+    commands.entity(bar)
+}
+
+/// Show the [`LogSearchBar`] and focus its input, selecting the previous search.
+pub(crate) fn open_log_search(
+    mut commands: Commands,
+    mut search: ResMut<LogSearch>,
+    mut bar: Single<&mut Node, With<LogSearchBar>>,
+    input: Single<&Children, With<LogSearchInput>>,
+    mut lines: Query<&mut EditableTextLine>,
+    mut focus: ResMut<InputFocus>,
+) {
+    search.open = true;
+    bar.display = Display::Flex;
+    // The text line is the first child of the input
+    let Some(line) = input.first().copied() else {
+        return;
+    };
+    if let Ok(mut text_line) = lines.get_mut(line) {
+        let length = text_line.text.chars().count();
+        text_line.selection_start = Some(CharPosition(0));
+        text_line.cursor_position = Some(CharPosition(length));
+    }
+    focus.set(line);
+    commands.trigger_targets(RenderWidget::show_cursor(), line);
+}
+
+/// Hide the [`LogSearchBar`], clearing the highlights.
+fn close_log_search(
+    mut search: ResMut<LogSearch>,
+    mut bar: Single<&mut Node, With<LogSearchBar>>,
+    mut focus: ResMut<InputFocus>,
+    parents: Query<&ChildOf>,
+    inputs: Query<(), With<LogSearchInput>>,
+) {
+    search.open = false;
+    bar.display = Display::None;
+    let focused_input = focus.0.is_some_and(|focused| {
+        parents
+            .iter_ancestors(focused)
+            .any(|ancestor| inputs.contains(ancestor))
+    });
+    if focused_input {
+        focus.clear();
+    }
+}
+
+/// Make the next match, or the previous one, the current one and scroll to its line.
+fn step_log_match(In(forward): In<bool>, mut commands: Commands, mut matches: ResMut<LogMatches>) {
+    let count = matches.lines.len();
+    if count == 0 {
+        return;
+    }
+    matches.current = if forward {
+        (matches.current + 1) % count
+    } else {
+        (matches.current + count - 1) % count
+    };
+    commands.run_system_cached_with(scroll_to_log_line, matches.lines[matches.current]);
+}
+
+/// Copy the text of the [`LogSearchInput`] to the [`LogSearch`].
+fn read_search_query(
+    inputs: Query<&TextInputValue, (With<LogSearchInput>, Changed<TextInputValue>)>,
+    mut search: ResMut<LogSearch>,
+) {
+    for value in &inputs {
+        if search.query != value.0 {
+            search.query = value.0.clone();
+        }
+    }
+}
+
+/// Find the [`LogMatches`] again when the search or the lines change.
+///
+/// The current match stays the same while lines are added, a new search starts from its first
+/// match and scrolls to it.
+fn find_log_matches(
+    mut commands: Commands,
+    search: Res<LogSearch>,
+    mut matches: ResMut<LogMatches>,
+    lines: Single<Option<Ref<Children>>, With<LogConsoleLines>>,
+    texts: Query<&LogConsoleLine>,
+) {
+    let lines = lines.into_inner();
+    let lines_changed = lines.as_ref().is_some_and(|children| children.is_changed());
+    if !search.is_changed() && !lines_changed {
+        return;
+    }
+    let previous = matches.current_match();
+    let mut found = Vec::new();
+    if search.open {
+        for line in lines.iter().flat_map(|children| children.iter()) {
+            let Ok(LogConsoleLine(text)) = texts.get(line) else {
+                continue;
+            };
+            let count = find_matches(text, &search.query, search.case_sensitive).len();
+            found.extend(std::iter::repeat_n(line, count));
+        }
+    }
+
+    let current = if search.is_changed() {
+        if let Some(first) = found.first() {
+            commands.run_system_cached_with(scroll_to_log_line, *first);
+        }
+        0
+    } else {
+        previous
+            .and_then(|(line, before)| {
+                let first = found.iter().position(|other| *other == line)?;
+                (found.get(first + before) == Some(&line)).then_some(first + before)
+            })
+            .unwrap_or(0)
+    };
+    if matches.lines != found || matches.current != current {
+        matches.lines = found;
+        matches.current = current;
+    }
+}
+
+/// Highlight the lines with a match and show the amount of matches.
+fn highlight_log_matches(
+    matches: Res<LogMatches>,
+    search: Res<LogSearch>,
+    mut lines: Query<(Entity, &mut BackgroundColor), With<LogConsoleLine>>,
+    mut count: Single<&mut Text, With<LogSearchCount>>,
+    theme: Res<Theme>,
+) {
+    if !matches.is_changed() && !search.is_changed() {
+        return;
+    }
+    let current = matches.current_match().map(|(line, _)| line);
+    let highlight = theme.text.selection_color;
+    for (line, mut background) in &mut lines {
+        let color = if current == Some(line) {
+            highlight
+        } else if matches.lines.contains(&line) {
+            highlight.with_alpha(MATCH_HIGHLIGHT_ALPHA)
+        } else {
+            Color::NONE
+        };
+        background.set_if_neq(BackgroundColor(color));
+    }
+    let text = if search.query.is_empty() {
+        String::new()
+    } else {
+        format_match_count(matches.current, matches.lines.len())
+    };
+    if count.0 != text {
+        count.0 = text;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_every_match() {
+        let log = "error[E0425]: cannot find value\n --> src/main.rs:2:5\nERROR: build failed";
+        assert_eq!(find_matches(log, "error", false).len(), 2);
+        assert_eq!(find_matches(log, "error", true), vec![0..5]);
+        assert_eq!(find_matches(log, "main.rs:2", false), vec![41..50]);
+        assert_eq!(find_matches("aaaa", "aa", false), vec![0..2, 2..4]);
+        assert_eq!(
+            find_matches("Éclair éclair", "ÉC", false),
+            vec![0..3, 8..11]
+        );
+        assert!(find_matches(log, "", false).is_empty());
+
+        assert_eq!(format_match_count(0, 0), "No matches");
+        assert_eq!(format_match_count(2, 12), "3 of 12");
+    }
+}
@@ -1,99 +1,168 @@
 //! The launcher for the Bevy Editor.
 //!
 //! The launcher provide a bunch of functionalities to manage your projects.
+//!
+//! Run with `--export-projects [FILE]`, it prints the project list as JSON instead, or writes it to
+//! the file. See [`bevy_editor::project::export`] for the format.
+//!
+//! Run with `--check-projects [--cargo-check] [FILE]`, it checks the health of the project list
+//! instead and prints the issues found, or writes them to the file. It exits with a failure when
+//! issues were found. See [`bevy_editor::project::health`] for what is checked.
+//!
+//! Run with `--create-project FOLDER [--template TEMPLATE]`, `--import-project FOLDER` or
+//! `--scan-projects FOLDER`, it runs the command without a window or a renderer and exits once
+//! done, failing when the command did. See [`HeadlessLauncherPlugin`] for what runs headless.
+//!
+//! Run with `--new-project`, `--settings` or `--focus-project FOLDER`, it opens the launcher into
+//! the new project window, the settings, or the project list focused on the project in the folder.
+//! The list is shown as usual when the folder isn't a project of the list. See [`StartView`].
 
-use std::path::PathBuf;
-
-use bevy::{
-    ecs::schedule::common_conditions::any_with_component,
-    prelude::*,
-    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
-};
+use std::{fs, process::ExitCode, time::Duration};
 
+use bevy::{app::ScheduleRunnerPlugin, log::LogPlugin, prelude::*};
 use bevy_editor::project::{
-    ProjectInfo, create_new_project, get_local_projects, set_project_list, templates::Templates,
+    CancellationToken,
+    export::ProjectExport,
+    get_local_projects,
+    health::{DEFAULT_CHECK_PARALLELISM, check_projects, health_report},
+};
+use bevy_editor_launcher::{
+    HeadlessCommand, HeadlessLauncherPlugin, LauncherPlugin, StartView, apply_window_size,
+    load_settings, log_console_layer,
 };
-use bevy_editor_styles::{StylesPlugin, Theme};
-use bevy_footer_bar::{FooterBarPlugin, FooterBarSet};
-use bevy_scroll_box::ScrollBoxPlugin;
-use ui::ProjectList;
-
-mod ui;
-
-/// The Task that creates a new project
-#[derive(Component)]
-struct CreateProjectTask(Task<std::io::Result<ProjectInfo>>);
 
-/// Check on the status of the [`CreateProjectTask`] and handle the result when done
-fn poll_create_project_task(
-    mut commands: Commands,
-    mut task_query: Query<(Entity, &mut CreateProjectTask)>,
-    query: Query<(Entity, &Children), With<ProjectList>>,
-    theme: Res<Theme>,
-    asset_server: Res<AssetServer>,
-    mut project_list: ResMut<ProjectInfoList>,
-) {
-    let (task_entity, mut task) = task_query.single_mut().unwrap();
-    if let Some(result) = block_on(future::poll_once(&mut task.0)) {
-        match result {
-            Ok(project_info) => {
-                // Add the new project to the list of projects
-                project_list.0.push(project_info.clone());
-                set_project_list(project_list.0.clone());
-                // Add new project node Ui element
-                commands.entity(task_entity).despawn();
-                let (project_list_entity, children) = query.iter().next().unwrap();
-                let plus_button_entity = children.last().unwrap();
+/// The flag exporting the project list instead of opening the launcher.
+const EXPORT_FLAG: &str = "--export-projects";
+/// The flag checking the health of the project list instead of opening the launcher.
+const CHECK_FLAG: &str = "--check-projects";
+/// The option of [`CHECK_FLAG`] also running `cargo check` in the projects.
+const CARGO_CHECK_FLAG: &str = "--cargo-check";
 
-                commands
-                    .entity(project_list_entity)
-                    .with_children(|builder| {
-                        ui::spawn_project_node(builder, &theme, &asset_server, &project_info);
-                    });
-                commands
-                    .entity(*plus_button_entity)
-                    .insert(ChildOf(project_list_entity));
-            }
-            Err(error) => {
-                error!("Failed to create new project: {:?}", error);
-                commands.entity(task_entity).despawn();
-            }
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some(EXPORT_FLAG) => return export_projects(args.get(1).cloned()),
+        Some(CHECK_FLAG) => return check_project_list(&args[1..]),
+        _ => {}
+    }
+    match HeadlessCommand::from_args(&args) {
+        Ok(Some(command)) => return run_headless(command),
+        Ok(None) => {}
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
         }
     }
-}
 
-/// Spawn a new [`CreateProjectTask`] to create a new project
-fn spawn_create_new_project_task(commands: &mut Commands, template: Templates, path: PathBuf) {
-    let task = IoTaskPool::get().spawn(async move { create_new_project(template, path).await });
-    commands.spawn(CreateProjectTask(task));
-}
+    let start_view = match StartView::from_args(&args) {
+        Ok(start_view) => start_view,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
 
-#[derive(Resource)]
-struct ProjectInfoList(Vec<ProjectInfo>);
+    let settings = load_settings();
+    let mut window = Window {
+        title: "Bevy Editor Launcher".to_string(),
+        ..default()
+    };
+    apply_window_size(&mut window, &settings.window);
 
-fn main() {
-    App::new()
+    let mut app = App::new();
+    if let Some(start_view) = start_view {
+        app.insert_resource(start_view);
+    }
+    app.insert_resource(settings)
         .add_plugins((
-            DefaultPlugins.set(WindowPlugin {
-                primary_window: Some(Window {
-                    title: "Bevy Editor Launcher".to_string(),
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(window),
+                    // Handled by the launcher, which can minimize to the tray instead
+                    close_when_requested: false,
+                    ..default()
+                })
+                .set(LogPlugin {
+                    custom_layer: log_console_layer,
                     ..default()
                 }),
-                ..default()
-            }),
-            StylesPlugin,
-            FooterBarPlugin,
-            ScrollBoxPlugin,
+            LauncherPlugin::default(),
+        ))
+        .run();
+    ExitCode::SUCCESS
+}
+
+/// How long a headless frame lasts at least, polling the running command 60 times per second
+/// instead of spinning a core while it waits on the disk or the network.
+const HEADLESS_FRAME_TIME: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+/// Run the command with the project list of the launcher, without a window or a renderer.
+fn run_headless(command: HeadlessCommand) -> ExitCode {
+    let exit = App::new()
+        .insert_resource(command)
+        .add_plugins((
+            MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(HEADLESS_FRAME_TIME)),
+            LogPlugin::default(),
+            HeadlessLauncherPlugin::default(),
         ))
-        .insert_resource(ProjectInfoList(get_local_projects()))
-        .add_systems(Startup, ui::setup)
-        .add_systems(
-            Update,
-            (
-                poll_create_project_task.run_if(any_with_component::<CreateProjectTask>),
-                ui::handle_notification_popups,
-            ),
-        )
-        .configure_sets(Startup, FooterBarSet.after(ui::setup))
         .run();
+    match exit {
+        AppExit::Success => ExitCode::SUCCESS,
+        AppExit::Error(_) => ExitCode::FAILURE,
+    }
+}
+
+/// Print the project list as JSON, or write it to the `output` file.
+fn export_projects(output: Option<String>) -> ExitCode {
+    let json = ProjectExport::new(&get_local_projects()).to_json();
+    let Some(output) = output else {
+        println!("{json}");
+        return ExitCode::SUCCESS;
+    };
+    match fs::write(&output, json + "\n") {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Couldn't write the project list to '{output}': {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Check the health of the project list and print the report, or write it to the file of the
+/// arguments.
+fn check_project_list(args: &[String]) -> ExitCode {
+    let cargo_check = args.iter().any(|arg| arg == CARGO_CHECK_FLAG);
+    let output = args.iter().find(|arg| !arg.starts_with("--"));
+
+    let paths: Vec<_> = get_local_projects()
+        .into_iter()
+        .map(|project| project.path)
+        .collect();
+    let issues = match check_projects(
+        &paths,
+        DEFAULT_CHECK_PARALLELISM,
+        cargo_check,
+        &CancellationToken::new(),
+    ) {
+        Ok(issues) => issues,
+        Err(error) => {
+            eprintln!("Couldn't check the projects: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let report = health_report(&issues, paths.len());
+    match output {
+        None => print!("{report}"),
+        Some(output) => {
+            if let Err(error) = fs::write(output, report) {
+                eprintln!("Couldn't write the report to '{output}': {error}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if issues.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
 }
@@ -0,0 +1,1451 @@
+//! The new project window, where the template and the creation options are chosen.
+//!
+//! The templates can be picked with the keyboard: Tab reaches the options, the arrow keys move the
+//! selection between the visible ones and <kbd>Enter</kbd> selects the focused one. The selected
+//! template is described below the options, and exposed to screen readers as a selected list item
+//! labeled with its name and description.
+//!
+//! The Git templates need the network, they are disabled while the [`Connectivity`] is offline.
+//! The templates whose folder is missing or unreadable aren't offered, see [`UnusableTemplates`].
+//! The project name and the URL of the Git templates are validated as they are typed. Once the
+//! location is picked in the [`FolderBrowser`](crate::folder_browser), the creation is reviewed in
+//! the [`CreationPreview`](crate::creation_preview) before it starts.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use bevy::{
+    a11y::{
+        AccessibilityNode,
+        accesskit::{Node as Accessible, Role},
+    },
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::{FocusedInput, InputFocus, InputFocusVisible},
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+    ui::InteractionDisabled,
+};
+use bevy_editor::project::{
+    RetryPolicy, enclosing_project,
+    templates::{TemplateManifest, Templates, validate_git_url},
+    toolchain::ToolchainInfo,
+    validate_project_name,
+};
+use bevy_editor_styles::{
+    Theme,
+    checkbox::{Checkbox, CheckboxChanged, spawn_checkbox},
+    dialog::{
+        ConfirmationDialog, DialogResult, close_dialog, spawn_dialog_button, spawn_dialog_root,
+    },
+    text_input::{TextInputValidator, TextInputValue, spawn_text_input},
+    tooltip::Tooltip,
+};
+use bevy_text_editing::{CharPosition, EditableTextLine, RenderWidget};
+
+use crate::{
+    CreationResult, LauncherConfig, ProjectInfoList, ProjectListStore,
+    confirmations::{Confirmation, offer_dont_ask_again},
+    connectivity::Connectivity,
+    creation_preview::show_creation_preview,
+    creation_progress::{ProjectCreationLogs, creation_log_path},
+    focus::{Activate, Focusable},
+    folder_browser::{FolderPicked, spawn_folder_browser},
+    settings::LauncherSettings,
+    task_completion::TaskChannel,
+    task_limit::TaskSlots,
+    toast::{ToastLevel, push_toast},
+};
+
+/// Plugin for the new project window.
+pub struct NewProjectPlugin;
+
+impl Plugin for NewProjectPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InstalledToolchain>()
+            .init_resource::<UnusableTemplates>()
+            .add_systems(
+                Startup,
+                (query_installed_toolchain, report_unusable_templates),
+            )
+            .add_systems(
+                Update,
+                (
+                    poll_installed_toolchain,
+                    update_template_options,
+                    filter_templates_by_category,
+                    suggest_project_name,
+                    disable_network_templates,
+                ),
+            );
+    }
+}
+
+/// The configured templates that can't be used along with why, left out of the [`LauncherConfig`].
+///
+/// They are checked once when the launcher starts, see
+/// [`check_templates`](bevy_editor::project::templates::check_templates).
+#[derive(Resource, Default)]
+pub(crate) struct UnusableTemplates(pub Vec<(Templates, String)>);
+
+/// Tell the user about the [`UnusableTemplates`], instead of letting them wonder where they went.
+fn report_unusable_templates(mut commands: Commands, unusable: Res<UnusableTemplates>) {
+    let message = match unusable.0.as_slice() {
+        [] => return,
+        [(template, reason)] => format!(
+            "The template '{}' isn't available: {reason}",
+            template.display_name()
+        ),
+        templates => format!(
+            "{} templates aren't available, see the logs for why",
+            templates.len()
+        ),
+    };
+    push_toast(&mut commands, ToastLevel::Warning, message);
+}
+
+/// The window used to configure a new project before creating it.
+#[derive(Component)]
+pub struct NewProjectWindow {
+    /// The selected template.
+    pub template: Templates,
+    /// Whether the project is opened once created.
+    pub open_after_creation: bool,
+    /// Whether a project created from a Git template starts with a new history.
+    pub fresh_history: bool,
+    /// The category the templates are filtered by, `None` showing them all.
+    pub category: Option<String>,
+}
+
+/// The option selecting a Git template, the URL is only known when creating the project.
+const GIT_TEMPLATE: Templates = Templates::Git {
+    url: String::new(),
+    fresh_history: true,
+};
+
+/// How many templates are kept in [`LauncherSettings::recent_templates`].
+const MAX_RECENT_TEMPLATES: usize = 3;
+
+/// The tooltip of the template options disabled while offline.
+const OFFLINE_TOOLTIP: &str =
+    "Cloning a Git template needs a network connection, which seems to be unavailable";
+
+/// The options of the Git template, only shown when it is selected.
+#[derive(Component)]
+struct GitTemplateOptions;
+
+/// The input of the URL of the Git template.
+#[derive(Component)]
+struct GitUrlInput;
+
+/// The checkbox of [`NewProjectWindow::fresh_history`].
+#[derive(Component)]
+struct FreshHistoryCheckbox;
+
+/// The input of the name of the project, the folder created in the picked location.
+#[derive(Component)]
+struct ProjectNameInput {
+    /// The name last suggested, replaced when another template is selected unless it was edited.
+    suggestion: String,
+}
+
+/// The Rust toolchain installed on the machine.
+#[derive(Resource, Default)]
+struct InstalledToolchain {
+    /// The toolchain, `None` if none was found.
+    toolchain: Option<ToolchainInfo>,
+    /// The query running in the background, the toolchain isn't known until it finishes.
+    task: Option<Task<Option<ToolchainInfo>>>,
+}
+
+/// A button selecting a template in the [`NewProjectWindow`].
+#[derive(Component)]
+struct TemplateOption {
+    template: Templates,
+    manifest: TemplateManifest,
+}
+
+/// A button selecting one of the [`LauncherSettings::recent_templates`], above the category tabs.
+#[derive(Component)]
+struct RecentTemplateOption(Templates);
+
+/// A template option needing the network, disabled while offline.
+#[derive(Component)]
+struct NeedsNetwork;
+
+/// A tab filtering the templates of the [`NewProjectWindow`] by category, `None` for all of them.
+#[derive(Component)]
+struct CategoryTab(Option<String>);
+
+/// The text describing the selected template.
+#[derive(Component)]
+struct TemplateDescription;
+
+/// The inputs of the variables declared by a template, only shown when the template is selected.
+#[derive(Component)]
+struct TemplateVariables(Templates);
+
+/// The input of the value of a template variable.
+#[derive(Component)]
+struct VariableInput {
+    template: Templates,
+    name: String,
+}
+
+/// Spawn the [`TemplateVariables`] inputs of the given template.
+fn spawn_template_variables<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    template: Templates,
+    manifest: &TemplateManifest,
+) -> EntityCommands<'a> {
+    let container = commands
+        .spawn((
+            Node {
+                display: Display::None,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            TemplateVariables(template.clone()),
+        ))
+        .id();
+
+    for variable in &manifest.variables {
+        let label = if variable.description.is_empty() {
+            variable.name.clone()
+        } else {
+            format!("{} - {}", variable.name, variable.description)
+        };
+        commands.spawn((
+            Text::new(label),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 13.0,
+                ..default()
+            },
+            TextColor(theme.text.text_color),
+            ChildOf(container),
+        ));
+        commands.spawn((
+            EditableTextLine::new(variable.default.clone().unwrap_or_default()),
+            VariableInput {
+                template: template.clone(),
+                name: variable.name.clone(),
+            },
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(24.0),
+                padding: UiRect::horizontal(Val::Px(6.0)),
+                ..default()
+            },
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            theme.button.background_color,
+            theme.button.border_radius,
+            ChildOf(container),
+        ));
+    }
+
+    commands.entity(container)
+}
+
+/// Spawn the [`GitTemplateOptions`] of the given [`NewProjectWindow`].
+fn spawn_git_template_options<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    root: Entity,
+) -> EntityCommands<'a> {
+    let container = commands
+        .spawn((
+            Node {
+                display: Display::None,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            GitTemplateOptions,
+        ))
+        .id();
+
+    commands.spawn((
+        Text::new("Repository URL"),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 13.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(container),
+    ));
+    spawn_text_input(commands, theme, "", "https://host/repository.git").insert((
+        GitUrlInput,
+        TextInputValidator::new(validate_git_url),
+        ChildOf(container),
+    ));
+    spawn_checkbox(commands, theme, "Start with a fresh history", true)
+        .insert((FreshHistoryCheckbox, ChildOf(container)))
+        .observe(
+            move |trigger: On<CheckboxChanged>, mut windows: Query<&mut NewProjectWindow>| {
+                if let Ok(mut window) = windows.get_mut(root) {
+                    window.fresh_history = trigger.event().0;
+                }
+            },
+        );
+
+    commands.entity(container)
+}
+
+/// Move the template to the front of the recent ones, keeping at most [`MAX_RECENT_TEMPLATES`].
+///
+/// The Git templates are told apart by their URL only.
+fn remember_recent_template(recent: &mut Vec<Templates>, template: Templates) {
+    recent.retain(|listed| match (listed, &template) {
+        (Templates::Git { url: listed, .. }, Templates::Git { url, .. }) => listed != url,
+        (listed, template) => listed != template,
+    });
+    recent.insert(0, template);
+    recent.truncate(MAX_RECENT_TEMPLATES);
+}
+
+/// The label of a recent template: the name in its manifest, or the repository name of a Git one.
+fn recent_template_label(
+    template: &Templates,
+    templates: &[(Templates, TemplateManifest)],
+) -> String {
+    if let Templates::Git { url, .. } = template {
+        let url = url.trim_end_matches('/');
+        let url = url.strip_suffix(".git").unwrap_or(url);
+        return url.rsplit(['/', ':']).next().unwrap_or(url).to_string();
+    }
+    templates
+        .iter()
+        .find(|(listed, _)| listed == template)
+        .map(|(_, manifest)| manifest.name.clone())
+        .unwrap_or_default()
+}
+
+/// The template to create the project from, with the options of the [`NewProjectWindow`] applied.
+fn selected_template(
+    window: &NewProjectWindow,
+    git_url: &Query<&TextInputValue, With<GitUrlInput>>,
+) -> Templates {
+    match window.template {
+        Templates::Git { .. } => Templates::Git {
+            url: git_url
+                .iter()
+                .next()
+                .map(|input| input.0.trim().to_string())
+                .unwrap_or_default(),
+            fresh_history: window.fresh_history,
+        },
+        ref template => template.clone(),
+    }
+}
+
+/// Open the [`NewProjectWindow`], unless it is already open.
+pub fn open_new_project_window(
+    mut commands: Commands,
+    theme: Res<Theme>,
+    mut settings: ResMut<LauncherSettings>,
+    config: Res<LauncherConfig>,
+    existing: Query<(), With<NewProjectWindow>>,
+) {
+    if !existing.is_empty() {
+        return;
+    }
+
+    // The manifests are loaded once, and the ones that fail reported
+    let mut templates = Vec::new();
+    for template in config.templates.iter().cloned().chain([GIT_TEMPLATE]) {
+        match template.manifest() {
+            Ok(manifest) => templates.push((template, manifest)),
+            Err(error) => push_toast(&mut commands, ToastLevel::Warning, error.to_string()),
+        }
+    }
+    let mut categories: Vec<String> = Vec::new();
+    for (_, manifest) in &templates {
+        if !categories
+            .iter()
+            .any(|category| category == manifest.category())
+        {
+            categories.push(manifest.category().to_string());
+        }
+    }
+    // A remembered category may be gone, like a removed custom template
+    let category = settings
+        .template_category
+        .clone()
+        .filter(|category| categories.contains(category));
+    // And so may the recent templates, the Git ones can't be checked before cloning them
+    let recent_templates: Vec<Templates> = settings
+        .recent_templates
+        .iter()
+        .filter(|recent| {
+            matches!(recent, Templates::Git { .. })
+                || templates.iter().any(|(template, _)| template == *recent)
+        })
+        .cloned()
+        .collect();
+    if recent_templates != settings.recent_templates {
+        settings.recent_templates.clone_from(&recent_templates);
+    }
+
+    // The first template of the remembered category is selected, not a hidden one
+    let template = templates
+        .iter()
+        .find(|(_, manifest)| {
+            category
+                .as_deref()
+                .is_none_or(|category| manifest.category() == category)
+        })
+        .map(|(template, _)| template.clone())
+        // Only the Git templates are left when no folder can be read
+        .unwrap_or_else(|| config.templates.first().cloned().unwrap_or(GIT_TEMPLATE));
+
+    let root = spawn_dialog_root(&mut commands);
+    commands.entity(root).insert(NewProjectWindow {
+        template,
+        open_after_creation: settings.open_after_creation,
+        fresh_history: true,
+        category,
+    });
+
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(460.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(root),
+        ))
+        .id();
+
+    commands.spawn((
+        Text::new("New Project"),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+
+    // Recent templates, selectable whatever the category
+    if !recent_templates.is_empty() {
+        let recent_row = commands
+            .spawn((
+                Node {
+                    display: Display::Flex,
+                    flex_wrap: FlexWrap::Wrap,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                ChildOf(panel),
+            ))
+            .id();
+        commands.spawn((
+            Text::new("Recent"),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 13.0,
+                ..default()
+            },
+            TextColor(theme.text.low_priority),
+            ChildOf(recent_row),
+        ));
+        for template in recent_templates {
+            let selected_template = template.clone();
+            let needs_network = matches!(template, Templates::Git { .. });
+            let mut option_ec = spawn_dialog_button(
+                &mut commands,
+                &theme,
+                recent_template_label(&template, &templates),
+                theme.button.background_color.0,
+            );
+            option_ec.insert((RecentTemplateOption(template), ChildOf(recent_row)));
+            if needs_network {
+                option_ec.insert(NeedsNetwork);
+            }
+            option_ec.observe(
+                move |trigger: On<Pointer<Click>>,
+                      mut windows: Query<&mut NewProjectWindow>,
+                      mut git_url: Query<(&mut TextInputValue, &Children), With<GitUrlInput>>,
+                      mut lines: Query<&mut EditableTextLine>,
+                      mut fresh_history: Query<&mut Checkbox, With<FreshHistoryCheckbox>>,
+                      options: Query<&TemplateOption>,
+                      disabled: Query<(), With<InteractionDisabled>>| {
+                    if disabled.contains(trigger.target()) {
+                        return;
+                    }
+                    let Ok(mut window) = windows.get_mut(root) else {
+                        return;
+                    };
+                    // The options of a Git template live in their inputs, the option stays the
+                    // generic one
+                    if let Templates::Git {
+                        url,
+                        fresh_history: fresh,
+                    } = &selected_template
+                    {
+                        window.template = GIT_TEMPLATE;
+                        window.fresh_history = *fresh;
+                        for mut checkbox in &mut fresh_history {
+                            checkbox.checked = *fresh;
+                        }
+                        for (mut value, children) in &mut git_url {
+                            value.0.clone_from(url);
+                            if let Some(mut line) =
+                                children.first().and_then(|line| lines.get_mut(*line).ok())
+                            {
+                                line.text.clone_from(url);
+                            }
+                        }
+                    } else {
+                        window.template = selected_template.clone();
+                    }
+                    // Show all the templates when the recent one isn't in the selected category
+                    let shown = options.iter().any(|option| {
+                        option.template == window.template
+                            && window
+                                .category
+                                .as_deref()
+                                .is_none_or(|category| option.manifest.category() == category)
+                    });
+                    if !shown {
+                        window.category = None;
+                    }
+                },
+            );
+        }
+    }
+
+    // Category tabs
+    let tabs = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(4.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    for category in [None].into_iter().chain(categories.into_iter().map(Some)) {
+        let selected_category = category.clone();
+        spawn_dialog_button(
+            &mut commands,
+            &theme,
+            category.clone().unwrap_or_else(|| "All".to_string()),
+            theme.button.background_color.0,
+        )
+        .insert((CategoryTab(category), ChildOf(tabs)))
+        .observe(
+            move |_trigger: On<Pointer<Click>>,
+                  mut windows: Query<&mut NewProjectWindow>,
+                  mut settings: ResMut<LauncherSettings>| {
+                if let Ok(mut window) = windows.get_mut(root) {
+                    window.category.clone_from(&selected_category);
+                }
+                settings.template_category.clone_from(&selected_category);
+            },
+        );
+    }
+
+    // Templates
+    let mut list = Accessible::new(Role::ListBox);
+    list.set_label("Templates");
+    let template_options = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                flex_wrap: FlexWrap::Wrap,
+                column_gap: Val::Px(8.0),
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            AccessibilityNode(list),
+            ChildOf(panel),
+        ))
+        .id();
+    for (template, manifest) in templates.iter().cloned() {
+        let clicked_template = template.clone();
+        let activated_template = template.clone();
+        let accessible = template_accessibility(&manifest, false);
+        let needs_network = matches!(template, Templates::Git { .. });
+        let mut option_ec = spawn_dialog_button(
+            &mut commands,
+            &theme,
+            manifest.name.clone(),
+            theme.button.background_color.0,
+        );
+        option_ec.insert((
+            TemplateOption { template, manifest },
+            Focusable(0),
+            AccessibilityNode(accessible),
+            ChildOf(template_options),
+        ));
+        if needs_network {
+            option_ec.insert(NeedsNetwork);
+        }
+        option_ec
+            .observe(
+                move |trigger: On<Pointer<Click>>,
+                      mut windows: Query<&mut NewProjectWindow>,
+                      disabled: Query<(), With<InteractionDisabled>>| {
+                    if disabled.contains(trigger.target()) {
+                        return;
+                    }
+                    if let Ok(mut window) = windows.get_mut(root) {
+                        window.template = clicked_template.clone();
+                    }
+                },
+            )
+            .observe(
+                move |trigger: On<Activate>,
+                      mut windows: Query<&mut NewProjectWindow>,
+                      disabled: Query<(), With<InteractionDisabled>>| {
+                    if disabled.contains(trigger.target()) {
+                        return;
+                    }
+                    if let Ok(mut window) = windows.get_mut(root) {
+                        window.template = activated_template.clone();
+                    }
+                },
+            )
+            .observe(
+                move |mut trigger: On<FocusedInput<KeyboardInput>>, mut commands: Commands| {
+                    let input = &trigger.event().input;
+                    if input.state != ButtonState::Pressed {
+                        return;
+                    }
+                    let step = match input.key_code {
+                        KeyCode::ArrowLeft | KeyCode::ArrowUp => -1,
+                        KeyCode::ArrowRight | KeyCode::ArrowDown => 1,
+                        _ => return,
+                    };
+                    trigger.propagate(false);
+                    commands.run_system_cached_with(
+                        move_template_selection,
+                        (root, trigger.target(), step),
+                    );
+                },
+            );
+    }
+    commands.spawn((
+        TemplateDescription,
+        Text::default(),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 13.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(panel),
+    ));
+    commands.spawn((
+        Text::new("Project name"),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 13.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+    spawn_text_input(&mut commands, &theme, "", "my-bevy-app").insert((
+        ProjectNameInput {
+            suggestion: String::new(),
+        },
+        TextInputValidator::new(|name| {
+            validate_project_name(name.trim()).map_err(|error| error.to_string())
+        }),
+        ChildOf(panel),
+    ));
+    for (template, manifest) in &templates {
+        if matches!(template, Templates::Git { .. }) {
+            continue;
+        }
+        spawn_template_variables(&mut commands, &theme, template.clone(), manifest)
+            .insert(ChildOf(panel));
+    }
+    spawn_git_template_options(&mut commands, &theme, root).insert(ChildOf(panel));
+
+    spawn_checkbox(
+        &mut commands,
+        &theme,
+        "Open after creation",
+        settings.open_after_creation,
+    )
+    .insert(ChildOf(panel))
+    .observe(
+        move |trigger: On<CheckboxChanged>,
+              mut windows: Query<&mut NewProjectWindow>,
+              mut settings: ResMut<LauncherSettings>| {
+            let open_after_creation = trigger.event().0;
+            if let Ok(mut window) = windows.get_mut(root) {
+                window.open_after_creation = open_after_creation;
+            }
+            // Remember the choice as the default for the next projects
+            settings.open_after_creation = open_after_creation;
+        },
+    );
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Cancel",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            close_dialog(&mut commands, root, false);
+        },
+    );
+    spawn_dialog_button(&mut commands, &theme, "Create", theme.button.hover_color)
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                commands.run_system_cached_with(create_project_from_window, root);
+            },
+        );
+}
+
+/// Check the project configured in the [`NewProjectWindow`] can be created, then create it.
+///
+/// The user is asked to confirm when a Git template is cloned while offline, then when the
+/// installed toolchain doesn't satisfy the template requirements.
+fn create_project_from_window(
+    In(root): In<Entity>,
+    mut commands: Commands,
+    windows: Query<&NewProjectWindow>,
+    inputs: Query<(&VariableInput, &EditableTextLine)>,
+    git_url: Query<&TextInputValue, With<GitUrlInput>>,
+    name_input: Query<&TextInputValue, With<ProjectNameInput>>,
+    connectivity: Res<Connectivity>,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+) {
+    let Ok(window) = windows.get(root) else {
+        return;
+    };
+
+    let name = project_name(&name_input);
+    if name.is_empty() {
+        push_toast(&mut commands, ToastLevel::Warning, "Enter a project name");
+        return;
+    }
+    if let Err(error) = validate_project_name(&name) {
+        push_toast(&mut commands, ToastLevel::Warning, error.to_string());
+        return;
+    }
+
+    let template = selected_template(window, &git_url);
+    if let Templates::Git { url, .. } = &template
+        && url.is_empty()
+    {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            "Enter the URL of the Git repository to clone",
+        );
+        return;
+    }
+    if let Templates::Git { url, .. } = &template
+        && let Err(reason) = validate_git_url(url)
+    {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!("Invalid repository URL: {reason}"),
+        );
+        return;
+    }
+
+    // Make sure every required variable has a value before asking for the folder
+    let manifest = match template.manifest() {
+        Ok(manifest) => manifest,
+        Err(error) => {
+            push_toast(&mut commands, ToastLevel::Error, error.to_string());
+            return;
+        }
+    };
+    let variables = template_variables(window, &inputs);
+    if let Err(reason) = manifest.resolve_variables(Path::new(""), &variables) {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!("Missing template value: {reason}"),
+        );
+        return;
+    }
+
+    let Templates::Git { url, .. } = &template else {
+        commands.run_system_cached_with(check_toolchain_and_create, root);
+        return;
+    };
+    if !connectivity.is_offline() {
+        commands.run_system_cached_with(check_toolchain_and_create, root);
+        return;
+    }
+    let dialog = ConfirmationDialog::new(
+        "No network connection",
+        format!("Cloning '{url}' needs a network connection, which seems to be unavailable."),
+    );
+    offer_dont_ask_again(dialog, Confirmation::CreateOffline, &settings)
+        .confirm_label("Create anyway")
+        .spawn(&mut commands, &theme)
+        .observe(move |trigger: On<DialogResult>, mut commands: Commands| {
+            if trigger.event().0 {
+                commands.run_system_cached_with(check_toolchain_and_create, root);
+            }
+        });
+}
+
+/// Create the project configured in the [`NewProjectWindow`], asking to confirm first when the
+/// installed toolchain doesn't satisfy the template requirements.
+fn check_toolchain_and_create(
+    In(root): In<Entity>,
+    mut commands: Commands,
+    windows: Query<&NewProjectWindow>,
+    git_url: Query<&TextInputValue, With<GitUrlInput>>,
+    toolchain: Res<InstalledToolchain>,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+) {
+    let Ok(window) = windows.get(root) else {
+        return;
+    };
+    // Already loaded without errors by `create_project_from_window`
+    let Ok(manifest) = selected_template(window, &git_url).manifest() else {
+        return;
+    };
+    if toolchain.task.is_some() {
+        push_toast(
+            &mut commands,
+            ToastLevel::Info,
+            "Still looking for the Rust toolchain, try again in a moment",
+        );
+        return;
+    }
+    let unmet = manifest.toolchain.unmet(toolchain.toolchain.as_ref());
+    if unmet.is_empty() {
+        commands.run_system_cached_with(pick_location_and_create, root);
+        return;
+    }
+    let dialog = ConfirmationDialog::new(
+        "Toolchain requirements not met",
+        format!(
+            "The '{}' template needs:\n- {}\nThe project may not build.",
+            manifest.name,
+            unmet.join("\n- ")
+        ),
+    );
+    offer_dont_ask_again(dialog, Confirmation::CreateWithoutToolchain, &settings)
+        .confirm_label("Create anyway")
+        .spawn(&mut commands, &theme)
+        .observe(move |trigger: On<DialogResult>, mut commands: Commands| {
+            if trigger.event().0 {
+                commands.run_system_cached_with(pick_location_and_create, root);
+            }
+        });
+}
+
+/// Ask the user for a location in the [`FolderBrowser`](crate::folder_browser), starting in the
+/// project root, and create the project configured in the [`NewProjectWindow`] in it.
+fn pick_location_and_create(
+    In(root): In<Entity>,
+    mut commands: Commands,
+    config: Res<LauncherConfig>,
+    theme: Res<Theme>,
+) {
+    let start = config
+        .project_root
+        .clone()
+        .or_else(|| std::env::current_dir().ok())
+        .unwrap_or_default();
+    spawn_folder_browser(&mut commands, &theme, "Choose the location", &start).observe(
+        move |trigger: On<FolderPicked>, mut commands: Commands| {
+            commands.run_system_cached_with(create_in_location, (root, trigger.event().0.clone()));
+        },
+    );
+}
+
+/// Review the creation of the project configured in the [`NewProjectWindow`] in the location, in
+/// a folder named after the project.
+fn create_in_location(
+    In((root, location)): In<(Entity, PathBuf)>,
+    mut commands: Commands,
+    name_input: Query<&TextInputValue, With<ProjectNameInput>>,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+) {
+    let path = location.join(project_name(&name_input));
+    if path.exists() {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!("'{}' already exists", path.display()),
+        );
+        return;
+    }
+
+    let Some(enclosing) = enclosing_project(&path) else {
+        commands.run_system_cached_with(review_creation, (root, path));
+        return;
+    };
+    let dialog = ConfirmationDialog::new(
+        "Inside another project",
+        format!(
+            "'{}' has a Cargo.toml, the new project would be nested in it.\n\
+             Cargo will treat it as part of that package or workspace.",
+            enclosing.display()
+        ),
+    );
+    offer_dont_ask_again(dialog, Confirmation::CreateInsideProject, &settings)
+        .confirm_label("Create anyway")
+        .spawn(&mut commands, &theme)
+        .observe(move |trigger: On<DialogResult>, mut commands: Commands| {
+            if trigger.event().0 {
+                commands.run_system_cached_with(review_creation, (root, path.clone()));
+            }
+        });
+}
+
+/// Show what creating the project configured in the [`NewProjectWindow`] in the given folder will
+/// do, see [`show_creation_preview`].
+fn review_creation(
+    In((root, path)): In<(Entity, PathBuf)>,
+    mut commands: Commands,
+    windows: Query<&NewProjectWindow>,
+    inputs: Query<(&VariableInput, &EditableTextLine)>,
+    git_url: Query<&TextInputValue, With<GitUrlInput>>,
+) {
+    let Ok(window) = windows.get(root) else {
+        return;
+    };
+    commands.run_system_cached_with(
+        show_creation_preview,
+        (
+            root,
+            path,
+            selected_template(window, &git_url),
+            template_variables(window, &inputs),
+        ),
+    );
+}
+
+/// Create the project configured in the [`NewProjectWindow`] in the given folder, closing the window.
+///
+/// The post-create command of the template only runs when the user allowed it.
+#[expect(clippy::too_many_arguments)]
+pub(crate) fn create_project_at(
+    In((root, path, run_post_create)): In<(Entity, PathBuf, bool)>,
+    mut commands: Commands,
+    windows: Query<&NewProjectWindow>,
+    inputs: Query<(&VariableInput, &EditableTextLine)>,
+    git_url: Query<&TextInputValue, With<GitUrlInput>>,
+    mut settings: ResMut<LauncherSettings>,
+    channel: Res<TaskChannel<CreationResult>>,
+    slots: Res<TaskSlots>,
+    store: Res<ProjectListStore>,
+) {
+    let Ok(window) = windows.get(root) else {
+        return;
+    };
+    let template = selected_template(window, &git_url);
+    remember_recent_template(&mut settings.recent_templates, template.clone());
+    let logs = ProjectCreationLogs::new(
+        settings.creation_log_lines,
+        settings
+            .creation_log_file
+            .then(|| creation_log_path(&path))
+            .as_deref(),
+    );
+    crate::spawn_create_new_project_task(
+        &mut commands,
+        settings.task_completion,
+        &channel,
+        &slots,
+        template,
+        path,
+        template_variables(window, &inputs),
+        run_post_create,
+        RetryPolicy::with_retries(settings.network_retries),
+        window.open_after_creation,
+        logs,
+        store.0.clone(),
+    );
+    close_dialog(&mut commands, root, true);
+}
+
+/// The trimmed name entered in the [`ProjectNameInput`].
+fn project_name(name_input: &Query<&TextInputValue, With<ProjectNameInput>>) -> String {
+    name_input
+        .iter()
+        .next()
+        .map(|input| input.0.trim().to_string())
+        .unwrap_or_default()
+}
+
+/// Append a number to `base` until it isn't `taken`, like `my-game-2`.
+fn unique_project_name(base: &str, taken: impl Fn(&str) -> bool) -> String {
+    if !taken(base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|number| format!("{base}-{number}"))
+        .find(|name| !taken(name))
+        .unwrap_or_default()
+}
+
+/// Suggest a project name for the selected template, fully selected so it can be typed over.
+///
+/// The name is only replaced if the user didn't edit the previous suggestion.
+fn suggest_project_name(
+    mut commands: Commands,
+    windows: Query<&NewProjectWindow, Changed<NewProjectWindow>>,
+    options: Query<&TemplateOption>,
+    mut name_input: Query<(&mut ProjectNameInput, &mut TextInputValue, &Children)>,
+    mut lines: Query<&mut EditableTextLine>,
+    mut focus: ResMut<InputFocus>,
+    project_list: Res<ProjectInfoList>,
+    config: Res<LauncherConfig>,
+) {
+    let Some(window) = windows.iter().next() else {
+        return;
+    };
+    let Some(option) = options
+        .iter()
+        .find(|option| option.template == window.template)
+    else {
+        return;
+    };
+    let Ok((mut input, mut value, children)) = name_input.single_mut() else {
+        return;
+    };
+    // The text is edited by the first child of the input
+    let Some(entity) = children.first().copied() else {
+        return;
+    };
+    let Ok(mut line) = lines.get_mut(entity) else {
+        return;
+    };
+    if !value.0.is_empty() && value.0 != input.suggestion {
+        return;
+    }
+
+    let name = unique_project_name(&option.manifest.suggested_project_name(), |name| {
+        project_list
+            .0
+            .iter()
+            .any(|project| project.name().as_deref() == Some(name))
+            || config
+                .project_root
+                .as_ref()
+                .is_some_and(|root| root.join(name).exists())
+    });
+    if value.0 == name {
+        return;
+    }
+    line.selection_start = Some(CharPosition(0));
+    line.cursor_position = Some(CharPosition(name.chars().count()));
+    line.text.clone_from(&name);
+    value.0.clone_from(&name);
+    input.suggestion = name;
+    focus.set(entity);
+    commands.trigger_targets(RenderWidget::show_cursor(), entity);
+}
+
+/// The values entered for the variables of the selected template.
+fn template_variables(
+    window: &NewProjectWindow,
+    inputs: &Query<(&VariableInput, &EditableTextLine)>,
+) -> HashMap<String, String> {
+    inputs
+        .iter()
+        .filter(|(input, _)| input.template == window.template)
+        .map(|(input, text)| (input.name.clone(), text.text.clone()))
+        .collect()
+}
+
+/// Query the installed toolchain once in the background, so templates can be checked against it.
+fn query_installed_toolchain(mut installed: ResMut<InstalledToolchain>) {
+    let task = IoTaskPool::get().spawn(async {
+        match ToolchainInfo::query() {
+            Ok(toolchain) => {
+                info!("Found Rust {} ({:?})", toolchain.version, toolchain.channel);
+                Some(toolchain)
+            }
+            Err(error) => {
+                warn!("Couldn't query the Rust toolchain: {:?}", error);
+                None
+            }
+        }
+    });
+    installed.task = Some(task);
+}
+
+/// Keep the toolchain found by [`query_installed_toolchain`] once it is done.
+fn poll_installed_toolchain(mut installed: ResMut<InstalledToolchain>) {
+    let Some(task) = &mut installed.task else {
+        return;
+    };
+    if let Some(toolchain) = block_on(future::poll_once(task)) {
+        installed.task = None;
+        installed.toolchain = toolchain;
+    }
+}
+
+/// Only show the templates of the selected category, highlighting its tab.
+///
+/// When the selected template is filtered out, the first visible one is selected instead, so the
+/// project is never created from a hidden template.
+fn filter_templates_by_category(
+    mut windows: Query<&mut NewProjectWindow, Changed<NewProjectWindow>>,
+    mut options: Query<(&TemplateOption, &mut Node)>,
+    mut tabs: Query<(&CategoryTab, &mut BackgroundColor)>,
+    theme: Res<Theme>,
+) {
+    let Some(mut window) = windows.iter_mut().next() else {
+        return;
+    };
+    let category = window.category.clone();
+    let shown = |option: &TemplateOption| {
+        category
+            .as_deref()
+            .is_none_or(|category| option.manifest.category() == category)
+    };
+    for (option, mut node) in &mut options {
+        node.display = if shown(option) {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    let selected_shown = options
+        .iter()
+        .any(|(option, _)| option.template == window.template && shown(option));
+    if !selected_shown && let Some((first, _)) = options.iter().find(|(option, _)| shown(option)) {
+        window.template = first.template.clone();
+    }
+    for (tab, mut background) in &mut tabs {
+        background.0 = if tab.0 == window.category {
+            theme.button.hover_color
+        } else {
+            theme.button.background_color.0
+        };
+    }
+}
+
+/// The text describing the template below the options: its description, category, toolchain
+/// requirements and the values to fill in.
+fn template_details(manifest: &TemplateManifest) -> String {
+    let mut details = Vec::new();
+    if !manifest.description.is_empty() {
+        details.push(manifest.description.clone());
+    }
+    details.push(format!("Category: {}", manifest.category()));
+
+    let toolchain = &manifest.toolchain;
+    let mut requirements = Vec::new();
+    if let Some(version) = &toolchain.min_rust_version {
+        requirements.push(format!("Rust {version} or newer"));
+    }
+    if let Some(edition) = &toolchain.edition {
+        requirements.push(format!("the {edition} edition"));
+    }
+    if toolchain.nightly {
+        requirements.push("a nightly toolchain".to_string());
+    }
+    if !requirements.is_empty() {
+        details.push(format!("Requires {}", requirements.join(", ")));
+    }
+
+    let required = manifest
+        .variables
+        .iter()
+        .filter(|variable| variable.default.is_none())
+        .count();
+    match (manifest.variables.len(), required) {
+        (0, _) => {}
+        (count, 0) => details.push(format!("{count} optional value(s)")),
+        (count, required) => details.push(format!("{count} value(s), {required} required")),
+    }
+    details.join("\n")
+}
+
+/// The screen reader node of a [`TemplateOption`], labeled with the name of its template.
+fn template_accessibility(manifest: &TemplateManifest, selected: bool) -> Accessible {
+    let mut accessible = Accessible::new(Role::ListBoxOption);
+    accessible.set_label(manifest.name.as_str());
+    accessible.set_description(template_details(manifest));
+    accessible.set_selected(selected);
+    accessible
+}
+
+/// The index `step` options away from `index` among `count` of them, wrapping around the ends.
+fn step_index(index: usize, count: usize, step: isize) -> usize {
+    (index as isize + step).rem_euclid(count as isize) as usize
+}
+
+/// Select and focus the visible [`TemplateOption`] `step` options away from the `from` one.
+fn move_template_selection(
+    In((root, from, step)): In<(Entity, Entity, isize)>,
+    mut windows: Query<&mut NewProjectWindow>,
+    options: Query<(&TemplateOption, &Node, Has<InteractionDisabled>)>,
+    parents: Query<&ChildOf>,
+    children: Query<&Children>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+) {
+    let Ok(mut window) = windows.get_mut(root) else {
+        return;
+    };
+    let Ok(siblings) = parents
+        .get(from)
+        .and_then(|parent| children.get(parent.parent()))
+    else {
+        return;
+    };
+    // The options hidden by the category filter or disabled are skipped, unless focused
+    let visible: Vec<Entity> = siblings
+        .iter()
+        .filter(|option| {
+            options.get(*option).is_ok_and(|(_, node, disabled)| {
+                node.display != Display::None && (!disabled || *option == from)
+            })
+        })
+        .collect();
+    let Some(index) = visible.iter().position(|option| *option == from) else {
+        return;
+    };
+    let target = visible[step_index(index, visible.len(), step)];
+    if let Ok((option, _, false)) = options.get(target) {
+        window.template = option.template.clone();
+    }
+    focus.0 = Some(target);
+    focus_visible.0 = true;
+}
+
+/// Disable the options [`NeedsNetwork`] while offline, dimming their label and telling why in a
+/// tooltip, and enable them again once back online.
+fn disable_network_templates(
+    mut commands: Commands,
+    connectivity: Res<Connectivity>,
+    options: Query<(Entity, &Children, Has<InteractionDisabled>), With<NeedsNetwork>>,
+    added: Query<(), Added<NeedsNetwork>>,
+    mut labels: Query<&mut TextColor>,
+    theme: Res<Theme>,
+) {
+    if !connectivity.is_changed() && added.is_empty() {
+        return;
+    }
+    let offline = connectivity.is_offline();
+    for (option, children, disabled) in &options {
+        if offline == disabled {
+            continue;
+        }
+        if offline {
+            commands
+                .entity(option)
+                .insert((InteractionDisabled, Tooltip::new(OFFLINE_TOOLTIP)));
+        } else {
+            commands
+                .entity(option)
+                .remove::<(InteractionDisabled, Tooltip)>();
+        }
+        let color = if offline {
+            theme.text.low_priority
+        } else {
+            theme.text.text_color
+        };
+        for child in children.iter() {
+            if let Ok(mut label) = labels.get_mut(child) {
+                label.0 = color;
+            }
+        }
+    }
+}
+
+/// Highlight the selected template and show its description and variables.
+///
+/// The selection is also reflected on the [`AccessibilityNode`] of the options.
+#[expect(clippy::too_many_arguments)]
+fn update_template_options(
+    windows: Query<Ref<NewProjectWindow>>,
+    git_url: Query<&TextInputValue, With<GitUrlInput>>,
+    edited_git_url: Query<(), (With<GitUrlInput>, Changed<TextInputValue>)>,
+    mut options: Query<(
+        &TemplateOption,
+        &mut BackgroundColor,
+        &mut AccessibilityNode,
+    )>,
+    mut recent_options: Query<
+        (&RecentTemplateOption, &mut BackgroundColor),
+        Without<TemplateOption>,
+    >,
+    mut descriptions: Query<&mut Text, With<TemplateDescription>>,
+    mut variables: Query<(&TemplateVariables, &mut Node)>,
+    mut git_options: Query<&mut Node, (With<GitTemplateOptions>, Without<TemplateVariables>)>,
+    theme: Res<Theme>,
+) {
+    let Some(window) = windows.iter().next() else {
+        return;
+    };
+    // The recent Git templates are selected by the URL typed
+    if !window.is_changed() && edited_git_url.is_empty() {
+        return;
+    }
+    let template = selected_template(&window, &git_url);
+    for mut node in &mut git_options {
+        node.display = if matches!(window.template, Templates::Git { .. }) {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    for (variables, mut node) in &mut variables {
+        node.display = if variables.0 == window.template {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    for (option, mut background, mut accessible) in &mut options {
+        let selected = option.template == window.template;
+        if selected {
+            background.0 = theme.button.hover_color;
+            for mut description in &mut descriptions {
+                description.0 = template_details(&option.manifest);
+            }
+        } else {
+            background.0 = theme.button.background_color.0;
+        }
+        accessible.set_selected(selected);
+    }
+    for (option, mut background) in &mut recent_options {
+        background.0 = if option.0 == template {
+            theme.button.hover_color
+        } else {
+            theme.button.background_color.0
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_editor::project::templates::TemplateVariable;
+
+    use super::*;
+
+    #[test]
+    fn deduplicate_project_names() {
+        let taken = ["my-game", "my-game-2"];
+        assert_eq!(
+            unique_project_name("my-game", |name| taken.contains(&name)),
+            "my-game-3"
+        );
+        assert_eq!(
+            unique_project_name("my-app", |name| taken.contains(&name)),
+            "my-app"
+        );
+    }
+
+    #[test]
+    fn keep_the_last_used_templates() {
+        let git = |url: &str, fresh_history| Templates::Git {
+            url: url.to_string(),
+            fresh_history,
+        };
+        let mut recent = Vec::new();
+        remember_recent_template(&mut recent, Templates::Blank);
+        remember_recent_template(&mut recent, git("https://example.com/starter.git", true));
+        remember_recent_template(&mut recent, Templates::GettingStarted);
+        remember_recent_template(&mut recent, Templates::Blank);
+        assert_eq!(
+            recent,
+            [
+                Templates::Blank,
+                Templates::GettingStarted,
+                git("https://example.com/starter.git", true)
+            ]
+        );
+
+        remember_recent_template(
+            &mut recent,
+            Templates::Custom(PathBuf::from("/templates/2d")),
+        );
+        remember_recent_template(&mut recent, git("https://example.com/starter.git", false));
+        assert_eq!(
+            recent,
+            [
+                git("https://example.com/starter.git", false),
+                Templates::Custom(PathBuf::from("/templates/2d")),
+                Templates::Blank,
+            ]
+        );
+        assert_eq!(recent_template_label(&recent[0], &[]), "starter");
+    }
+    #[test]
+    fn describe_the_templates_and_move_between_them() {
+        let mut manifest = TemplateManifest {
+            name: "2D Platformer".to_string(),
+            description: "A character jumping on platforms".to_string(),
+            category: Some("2D".to_string()),
+            ..default()
+        };
+        assert_eq!(
+            template_details(&manifest),
+            "A character jumping on platforms\nCategory: 2D"
+        );
+        manifest.toolchain.edition = Some("2024".to_string());
+        manifest.toolchain.nightly = true;
+        manifest.variables = vec![
+            TemplateVariable {
+                name: "author".to_string(),
+                ..default()
+            },
+            TemplateVariable {
+                name: "license".to_string(),
+                default: Some("MIT".to_string()),
+                ..default()
+            },
+        ];
+        assert_eq!(
+            template_details(&manifest),
+            "A character jumping on platforms\nCategory: 2D\n\
+             Requires the 2024 edition, a nightly toolchain\n2 value(s), 1 required"
+        );
+
+        assert_eq!(step_index(0, 3, 1), 1);
+        assert_eq!(step_index(2, 3, 1), 0, "wraps past the last option");
+        assert_eq!(step_index(0, 3, -1), 2, "wraps before the first option");
+    }
+}
@@ -0,0 +1,404 @@
+//! Opening projects from the launcher.
+//!
+//! The projects opened in the editor are acknowledged with an [`OpenProjectAck`] once they have
+//! started, failed, or after the [`OPEN_ACK_TIMEOUT`] without news, see [`OpenProjectEvent`].
+
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+    process::Child,
+    time::{Duration, Instant, SystemTime},
+};
+
+use bevy::prelude::*;
+use bevy_editor::project::{
+    ProjectError, ProjectInfo, open_ack::open_ack_path, run_project_at,
+    workspace::workspace_members,
+};
+use bevy_footer_bar::FooterBarStatus;
+
+use crate::{
+    ProjectInfoList, ProjectListStore,
+    badges::{FREQUENTLY_OPENED, refresh_project_badges},
+    bug_report::ProjectErrors,
+    open_with::open_with_tool,
+    session::ProjectSession,
+    settings::{LauncherSettings, OpenBehavior, OpenWith},
+    toast::{ToastLevel, push_toast},
+    ui::ProjectNode,
+    workspace::{binary_members, show_member_picker},
+};
+
+/// Plugin handling the [`OpenProjectEvent`]s.
+pub struct OpenProjectPlugin;
+
+impl Plugin for OpenProjectPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OpenProjectEvent>()
+            .add_event::<OpenProjectAck>()
+            .add_systems(Update, (open_projects, watch_pending_openings).chain());
+    }
+}
+
+/// How long an opening in the editor waits for its acknowledgment before giving up on it, the
+/// building of the project included.
+const OPEN_ACK_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// Request to open the project at the given path.
+///
+/// The project must be part of the [`ProjectInfoList`].
+///
+/// Once the project is run in the editor, exactly one [`OpenProjectAck`] follows it: the editor
+/// acknowledges the opening once started (see [`bevy_editor::project::open_ack`]), cargo exiting
+/// with a failure before that means the project couldn't build or start, and without news after
+/// the [`OPEN_ACK_TIMEOUT`] the opening is left unconfirmed. The "Opening" status is kept until
+/// then, and with [`OpenBehavior::Replace`] the launcher only closes once the opening isn't
+/// failed. The projects that can't be run at all, like the missing ones, and the ones opened with
+/// an external tool are reported right away instead.
+#[derive(Event, BufferedEvent, Debug, Clone)]
+pub struct OpenProjectEvent {
+    /// The root folder of the project to open.
+    pub path: PathBuf,
+    /// Overrides the [`OpenBehavior`] of the [`LauncherSettings`] when set.
+    pub behavior: Option<OpenBehavior>,
+    /// Overrides the [`OpenWith`] tool of the [`LauncherSettings`] when set.
+    pub with: Option<OpenWith>,
+    /// The file to open in the project, relative to its root, like `src/main.rs`.
+    ///
+    /// The Bevy editor receives it through
+    /// [`OPEN_TARGET_VAR`](bevy_editor::project::OPEN_TARGET_VAR), see
+    /// [`open_target`](bevy_editor::project::open_target) for how it is interpreted.
+    /// The external tools are given the file along with the project folder, when they support it.
+    pub target: Option<PathBuf>,
+}
+
+/// The outcome of opening a project in the editor, reported by an [`OpenProjectAck`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpenOutcome {
+    /// The editor acknowledged the opening, or the project ran and exited without the editor.
+    Launched,
+    /// Cargo exited with a failure before the acknowledgment, with why.
+    Failed(String),
+    /// Nothing was heard of the project before the [`OPEN_ACK_TIMEOUT`], it may still be building.
+    Unconfirmed,
+}
+
+/// Sent once a project run for an [`OpenProjectEvent`] is acknowledged, see its protocol.
+#[derive(Event, BufferedEvent, Debug, Clone, PartialEq, Eq)]
+pub struct OpenProjectAck {
+    /// The root folder of the opened project.
+    pub path: PathBuf,
+    /// Whether the opening succeeded.
+    pub outcome: OpenOutcome,
+}
+
+/// A project run in the editor, waiting for its [`OpenProjectAck`].
+#[derive(Component)]
+struct PendingOpening {
+    path: PathBuf,
+    name: String,
+    /// The cargo process running the project.
+    child: Child,
+    /// The file the editor writes to acknowledge the opening.
+    ack: PathBuf,
+    started: Instant,
+    behavior: OpenBehavior,
+}
+
+/// The outcome of the opening, `None` while it is still pending.
+///
+/// `exited` is the result of the cargo process once it exited, the ones exiting successfully before
+/// the acknowledgment are projects without the editor that ran and closed.
+fn opening_outcome(
+    acknowledged: bool,
+    exited: Option<Result<(), String>>,
+    waited: Duration,
+) -> Option<OpenOutcome> {
+    if acknowledged {
+        return Some(OpenOutcome::Launched);
+    }
+    match exited {
+        Some(Ok(())) => Some(OpenOutcome::Launched),
+        Some(Err(reason)) => Some(OpenOutcome::Failed(reason)),
+        None if waited >= OPEN_ACK_TIMEOUT => Some(OpenOutcome::Unconfirmed),
+        None => None,
+    }
+}
+
+/// The environment variables applied when running a project, like `RUST_LOG=debug, ASSETS=assets`.
+fn env_summary(env: &BTreeMap<String, String>) -> String {
+    env.iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Record that the project at the path was opened, returning its new open count.
+///
+/// The list isn't saved right away but by the auto-save, so opening projects often doesn't write
+/// it every time, and a crash loses the openings of the last interval at most.
+fn record_opening(projects: &mut [ProjectInfo], path: &Path) -> Option<u32> {
+    let opened = projects.iter_mut().find(|project| project.path == path)?;
+    opened.last_opened = SystemTime::now();
+    opened.open_count = opened.open_count.saturating_add(1);
+    Some(opened.open_count)
+}
+
+/// Open the requested projects, removing the ones that can't be found from the list.
+///
+/// The projects opened with an external tool never close the launcher.
+fn open_projects(
+    mut events: EventReader<OpenProjectEvent>,
+    mut commands: Commands,
+    query_nodes: Query<(Entity, &ProjectNode)>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    settings: Res<LauncherSettings>,
+    mut footer_status: Query<&mut Text, With<FooterBarStatus>>,
+    mut session: ResMut<ProjectSession>,
+    mut errors: ResMut<ProjectErrors>,
+) {
+    for event in events.read() {
+        let Some(project) = project_list
+            .0
+            .iter()
+            .find(|p| p.path == event.path)
+            .cloned()
+        else {
+            continue;
+        };
+        let project_name = project.name().unwrap_or_else(|| "Unknown".to_string());
+
+        let tool = event.with.unwrap_or(settings.open_with);
+        let opened = if tool != OpenWith::Editor {
+            match open_with_tool(
+                &project.path,
+                tool,
+                settings.open_with_command.as_deref(),
+                event.target.as_deref(),
+            ) {
+                Ok(()) => {
+                    push_toast(
+                        &mut commands,
+                        ToastLevel::Success,
+                        format!("Opened '{project_name}' with the {}", tool.label()),
+                    );
+                    true
+                }
+                Err(error) => {
+                    let message = match error.kind() {
+                        io::ErrorKind::NotFound => {
+                            format!("The {} wasn't found, is it installed?", tool.label())
+                        }
+                        io::ErrorKind::InvalidInput => {
+                            "Set the custom command in the launcher settings first".to_string()
+                        }
+                        _ => format!("Failed to open '{project_name}': {error}"),
+                    };
+                    push_toast(&mut commands, ToastLevel::Warning, message);
+                    false
+                }
+            }
+        } else {
+            // Cargo can't tell which binary of a workspace to run, unless there is only one
+            if project.default_member.is_none()
+                && let Some(members) =
+                    workspace_members(&project.path).map(|members| binary_members(&members))
+                && members.len() > 1
+            {
+                commands.run_system_cached_with(show_member_picker, (event.clone(), members));
+                continue;
+            }
+
+            if !project.env.is_empty() {
+                info!(
+                    "Running '{project_name}' with {}",
+                    env_summary(&project.env)
+                );
+            }
+            match run_project_at(&project, event.target.as_deref()) {
+                Ok(child) => {
+                    session.opened(project.path.clone());
+                    for mut status in &mut footer_status {
+                        status.0 = format!("Opening '{project_name}'...");
+                    }
+                    commands.spawn(PendingOpening {
+                        ack: open_ack_path(&project.path),
+                        path: project.path.clone(),
+                        name: project_name,
+                        child,
+                        started: Instant::now(),
+                        behavior: event.behavior.unwrap_or(settings.open_behavior),
+                    });
+                    true
+                }
+                Err(error) => {
+                    error!("Failed to run project: {:?}", error);
+                    let remove_project = match &error {
+                        ProjectError::NotFound(_) => {
+                            push_toast(
+                                &mut commands,
+                                ToastLevel::Warning,
+                                format!("Project not found: '{project_name}'"),
+                            );
+                            true
+                        }
+                        ProjectError::NotAProject { .. } => {
+                            push_toast(
+                                &mut commands,
+                                ToastLevel::Error,
+                                format!("Failed to run project: '{project_name}'"),
+                            );
+                            true
+                        }
+                        _ => {
+                            errors.0.insert(project.path.clone(), error.to_string());
+                            push_toast(
+                                &mut commands,
+                                ToastLevel::Error,
+                                format!(
+                                    "Error running project: '{error}', its menu can create a bug report"
+                                ),
+                            );
+                            false
+                        }
+                    };
+                    if remove_project {
+                        // Remove project from list
+                        project_list.0.retain(|p| p.path != project.path);
+                        store.set(project_list.0.clone());
+                        // Remove project node from UI
+                        for (entity, node) in &query_nodes {
+                            if node.0 == project.path {
+                                commands.entity(entity).despawn();
+                            }
+                        }
+                    }
+                    false
+                }
+            }
+        };
+
+        // The badge of the frequently opened projects shows once they reach the threshold
+        if opened && record_opening(&mut project_list.0, &project.path) == Some(FREQUENTLY_OPENED) {
+            for (entity, _) in query_nodes
+                .iter()
+                .filter(|(_, node)| node.0 == project.path)
+            {
+                commands.run_system_cached_with(refresh_project_badges, entity);
+            }
+        }
+    }
+}
+
+/// Send the [`OpenProjectAck`] of the [`PendingOpening`]s that are over, clearing their status and
+/// reporting their failures.
+fn watch_pending_openings(
+    mut commands: Commands,
+    mut openings: Query<(Entity, &mut PendingOpening)>,
+    mut acks: EventWriter<OpenProjectAck>,
+    mut exit: EventWriter<AppExit>,
+    mut footer_status: Query<&mut Text, With<FooterBarStatus>>,
+    mut errors: ResMut<ProjectErrors>,
+) {
+    for (entity, mut opening) in &mut openings {
+        let exited = match opening.child.try_wait() {
+            Ok(None) => None,
+            Ok(Some(status)) if status.success() => Some(Ok(())),
+            Ok(Some(status)) => Some(Err(format!(
+                "cargo exited with {status}, the project may not build"
+            ))),
+            Err(error) => Some(Err(format!("couldn't watch cargo: {error}"))),
+        };
+        let Some(outcome) =
+            opening_outcome(opening.ack.exists(), exited, opening.started.elapsed())
+        else {
+            continue;
+        };
+        commands.entity(entity).despawn();
+        for mut status in &mut footer_status {
+            status.0.clear();
+        }
+
+        let name = &opening.name;
+        match &outcome {
+            OpenOutcome::Launched | OpenOutcome::Unconfirmed
+                if opening.behavior == OpenBehavior::Replace =>
+            {
+                exit.write(AppExit::Success);
+            }
+            OpenOutcome::Launched => push_toast(
+                &mut commands,
+                ToastLevel::Success,
+                format!("Opened '{name}'"),
+            ),
+            OpenOutcome::Unconfirmed => push_toast(
+                &mut commands,
+                ToastLevel::Info,
+                format!("'{name}' didn't confirm it started, it may still be building"),
+            ),
+            OpenOutcome::Failed(reason) => {
+                errors.0.insert(opening.path.clone(), reason.clone());
+                push_toast(
+                    &mut commands,
+                    ToastLevel::Error,
+                    format!("Couldn't open '{name}': {reason}, its menu can create a bug report"),
+                );
+            }
+        }
+        acks.write(OpenProjectAck {
+            path: opening.path.clone(),
+            outcome,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_the_openings() {
+        let mut projects = vec![ProjectInfo {
+            open_count: 2,
+            ..ProjectInfo::new("/projects/game")
+        }];
+        assert_eq!(
+            record_opening(&mut projects, Path::new("/projects/game")),
+            Some(3)
+        );
+        assert!(projects[0].last_opened > SystemTime::UNIX_EPOCH);
+        assert_eq!(
+            record_opening(&mut projects, Path::new("/projects/tool")),
+            None
+        );
+    }
+
+    #[test]
+    fn acknowledge_the_openings() {
+        let second = Duration::from_secs(1);
+        assert_eq!(opening_outcome(false, None, second), None);
+        assert_eq!(
+            opening_outcome(true, None, second),
+            Some(OpenOutcome::Launched)
+        );
+        assert_eq!(
+            opening_outcome(false, Some(Ok(())), second),
+            Some(OpenOutcome::Launched)
+        );
+        assert_eq!(
+            opening_outcome(false, Some(Err("exit status: 101".to_string())), second),
+            Some(OpenOutcome::Failed("exit status: 101".to_string()))
+        );
+        assert_eq!(
+            opening_outcome(false, None, OPEN_ACK_TIMEOUT),
+            Some(OpenOutcome::Unconfirmed)
+        );
+        assert_eq!(
+            opening_outcome(true, Some(Err("crashed".to_string())), OPEN_ACK_TIMEOUT),
+            Some(OpenOutcome::Launched),
+            "the failures after the acknowledgment aren't the opening's"
+        );
+    }
+}
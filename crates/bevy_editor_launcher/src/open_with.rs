@@ -0,0 +1,139 @@
+//! Opening a project folder with another tool than the Bevy editor.
+
+use std::{io, path::Path, process::Command};
+
+use bevy::prelude::*;
+
+use crate::{settings::OpenWith, terminal::expand_command};
+
+impl OpenWith {
+    /// The name of the tool, for the menus and messages.
+    pub fn label(self) -> &'static str {
+        match self {
+            OpenWith::Editor => "Bevy editor",
+            OpenWith::VsCode => "VS Code",
+            OpenWith::Custom => "custom command",
+        }
+    }
+}
+
+/// The program and arguments opening the folder with the external tool.
+///
+/// `target` is a file to open in the folder, relative to it. VS Code opens it next to the folder,
+/// the custom command gets it through its `{target}` placeholders.
+/// Returns `None` for [`OpenWith::Editor`], which is run with cargo instead,
+/// and for [`OpenWith::Custom`] when no command is configured.
+pub(crate) fn tool_command_line(
+    tool: OpenWith,
+    path: &Path,
+    custom_command: Option<&str>,
+    target: Option<&Path>,
+) -> Option<Vec<String>> {
+    let target = target.map(|target| path.join(target).to_string_lossy().into_owned());
+    let mut command_line = match tool {
+        OpenWith::Editor => return None,
+        // `code` is a batch script on Windows, which can only be started through the shell
+        OpenWith::VsCode if cfg!(target_os = "windows") => vec![
+            "cmd".to_string(),
+            "/C".to_string(),
+            "code".to_string(),
+            path.to_string_lossy().into_owned(),
+        ],
+        OpenWith::VsCode => vec!["code".to_string(), path.to_string_lossy().into_owned()],
+        OpenWith::Custom => {
+            let command = custom_command.filter(|command| !command.trim().is_empty())?;
+            return Some(
+                expand_command(command, path)
+                    .into_iter()
+                    .filter_map(|part| match &target {
+                        Some(target) => Some(part.replace("{target}", target)),
+                        // Without a target, its placeholders are dropped
+                        None => Some(part.replace("{target}", "")).filter(|part| !part.is_empty()),
+                    })
+                    .collect(),
+            );
+        }
+    };
+    command_line.extend(target);
+    Some(command_line)
+}
+
+/// Open the folder with the given external tool, and the `target` file in it if set.
+///
+/// Returns a [`io::ErrorKind::NotFound`] error when the tool isn't installed,
+/// and a [`io::ErrorKind::InvalidInput`] one when there is nothing to run.
+pub fn open_with_tool(
+    path: &Path,
+    tool: OpenWith,
+    custom_command: Option<&str>,
+    target: Option<&Path>,
+) -> io::Result<()> {
+    let Some(command_line) = tool_command_line(tool, path, custom_command, target) else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("No command to open projects with the {}", tool.label()),
+        ));
+    };
+    let (program, args) = command_line
+        .split_first()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "The command is empty"))?;
+    Command::new(program).args(args).current_dir(path).spawn()?;
+    info!("Opened '{}' with the {}", path.display(), tool.label());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_lines_of_the_tools() {
+        let path = Path::new("/projects/game");
+        assert_eq!(tool_command_line(OpenWith::Editor, path, None, None), None);
+        assert_eq!(
+            tool_command_line(OpenWith::VsCode, path, None, None)
+                .unwrap()
+                .last()
+                .map(String::as_str),
+            Some("/projects/game")
+        );
+        assert_eq!(
+            tool_command_line(OpenWith::Custom, path, Some("zed --new {path}"), None),
+            Some(vec![
+                "zed".to_string(),
+                "--new".to_string(),
+                "/projects/game".to_string()
+            ])
+        );
+        assert_eq!(
+            tool_command_line(OpenWith::Custom, path, Some("  "), None),
+            None
+        );
+        assert_eq!(tool_command_line(OpenWith::Custom, path, None, None), None);
+    }
+
+    #[test]
+    fn command_lines_with_a_target() {
+        let path = Path::new("/projects/game");
+        let main = Some(Path::new("src/main.rs"));
+        assert_eq!(
+            tool_command_line(OpenWith::VsCode, path, None, main)
+                .unwrap()
+                .last()
+                .map(String::as_str),
+            Some("/projects/game/src/main.rs")
+        );
+        assert_eq!(
+            tool_command_line(OpenWith::Custom, path, Some("zed {path} {target}"), main),
+            Some(vec![
+                "zed".to_string(),
+                "/projects/game".to_string(),
+                "/projects/game/src/main.rs".to_string()
+            ])
+        );
+        assert_eq!(
+            tool_command_line(OpenWith::Custom, path, Some("zed {path} {target}"), None),
+            Some(vec!["zed".to_string(), "/projects/game".to_string()])
+        );
+    }
+}
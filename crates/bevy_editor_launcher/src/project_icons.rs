@@ -0,0 +1,327 @@
+//! The icons next to the project names, set from the details panel for a quick recognition.
+//!
+//! See [`ProjectIcon`] for the icons, an emoji or an image. The images are usually outside of the
+//! assets folder, so they are decoded in the background like the thumbnails and cached by path.
+//! Projects without an icon, or whose image fails to load, get a tile with their first letter on
+//! the color of their placeholder.
+//!
+//! The text font of the theme has no emoji, so they are drawn with the emoji font of the system,
+//! see [`EmojiFont`]. Without one, the emoji icons get the generated tile rather than a blank box.
+
+use std::{collections::HashMap, fs, path::PathBuf};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use bevy_editor::project::{ProjectIcon, ProjectInfo};
+use bevy_editor_styles::Theme;
+
+use crate::{
+    ProjectInfoList,
+    thumbnails::{decode_image, placeholder_color, placeholder_initials},
+};
+
+/// The size of the icon tiles, in pixels.
+const ICON_SIZE: f32 = 24.0;
+
+/// Where the emoji fonts of the supported platforms are usually installed, the first found is used.
+const EMOJI_FONT_PATHS: &[&str] = &[
+    "/usr/share/fonts/truetype/noto/NotoColorEmoji.ttf",
+    "/usr/share/fonts/noto/NotoColorEmoji.ttf",
+    "/usr/share/fonts/google-noto-emoji/NotoColorEmoji.ttf",
+    "/usr/share/fonts/TTF/NotoColorEmoji.ttf",
+    "/System/Library/Fonts/Apple Color Emoji.ttc",
+    "C:\\Windows\\Fonts\\seguiemj.ttf",
+];
+
+/// Plugin keeping the [`ProjectIconTile`]s in sync with the icons of their project.
+pub struct ProjectIconsPlugin;
+
+impl Plugin for ProjectIconsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<IconImages>()
+            .init_resource::<IconLoads>()
+            .init_resource::<EmojiFont>()
+            .add_systems(Startup, load_emoji_font)
+            .add_systems(Update, (finish_icon_loads, show_project_icons).chain());
+    }
+}
+
+/// The tile showing the icon of the project at the given path.
+#[derive(Component)]
+struct ProjectIconTile {
+    path: PathBuf,
+    /// What the tile shows, `None` until it is filled.
+    shown: Option<ShownIcon>,
+}
+
+/// The content of a [`ProjectIconTile`].
+#[derive(Debug, Clone, PartialEq)]
+enum ShownIcon {
+    /// The first letter of the name, on the color of the placeholder.
+    Generated,
+    Text(String),
+    Image(Handle<Image>),
+}
+
+/// The emoji font found on the system, `None` when there is none.
+#[derive(Resource, Default)]
+struct EmojiFont(Option<Handle<Font>>);
+
+/// Whether the icon text needs the [`EmojiFont`], the text font only drawing letters and the common
+/// symbols.
+fn needs_emoji_font(text: &str) -> bool {
+    text.chars().any(|character| character as u32 >= 0x2100)
+}
+
+/// Load the first emoji font of the [`EMOJI_FONT_PATHS`] the system has.
+fn load_emoji_font(mut emoji_font: ResMut<EmojiFont>, mut fonts: ResMut<Assets<Font>>) {
+    let font = EMOJI_FONT_PATHS.iter().find_map(|path| {
+        let font = Font::try_from_bytes(fs::read(path).ok()?)
+            .inspect_err(|error| warn!("Couldn't load the emoji font '{path}': {error}"))
+            .ok()?;
+        Some(fonts.add(font))
+    });
+    if font.is_none() {
+        info!("No emoji font was found, the emoji icons are replaced by the project initials");
+    }
+    emoji_font.0 = font;
+}
+
+/// The decoded icon images by path, `None` for the ones that failed to decode.
+#[derive(Resource, Default)]
+struct IconImages(HashMap<PathBuf, Option<Handle<Image>>>);
+
+/// The icon images being decoded, by path.
+#[derive(Resource, Default)]
+struct IconLoads(HashMap<PathBuf, Task<Result<Image, String>>>);
+
+/// Spawn the icon tile of the project, filled once the launcher knows how to show its icon.
+pub(crate) fn spawn_project_icon(
+    parent: &mut ChildSpawnerCommands,
+    theme: &Theme,
+    project: &ProjectInfo,
+) {
+    parent.spawn((
+        ProjectIconTile {
+            path: project.path.clone(),
+            shown: None,
+        },
+        Node {
+            width: Val::Px(ICON_SIZE),
+            height: Val::Px(ICON_SIZE),
+            flex_shrink: 0.0,
+            margin: UiRect::right(Val::Px(8.0)),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        theme.button.border_radius,
+        Pickable::IGNORE,
+    ));
+}
+
+/// What the tile of the project shows, starting to decode its image when it isn't yet.
+///
+/// An emoji is only shown when `has_emoji_font` is set.
+fn icon_to_show(
+    project: &ProjectInfo,
+    images: &IconImages,
+    loads: &mut IconLoads,
+    has_emoji_font: bool,
+) -> ShownIcon {
+    let icon = project
+        .icon
+        .as_deref()
+        .and_then(|icon| ProjectIcon::parse(icon, &project.path));
+    match icon {
+        None => ShownIcon::Generated,
+        Some(ProjectIcon::Text(text)) if needs_emoji_font(&text) && !has_emoji_font => {
+            ShownIcon::Generated
+        }
+        Some(ProjectIcon::Text(text)) => ShownIcon::Text(text),
+        Some(ProjectIcon::Image(path)) => match images.0.get(&path) {
+            Some(Some(image)) => ShownIcon::Image(image.clone()),
+            Some(None) => ShownIcon::Generated,
+            None => {
+                loads
+                    .0
+                    .entry(path.clone())
+                    .or_insert_with(|| IoTaskPool::get().spawn(async move { decode_image(&path) }));
+                ShownIcon::Generated
+            }
+        },
+    }
+}
+
+/// Cache the decoded icon images.
+fn finish_icon_loads(
+    mut loads: ResMut<IconLoads>,
+    mut images: ResMut<IconImages>,
+    mut assets: ResMut<Assets<Image>>,
+) {
+    if loads.0.is_empty() {
+        return;
+    }
+    loads.0.retain(|path, task| {
+        let Some(result) = block_on(future::poll_once(task)) else {
+            return true;
+        };
+        let image = match result {
+            Ok(image) => Some(assets.add(image)),
+            Err(error) => {
+                warn!("Couldn't load the icon '{}': {error}", path.display());
+                None
+            }
+        };
+        images.0.insert(path.clone(), image);
+        false
+    });
+}
+
+/// Fill the [`ProjectIconTile`]s whose icon changed, or that were just spawned.
+fn show_project_icons(
+    mut commands: Commands,
+    mut tiles: Query<(Entity, &mut ProjectIconTile, &mut BackgroundColor)>,
+    added: Query<(), Added<ProjectIconTile>>,
+    project_list: Res<ProjectInfoList>,
+    images: Res<IconImages>,
+    mut loads: ResMut<IconLoads>,
+    emoji_font: Res<EmojiFont>,
+    theme: Res<Theme>,
+) {
+    if !project_list.is_changed()
+        && !images.is_changed()
+        && !emoji_font.is_changed()
+        && added.is_empty()
+    {
+        return;
+    }
+    for (tile, mut icon_tile, mut background) in &mut tiles {
+        let Some(project) = project_list
+            .0
+            .iter()
+            .find(|project| project.path == icon_tile.path)
+        else {
+            continue;
+        };
+        let shown = icon_to_show(project, &images, &mut loads, emoji_font.0.is_some());
+        if icon_tile.shown.as_ref() == Some(&shown) {
+            continue;
+        }
+
+        commands.entity(tile).despawn_related::<Children>();
+        let name = project.name().unwrap_or_default();
+        background.0 = match shown {
+            ShownIcon::Generated => placeholder_color(&name),
+            _ => Color::NONE,
+        };
+        match &shown {
+            ShownIcon::Generated => {
+                let letter: String = placeholder_initials(&name).chars().take(1).collect();
+                commands.spawn((
+                    Text::new(letter),
+                    TextFont {
+                        font: theme.text.font.clone(),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(theme.text.high_priority),
+                    Pickable::IGNORE,
+                    ChildOf(tile),
+                ));
+            }
+            ShownIcon::Text(text) => {
+                let font = match &emoji_font.0 {
+                    Some(emoji) if needs_emoji_font(text) => emoji.clone(),
+                    _ => theme.text.font.clone(),
+                };
+                commands.spawn((
+                    Text::new(text.clone()),
+                    TextFont {
+                        font,
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(theme.text.text_color),
+                    Pickable::IGNORE,
+                    ChildOf(tile),
+                ));
+            }
+            ShownIcon::Image(image) => {
+                commands.spawn((
+                    ImageNode::new(image.clone()),
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    Pickable::IGNORE,
+                    ChildOf(tile),
+                ));
+            }
+        }
+        icon_tile.shown = Some(shown);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::tasks::TaskPool;
+
+    use super::*;
+
+    fn project(icon: Option<&str>) -> ProjectInfo {
+        ProjectInfo {
+            icon: icon.map(String::from),
+            ..ProjectInfo::new("/projects/game")
+        }
+    }
+
+    #[test]
+    fn pick_the_icon_to_show() {
+        IoTaskPool::get_or_init(TaskPool::new);
+        let mut images = IconImages::default();
+        let mut loads = IconLoads::default();
+        assert_eq!(
+            icon_to_show(&project(None), &images, &mut loads, true),
+            ShownIcon::Generated
+        );
+        assert_eq!(
+            icon_to_show(&project(Some("🚀")), &images, &mut loads, true),
+            ShownIcon::Text("🚀".to_string())
+        );
+        assert_eq!(
+            icon_to_show(&project(Some("🚀")), &images, &mut loads, false),
+            ShownIcon::Generated,
+            "instead of a blank box without an emoji font"
+        );
+        assert_eq!(
+            icon_to_show(&project(Some("G")), &images, &mut loads, false),
+            ShownIcon::Text("G".to_string())
+        );
+
+        let image = PathBuf::from("/projects/game/icon.png");
+        assert_eq!(
+            icon_to_show(&project(Some("icon.png")), &images, &mut loads, true),
+            ShownIcon::Generated,
+            "while the image loads"
+        );
+        assert!(loads.0.contains_key(&image));
+
+        let handle = Handle::default();
+        images.0.insert(image.clone(), Some(handle.clone()));
+        assert_eq!(
+            icon_to_show(&project(Some("icon.png")), &images, &mut loads, true),
+            ShownIcon::Image(handle)
+        );
+        images.0.insert(image, None);
+        assert_eq!(
+            icon_to_show(&project(Some("icon.png")), &images, &mut loads, true),
+            ShownIcon::Generated,
+            "when the image failed to load"
+        );
+    }
+}
@@ -0,0 +1,428 @@
+//! A row of compact cards above the project list, with the pinned and most recently opened projects.
+//!
+//! The header of the row collapses it, the cards sliding out of view unless the animations are
+//! reduced. The collapsed state is kept in the [`LauncherSettings`].
+
+use std::time::{Duration, SystemTime};
+
+use bevy::prelude::*;
+use bevy_editor::project::ProjectInfo;
+use bevy_editor_styles::Theme;
+use bevy_scroll_box::spawn_scroll_box;
+
+use crate::{
+    ProjectInfoList, ProjectListStore,
+    focus::{Activate, Focusable},
+    hover::CardElevation,
+    open::OpenProjectEvent,
+    settings::LauncherSettings,
+    ui::{ProjectNode, project_context_menu},
+};
+
+/// The amount of recently opened projects shown next to the pinned ones.
+const QUICK_ACCESS_RECENT: usize = 5;
+/// The height of the cards of the expanded row, in pixels.
+const CARDS_HEIGHT: f32 = 64.0;
+
+/// Plugin keeping the [`QuickAccessRow`] in sync with the [`ProjectInfoList`].
+pub struct QuickAccessPlugin;
+
+impl Plugin for QuickAccessPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                update_quick_access.run_if(resource_changed::<ProjectInfoList>),
+                animate_quick_access_collapse,
+            ),
+        );
+    }
+}
+
+/// The row holding the quick access cards, hidden when there are none.
+#[derive(Component)]
+struct QuickAccessRow;
+
+/// The node the quick access cards are spawned into.
+#[derive(Component)]
+struct QuickAccessCards;
+
+/// The part of the [`QuickAccessRow`] below its header, its height animated when collapsing.
+#[derive(Component)]
+struct QuickAccessBody {
+    /// From 0 when collapsed to 1 when expanded.
+    progress: f32,
+}
+
+/// The text of the header telling what a click does.
+#[derive(Component)]
+struct CollapseLabel;
+
+/// The text of the [`CollapseLabel`].
+pub(crate) fn collapse_label(collapsed: bool) -> &'static str {
+    if collapsed { "Show" } else { "Hide" }
+}
+
+/// Collapse the [`QuickAccessRow`], or expand it if it already is.
+fn toggle_quick_access(mut settings: ResMut<LauncherSettings>) {
+    settings.quick_access_collapsed = !settings.quick_access_collapsed;
+}
+
+/// Spawn the [`QuickAccessRow`], filled in by the [`QuickAccessPlugin`].
+pub(crate) fn spawn_quick_access_row<'a>(
+    commands: &'a mut Commands,
+    theme: &Res<Theme>,
+    settings: &LauncherSettings,
+) -> EntityCommands<'a> {
+    let collapsed = settings.quick_access_collapsed;
+    let row = commands
+        .spawn((
+            QuickAccessRow,
+            Node {
+                width: Val::Percent(100.0),
+                display: Display::None,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+        ))
+        .id();
+    commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                column_gap: Val::Px(8.0),
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(2.0)),
+                ..default()
+            },
+            Focusable(1),
+            ChildOf(row),
+            children![
+                (
+                    Text::new("Quick access"),
+                    TextFont {
+                        font: theme.text.font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(theme.text.low_priority),
+                    Pickable::IGNORE,
+                ),
+                (
+                    CollapseLabel,
+                    Text::new(collapse_label(collapsed)),
+                    TextFont {
+                        font: theme.text.font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(theme.text.text_color),
+                    Pickable::IGNORE,
+                ),
+            ],
+        ))
+        .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            commands.run_system_cached(toggle_quick_access);
+        })
+        .observe(|_trigger: On<Activate>, mut commands: Commands| {
+            commands.run_system_cached(toggle_quick_access);
+        });
+    let body = commands
+        .spawn((
+            QuickAccessBody {
+                progress: if collapsed { 0.0 } else { 1.0 },
+            },
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(if collapsed { 0.0 } else { CARDS_HEIGHT }),
+                display: if collapsed {
+                    Display::None
+                } else {
+                    Display::Flex
+                },
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            ChildOf(row),
+        ))
+        .id();
+    spawn_scroll_box(
+        commands,
+        theme,
+        Overflow::scroll_x(),
+        Some(|commands: &mut Commands, content: Entity| {
+            commands.spawn((
+                QuickAccessCards,
+                Node {
+                    display: Display::Flex,
+                    column_gap: Val::Px(8.0),
+                    padding: UiRect::horizontal(Val::Px(10.0)),
+                    ..default()
+                },
+                ChildOf(content),
+            ));
+        }),
+    )
+    .insert(ChildOf(body));
+    commands.entity(row)
+}
+
+/// The eased fraction of the height shown for the animation `progress`, ending smoothly.
+pub(crate) fn eased_height(progress: f32) -> f32 {
+    1.0 - (1.0 - progress).powi(3)
+}
+
+/// Move the animation `progress` of a collapsing section toward `target` for a frame lasting
+/// `delta` seconds, instantly when the animations are reduced.
+pub(crate) fn step_collapse(
+    progress: f32,
+    target: f32,
+    settings: &LauncherSettings,
+    delta: f32,
+) -> f32 {
+    let duration = Duration::from_millis(settings.collapse_animation_ms).as_secs_f32();
+    if settings.reduce_motion || duration <= 0.0 {
+        target
+    } else if target > progress {
+        (progress + delta / duration).min(target)
+    } else {
+        (progress - delta / duration).max(target)
+    }
+}
+
+/// Move the [`QuickAccessBody`] toward the collapsed state of the settings, instantly when the
+/// animations are reduced.
+///
+/// The height of the body changes every frame, so the project list below and its scroll box follow
+/// it. The fully collapsed body isn't displayed, so the cards can't be focused.
+fn animate_quick_access_collapse(
+    mut bodies: Query<(&mut QuickAccessBody, &mut Node)>,
+    mut labels: Query<&mut Text, With<CollapseLabel>>,
+    settings: Res<LauncherSettings>,
+    time: Res<Time>,
+) {
+    let target = if settings.quick_access_collapsed {
+        0.0
+    } else {
+        1.0
+    };
+    if settings.is_changed() {
+        for mut label in &mut labels {
+            let text = collapse_label(settings.quick_access_collapsed);
+            if label.0 != text {
+                label.0 = text.to_string();
+            }
+        }
+    }
+    for (mut body, mut node) in &mut bodies {
+        if body.progress == target {
+            continue;
+        }
+        body.progress = step_collapse(body.progress, target, &settings, time.delta_secs());
+        node.height = Val::Px(CARDS_HEIGHT * eased_height(body.progress));
+        node.display = if body.progress > 0.0 {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+}
+
+/// The projects shown in the quick access: the pinned ones, then the most recently opened ones.
+fn quick_access_projects(projects: &[ProjectInfo]) -> Vec<&ProjectInfo> {
+    let mut recent: Vec<&ProjectInfo> = projects
+        .iter()
+        .filter(|project| !project.pinned && project.last_opened > SystemTime::UNIX_EPOCH)
+        .collect();
+    recent.sort_by(|a, b| b.last_opened.cmp(&a.last_opened));
+    projects
+        .iter()
+        .filter(|project| project.pinned)
+        .chain(recent.into_iter().take(QUICK_ACCESS_RECENT))
+        .collect()
+}
+
+/// Rebuild the quick access cards, hiding the [`QuickAccessRow`] when there is nothing to show.
+fn update_quick_access(
+    mut commands: Commands,
+    project_list: Res<ProjectInfoList>,
+    mut rows: Query<&mut Node, With<QuickAccessRow>>,
+    cards: Query<Entity, With<QuickAccessCards>>,
+    theme: Res<Theme>,
+) {
+    let projects = quick_access_projects(&project_list.0);
+    for mut row in &mut rows {
+        row.display = if projects.is_empty() {
+            Display::None
+        } else {
+            Display::Flex
+        };
+    }
+
+    for cards in &cards {
+        commands.entity(cards).despawn_related::<Children>();
+        for project in &projects {
+            spawn_quick_access_card(&mut commands, &theme, project).insert(ChildOf(cards));
+        }
+    }
+}
+
+fn spawn_quick_access_card<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    project: &ProjectInfo,
+) -> EntityCommands<'a> {
+    let path = project.path.clone();
+    let activate_path = path.clone();
+    let mut card = commands.spawn((
+        Node {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            min_width: Val::Px(140.0),
+            height: Val::Px(44.0),
+            margin: UiRect::vertical(Val::Px(5.0)),
+            padding: UiRect::horizontal(Val::Px(10.0)),
+            ..default()
+        },
+        theme.button.background_color,
+        theme.button.border_radius,
+        Focusable(1),
+        CardElevation::default(),
+        children![
+            (
+                Text::new(project.name().unwrap_or_default()),
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(theme.text.text_color),
+                Pickable::IGNORE,
+            ),
+            (
+                Text::new(if project.pinned { "Pinned" } else { "Recent" }),
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(theme.text.low_priority),
+                Pickable::IGNORE,
+            ),
+        ],
+    ));
+    card.observe(
+        move |trigger: On<Pointer<Release>>, mut open_events: EventWriter<OpenProjectEvent>| {
+            if trigger.event().button == PointerButton::Primary {
+                open_events.write(OpenProjectEvent {
+                    path: path.clone(),
+                    behavior: None,
+                    with: None,
+                    target: None,
+                });
+            }
+        },
+    )
+    .observe(
+        move |_trigger: On<Activate>, mut open_events: EventWriter<OpenProjectEvent>| {
+            open_events.write(OpenProjectEvent {
+                path: activate_path.clone(),
+                behavior: None,
+                with: None,
+                target: None,
+            });
+        },
+    );
+    card
+}
+
+/// Pin the project of the given [`ProjectNode`] to the quick access, or unpin it if it already is.
+pub(crate) fn toggle_pinned(
+    In(project_entity): In<Entity>,
+    mut commands: Commands,
+    query_nodes: Query<&ProjectNode>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+) {
+    let Ok(ProjectNode(path)) = query_nodes.get(project_entity) else {
+        return;
+    };
+    let Some(project) = project_list.0.iter_mut().find(|p| &p.path == path) else {
+        return;
+    };
+    project.pinned = !project.pinned;
+    let pinned = project.pinned;
+    store.set(project_list.0.clone());
+    // The menu labels depend on the pinned state
+    commands
+        .entity(project_entity)
+        .insert(project_context_menu(pinned));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, time::Duration};
+
+    use super::*;
+
+    fn project(path: &str, opened_secs: u64, pinned: bool) -> ProjectInfo {
+        ProjectInfo {
+            last_opened: SystemTime::UNIX_EPOCH + Duration::from_secs(opened_secs),
+            pinned,
+            ..ProjectInfo::new(path)
+        }
+    }
+
+    fn paths(projects: &[&ProjectInfo]) -> Vec<PathBuf> {
+        projects
+            .iter()
+            .map(|project| project.path.clone())
+            .collect()
+    }
+
+    #[test]
+    fn pinned_then_most_recent() {
+        let projects = [
+            project("/old", 10, false),
+            project("/pinned", 5, true),
+            project("/never", 0, false),
+            project("/new", 20, false),
+        ];
+        assert_eq!(
+            paths(&quick_access_projects(&projects)),
+            vec![
+                PathBuf::from("/pinned"),
+                PathBuf::from("/new"),
+                PathBuf::from("/old")
+            ]
+        );
+        assert!(quick_access_projects(&[project("/never", 0, false)]).is_empty());
+    }
+
+    #[test]
+    fn collapse_the_row() {
+        assert_eq!(eased_height(0.0), 0.0);
+        assert_eq!(eased_height(1.0), 1.0);
+        assert!(eased_height(0.5) > 0.5, "slows down at the end");
+
+        let mut app = App::new();
+        app.insert_resource(LauncherSettings {
+            reduce_motion: true,
+            ..default()
+        })
+        .init_resource::<Time>()
+        .add_systems(Update, animate_quick_access_collapse);
+        let body = app
+            .world_mut()
+            .spawn((QuickAccessBody { progress: 1.0 }, Node::default()))
+            .id();
+
+        app.world_mut()
+            .resource_mut::<LauncherSettings>()
+            .quick_access_collapsed = true;
+        app.update();
+        let node = app.world().get::<Node>(body).unwrap();
+        assert_eq!(node.height, Val::Px(0.0));
+        assert_eq!(node.display, Display::None);
+    }
+}
@@ -0,0 +1,131 @@
+//! Asking before quitting when it would lose or interrupt something, see [`request_quit`].
+//!
+//! Nothing pending, the launcher quits right away. Otherwise a [`ConfirmationDialog`] lists the
+//! saves that failed, whose edits are lost on exit, and the tasks that would be interrupted, like
+//! the projects being created. It offers not to ask again, see
+//! [`Confirmation::QuitWithPendingWork`].
+//!
+//! The edits of the project list that just weren't saved yet don't count, the
+//! [`AutoSavePlugin`](crate::auto_save::AutoSavePlugin) saves them on exit.
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use bevy_editor_styles::{
+    Theme,
+    busy::BusyActions,
+    dialog::{ConfirmationDialog, DialogResult},
+};
+
+use crate::{
+    CreateProjectTask,
+    clean::CLEAN_ACTION,
+    confirmations::{Confirmation, offer_dont_ask_again},
+    save_status::{SaveFailures, SaveTarget},
+    settings::LauncherSettings,
+};
+
+/// The dialog confirming to quit, so that quitting again while it shows doesn't open another one.
+#[derive(Component)]
+struct QuitConfirmation;
+
+/// What quitting now would lose or interrupt, a sentence each, empty when nothing is pending.
+fn pending_work(failed_saves: &[&str], creations: usize, cleaning: bool) -> Vec<String> {
+    let mut pending: Vec<String> = failed_saves
+        .iter()
+        .map(|label| {
+            format!("The latest changes to the {label} couldn't be saved, they would be lost.")
+        })
+        .collect();
+    match creations {
+        0 => {}
+        1 => pending.push(
+            "A project is still being created, its folder would be left incomplete.".to_string(),
+        ),
+        count => pending.push(format!(
+            "{count} projects are still being created, their folders would be left incomplete."
+        )),
+    }
+    if cleaning {
+        pending.push("The build folders of projects are still being deleted.".to_string());
+    }
+    pending
+}
+
+/// Quit the launcher by closing the `window` the user closed, or exiting when `None`, asking first
+/// if something is [pending](pending_work).
+#[expect(clippy::too_many_arguments)]
+pub(crate) fn request_quit(
+    In(window): In<Option<Entity>>,
+    mut commands: Commands,
+    failures: Res<SaveFailures>,
+    creations: Query<(), With<CreateProjectTask>>,
+    busy: Res<BusyActions>,
+    confirmations: Query<(), With<QuitConfirmation>>,
+    mut primary_window: Query<&mut Window, With<PrimaryWindow>>,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+) {
+    let failed_saves: Vec<&str> = failures.targets().map(SaveTarget::label).collect();
+    let pending = pending_work(
+        &failed_saves,
+        creations.iter().count(),
+        busy.is_busy(CLEAN_ACTION),
+    );
+    if pending.is_empty() {
+        quit(&mut commands, window);
+        return;
+    }
+    if !confirmations.is_empty() {
+        return;
+    }
+
+    // Quitting from the tray while minimized to it, the dialog must be seen
+    if let Ok(mut primary_window) = primary_window.single_mut() {
+        primary_window.visible = true;
+        primary_window.focused = true;
+    }
+    let dialog = ConfirmationDialog::new("Quit the launcher?", pending.join("\n"));
+    offer_dont_ask_again(dialog, Confirmation::QuitWithPendingWork, &settings)
+        .confirm_label("Quit")
+        .spawn(&mut commands, &theme)
+        .insert(QuitConfirmation)
+        .observe(move |trigger: On<DialogResult>, mut commands: Commands| {
+            if trigger.event().0 {
+                quit(&mut commands, window);
+            }
+        });
+}
+
+fn quit(commands: &mut Commands, window: Option<Entity>) {
+    match window {
+        // The app exits once its last window is closed
+        Some(window) => commands.entity(window).despawn(),
+        None => {
+            commands.write_event(AppExit::Success);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_ask_when_something_is_pending() {
+        assert!(pending_work(&[], 0, false).is_empty());
+        assert_eq!(
+            pending_work(&["settings"], 0, false),
+            vec![
+                "The latest changes to the settings couldn't be saved, they would be lost."
+                    .to_string()
+            ]
+        );
+        assert_eq!(
+            pending_work(&[], 2, true),
+            vec![
+                "2 projects are still being created, their folders would be left incomplete."
+                    .to_string(),
+                "The build folders of projects are still being deleted.".to_string(),
+            ]
+        );
+    }
+}
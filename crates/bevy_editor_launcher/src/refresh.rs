@@ -0,0 +1,165 @@
+//! Reloading the project list from disk, highlighting what changed.
+
+use std::{path::PathBuf, time::Duration};
+
+use bevy::prelude::*;
+use bevy_editor::project::{ProjectInfo, project_exists};
+use bevy_editor_styles::Theme;
+use bevy_footer_bar::FooterBarStatus;
+
+use crate::{
+    ProjectInfoList, ProjectListStore,
+    ui::{NewProjectButton, ProjectList, ProjectNode, append_project_node},
+};
+
+/// The [`BusyAction`](bevy_editor_styles::busy::BusyAction) of the buttons refreshing the list,
+/// busy until the badges of the refreshed projects are computed.
+pub(crate) const REFRESH_ACTION: &str = "refresh";
+
+/// How long the nodes stay highlighted after a refresh.
+const HIGHLIGHT_DURATION: Duration = Duration::from_secs(2);
+
+/// Plugin handling the [`RefreshProjectsEvent`]s.
+pub struct RefreshPlugin;
+
+impl Plugin for RefreshPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RefreshProjectsEvent>()
+            .add_systems(Update, (refresh_projects, fade_refresh_highlights).chain());
+    }
+}
+
+/// Request to reload the project list from disk.
+#[derive(Event, BufferedEvent, Debug, Clone, Default)]
+pub struct RefreshProjectsEvent;
+
+/// A highlight of a [`ProjectNode`] that changed during the last refresh, fading back to the normal color.
+#[derive(Component)]
+struct RefreshHighlight {
+    color: Color,
+    timer: Timer,
+}
+
+/// The changes between two project lists.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct ProjectListDiff {
+    /// The projects that are only in the new list.
+    pub added: Vec<ProjectInfo>,
+    /// The paths of the projects that are only in the old list.
+    pub removed: Vec<PathBuf>,
+}
+
+impl ProjectListDiff {
+    /// Compare the old and the new project lists.
+    pub fn new(old: &[ProjectInfo], new: &[ProjectInfo]) -> Self {
+        Self {
+            added: new
+                .iter()
+                .filter(|project| !old.contains(project))
+                .cloned()
+                .collect(),
+            removed: old
+                .iter()
+                .filter(|project| !new.contains(project))
+                .map(|project| project.path.clone())
+                .collect(),
+        }
+    }
+}
+
+/// Reload the project list, update the [`ProjectNode`]s incrementally and highlight the changes.
+fn refresh_projects(
+    mut events: EventReader<RefreshProjectsEvent>,
+    mut commands: Commands,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    nodes: Query<(Entity, &ProjectNode)>,
+    list: Single<Entity, With<ProjectList>>,
+    new_project_button: Single<Entity, With<NewProjectButton>>,
+    mut footer_status: Query<&mut Text, With<FooterBarStatus>>,
+    theme: Res<Theme>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    events.clear();
+
+    let projects = store.load();
+    let diff = ProjectListDiff::new(&project_list.0, &projects);
+    project_list.0 = projects;
+
+    for (entity, node) in &nodes {
+        if diff.removed.contains(&node.0) {
+            commands.entity(entity).despawn();
+        }
+    }
+    for project in &diff.added {
+        let entity =
+            append_project_node(&mut commands, *list, *new_project_button, &theme, project);
+        commands.entity(entity).insert(RefreshHighlight {
+            color: theme.text.high_priority,
+            timer: Timer::new(HIGHLIGHT_DURATION, TimerMode::Once),
+        });
+    }
+
+    let mut missing = 0;
+    for (entity, node) in &nodes {
+        if !diff.removed.contains(&node.0) && !project_exists(&node.0) {
+            missing += 1;
+            commands.entity(entity).insert(RefreshHighlight {
+                color: theme.status.warning,
+                timer: Timer::new(HIGHLIGHT_DURATION, TimerMode::Once),
+            });
+        }
+    }
+
+    let summary = if diff.added.is_empty() && diff.removed.is_empty() && missing == 0 {
+        "Project list is up to date".to_string()
+    } else {
+        format!(
+            "Added {}, removed {}, {missing} missing",
+            diff.added.len(),
+            diff.removed.len()
+        )
+    };
+    info!("Refreshed project list: {summary}");
+    for mut status in &mut footer_status {
+        status.0.clone_from(&summary);
+    }
+}
+
+/// Fade the [`RefreshHighlight`]s back to the normal node color.
+fn fade_refresh_highlights(
+    mut commands: Commands,
+    mut highlights: Query<(Entity, &mut RefreshHighlight, &mut BackgroundColor)>,
+    theme: Res<Theme>,
+    time: Res<Time>,
+) {
+    for (entity, mut highlight, mut background) in &mut highlights {
+        let normal = theme.button.background_color.0;
+        if highlight.timer.tick(time.delta()).is_finished() {
+            background.0 = normal;
+            commands.entity(entity).remove::<RefreshHighlight>();
+            continue;
+        }
+        background.0 = highlight.color.mix(&normal, highlight.timer.fraction());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_project_lists() {
+        let old = ["/a", "/b"].map(ProjectInfo::new);
+        let new = ["/b", "/c", "/d"].map(ProjectInfo::new);
+        let diff = ProjectListDiff::new(&old, &new);
+        assert_eq!(
+            diff.added,
+            vec![ProjectInfo::new("/c"), ProjectInfo::new("/d")]
+        );
+        assert_eq!(diff.removed, vec![PathBuf::from("/a")]);
+        assert_eq!(ProjectListDiff::new(&new, &new), ProjectListDiff::default());
+    }
+}
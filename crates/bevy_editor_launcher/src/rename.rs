@@ -0,0 +1,255 @@
+//! Renaming the folder of a project, from the context menu of its [`ProjectNode`] or the
+//! [`LauncherAction::RenameProject`](crate::LauncherAction::RenameProject) shortcut.
+//!
+//! The node of the renamed project is rebuilt in place, and the project list updated, so the
+//! project keeps its place, pin and history.
+
+use bevy::{input_focus::InputFocus, prelude::*};
+use bevy_editor::project::{rename_project, validate_project_name};
+use bevy_editor_styles::{
+    Theme,
+    dialog::{DialogResult, close_dialog, spawn_dialog_button, spawn_dialog_root},
+    text_input::{TextInputSubmit, TextInputValidator, TextInputValue, spawn_text_input},
+};
+use bevy_text_editing::{CharPosition, EditableTextLine, RenderWidget};
+
+use crate::{
+    ProjectInfoList, ProjectListStore,
+    toast::{ToastLevel, push_toast},
+    ui::{ProjectNode, spawn_project_node},
+};
+
+/// The dialog renaming the project of a [`ProjectNode`].
+#[derive(Component)]
+struct RenameDialog {
+    /// The node of the project.
+    node: Entity,
+    /// The input of the new name.
+    input: Entity,
+}
+
+/// Open the dialog renaming the project of the given [`ProjectNode`].
+pub(crate) fn show_rename_dialog(
+    In(project_entity): In<Entity>,
+    mut commands: Commands,
+    nodes: Query<&ProjectNode>,
+    theme: Res<Theme>,
+) {
+    let Ok(ProjectNode(path)) = nodes.get(project_entity) else {
+        return;
+    };
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let dialog = spawn_dialog_root(&mut commands);
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(420.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(format!("Rename {name}")),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+    commands.spawn((
+        Text::new("The folder of the project is renamed, close the project in the editor first."),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(panel),
+    ));
+    let input = spawn_text_input(&mut commands, &theme, name, "my-bevy-app")
+        .insert((
+            TextInputValidator::new(|name| {
+                validate_project_name(name.trim()).map_err(|error| error.to_string())
+            }),
+            ChildOf(panel),
+        ))
+        .observe(
+            move |_trigger: On<TextInputSubmit>, mut commands: Commands| {
+                commands.run_system_cached_with(rename_project_node, dialog);
+            },
+        )
+        .id();
+    commands
+        .entity(dialog)
+        .insert(RenameDialog {
+            node: project_entity,
+            input,
+        })
+        .observe(
+            move |trigger: On<DialogResult>, mut focus: ResMut<InputFocus>| {
+                // Cancelled, the keyboard is back on the project
+                if !trigger.event().0 {
+                    focus.set(project_entity);
+                }
+            },
+        );
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Cancel",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            close_dialog(&mut commands, dialog, false);
+        },
+    );
+    spawn_dialog_button(&mut commands, &theme, "Rename", theme.button.hover_color)
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                commands.run_system_cached_with(rename_project_node, dialog);
+            },
+        );
+    // Once the input is spawned, ready to type the new name over the current one
+    commands.run_system_cached_with(focus_rename_input, input);
+}
+
+/// Focus the name input of a [`RenameDialog`], its text selected.
+fn focus_rename_input(
+    In(input): In<Entity>,
+    mut commands: Commands,
+    children: Query<&Children>,
+    mut lines: Query<&mut EditableTextLine>,
+    mut focus: ResMut<InputFocus>,
+) {
+    let Some(line_entity) = children
+        .get(input)
+        .ok()
+        .and_then(|children| children.first().copied())
+    else {
+        return;
+    };
+    let Ok(mut line) = lines.get_mut(line_entity) else {
+        return;
+    };
+    let length = line.text.chars().count();
+    line.selection_start = Some(CharPosition(0));
+    line.cursor_position = Some(CharPosition(length));
+    focus.set(line_entity);
+    commands.trigger_targets(RenderWidget::show_cursor(), line_entity);
+}
+
+/// Rename the project of the [`RenameDialog`] to the name typed, rebuilding its node in place.
+///
+/// The dialog stays open when the name is invalid or the folder can't be renamed.
+#[expect(clippy::too_many_arguments)]
+fn rename_project_node(
+    In(dialog): In<Entity>,
+    mut commands: Commands,
+    dialogs: Query<&RenameDialog>,
+    inputs: Query<&TextInputValue>,
+    nodes: Query<(&ProjectNode, &ChildOf)>,
+    children: Query<&Children>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    mut focus: ResMut<InputFocus>,
+    theme: Res<Theme>,
+) {
+    let Ok(rename) = dialogs.get(dialog) else {
+        return;
+    };
+    let name = inputs
+        .get(rename.input)
+        .map(|value| value.0.trim().to_string())
+        .unwrap_or_default();
+    if let Err(error) = validate_project_name(&name) {
+        push_toast(&mut commands, ToastLevel::Error, error.to_string());
+        return;
+    }
+    let Ok((ProjectNode(path), ChildOf(list))) = nodes.get(rename.node) else {
+        close_dialog(&mut commands, dialog, false);
+        return;
+    };
+    if path
+        .file_name()
+        .is_some_and(|current| current == name.as_str())
+    {
+        close_dialog(&mut commands, dialog, false);
+        return;
+    }
+    let Some(project) = project_list
+        .0
+        .iter_mut()
+        .find(|project| &project.path == path)
+    else {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!("'{}' isn't in the project list anymore", path.display()),
+        );
+        close_dialog(&mut commands, dialog, false);
+        return;
+    };
+
+    let renamed = match rename_project(project, &name, &*store.0) {
+        Ok(renamed) => renamed,
+        Err(error) => {
+            push_toast(
+                &mut commands,
+                ToastLevel::Error,
+                format!("Couldn't rename the project: {error}"),
+            );
+            return;
+        }
+    };
+    *project = renamed.clone();
+
+    let list = *list;
+    let index = children
+        .get(list)
+        .ok()
+        .and_then(|nodes| nodes.iter().position(|node| node == rename.node))
+        .unwrap_or_default();
+    let mut node = None;
+    commands.entity(list).with_children(|builder| {
+        node = Some(spawn_project_node(builder, &theme, &renamed).id());
+    });
+    let node = node.unwrap();
+    commands.entity(list).insert_children(index, &[node]);
+    commands.entity(rename.node).despawn();
+    focus.set(node);
+
+    push_toast(
+        &mut commands,
+        ToastLevel::Success,
+        format!("Renamed the project to {name}"),
+    );
+    close_dialog(&mut commands, dialog, true);
+}
@@ -0,0 +1,252 @@
+//! Tracking the saves of the settings and of the project list that failed.
+//!
+//! A failed save keeps the edits in memory, and a banner stays at the top of the launcher until a
+//! save succeeds, with a button retrying the failed saves right away.
+
+use std::{collections::BTreeMap, io};
+
+use bevy::prelude::*;
+use bevy_editor_styles::{Theme, dialog::spawn_dialog_button};
+
+/// Plugin showing the [`SaveFailures`] in a banner.
+pub struct SaveStatusPlugin;
+
+impl Plugin for SaveStatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SaveFailures>()
+            .add_event::<RetrySaveEvent>()
+            .add_systems(Startup, spawn_save_banner)
+            .add_systems(
+                Update,
+                update_save_banner.run_if(resource_changed::<SaveFailures>),
+            );
+    }
+}
+
+/// What failed to be saved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum SaveTarget {
+    /// The [`LauncherSettings`](crate::LauncherSettings).
+    Settings,
+    /// The [`ProjectInfoList`](crate::ProjectInfoList).
+    ProjectList,
+}
+
+impl SaveTarget {
+    /// What the target is, like `settings`, meant to be displayed.
+    pub fn label(self) -> &'static str {
+        match self {
+            SaveTarget::Settings => "settings",
+            SaveTarget::ProjectList => "project list",
+        }
+    }
+}
+
+/// Whether a failed save is worth retrying on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FailureKind {
+    /// The save may succeed later without the user doing anything, like when the disk is busy.
+    Transient,
+    /// The save keeps failing until the user fixes the cause, like the permissions of the folder.
+    Permanent,
+}
+
+impl FailureKind {
+    /// The kind of the failure of a save with this error.
+    ///
+    /// A full disk is transient, other apps or the user freeing space is common enough, while a
+    /// file in the way of the data folder is permanent.
+    pub fn of(error: &io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::AlreadyExists
+            | io::ErrorKind::PermissionDenied
+            | io::ErrorKind::ReadOnlyFilesystem
+            | io::ErrorKind::InvalidData
+            | io::ErrorKind::NotADirectory
+            | io::ErrorKind::IsADirectory => FailureKind::Permanent,
+            _ => FailureKind::Transient,
+        }
+    }
+}
+
+/// The last failed save of a [`SaveTarget`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SaveFailure {
+    pub kind: FailureKind,
+    /// The error that made the save fail.
+    pub message: String,
+}
+
+/// The [`SaveTarget`]s whose last save failed.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct SaveFailures(BTreeMap<SaveTarget, SaveFailure>);
+
+impl SaveFailures {
+    /// The last failed save of the target, `None` if it was saved.
+    pub fn get(&self, target: SaveTarget) -> Option<&SaveFailure> {
+        self.0.get(&target)
+    }
+
+    /// The targets whose last save failed.
+    pub fn targets(&self) -> impl Iterator<Item = SaveTarget> + '_ {
+        self.0.keys().copied()
+    }
+}
+
+/// Request to retry the failed saves right away.
+#[derive(Event, BufferedEvent, Debug, Clone, Default)]
+pub(crate) struct RetrySaveEvent;
+
+/// Record the result of a save of the target, and return the kind of failure if it failed.
+///
+/// The [`SaveFailures`] are only changed when the target starts or stops failing, so retrying a
+/// failing save doesn't rebuild the banner.
+pub(crate) fn report_save(
+    failures: &mut ResMut<SaveFailures>,
+    target: SaveTarget,
+    result: &io::Result<()>,
+) -> Option<FailureKind> {
+    let failure = result.as_ref().err().map(|error| SaveFailure {
+        kind: FailureKind::of(error),
+        message: error.to_string(),
+    });
+    if failures.get(target) != failure.as_ref() {
+        match &failure {
+            Some(failure) => {
+                error!("Unable to save the {}: {}", target.label(), failure.message);
+                failures.0.insert(target, failure.clone());
+            }
+            None => {
+                info!("The {} saved successfully again", target.label());
+                failures.0.remove(&target);
+            }
+        }
+    }
+    failure.map(|failure| failure.kind)
+}
+
+/// The banner listing the [`SaveFailures`], hidden while there are none.
+#[derive(Component)]
+struct SaveBanner;
+
+fn spawn_save_banner(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.0),
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            display: Display::None,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        GlobalZIndex(6),
+        Pickable::IGNORE,
+        SaveBanner,
+    ));
+}
+
+fn update_save_banner(
+    mut commands: Commands,
+    failures: Res<SaveFailures>,
+    banner: Single<(Entity, &mut Node), With<SaveBanner>>,
+    theme: Res<Theme>,
+) {
+    let (banner, mut node) = banner.into_inner();
+    commands.entity(banner).despawn_related::<Children>();
+    if failures.0.is_empty() {
+        node.display = Display::None;
+        return;
+    }
+    node.display = Display::Flex;
+
+    let panel = commands
+        .spawn((
+            Node {
+                max_width: Val::Px(560.0),
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.0),
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            BorderColor::all(theme.status.warning),
+            theme.general.border_radius,
+            ChildOf(banner),
+        ))
+        .id();
+    let lines = commands
+        .spawn((
+            Node {
+                flex_grow: 1.0,
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    let font = |size: f32| TextFont {
+        font: theme.text.font.clone(),
+        font_size: size,
+        ..default()
+    };
+    for (target, failure) in &failures.0 {
+        commands.spawn((
+            Text::new(format!(
+                "Your {} couldn't be saved, the changes are kept until they are: {}",
+                target.label(),
+                failure.message
+            )),
+            font(13.0),
+            TextColor(theme.status.warning),
+            ChildOf(lines),
+        ));
+    }
+    if failures
+        .0
+        .values()
+        .any(|failure| failure.kind == FailureKind::Permanent)
+    {
+        commands.spawn((
+            Text::new("Check the permissions of the Bevy Editor's data folder, then retry."),
+            font(12.0),
+            TextColor(theme.text.low_priority),
+            ChildOf(lines),
+        ));
+    }
+
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Retry",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(panel))
+    .observe(
+        |_trigger: On<Pointer<Click>>, mut retry: EventWriter<RetrySaveEvent>| {
+            retry.write(RetrySaveEvent);
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_the_failures() {
+        let kind = |kind: io::ErrorKind| FailureKind::of(&io::Error::from(kind));
+        assert_eq!(
+            kind(io::ErrorKind::PermissionDenied),
+            FailureKind::Permanent
+        );
+        assert_eq!(kind(io::ErrorKind::NotADirectory), FailureKind::Permanent);
+        assert_eq!(kind(io::ErrorKind::AlreadyExists), FailureKind::Permanent);
+        assert_eq!(kind(io::ErrorKind::StorageFull), FailureKind::Transient);
+        assert_eq!(kind(io::ErrorKind::Interrupted), FailureKind::Transient);
+    }
+}
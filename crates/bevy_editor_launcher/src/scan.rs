@@ -0,0 +1,444 @@
+//! Scanning a folder tree for Bevy projects, to add the ones found to the project list.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        Mutex,
+        mpsc::{self, Receiver},
+    },
+};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use bevy_editor::project::{
+    CancellationToken, import_projects, is_same_project,
+    scan::{DEFAULT_SCAN_DEPTH, ScanProgress, scan_for_projects},
+};
+use bevy_editor_styles::{
+    Theme,
+    busy::BusyActions,
+    dialog::{DialogResult, close_dialog, spawn_dialog_button, spawn_dialog_root},
+};
+use bevy_footer_bar::FooterBarStatus;
+use bevy_scroll_box::{ScrollShadows, spawn_scroll_box};
+
+use crate::{
+    LauncherConfig, ProjectInfoList, ProjectListStore,
+    toast::{ToastLevel, push_toast},
+    ui::{NewProjectButton, ProjectList, append_project_node},
+};
+
+/// The [`BusyAction`](bevy_editor_styles::busy::BusyAction) of the buttons starting a scan.
+pub(crate) const SCAN_ACTION: &str = "scan";
+
+/// Plugin scanning folder trees for projects, see [`start_project_scan`].
+pub struct ScanPlugin;
+
+impl Plugin for ScanPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, receive_scan_progress);
+    }
+}
+
+/// The task scanning a folder tree, stored on the dialog showing its results.
+///
+/// Closing the dialog cancels the scan.
+#[derive(Component)]
+struct ScanProjectsTask {
+    /// The scan itself, `None` once it is done
+    task: Option<Task<io::Result<Vec<PathBuf>>>>,
+    /// The folder being scanned
+    root: PathBuf,
+    /// The progress reported by the task
+    progress: Mutex<Receiver<ScanProgress>>,
+    /// The number of folders looked into so far
+    visited: usize,
+    /// The projects found that weren't in the project list yet
+    found: Vec<PathBuf>,
+}
+
+impl ScanProjectsTask {
+    /// A sentence describing the progress of the scan.
+    fn description(&self) -> String {
+        let state = if self.task.is_some() {
+            "Scanning"
+        } else {
+            "Scanned"
+        };
+        format!(
+            "{state} '{}': {} folders, {} new projects",
+            self.root.display(),
+            self.visited,
+            self.found.len()
+        )
+    }
+}
+
+/// The text of the scan dialog describing the progress.
+#[derive(Component)]
+struct ScanStatus;
+
+/// The node the [`ScanResult`]s are listed in.
+#[derive(Component)]
+struct ScanResults;
+
+/// A project found by the scan, with a button to add it.
+#[derive(Component)]
+struct ScanResult(PathBuf);
+
+/// The button of a [`ScanResult`] adding it to the project list.
+#[derive(Component)]
+struct ScanAddButton;
+
+/// Pick a folder and scan it for projects, showing the results in a dialog as they are found.
+///
+/// Only one scan runs at a time.
+pub(crate) fn start_project_scan(
+    mut commands: Commands,
+    scans: Query<(), With<ScanProjectsTask>>,
+    config: Res<LauncherConfig>,
+    mut busy: ResMut<BusyActions>,
+    theme: Res<Theme>,
+) {
+    if !scans.is_empty() {
+        return;
+    }
+    let mut dialog = rfd::FileDialog::new();
+    if let Some(project_root) = &config.project_root {
+        dialog = dialog.set_directory(project_root);
+    }
+    let Some(root) = dialog.pick_folder() else {
+        return;
+    };
+
+    let (sender, progress) = mpsc::channel();
+    let cancel = CancellationToken::new();
+    let task_root = root.clone();
+    let task_cancel = cancel.clone();
+    let task = IoTaskPool::get().spawn(async move {
+        scan_for_projects(&task_root, DEFAULT_SCAN_DEPTH, &task_cancel, |progress| {
+            // The receiver is gone once the dialog is closed, nothing to report to then
+            let _ = sender.send(progress);
+        })
+    });
+    info!("Scanning '{}' for projects", root.display());
+
+    let scan = ScanProjectsTask {
+        task: Some(task),
+        root,
+        progress: Mutex::new(progress),
+        visited: 0,
+        found: Vec::new(),
+    };
+    let description = scan.description();
+
+    let dialog = spawn_dialog_root(&mut commands);
+    busy.start(SCAN_ACTION, dialog);
+    commands
+        .entity(dialog)
+        .insert(scan)
+        .observe(move |_trigger: On<DialogResult>| {
+            cancel.cancel();
+        });
+
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(500.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    commands.spawn((
+        Text::new("Scan for projects"),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+    commands.spawn((
+        ScanStatus,
+        Text::new(description),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(panel),
+    ));
+
+    let results = commands
+        .spawn((
+            Node {
+                height: Val::Px(240.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_scroll_box(
+        &mut commands,
+        &theme,
+        Overflow::scroll_y(),
+        Some(|commands: &mut Commands, content: Entity| {
+            commands.spawn((
+                ScanResults,
+                Node {
+                    width: Val::Percent(100.0),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                ChildOf(content),
+            ));
+        }),
+    )
+    .insert((ScrollShadows, ChildOf(results)));
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Close",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            close_dialog(&mut commands, dialog, false);
+        },
+    );
+    spawn_dialog_button(&mut commands, &theme, "Add all", theme.button.hover_color)
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                commands.run_system_cached_with(add_scanned_projects, (dialog, None));
+            },
+        );
+}
+
+/// Collect the progress of the scans, listing the new projects found, and handle their result when done.
+fn receive_scan_progress(
+    mut commands: Commands,
+    mut scans: Query<(Entity, &mut ScanProjectsTask)>,
+    project_list: Res<ProjectInfoList>,
+    children: Query<&Children>,
+    results: Query<(), With<ScanResults>>,
+    mut statuses: Query<&mut Text, With<ScanStatus>>,
+    mut footer_status: Query<&mut Text, (With<FooterBarStatus>, Without<ScanStatus>)>,
+    mut busy: ResMut<BusyActions>,
+    theme: Res<Theme>,
+) {
+    for (dialog, mut scan) in &mut scans {
+        let progress: Vec<ScanProgress> = match scan.progress.lock() {
+            Ok(progress) => progress.try_iter().collect(),
+            Err(_) => Vec::new(),
+        };
+        let finished = scan
+            .task
+            .as_mut()
+            .and_then(|task| block_on(future::poll_once(task)));
+        if progress.is_empty() && finished.is_none() {
+            continue;
+        }
+
+        let results_entity = children
+            .iter_descendants(dialog)
+            .find(|descendant| results.contains(*descendant));
+        for progress in progress {
+            match progress {
+                ScanProgress::Visiting(_) => scan.visited += 1,
+                ScanProgress::Found(path) => {
+                    if project_list
+                        .0
+                        .iter()
+                        .any(|project| is_same_project(&project.path, &path))
+                    {
+                        continue;
+                    }
+                    if let Some(results_entity) = results_entity {
+                        spawn_scan_result(&mut commands, &theme, dialog, results_entity, &path);
+                    }
+                    scan.found.push(path);
+                }
+            }
+        }
+
+        if let Some(result) = finished {
+            scan.task = None;
+            busy.finish(SCAN_ACTION, dialog);
+            match result {
+                Ok(_) => info!("Finished scanning '{}'", scan.root.display()),
+                Err(error) => push_toast(
+                    &mut commands,
+                    ToastLevel::Error,
+                    format!("Failed to scan for projects: {error}"),
+                ),
+            }
+        }
+
+        let description = scan.description();
+        for descendant in children.iter_descendants(dialog) {
+            if let Ok(mut status) = statuses.get_mut(descendant) {
+                status.0.clone_from(&description);
+            }
+        }
+        for mut status in &mut footer_status {
+            status.0.clone_from(&description);
+        }
+    }
+}
+
+/// Spawn the row of a project found by the scan in the [`ScanResults`].
+fn spawn_scan_result(
+    commands: &mut Commands,
+    theme: &Theme,
+    dialog: Entity,
+    results: Entity,
+    path: &Path,
+) {
+    let row = commands
+        .spawn((
+            ScanResult(path.to_path_buf()),
+            Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::SpaceBetween,
+                column_gap: Val::Px(8.0),
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                ..default()
+            },
+            ChildOf(results),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(path.display().to_string()),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(row),
+    ));
+    let path = path.to_path_buf();
+    spawn_dialog_button(commands, theme, "Add", theme.button.background_color.0)
+        .insert((ScanAddButton, ChildOf(row)))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                commands.run_system_cached_with(add_scanned_projects, (dialog, Some(path.clone())));
+            },
+        );
+}
+
+/// Add the given project found by the scan of the dialog to the project list, or all of them for `None`.
+#[expect(clippy::too_many_arguments)]
+fn add_scanned_projects(
+    In((dialog, path)): In<(Entity, Option<PathBuf>)>,
+    mut commands: Commands,
+    scans: Query<&ScanProjectsTask>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    project_list_entity: Single<Entity, With<ProjectList>>,
+    new_project_button: Single<Entity, With<NewProjectButton>>,
+    rows: Query<(&ScanResult, &Children)>,
+    add_buttons: Query<(), With<ScanAddButton>>,
+    children: Query<&Children>,
+    theme: Res<Theme>,
+) {
+    let Ok(scan) = scans.get(dialog) else {
+        return;
+    };
+    let candidates: Vec<PathBuf> = scan
+        .found
+        .iter()
+        .filter(|found| {
+            path.as_ref().is_none_or(|path| path == *found)
+                && !project_list
+                    .0
+                    .iter()
+                    .any(|project| is_same_project(&project.path, found))
+        })
+        .cloned()
+        .collect();
+    // The project list is saved once for all of them
+    let mut added = Vec::new();
+    for (found, result) in candidates
+        .iter()
+        .zip(import_projects(&candidates, &*store.0))
+    {
+        let project = match result {
+            Ok(project) => project,
+            Err(error) => {
+                push_toast(
+                    &mut commands,
+                    ToastLevel::Warning,
+                    format!("Couldn't add the project: {error}"),
+                );
+                continue;
+            }
+        };
+        append_project_node(
+            &mut commands,
+            *project_list_entity,
+            *new_project_button,
+            &theme,
+            &project,
+        );
+        project_list.0.push(project);
+        added.push(found.clone());
+    }
+    if added.is_empty() {
+        return;
+    }
+    info!("Added {} scanned projects", added.len());
+
+    for descendant in children.iter_descendants(dialog) {
+        let Ok((result, row_children)) = rows.get(descendant) else {
+            continue;
+        };
+        if !added.contains(&result.0) {
+            continue;
+        }
+        for child in row_children.iter() {
+            if add_buttons.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+        commands.spawn((
+            Text::new("Added"),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(theme.text.low_priority),
+            ChildOf(descendant),
+        ));
+    }
+}
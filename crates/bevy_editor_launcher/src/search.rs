@@ -0,0 +1,256 @@
+//! Filtering the project list by name, with the matched part of the names highlighted.
+//!
+//! The search box is focused with the [`LauncherAction::FocusSearch`] shortcuts, <kbd>/</kbd> or
+//! <kbd>Ctrl</kbd>/<kbd>Cmd</kbd> + <kbd>F</kbd> by default.
+
+use std::ops::Range;
+
+use bevy::{input_focus::InputFocus, prelude::*};
+use bevy_editor_styles::Theme;
+use bevy_text_editing::{CharPosition, EditableTextLine, HasFocus, RenderWidget};
+
+use crate::{
+    focus::Focusable,
+    keybindings::{Keybindings, LauncherAction},
+    ui::ProjectNode,
+};
+
+/// Plugin filtering the [`ProjectNode`]s with the [`SearchBox`].
+pub struct SearchPlugin;
+
+impl Plugin for SearchPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                focus_search_on_shortcut,
+                filter_projects,
+                update_search_hint,
+            ),
+        );
+    }
+}
+
+/// The text input used to filter the project list.
+#[derive(Component)]
+pub struct SearchBox;
+
+/// The hint shown over the [`SearchBox`] while it is empty.
+#[derive(Component)]
+struct SearchHint;
+
+/// The text displaying the name of a project, made of the part before the match,
+/// a [`TextSpan`] with the match and a [`TextSpan`] with the part after it.
+#[derive(Component)]
+pub struct ProjectNameLabel(pub String);
+
+/// Spawn the [`SearchBox`], with its [`SearchHint`] listing the shortcuts, returning their container.
+pub(crate) fn spawn_search_box<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    keybindings: &Keybindings,
+) -> EntityCommands<'a> {
+    let shortcuts = keybindings.label(LauncherAction::FocusSearch);
+    let hint = if shortcuts.is_empty() {
+        "Search projects".to_string()
+    } else {
+        format!("Search projects ({shortcuts})")
+    };
+    let mut container = commands.spawn(Node {
+        margin: UiRect::all(Val::Px(10.0)),
+        ..default()
+    });
+    container.with_children(|parent| {
+        parent.spawn((
+            SearchBox,
+            EditableTextLine::new(""),
+            Focusable(0),
+            Node {
+                width: Val::Px(300.0),
+                height: Val::Px(26.0),
+                padding: UiRect::horizontal(Val::Px(8.0)),
+                ..default()
+            },
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            theme.button.background_color,
+            theme.button.border_radius,
+        ));
+        // A sibling of the search box, as the text line manages its own children
+        parent.spawn((
+            SearchHint,
+            Text::new(hint),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(theme.text.low_priority),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(8.0),
+                top: Val::Px(4.0),
+                ..default()
+            },
+            Pickable::IGNORE,
+        ));
+    });
+    container
+}
+
+/// Focus the [`SearchBox`] and select its text on a [`LauncherAction::FocusSearch`] shortcut,
+/// unless a text field is already focused.
+fn focus_search_on_shortcut(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    keybindings: Res<Keybindings>,
+    mut focus: ResMut<InputFocus>,
+    text_fields: Query<(), With<EditableTextLine>>,
+    mut search: Query<(Entity, &mut EditableTextLine), With<SearchBox>>,
+) {
+    let shortcut = keybindings.just_pressed(LauncherAction::FocusSearch, &keys);
+    if !shortcut || focus.0.is_some_and(|focused| text_fields.contains(focused)) {
+        return;
+    }
+    let Ok((entity, mut search)) = search.single_mut() else {
+        return;
+    };
+
+    let length = search.text.chars().count();
+    search.selection_start = Some(CharPosition(0));
+    search.cursor_position = Some(CharPosition(length));
+    focus.set(entity);
+    commands.trigger_targets(RenderWidget::show_cursor(), entity);
+}
+
+/// Only show the [`SearchHint`] while the [`SearchBox`] is empty and not focused.
+fn update_search_hint(
+    search: Query<(&EditableTextLine, &HasFocus), With<SearchBox>>,
+    mut hints: Query<&mut Visibility, With<SearchHint>>,
+) {
+    let Ok((search, has_focus)) = search.single() else {
+        return;
+    };
+    let visibility = if search.text.is_empty() && !has_focus.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut hint in &mut hints {
+        hint.set_if_neq(visibility);
+    }
+}
+
+/// The label of a project, see [`ProjectNameLabel`].
+pub(crate) fn project_name_label(theme: &Theme, name: String) -> impl Bundle {
+    (
+        ProjectNameLabel(name.clone()),
+        Text::new(name),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 16.0,
+            ..default()
+        },
+        children![
+            (
+                TextSpan::default(),
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(theme.text.high_priority),
+            ),
+            (
+                TextSpan::default(),
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+            ),
+        ],
+    )
+}
+
+/// Find the first case-insensitive occurrence of `query` in `name`, as a byte range of `name`.
+fn find_match(name: &str, query: &str) -> Option<Range<usize>> {
+    if query.is_empty() {
+        return None;
+    }
+    name.char_indices().find_map(|(start, _)| {
+        let mut rest = name[start..].char_indices();
+        let mut end = start;
+        for query_char in query.chars() {
+            let (offset, name_char) = rest.next()?;
+            if !name_char.to_lowercase().eq(query_char.to_lowercase()) {
+                return None;
+            }
+            end = start + offset + name_char.len_utf8();
+        }
+        Some(start..end)
+    })
+}
+
+/// Hide the projects not matching the search and highlight the match in the others.
+fn filter_projects(
+    search: Query<Ref<EditableTextLine>, With<SearchBox>>,
+    added_nodes: Query<(), Added<ProjectNode>>,
+    mut nodes: Query<(Entity, &mut Node), With<ProjectNode>>,
+    children: Query<&Children>,
+    mut labels: Query<(&ProjectNameLabel, &mut Text, &Children)>,
+    mut spans: Query<&mut TextSpan>,
+) {
+    let Ok(search) = search.single() else {
+        return;
+    };
+    if !search.is_changed() && added_nodes.is_empty() {
+        return;
+    }
+    let query = search.text.trim();
+
+    for (entity, mut node) in &mut nodes {
+        let Some(label_entity) = children
+            .iter_descendants(entity)
+            .find(|descendant| labels.contains(*descendant))
+        else {
+            continue;
+        };
+        let Ok((label, mut text, label_children)) = labels.get_mut(label_entity) else {
+            continue;
+        };
+
+        let matched = find_match(&label.0, query);
+        node.display = if query.is_empty() || matched.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+
+        let range = matched.unwrap_or(label.0.len()..label.0.len());
+        text.0 = label.0[..range.start].to_string();
+        let parts = [&label.0[range.clone()], &label.0[range.end..]];
+        for (span, part) in label_children.iter().zip(parts) {
+            if let Ok(mut span) = spans.get_mut(span) {
+                span.0 = part.to_string();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn match_is_case_insensitive() {
+        assert_eq!(find_match("My Game", "game"), Some(3..7));
+        assert_eq!(find_match("My Game", "MY"), Some(0..2));
+        assert_eq!(find_match("Éclair", "éc"), Some(0..3));
+        assert_eq!(find_match("My Game", "games"), None);
+        assert_eq!(find_match("My Game", ""), None);
+    }
+}
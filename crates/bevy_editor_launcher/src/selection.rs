@@ -0,0 +1,528 @@
+//! Selecting several projects with <kbd>Ctrl</kbd>/<kbd>Cmd</kbd> or <kbd>Shift</kbd> + click,
+//! and the bar of the actions applied to all of them.
+
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy_editor::project::delete_projects;
+use bevy_editor_styles::{
+    Theme,
+    dialog::{ConfirmationDialog, DialogResult, spawn_dialog_button},
+};
+
+use crate::{
+    ProjectInfoList, ProjectListStore,
+    clean::confirm_clean_projects,
+    confirmations::{Confirmation, offer_dont_ask_again},
+    settings::LauncherSettings,
+    tags::{BulkLabel, show_bulk_label_dialog},
+    toast::{ToastLevel, push_toast},
+    ui::{ProjectList, ProjectNode, project_context_menu},
+};
+
+/// The amount of project names listed in the bulk delete confirmation.
+const LISTED_NAMES: usize = 5;
+/// The width of the border of the [`ProjectNode`]s, colored while they are selected.
+///
+/// The outline is left to the focus ring, so both can be seen on the same node.
+pub(crate) const SELECTION_BORDER: f32 = 2.0;
+
+/// Plugin handling the [`ProjectSelection`] and its action bar.
+pub struct SelectionPlugin;
+
+impl Plugin for SelectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProjectSelection>().add_systems(
+            Update,
+            (
+                forget_removed_projects.run_if(resource_changed::<ProjectInfoList>),
+                update_selection.run_if(resource_changed::<ProjectSelection>),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// How a click with a modifier changes the [`ProjectSelection`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SelectMode {
+    /// Add the project to the selection, or remove it if it already is selected.
+    Toggle,
+    /// Select every project from the last toggled one to this one.
+    Range,
+}
+
+impl SelectMode {
+    /// The mode of the modifiers held, `None` for a plain click.
+    pub fn from_keys(keys: &ButtonInput<KeyCode>) -> Option<Self> {
+        #[cfg(target_os = "macos")]
+        let toggle_keys = [KeyCode::SuperLeft, KeyCode::SuperRight];
+        #[cfg(not(target_os = "macos"))]
+        let toggle_keys = [KeyCode::ControlLeft, KeyCode::ControlRight];
+
+        if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            Some(SelectMode::Range)
+        } else if keys.any_pressed(toggle_keys) {
+            Some(SelectMode::Toggle)
+        } else {
+            None
+        }
+    }
+}
+
+/// The paths of the selected projects, in selection order.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct ProjectSelection {
+    selected: Vec<PathBuf>,
+    /// The project the ranges start from.
+    anchor: Option<PathBuf>,
+}
+
+impl ProjectSelection {
+    /// Whether the project is selected.
+    pub fn contains(&self, path: &PathBuf) -> bool {
+        self.selected.contains(path)
+    }
+
+    /// Select the project, or unselect it if it is already selected.
+    pub fn toggle(&mut self, path: PathBuf) {
+        if let Some(index) = self.selected.iter().position(|selected| *selected == path) {
+            self.selected.remove(index);
+        } else {
+            self.selected.push(path.clone());
+        }
+        self.anchor = Some(path);
+    }
+
+    /// Select the projects of `order` from the anchor to `path`, or just `path` without an anchor.
+    pub fn select_range(&mut self, order: &[PathBuf], path: PathBuf) {
+        let end = order.iter().position(|listed| *listed == path);
+        let start = self
+            .anchor
+            .as_ref()
+            .and_then(|anchor| order.iter().position(|listed| listed == anchor));
+        let (Some(start), Some(end)) = (start, end) else {
+            self.toggle(path);
+            return;
+        };
+        for listed in &order[start.min(end)..=start.max(end)] {
+            if !self.selected.contains(listed) {
+                self.selected.push(listed.clone());
+            }
+        }
+    }
+
+    /// Unselect every project.
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+}
+
+/// The bar of the bulk actions, shown while projects are selected.
+#[derive(Component)]
+struct SelectionBar;
+
+/// The text of the [`SelectionBar`] with the amount of selected projects.
+#[derive(Component)]
+struct SelectionCount;
+
+/// Spawn the [`SelectionBar`], hidden until projects are selected.
+pub(crate) fn spawn_selection_bar<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+) -> EntityCommands<'a> {
+    let bar = commands
+        .spawn((
+            SelectionBar,
+            Node {
+                display: Display::None,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+                ..default()
+            },
+            theme.pane.header_background_color,
+        ))
+        .id();
+    commands.spawn((
+        SelectionCount,
+        Text::default(),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        Node {
+            flex_grow: 1.0,
+            ..default()
+        },
+        ChildOf(bar),
+    ));
+
+    let actions: [(&str, fn(&mut Commands)); 7] = [
+        ("Pin", |commands| commands.run_system_cached(pin_selected)),
+        ("Tag...", |commands| {
+            commands.run_system_cached_with(label_selected, BulkLabel::Tag);
+        }),
+        ("Move to collection...", |commands| {
+            commands.run_system_cached_with(label_selected, BulkLabel::Collection);
+        }),
+        ("Remove from list", |commands| {
+            commands.run_system_cached(remove_selected);
+        }),
+        ("Clean...", |commands| {
+            commands.run_system_cached(clean_selected);
+        }),
+        ("Delete...", |commands| {
+            commands.run_system_cached(confirm_delete_selected);
+        }),
+        ("Clear selection", |commands| {
+            commands.run_system_cached(clear_selection);
+        }),
+    ];
+    for (label, action) in actions {
+        spawn_dialog_button(commands, theme, label, theme.button.background_color.0)
+            .insert(ChildOf(bar))
+            .observe(
+                move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                    action(&mut commands);
+                },
+            );
+    }
+    commands.entity(bar)
+}
+
+/// "1 project" or "`count` projects".
+pub(crate) fn project_count(count: usize) -> String {
+    match count {
+        1 => "1 project".to_string(),
+        count => format!("{count} projects"),
+    }
+}
+
+fn clear_selection(mut selection: ResMut<ProjectSelection>) {
+    selection.clear();
+}
+
+/// Change the [`ProjectSelection`] after a click with a modifier on the given [`ProjectNode`].
+pub(crate) fn select_project_node(
+    In((project_entity, mode)): In<(Entity, SelectMode)>,
+    nodes: Query<&ProjectNode>,
+    list: Single<Entity, With<ProjectList>>,
+    children: Query<&Children>,
+    mut selection: ResMut<ProjectSelection>,
+) {
+    let Ok(ProjectNode(path)) = nodes.get(project_entity) else {
+        return;
+    };
+    match mode {
+        SelectMode::Toggle => selection.toggle(path.clone()),
+        SelectMode::Range => {
+            // The nodes of the collection groups are in the order they are shown
+            let order: Vec<PathBuf> = children
+                .iter_descendants_depth_first(*list)
+                .filter_map(|child| nodes.get(child).ok())
+                .map(|node| node.0.clone())
+                .collect();
+            selection.select_range(&order, path.clone());
+        }
+    }
+}
+
+/// Unselect the projects that aren't in the list anymore.
+fn forget_removed_projects(
+    project_list: Res<ProjectInfoList>,
+    mut selection: ResMut<ProjectSelection>,
+) {
+    let removed = |path: &PathBuf| !project_list.0.iter().any(|project| project.path == *path);
+    if selection.selected.iter().any(removed) {
+        selection.selected.retain(|path| !removed(path));
+    }
+}
+
+/// Color the border of the selected [`ProjectNode`]s and show the [`SelectionBar`] while some are.
+fn update_selection(
+    selection: Res<ProjectSelection>,
+    mut nodes: Query<(&ProjectNode, &mut BorderColor)>,
+    mut bars: Query<&mut Node, With<SelectionBar>>,
+    mut counts: Query<&mut Text, With<SelectionCount>>,
+    theme: Res<Theme>,
+) {
+    for (node, mut border) in &mut nodes {
+        let color = if selection.contains(&node.0) {
+            theme.button.hover_color
+        } else {
+            Color::NONE
+        };
+        border.set_if_neq(BorderColor::all(color));
+    }
+
+    let count = selection.selected.len();
+    for mut bar in &mut bars {
+        bar.display = if count == 0 {
+            Display::None
+        } else {
+            Display::Flex
+        };
+    }
+    for mut text in &mut counts {
+        text.0 = format!("{} selected", project_count(count));
+    }
+}
+
+/// Pin the selected projects to the quick access, or unpin them if they all already are.
+fn pin_selected(
+    mut commands: Commands,
+    selection: Res<ProjectSelection>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    nodes: Query<(Entity, &ProjectNode)>,
+) {
+    let selected = |path: &PathBuf| selection.contains(path);
+    let pinned = !project_list
+        .0
+        .iter()
+        .filter(|project| selected(&project.path))
+        .all(|project| project.pinned);
+    for project in project_list
+        .0
+        .iter_mut()
+        .filter(|project| selected(&project.path))
+    {
+        project.pinned = pinned;
+    }
+    store.set(project_list.0.clone());
+    // The menu labels depend on the pinned state
+    for (entity, node) in &nodes {
+        if selected(&node.0) {
+            commands.entity(entity).insert(project_context_menu(pinned));
+        }
+    }
+}
+
+/// Ask for the tag to add to the selected projects, or the collection to move them to.
+fn label_selected(
+    In(label): In<BulkLabel>,
+    mut commands: Commands,
+    selection: Res<ProjectSelection>,
+) {
+    commands.run_system_cached_with(show_bulk_label_dialog, (label, selection.selected.clone()));
+}
+
+/// Remove the selected projects from the list, leaving their folders untouched.
+fn remove_selected(
+    mut commands: Commands,
+    mut selection: ResMut<ProjectSelection>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    nodes: Query<(Entity, &ProjectNode)>,
+) {
+    let count = selection.selected.len();
+    project_list
+        .0
+        .retain(|project| !selection.contains(&project.path));
+    store.set(project_list.0.clone());
+    for (entity, node) in &nodes {
+        if selection.contains(&node.0) {
+            commands.entity(entity).despawn();
+        }
+    }
+    selection.clear();
+    push_toast(
+        &mut commands,
+        ToastLevel::Info,
+        format!("Removed {} from the list", project_count(count)),
+    );
+}
+
+/// Ask for a confirmation before cleaning the build artifacts of the selected projects.
+fn clean_selected(mut commands: Commands, selection: Res<ProjectSelection>) {
+    commands.run_system_cached_with(confirm_clean_projects, selection.selected.clone());
+}
+
+/// Ask for a confirmation listing the selected projects before deleting them.
+fn confirm_delete_selected(
+    mut commands: Commands,
+    selection: Res<ProjectSelection>,
+    settings: Res<LauncherSettings>,
+    theme: Res<Theme>,
+) {
+    let count = selection.selected.len();
+    if count == 0 {
+        return;
+    }
+    let mut names: Vec<String> = selection
+        .selected
+        .iter()
+        .take(LISTED_NAMES)
+        .map(|path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        })
+        .collect();
+    if count > LISTED_NAMES {
+        names.push(format!("and {} more", count - LISTED_NAMES));
+    }
+    let dialog = ConfirmationDialog::new(
+        format!("Delete {}?", project_count(count)),
+        format!(
+            "Their folders will be deleted from the disk:\n- {}",
+            names.join("\n- ")
+        ),
+    );
+    // The projects listed are the ones deleted, even if the selection changes meanwhile
+    let paths = selection.selected.clone();
+    offer_dont_ask_again(dialog, Confirmation::DeleteProjects, &settings)
+        .confirm_label("Delete")
+        .spawn(&mut commands, &theme)
+        .observe(move |trigger: On<DialogResult>, mut commands: Commands| {
+            if trigger.event().0 {
+                commands.run_system_cached_with(delete_projects_at, paths.clone());
+            }
+        });
+}
+
+/// Delete the folders of the projects at the paths and remove them from the list and the
+/// selection.
+fn delete_projects_at(
+    In(paths): In<Vec<PathBuf>>,
+    mut commands: Commands,
+    mut selection: ResMut<ProjectSelection>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    nodes: Query<(Entity, &ProjectNode)>,
+) {
+    let projects: Vec<_> = project_list
+        .0
+        .iter()
+        .filter(|project| paths.contains(&project.path))
+        .cloned()
+        .collect();
+    let results = delete_projects(&projects, &*store.0);
+
+    let mut failed = Vec::new();
+    for (project, result) in projects.iter().zip(results) {
+        match result {
+            Ok(()) => {
+                project_list.0.retain(|listed| listed.path != project.path);
+                for (entity, node) in &nodes {
+                    if node.0 == project.path {
+                        commands.entity(entity).despawn();
+                    }
+                }
+            }
+            Err(error) => {
+                warn!("Failed to delete '{}': {error}", project.path.display());
+                failed.push(project.name().unwrap_or_default());
+            }
+        }
+    }
+    selection.selected.retain(|path| !paths.contains(path));
+
+    let deleted = projects.len() - failed.len();
+    if failed.is_empty() {
+        push_toast(
+            &mut commands,
+            ToastLevel::Success,
+            format!("Deleted {}", project_count(deleted)),
+        );
+    } else {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!(
+                "Deleted {}, couldn't delete {}",
+                project_count(deleted),
+                failed.join(", ")
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input_focus::{InputFocus, InputFocusVisible};
+
+    use super::*;
+    use crate::focus::update_focus_ring;
+
+    fn paths(names: &[&str]) -> Vec<PathBuf> {
+        names.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn toggle_and_select_ranges() {
+        let order = paths(&["a", "b", "c", "d", "e"]);
+        let mut selection = ProjectSelection::default();
+
+        selection.toggle(PathBuf::from("b"));
+        selection.select_range(&order, PathBuf::from("d"));
+        assert_eq!(selection.selected, paths(&["b", "c", "d"]));
+
+        selection.toggle(PathBuf::from("c"));
+        assert_eq!(selection.selected, paths(&["b", "d"]));
+        selection.select_range(&order, PathBuf::from("a"));
+        assert_eq!(selection.selected, paths(&["b", "d", "a", "c"]));
+
+        selection.clear();
+        selection.select_range(&order, PathBuf::from("e"));
+        assert_eq!(selection.selected, paths(&["e"]));
+    }
+
+    #[test]
+    fn keep_the_focus_ring_and_the_selection_apart() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_resource::<Theme>()
+            .init_resource::<ProjectSelection>()
+            .init_resource::<InputFocus>()
+            .insert_resource(InputFocusVisible(true))
+            .add_systems(Update, (update_selection, update_focus_ring).chain());
+        let selected_color = app.world().resource::<Theme>().button.hover_color;
+        let a = app
+            .world_mut()
+            .spawn((
+                ProjectNode(PathBuf::from("a")),
+                BorderColor::all(Color::NONE),
+            ))
+            .id();
+        let b = app
+            .world_mut()
+            .spawn((
+                ProjectNode(PathBuf::from("b")),
+                BorderColor::all(Color::NONE),
+            ))
+            .id();
+        let state = |app: &App, node: Entity| {
+            let entity = app.world().entity(node);
+            (
+                entity.get::<BorderColor>().unwrap().top == selected_color,
+                entity.contains::<Outline>(),
+            )
+        };
+
+        app.world_mut()
+            .resource_mut::<ProjectSelection>()
+            .toggle(PathBuf::from("a"));
+        app.world_mut().resource_mut::<InputFocus>().set(b);
+        app.update();
+        assert_eq!(state(&app, a), (true, false));
+        assert_eq!(state(&app, b), (false, true));
+
+        // Moving the focus keeps the selection
+        app.world_mut().resource_mut::<InputFocus>().set(a);
+        app.update();
+        assert_eq!(state(&app, a), (true, true));
+        assert_eq!(state(&app, b), (false, false));
+
+        // Changing the selection keeps the focus ring
+        app.world_mut()
+            .resource_mut::<ProjectSelection>()
+            .toggle(PathBuf::from("a"));
+        app.update();
+        assert_eq!(state(&app, a), (false, true));
+    }
+}
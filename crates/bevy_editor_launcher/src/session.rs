@@ -0,0 +1,163 @@
+//! Reopening the projects of the last session, when [`LauncherSettings::restore_session`] is enabled.
+//!
+//! The projects opened in the editor are remembered in [`LauncherSettings::last_session`] when
+//! the launcher exits. At the next launch, a confirmation is asked before they are opened again.
+
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy_editor::project::ProjectInfo;
+use bevy_editor_styles::{
+    Theme,
+    dialog::{ConfirmationDialog, DialogResult},
+};
+
+use crate::{
+    ProjectInfoList,
+    confirmations::{Confirmation, offer_dont_ask_again},
+    open::OpenProjectEvent,
+    settings::{LauncherSettings, OpenBehavior, OpenWith, save_settings_on_change},
+    toast::{ToastLevel, push_toast},
+};
+
+/// Plugin recording the projects opened during the session and offering to restore the last one.
+pub struct SessionPlugin;
+
+impl Plugin for SessionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProjectSession>()
+            .add_systems(Startup, offer_session_restore)
+            // Before the settings are saved, so the session is part of the exit flush
+            .add_systems(Last, record_session_on_exit.before(save_settings_on_change));
+    }
+}
+
+/// The projects opened in the editor since the launcher started, in opening order.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct ProjectSession(Vec<PathBuf>);
+
+impl ProjectSession {
+    /// Remember that the project was opened in the editor.
+    pub fn opened(&mut self, path: PathBuf) {
+        if !self.0.contains(&path) {
+            self.0.push(path);
+        }
+    }
+}
+
+/// Split the projects of the last session into the ones that can be reopened and the missing ones,
+/// which were removed from the list or whose folder is gone.
+fn restorable_projects(
+    last_session: &[PathBuf],
+    projects: &[ProjectInfo],
+) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    last_session
+        .iter()
+        .cloned()
+        .partition(|path| path.is_dir() && projects.iter().any(|project| project.path == *path))
+}
+
+fn offer_session_restore(
+    mut commands: Commands,
+    settings: Res<LauncherSettings>,
+    project_list: Res<ProjectInfoList>,
+    theme: Res<Theme>,
+) {
+    if !settings.restore_session || settings.last_session.is_empty() {
+        return;
+    }
+
+    let (restored, missing) = restorable_projects(&settings.last_session, &project_list.0);
+    if !missing.is_empty() {
+        let names: Vec<_> = missing
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!(
+                "Skipped the missing projects of the last session: {}",
+                names.join(", ")
+            ),
+        );
+    }
+    if restored.is_empty() {
+        return;
+    }
+
+    let names: Vec<_> = restored
+        .iter()
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .collect();
+    let dialog = ConfirmationDialog::new(
+        "Restore last session?",
+        format!("Reopen {} in the editor?", names.join(", ")),
+    );
+    offer_dont_ask_again(dialog, Confirmation::RestoreSession, &settings)
+        .confirm_label("Reopen")
+        .spawn(&mut commands, &theme)
+        .observe(
+            move |trigger: On<DialogResult>, mut events: EventWriter<OpenProjectEvent>| {
+                if !trigger.event().0 {
+                    return;
+                }
+                // In new windows, so the launcher doesn't close after the first project
+                events.write_batch(restored.iter().map(|path| OpenProjectEvent {
+                    path: path.clone(),
+                    behavior: Some(OpenBehavior::NewWindow),
+                    with: Some(OpenWith::Editor),
+                    target: None,
+                }));
+            },
+        );
+}
+
+/// Remember the projects of the session in the [`LauncherSettings`] when the app exits.
+fn record_session_on_exit(
+    mut exit: EventReader<AppExit>,
+    session: Res<ProjectSession>,
+    mut settings: ResMut<LauncherSettings>,
+) {
+    if exit.is_empty() {
+        return;
+    }
+    exit.clear();
+
+    // Only touched when different, to avoid an unneeded save
+    if settings.last_session != session.0 {
+        settings.last_session = session.0.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skip_missing_projects_of_the_session() {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_editor_launcher_session_test_{}",
+            std::process::id()
+        ));
+        let kept = root.join("kept");
+        let deleted = root.join("deleted");
+        let unlisted = root.join("unlisted");
+        std::fs::create_dir_all(&kept).unwrap();
+        std::fs::create_dir_all(&unlisted).unwrap();
+
+        let projects: Vec<_> = [&kept, &deleted]
+            .into_iter()
+            .map(|path| ProjectInfo::new(path.clone()))
+            .collect();
+        let (restored, missing) = restorable_projects(
+            &[kept.clone(), deleted.clone(), unlisted.clone()],
+            &projects,
+        );
+        assert_eq!(restored, vec![kept]);
+        assert_eq!(missing, vec![deleted, unlisted]);
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
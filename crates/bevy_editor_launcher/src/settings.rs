@@ -0,0 +1,483 @@
+//! The launcher settings, persisted in the Bevy Editor's data folder.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bevy::prelude::*;
+use bevy_editor::project::{data_dir, retry::DEFAULT_RETRIES, templates::Templates};
+use bevy_editor_styles::ThemeMode;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    keybindings::LauncherAction,
+    save_status::{FailureKind, RetrySaveEvent, SaveFailures, SaveTarget, report_save},
+};
+
+/// The name of the launcher settings file, inside the Bevy Editor's data folder.
+const SETTINGS_FILE: &str = "launcher_settings.ron";
+/// How long to wait after the last change before writing the settings to disk.
+const SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+/// How long to wait before saving the settings again after a transient failure.
+const SAVE_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Plugin saving the [`LauncherSettings`] to disk when they change.
+///
+/// Pending changes are written right away when the app exits. When a save fails, the settings are
+/// kept in memory and saved again until it succeeds, see [`SaveFailures`].
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<LauncherSettings>() {
+            app.insert_resource(load_settings());
+        }
+        app.init_resource::<SettingsFile>()
+            .init_resource::<PendingSettingsSave>()
+            .init_resource::<SaveFailures>()
+            .add_event::<RetrySaveEvent>()
+            .add_systems(
+                Last,
+                (save_settings_on_change, flush_settings_on_exit).chain(),
+            );
+    }
+}
+
+/// The user preferences of the launcher.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LauncherSettings {
+    /// The state of the launcher window, restored at startup.
+    pub window: WindowState,
+    /// What happens to the launcher when a project is opened.
+    pub open_behavior: OpenBehavior,
+    /// The tool the projects are opened with, the last one picked from the "Open with" options.
+    pub open_with: OpenWith,
+    /// The command of the [`OpenWith::Custom`] tool.
+    ///
+    /// `{path}` is replaced by the project folder, for example `zed {path}`, and `{target}` by the
+    /// file to open in it, if any.
+    pub open_with_command: Option<String>,
+    /// Whether a newly created project is opened right away.
+    pub open_after_creation: bool,
+    /// The number of log lines kept in memory for each project creation, the oldest are dropped
+    /// past it. `None` keeps all of them.
+    pub creation_log_lines: Option<usize>,
+    /// Whether the full log of each project creation is written to the `logs` folder of the Bevy
+    /// Editor's data folder, including the lines dropped from memory.
+    pub creation_log_file: bool,
+    /// Whether the creation logs show the newest lines at the top, instead of at the bottom.
+    pub creation_log_newest_first: bool,
+    /// The command used to open a terminal in a project folder, instead of the platform default.
+    ///
+    /// `{path}` is replaced by the project folder, for example `wezterm start --cwd {path}`.
+    pub terminal_command: Option<String>,
+    /// Where the progress of the project creations is displayed.
+    pub creation_progress: ProgressDisplay,
+    /// How the launcher finds out that a project creation is done.
+    pub task_completion: TaskCompletion,
+    /// How many of the tasks running cargo, like creating, checking or cleaning projects, run at
+    /// once, the others wait for one to finish. `None` runs all of them at once.
+    pub max_concurrent_tasks: Option<usize>,
+    /// Whether closing the window keeps the launcher running in the system tray, when available.
+    pub minimize_to_tray: bool,
+    /// Whether the launcher window is kept above the other windows, where the platform allows it.
+    pub always_on_top: bool,
+    /// The template category last selected in the new project window, `None` for all of them.
+    pub template_category: Option<String>,
+    /// The templates last used to create a project, the most recent first.
+    ///
+    /// The ones that aren't available anymore, like removed custom templates, are dropped
+    /// when the new project window opens.
+    pub recent_templates: Vec<Templates>,
+    /// The Bevy version the projects are compared to, like `0.16`, older ones get an outdated badge.
+    ///
+    /// `None` compares them to the newest version among the projects if
+    /// [`detect_bevy_version`](Self::detect_bevy_version) is set, and disables the comparison
+    /// otherwise.
+    pub current_bevy_version: Option<String>,
+    /// Whether the projects are compared to the newest Bevy version among them, when
+    /// [`current_bevy_version`](Self::current_bevy_version) isn't set.
+    pub detect_bevy_version: bool,
+    /// How many minor Bevy versions a project can be behind the current one before it gets the
+    /// outdated badge.
+    pub outdated_bevy_gap: u64,
+    /// The badges shown on the project nodes, from left to right, the others are hidden.
+    pub badges: Vec<BadgeKind>,
+    /// Whether the launcher is dark, light or high contrast, applied at startup.
+    pub theme: ThemeMode,
+    /// The order of the project list.
+    pub project_sort: ProjectSort,
+    /// The folder the new projects are created in, when the [`LauncherPlugin`](crate::LauncherPlugin)
+    /// doesn't set one.
+    pub default_project_root: Option<PathBuf>,
+    /// Whether the animations are skipped, for accessibility.
+    pub reduce_motion: bool,
+    /// How long collapsing or expanding a section like the quick access or a collection takes, in
+    /// milliseconds.
+    pub collapse_animation_ms: u64,
+    /// Whether the quick access row above the project list is collapsed.
+    pub quick_access_collapsed: bool,
+    /// The names of the collections whose group is collapsed in the project list.
+    pub collapsed_collections: BTreeSet<String>,
+    /// Whether to offer reopening the projects of the last session at startup.
+    pub restore_session: bool,
+    /// The projects opened in the editor during the last session, recorded when the launcher exits.
+    pub last_session: Vec<PathBuf>,
+    /// The shortcuts replacing the default ones of their action, like `NewProject: ["Ctrl+Shift+N"]`.
+    ///
+    /// An empty list unbinds the action, see [`Keybindings`](crate::Keybindings) for the syntax.
+    pub keybindings: BTreeMap<LauncherAction, Vec<String>>,
+    /// The keys of the confirmations the user asked not to show again, like `restore_session`.
+    ///
+    /// Empty it to ask for all the confirmations again.
+    pub suppressed_confirmations: BTreeSet<String>,
+    /// Whether the launcher checks the network connection regularly, to disable the Git templates
+    /// while offline.
+    pub check_connectivity: bool,
+    /// How many times a network step, like cloning a Git template, is retried after failing
+    /// transiently, waiting longer each time. 0 fails right away.
+    pub network_retries: u32,
+    /// Whether the confirmations deleting files, like cleaning or deleting projects, also offer not
+    /// to be asked again.
+    pub suppress_file_deleting_confirmations: bool,
+    /// The width of the details panel in pixels, changed by dragging its edge.
+    pub details_panel_width: f32,
+    /// The launcher version whose "What's new" panel was dismissed, it shows again for newer ones.
+    pub last_seen_version: Option<String>,
+}
+
+impl Default for LauncherSettings {
+    fn default() -> Self {
+        Self {
+            window: WindowState::default(),
+            open_behavior: OpenBehavior::default(),
+            open_with: OpenWith::default(),
+            open_with_command: None,
+            open_after_creation: true,
+            creation_log_lines: None,
+            creation_log_file: false,
+            creation_log_newest_first: false,
+            terminal_command: None,
+            creation_progress: ProgressDisplay::default(),
+            task_completion: TaskCompletion::default(),
+            max_concurrent_tasks: None,
+            minimize_to_tray: false,
+            always_on_top: false,
+            template_category: None,
+            recent_templates: Vec::new(),
+            current_bevy_version: None,
+            detect_bevy_version: true,
+            outdated_bevy_gap: 1,
+            badges: BadgeKind::ALL.to_vec(),
+            theme: ThemeMode::default(),
+            project_sort: ProjectSort::default(),
+            default_project_root: None,
+            reduce_motion: false,
+            collapse_animation_ms: 150,
+            quick_access_collapsed: false,
+            collapsed_collections: BTreeSet::new(),
+            restore_session: false,
+            last_session: Vec::new(),
+            keybindings: BTreeMap::new(),
+            suppressed_confirmations: BTreeSet::new(),
+            check_connectivity: true,
+            network_retries: DEFAULT_RETRIES,
+            suppress_file_deleting_confirmations: false,
+            details_panel_width: 340.0,
+            last_seen_version: None,
+        }
+    }
+}
+
+/// What happens to the launcher when a project is opened.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OpenBehavior {
+    /// The project replaces the launcher, which closes itself.
+    #[default]
+    Replace,
+    /// The project opens in a new window and the launcher stays open.
+    NewWindow,
+}
+
+/// The tool a project is opened with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OpenWith {
+    /// The Bevy editor, running the project with cargo.
+    #[default]
+    Editor,
+    /// Visual Studio Code, with its `code` command.
+    VsCode,
+    /// The [`LauncherSettings::open_with_command`].
+    Custom,
+}
+
+/// Where the progress of long running operations is displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProgressDisplay {
+    /// In an overlay, that can be hidden, and in the footer bar.
+    #[default]
+    Overlay,
+    /// Only in the footer bar.
+    FooterOnly,
+}
+
+/// The order of the project list.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProjectSort {
+    /// The order the projects were added to the list in.
+    #[default]
+    Added,
+    /// The most recently opened first.
+    RecentlyOpened,
+    /// The most often opened first.
+    MostUsed,
+    /// Alphabetically by name.
+    Name,
+}
+
+/// A badge of the project nodes, see [`LauncherSettings::badges`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BadgeKind {
+    /// The project folder doesn't exist anymore.
+    Missing,
+    /// The folder isn't a Bevy project.
+    NotAProject,
+    /// The project depends on an outdated Bevy version.
+    OutdatedBevy,
+    /// The git repository of the project has uncommitted changes.
+    UncommittedChanges,
+    /// The project is opened frequently.
+    FrequentlyOpened,
+}
+
+/// How the launcher finds out that its background tasks are done.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TaskCompletion {
+    /// Each task is polled every frame until it is done.
+    #[default]
+    Polling,
+    /// The tasks send their result over a channel, checked once per frame for all of them.
+    Channel,
+}
+
+/// The last known state of the launcher window.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowState {
+    /// The name of the monitor the window was on.
+    pub monitor_name: Option<String>,
+    /// The index of the monitor the window was on, sorted by position, used when the name isn't available.
+    pub monitor_index: Option<usize>,
+    /// The position of the window relative to its monitor, in logical pixels.
+    pub position: Option<(f32, f32)>,
+    /// The size of the window, in logical pixels.
+    pub size: Option<(f32, f32)>,
+}
+
+/// The file the [`LauncherSettings`] are saved to, in the Bevy Editor's data folder by default.
+#[derive(Resource, Debug, Clone)]
+pub struct SettingsFile(pub PathBuf);
+
+impl Default for SettingsFile {
+    fn default() -> Self {
+        Self(data_dir().join(SETTINGS_FILE))
+    }
+}
+
+/// Timer started when the [`LauncherSettings`] change, the settings are saved once it finishes.
+#[derive(Resource, Default)]
+struct PendingSettingsSave(Option<Timer>);
+
+/// Load the [`LauncherSettings`] from disk, falling back to the default ones.
+pub fn load_settings() -> LauncherSettings {
+    let path = data_dir().join(SETTINGS_FILE);
+    if !path.exists() {
+        return LauncherSettings::default();
+    }
+
+    let result = File::open(&path).and_then(|file| {
+        ron::de::from_reader(file).map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Couldn't parse launcher settings file: {error}"),
+            )
+        })
+    });
+    match result {
+        Ok(settings) => settings,
+        Err(error) => {
+            warn!("Failed to load launcher settings: {:?}", error);
+            LauncherSettings::default()
+        }
+    }
+}
+
+/// Save the [`LauncherSettings`] to disk.
+pub fn save_settings(settings: &LauncherSettings) -> io::Result<()> {
+    save_settings_to(&SettingsFile::default().0, settings)
+}
+
+/// Save the [`LauncherSettings`] to the given file.
+pub fn save_settings_to(path: &Path, settings: &LauncherSettings) -> io::Result<()> {
+    if let Some(folder) = path.parent() {
+        std::fs::create_dir_all(folder)?;
+    }
+    let file = File::create(path)?;
+    ron::Options::default()
+        .to_io_writer_pretty(file, settings, ron::ser::PrettyConfig::default())
+        .map_err(|error| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Failed to serialize launcher settings: {error}"),
+            )
+        })
+}
+
+pub(crate) fn save_settings_on_change(
+    settings: Res<LauncherSettings>,
+    mut pending: ResMut<PendingSettingsSave>,
+    mut failures: ResMut<SaveFailures>,
+    mut retry: EventReader<RetrySaveEvent>,
+    file: Res<SettingsFile>,
+    time: Res<Time>,
+) {
+    if settings.is_changed() && !settings.is_added() {
+        pending.0 = Some(Timer::new(SAVE_DEBOUNCE, TimerMode::Once));
+    }
+
+    let retry = retry.read().count() > 0 && failures.get(SaveTarget::Settings).is_some();
+    let due = pending
+        .0
+        .as_mut()
+        .is_some_and(|timer| timer.tick(time.delta()).is_finished());
+    if !due && !retry {
+        return;
+    }
+
+    pending.0 = None;
+    let result = save_settings_to(&file.0, &settings);
+    // A permanent failure waits for the next change or a retry of the user
+    if report_save(&mut failures, SaveTarget::Settings, &result) == Some(FailureKind::Transient) {
+        pending.0 = Some(Timer::new(SAVE_RETRY_INTERVAL, TimerMode::Once));
+    }
+}
+
+/// Write the pending changes without waiting for the debounce when the app is about to exit.
+fn flush_settings_on_exit(
+    mut exit: EventReader<AppExit>,
+    settings: Res<LauncherSettings>,
+    mut pending: ResMut<PendingSettingsSave>,
+    mut failures: ResMut<SaveFailures>,
+    file: Res<SettingsFile>,
+) {
+    if exit.is_empty() {
+        return;
+    }
+    exit.clear();
+
+    if pending.0.take().is_some() || failures.get(SaveTarget::Settings).is_some() {
+        info!("Saving launcher settings before exiting");
+        let result = save_settings_to(&file.0, &settings);
+        report_save(&mut failures, SaveTarget::Settings, &result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flush_pending_settings_on_exit() {
+        let path = std::env::temp_dir()
+            .join(format!("bevy_editor_launcher_test_{}", std::process::id()))
+            .join(SETTINGS_FILE);
+        let _ = std::fs::remove_file(&path);
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<AppExit>()
+            .insert_resource(SettingsFile(path.clone()))
+            .insert_resource(LauncherSettings::default())
+            .add_plugins(SettingsPlugin);
+        app.update();
+
+        // The time never advances, the debounced save can only happen through the exit flush
+        app.world_mut()
+            .resource_mut::<LauncherSettings>()
+            .open_after_creation = false;
+        app.update();
+        assert!(!path.exists());
+
+        app.world_mut().write_event(AppExit::Success);
+        app.update();
+
+        let saved: LauncherSettings = ron::de::from_reader(File::open(&path).unwrap()).unwrap();
+        assert!(!saved.open_after_creation);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn warn_when_the_settings_fail_to_save() {
+        // The parent of the settings file is a file, so its folder can't be created
+        let blocker = std::env::temp_dir().join(format!(
+            "bevy_editor_launcher_test_blocked_{}",
+            std::process::id()
+        ));
+        std::fs::write(&blocker, "").unwrap();
+        let path = blocker.join(SETTINGS_FILE);
+
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .add_event::<AppExit>()
+            .insert_resource(SettingsFile(path.clone()))
+            .insert_resource(LauncherSettings::default())
+            .add_plugins(SettingsPlugin);
+        app.update();
+        assert!(
+            app.world()
+                .resource::<SaveFailures>()
+                .get(SaveTarget::Settings)
+                .is_none()
+        );
+
+        app.world_mut()
+            .resource_mut::<LauncherSettings>()
+            .open_after_creation = false;
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(SAVE_DEBOUNCE);
+        app.update();
+        let failure = app
+            .world()
+            .resource::<SaveFailures>()
+            .get(SaveTarget::Settings)
+            .cloned()
+            .expect("the failed save is reported");
+        assert_eq!(failure.kind, FailureKind::Permanent);
+        // The change is kept in memory, waiting for a successful save
+        assert!(
+            !app.world()
+                .resource::<LauncherSettings>()
+                .open_after_creation
+        );
+
+        // Retrying once the cause is fixed clears the failure
+        std::fs::remove_file(&blocker).unwrap();
+        app.world_mut().write_event(RetrySaveEvent);
+        app.update();
+        assert!(
+            app.world()
+                .resource::<SaveFailures>()
+                .get(SaveTarget::Settings)
+                .is_none()
+        );
+        let saved: LauncherSettings = ron::de::from_reader(File::open(&path).unwrap()).unwrap();
+        assert!(!saved.open_after_creation);
+        let _ = std::fs::remove_dir_all(&blocker);
+    }
+}
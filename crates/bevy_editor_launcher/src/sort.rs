@@ -0,0 +1,188 @@
+//! Sorting the project list, by the order the projects were added, how recently or how often they
+//! were opened, or their name.
+//!
+//! The [`ProjectSort`] picked in the top bar is stored in the [`LauncherSettings`].
+
+use std::cmp::Reverse;
+
+use bevy::prelude::*;
+use bevy_editor::project::ProjectInfo;
+use bevy_editor_styles::{
+    Theme,
+    dropdown::{DropdownChanged, spawn_dropdown},
+};
+
+use crate::{
+    ProjectInfoList,
+    settings::{LauncherSettings, ProjectSort},
+    ui::ProjectNode,
+};
+
+impl ProjectSort {
+    /// All the sorts, in the order of the dropdown.
+    pub const ALL: [ProjectSort; 4] = [
+        ProjectSort::Added,
+        ProjectSort::RecentlyOpened,
+        ProjectSort::MostUsed,
+        ProjectSort::Name,
+    ];
+
+    /// The name of the sort, for the dropdown.
+    pub fn label(self) -> &'static str {
+        match self {
+            ProjectSort::Added => "Date added",
+            ProjectSort::RecentlyOpened => "Recently opened",
+            ProjectSort::MostUsed => "Most used",
+            ProjectSort::Name => "Name",
+        }
+    }
+}
+
+/// Plugin keeping the [`ProjectNode`]s in the order of the [`ProjectSort`].
+pub struct SortPlugin;
+
+impl Plugin for SortPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, sort_project_nodes);
+    }
+}
+
+/// Spawn the dropdown picking the [`ProjectSort`] of the settings.
+pub(crate) fn spawn_sort_dropdown<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    selected: ProjectSort,
+) -> EntityCommands<'a> {
+    let index = ProjectSort::ALL
+        .iter()
+        .position(|sort| *sort == selected)
+        .unwrap_or_default();
+    let mut dropdown_ec = spawn_dropdown(
+        commands,
+        theme,
+        ProjectSort::ALL.map(ProjectSort::label),
+        index,
+    );
+    dropdown_ec.observe(
+        |trigger: On<DropdownChanged>, mut settings: ResMut<LauncherSettings>| {
+            if let Some(sort) = ProjectSort::ALL.get(trigger.event().0) {
+                settings.project_sort = *sort;
+            }
+        },
+    );
+    dropdown_ec
+}
+
+/// The order of the projects for the sort, as indices into `projects`.
+///
+/// The projects are given in the order they were added, which breaks the ties.
+fn sorted_indices(projects: &[&ProjectInfo], sort: ProjectSort) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..projects.len()).collect();
+    match sort {
+        ProjectSort::Added => {}
+        ProjectSort::RecentlyOpened => {
+            indices.sort_by_key(|index| Reverse(projects[*index].last_opened));
+        }
+        ProjectSort::MostUsed => indices.sort_by_key(|index| {
+            let project = projects[*index];
+            (Reverse(project.open_count), Reverse(project.last_opened))
+        }),
+        ProjectSort::Name => indices
+            .sort_by_cached_key(|index| projects[*index].name().unwrap_or_default().to_lowercase()),
+    }
+    indices
+}
+
+/// Reorder the [`ProjectNode`]s when the sort, the nodes or the projects change.
+///
+/// The nodes are sorted inside the [`ProjectList`](crate::ui::ProjectList) and inside each
+/// [`CollectionGroup`](crate::collection_groups::CollectionGroup). The other children of the lists,
+/// like the new project button or the groups, stay after the nodes.
+pub(crate) fn sort_project_nodes(
+    mut commands: Commands,
+    settings: Res<LauncherSettings>,
+    project_list: Res<ProjectInfoList>,
+    moved: Query<(), (With<ProjectNode>, Changed<ChildOf>)>,
+    nodes: Query<(&ProjectNode, &ChildOf)>,
+    children: Query<&Children>,
+    mut sorted_by: Local<Option<ProjectSort>>,
+) {
+    if *sorted_by == Some(settings.project_sort) && moved.is_empty() && !project_list.is_changed() {
+        return;
+    }
+    *sorted_by = Some(settings.project_sort);
+
+    let mut lists: Vec<Entity> = nodes.iter().map(|(_, ChildOf(list))| *list).collect();
+    lists.sort();
+    lists.dedup();
+    for list in lists {
+        let Ok(children) = children.get(list) else {
+            continue;
+        };
+        // The nodes of the projects, in the order the projects were added
+        let mut listed: Vec<(Entity, &ProjectInfo)> = children
+            .iter()
+            .filter_map(|child| {
+                let (ProjectNode(path), _) = nodes.get(child).ok()?;
+                let project = project_list
+                    .0
+                    .iter()
+                    .find(|project| project.path == *path)?;
+                Some((child, project))
+            })
+            .collect();
+        listed.sort_by_key(|(_, project)| {
+            project_list
+                .0
+                .iter()
+                .position(|listed| listed.path == project.path)
+        });
+        let projects: Vec<&ProjectInfo> = listed.iter().map(|(_, project)| *project).collect();
+        let mut order: Vec<Entity> = sorted_indices(&projects, settings.project_sort)
+            .into_iter()
+            .map(|index| listed[index].0)
+            .collect();
+        let others: Vec<Entity> = children
+            .iter()
+            .filter(|child| !order.contains(child))
+            .collect();
+        order.extend(others);
+
+        if !order.iter().copied().eq(children.iter()) {
+            commands.entity(list).replace_children(&order);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::*;
+
+    fn project(path: &str, opened_secs: u64, open_count: u32) -> ProjectInfo {
+        ProjectInfo {
+            last_opened: SystemTime::UNIX_EPOCH + Duration::from_secs(opened_secs),
+            open_count,
+            ..ProjectInfo::new(path)
+        }
+    }
+
+    #[test]
+    fn sort_the_projects() {
+        let projects = [
+            project("/projects/tool", 30, 2),
+            project("/projects/Game", 10, 8),
+            project("/projects/demo", 20, 2),
+        ];
+        let projects: Vec<&ProjectInfo> = projects.iter().collect();
+        assert_eq!(sorted_indices(&projects, ProjectSort::Added), [0, 1, 2]);
+        assert_eq!(
+            sorted_indices(&projects, ProjectSort::RecentlyOpened),
+            [0, 2, 1]
+        );
+        // The ties are broken by the most recent opening
+        assert_eq!(sorted_indices(&projects, ProjectSort::MostUsed), [1, 0, 2]);
+        assert_eq!(sorted_indices(&projects, ProjectSort::Name), [2, 1, 0]);
+    }
+}
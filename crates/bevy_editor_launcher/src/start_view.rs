@@ -0,0 +1,195 @@
+//! Opening the launcher straight into a view given on the command line, for scripts and links, see
+//! [`StartView`].
+//!
+//! Unlike the [`HeadlessCommand`](crate::HeadlessCommand)s, the launcher opens as usual, the view
+//! is only shown once it is set up.
+
+use std::path::PathBuf;
+
+use bevy::{
+    input_focus::{InputFocus, InputFocusVisible},
+    prelude::*,
+};
+use bevy_editor::project::{is_same_project, project_exists};
+use bevy_scroll_box::ScrollBox;
+
+use crate::{
+    ProjectInfoList,
+    details::show_project_details,
+    first_run::show_setup_dialog,
+    new_project::open_new_project_window,
+    settings::SettingsFile,
+    toast::{ToastLevel, push_toast},
+    ui::{ProjectList, ProjectNode},
+};
+
+/// The flag opening the new project window.
+const NEW_PROJECT_FLAG: &str = "--new-project";
+/// The flag opening the settings.
+const SETTINGS_FLAG: &str = "--settings";
+/// The flag focusing a project of the list, followed by its folder.
+const FOCUS_PROJECT_FLAG: &str = "--focus-project";
+
+/// Plugin opening the [`StartView`], when inserted as a resource before running the app.
+pub(crate) struct StartViewPlugin;
+
+impl Plugin for StartViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, open_start_view.run_if(resource_exists::<StartView>));
+    }
+}
+
+/// The view the launcher opens into, inserted as a resource before running the app.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub enum StartView {
+    /// The new project window.
+    NewProject,
+    /// The settings, in the dialog of the first run setup.
+    Settings,
+    /// The project list, scrolled to the project in the folder, focused and its details open.
+    ///
+    /// The list is shown as usual when the folder isn't a project of the list.
+    Project(PathBuf),
+}
+
+impl StartView {
+    /// The view given by the command line arguments, without the program name, `None` when they
+    /// don't ask for one.
+    pub fn from_args(args: &[String]) -> Result<Option<Self>, String> {
+        match args.first().map(String::as_str) {
+            Some(NEW_PROJECT_FLAG) => Ok(Some(StartView::NewProject)),
+            Some(SETTINGS_FLAG) => Ok(Some(StartView::Settings)),
+            Some(FOCUS_PROJECT_FLAG) => match args.get(1).filter(|path| !path.starts_with("--")) {
+                Some(path) => Ok(Some(StartView::Project(PathBuf::from(path)))),
+                None => Err(format!("{FOCUS_PROJECT_FLAG} needs a folder")),
+            },
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Open the [`StartView`] once the launcher is set up, then forget it.
+///
+/// A project of the list is only focused once the project list is laid out, to scroll to it. The
+/// folders that aren't in the list are reported right away.
+#[expect(clippy::too_many_arguments)]
+fn open_start_view(
+    mut commands: Commands,
+    start_view: Res<StartView>,
+    settings_file: Res<SettingsFile>,
+    project_list: Res<ProjectInfoList>,
+    lists: Query<(Entity, &ComputedNode), With<ProjectList>>,
+    nodes: Query<(Entity, &ProjectNode)>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+) {
+    match &*start_view {
+        StartView::NewProject => commands.run_system_cached(open_new_project_window),
+        // Without a settings file, the first run setup asking for them is already open
+        StartView::Settings if settings_file.0.exists() => {
+            commands.run_system_cached_with(show_setup_dialog, false);
+        }
+        StartView::Settings => {}
+        StartView::Project(path) => {
+            let listed = project_list
+                .0
+                .iter()
+                .any(|project| is_same_project(&project.path, path));
+            let reason = if listed {
+                let Ok((list, list_node)) = lists.single() else {
+                    return;
+                };
+                if list_node.is_empty() {
+                    return;
+                }
+                let node = nodes
+                    .iter()
+                    .find(|(_, ProjectNode(shown))| is_same_project(shown, path));
+                match node {
+                    Some((node, _)) => {
+                        focus.set(node);
+                        focus_visible.0 = true;
+                        commands.run_system_cached_with(scroll_to_project_node, (list, node));
+                        commands.run_system_cached_with(show_project_details, node);
+                        None
+                    }
+                    None => Some("isn't shown in the project list"),
+                }
+            } else if project_exists(path) {
+                Some("isn't in the project list")
+            } else {
+                Some("doesn't exist")
+            };
+            if let Some(reason) = reason {
+                warn!("Can't focus '{}', it {reason}", path.display());
+                push_toast(
+                    &mut commands,
+                    ToastLevel::Warning,
+                    format!("'{}' {reason}, showing all the projects", path.display()),
+                );
+            }
+        }
+    }
+    commands.remove_resource::<StartView>();
+}
+
+/// Scroll the [`ProjectList`] for the row of the [`ProjectNode`] to be at its top.
+fn scroll_to_project_node(
+    In((list, node)): In<(Entity, Entity)>,
+    nodes: Query<(&ComputedNode, &UiGlobalTransform)>,
+    parents: Query<&ChildOf>,
+    mut scroll_boxes: Query<(&mut ScrollBox, &ComputedNode)>,
+) {
+    let (Ok((list_node, list_transform)), Ok((project_node, project_transform))) =
+        (nodes.get(list), nodes.get(node))
+    else {
+        return;
+    };
+    let Ok(ChildOf(box_entity)) = parents.get(list) else {
+        return;
+    };
+    let Ok((mut scroll_box, box_node)) = scroll_boxes.get_mut(*box_entity) else {
+        return;
+    };
+    // The transforms are the centers of the nodes, in physical pixels
+    let top = |node: &ComputedNode, transform: &UiGlobalTransform| {
+        transform.translation.y - node.size().y / 2.0
+    };
+    let scale = list_node.inverse_scale_factor();
+    let offset = (top(project_node, project_transform) - top(list_node, list_transform)) * scale;
+    let content_height = list_node.size().y * scale;
+    let box_height = box_node.size().y * box_node.inverse_scale_factor();
+    scroll_box.scroll_to(offset.min((content_height - box_height).max(0.0)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn read_the_start_view() {
+        assert_eq!(StartView::from_args(&args(&[])), Ok(None));
+        assert_eq!(
+            StartView::from_args(&args(&["--create-project", "/games/space"])),
+            Ok(None)
+        );
+        assert_eq!(
+            StartView::from_args(&args(&["--new-project"])),
+            Ok(Some(StartView::NewProject))
+        );
+        assert_eq!(
+            StartView::from_args(&args(&["--settings"])),
+            Ok(Some(StartView::Settings))
+        );
+        assert_eq!(
+            StartView::from_args(&args(&["--focus-project", "/games/space"])),
+            Ok(Some(StartView::Project(PathBuf::from("/games/space"))))
+        );
+        assert!(StartView::from_args(&args(&["--focus-project"])).is_err());
+        assert!(StartView::from_args(&args(&["--focus-project", "--settings"])).is_err());
+    }
+}
@@ -0,0 +1,340 @@
+//! The tags and the collection of the projects, shown as chips on their [`ProjectNode`]s, and the
+//! dialog labeling several projects at once from the selection bar.
+//!
+//! Removing a tag from its chip only changes that project.
+
+use std::path::PathBuf;
+
+use bevy::{input_focus::InputFocus, prelude::*};
+use bevy_editor_styles::{
+    Theme,
+    chip::{Chip, ChipRemoved, ChipRole},
+    dialog::{DialogResult, close_dialog, spawn_dialog_button, spawn_dialog_root},
+    text_input::{TextInputSubmit, TextInputValue, spawn_text_input},
+};
+
+use crate::{
+    ProjectInfoList, ProjectListStore,
+    selection::project_count,
+    toast::{ToastLevel, push_toast},
+    ui::ProjectNode,
+};
+
+/// Plugin showing the tags and collections of the [`ProjectNode`]s.
+pub struct TagsPlugin;
+
+impl Plugin for TagsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_tag_rows);
+    }
+}
+
+/// The row of the tag chips of a [`ProjectNode`], placed over its preview.
+#[derive(Component)]
+struct TagRow;
+
+/// The tags and the collection shown by the [`TagRow`] of a [`ProjectNode`].
+#[derive(Component, PartialEq, Eq)]
+struct ShownTags {
+    tags: Vec<String>,
+    collection: Option<String>,
+}
+
+/// What the [`BulkLabelDialog`] changes on the projects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BulkLabel {
+    /// Add a tag to the projects.
+    Tag,
+    /// Move the projects to a collection, or out of their collection when empty.
+    Collection,
+}
+
+/// The dialog labeling several projects at once.
+#[derive(Component)]
+struct BulkLabelDialog {
+    label: BulkLabel,
+    projects: Vec<PathBuf>,
+    /// The input of the tag or the collection.
+    input: Entity,
+}
+
+/// Open the dialog adding a tag to the projects, or moving them to a collection.
+pub(crate) fn show_bulk_label_dialog(
+    In((label, projects)): In<(BulkLabel, Vec<PathBuf>)>,
+    mut commands: Commands,
+    project_list: Res<ProjectInfoList>,
+    theme: Res<Theme>,
+) {
+    if projects.is_empty() {
+        return;
+    }
+    let (title, hint, placeholder, confirm) = match label {
+        BulkLabel::Tag => (
+            format!("Tag {}", project_count(projects.len())),
+            "The tag is added to the tags they already have.",
+            "prototype",
+            "Tag",
+        ),
+        BulkLabel::Collection => (
+            format!("Move {} to a collection", project_count(projects.len())),
+            "Leave the name empty to take them out of their collection.",
+            "Game jams",
+            "Move",
+        ),
+    };
+    // The collection they all are in is the one to edit
+    let current = match label {
+        BulkLabel::Tag => String::new(),
+        BulkLabel::Collection => {
+            let mut collections = project_list
+                .0
+                .iter()
+                .filter(|project| projects.contains(&project.path))
+                .map(|project| project.collection.clone());
+            let first = collections.next().flatten();
+            if collections.all(|collection| collection == first) {
+                first.unwrap_or_default()
+            } else {
+                String::new()
+            }
+        }
+    };
+
+    let dialog = spawn_dialog_root(&mut commands);
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(420.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(title),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+    commands.spawn((
+        Text::new(hint),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(panel),
+    ));
+    let input = spawn_text_input(&mut commands, &theme, current, placeholder)
+        .insert(ChildOf(panel))
+        .observe(
+            move |_trigger: On<TextInputSubmit>, mut commands: Commands| {
+                commands.run_system_cached_with(apply_bulk_label, dialog);
+            },
+        )
+        .id();
+    commands.entity(dialog).insert(BulkLabelDialog {
+        label,
+        projects,
+        input,
+    });
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Cancel",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            close_dialog(&mut commands, dialog, false);
+        },
+    );
+    spawn_dialog_button(&mut commands, &theme, confirm, theme.button.hover_color)
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                commands.run_system_cached_with(apply_bulk_label, dialog);
+            },
+        );
+    commands.run_system_cached_with(focus_label_input, input);
+}
+
+/// Focus the input of a [`BulkLabelDialog`], ready to type.
+fn focus_label_input(
+    In(input): In<Entity>,
+    children: Query<&Children>,
+    mut focus: ResMut<InputFocus>,
+) {
+    if let Some(line) = children
+        .get(input)
+        .ok()
+        .and_then(|children| children.first().copied())
+    {
+        focus.set(line);
+    }
+}
+
+/// Apply the tag or the collection typed in the [`BulkLabelDialog`] to its projects, saving the
+/// list once.
+fn apply_bulk_label(
+    In(dialog): In<Entity>,
+    mut commands: Commands,
+    dialogs: Query<&BulkLabelDialog>,
+    inputs: Query<&TextInputValue>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+) {
+    let Ok(bulk) = dialogs.get(dialog) else {
+        return;
+    };
+    let value = inputs
+        .get(bulk.input)
+        .map(|value| value.0.trim().to_string())
+        .unwrap_or_default();
+    if bulk.label == BulkLabel::Tag && value.is_empty() {
+        push_toast(&mut commands, ToastLevel::Error, "Type the tag to add");
+        return;
+    }
+
+    let mut changed = 0;
+    for project in project_list
+        .0
+        .iter_mut()
+        .filter(|project| bulk.projects.contains(&project.path))
+    {
+        let project_changed = match bulk.label {
+            BulkLabel::Tag => project.add_tag(&value),
+            BulkLabel::Collection => {
+                let collection = (!value.is_empty()).then(|| value.clone());
+                let moved = project.collection != collection;
+                project.collection = collection;
+                moved
+            }
+        };
+        changed += usize::from(project_changed);
+    }
+    if changed > 0 {
+        store.set(project_list.0.clone());
+    }
+    let message = match bulk.label {
+        BulkLabel::Tag => format!("Tagged {} with {value}", project_count(changed)),
+        BulkLabel::Collection if value.is_empty() => {
+            format!("Took {} out of their collection", project_count(changed))
+        }
+        BulkLabel::Collection => format!("Moved {} to {value}", project_count(changed)),
+    };
+    push_toast(&mut commands, ToastLevel::Success, message);
+    close_dialog(&mut commands, dialog, true);
+}
+
+/// Remove a tag from a project, from the chip of its [`ProjectNode`].
+fn remove_project_tag(
+    In((path, tag)): In<(PathBuf, String)>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+) {
+    let Some(project) = project_list
+        .0
+        .iter_mut()
+        .find(|project| project.path == path)
+    else {
+        return;
+    };
+    project.tags.retain(|listed| *listed != tag);
+    store.set(project_list.0.clone());
+}
+
+/// Rebuild the [`TagRow`] of the [`ProjectNode`]s whose tags or collection changed.
+fn update_tag_rows(
+    mut commands: Commands,
+    project_list: Res<ProjectInfoList>,
+    nodes: Query<(Entity, &ProjectNode, Option<&ShownTags>, Option<&Children>)>,
+    added: Query<(), Added<ProjectNode>>,
+    rows: Query<(), With<TagRow>>,
+    theme: Res<Theme>,
+) {
+    if !project_list.is_changed() && added.is_empty() {
+        return;
+    }
+    for (entity, ProjectNode(path), shown, children) in &nodes {
+        let Some(project) = project_list.0.iter().find(|project| project.path == *path) else {
+            continue;
+        };
+        let tags = ShownTags {
+            tags: project.tags.clone(),
+            collection: project.collection.clone(),
+        };
+        if shown == Some(&tags) {
+            continue;
+        }
+        for child in children.into_iter().flat_map(|children| children.iter()) {
+            if rows.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+        if !tags.tags.is_empty() || tags.collection.is_some() {
+            let row = commands
+                .spawn((
+                    TagRow,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(8.0),
+                        right: Val::Px(8.0),
+                        bottom: Val::Percent(24.0),
+                        display: Display::Flex,
+                        flex_wrap: FlexWrap::Wrap,
+                        column_gap: Val::Px(4.0),
+                        row_gap: Val::Px(4.0),
+                        ..default()
+                    },
+                    ChildOf(entity),
+                ))
+                .id();
+            if let Some(collection) = &tags.collection {
+                Chip::new(collection.clone())
+                    .role(ChipRole::Accent)
+                    .spawn(&mut commands, &theme)
+                    .insert(ChildOf(row));
+            }
+            for tag in &tags.tags {
+                let path = path.clone();
+                let tag = tag.clone();
+                Chip::new(tag.clone())
+                    .removable()
+                    .spawn(&mut commands, &theme)
+                    .insert(ChildOf(row))
+                    .observe(move |_trigger: On<ChipRemoved>, mut commands: Commands| {
+                        commands.run_system_cached_with(
+                            remove_project_tag,
+                            (path.clone(), tag.clone()),
+                        );
+                    });
+            }
+        }
+        commands.entity(entity).insert(tags);
+    }
+}
@@ -0,0 +1,129 @@
+//! Finding out when the background tasks of the launcher are done, see [`TaskCompletion`].
+//!
+//! A [`BackgroundTask`] is either polled by the system handling its result, which owns the
+//! [`Task`], or sends its result to a [`TaskChannel`] drained by that system. Polling is simple and
+//! ties the result to the entity holding the task, but finding a finished task means polling each
+//! one every frame. The channel only costs a `try_recv` per frame however many tasks run, and the
+//! results can come from anywhere, like several tasks or a task streaming partial results, at the
+//! price of a resource per result type and of checking that the entity still wants the result.
+
+use std::{
+    future::Future,
+    sync::{
+        Mutex,
+        mpsc::{self, Receiver, Sender},
+    },
+};
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+
+use crate::TaskCompletion;
+
+/// The results sent by the [`BackgroundTask`]s using [`TaskCompletion::Channel`], with the entity
+/// each task was spawned for.
+#[derive(Resource)]
+pub(crate) struct TaskChannel<T> {
+    sender: Sender<(Entity, T)>,
+    receiver: Mutex<Receiver<(Entity, T)>>,
+}
+
+impl<T> Default for TaskChannel<T> {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            sender,
+            receiver: Mutex::new(receiver),
+        }
+    }
+}
+
+impl<T> TaskChannel<T> {
+    /// The results received since the last call, oldest first.
+    pub fn drain(&self) -> Vec<(Entity, T)> {
+        match self.receiver.lock() {
+            Ok(receiver) => receiver.try_iter().collect(),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+/// A task running in the background, its result found as set by its [`TaskCompletion`].
+///
+/// Dropping it cancels the task in both cases, so despawning the entity holding it is enough to
+/// stop the work.
+pub(crate) enum BackgroundTask<T> {
+    /// The result is taken by polling the task, see [`BackgroundTask::poll`].
+    Polled(Task<T>),
+    /// The task sends its result to the [`TaskChannel`] once done.
+    Sending(Task<()>),
+}
+
+impl<T: Send + 'static> BackgroundTask<T> {
+    /// Run the future on the [`IoTaskPool`] for the given entity.
+    pub fn spawn(
+        completion: TaskCompletion,
+        entity: Entity,
+        channel: &TaskChannel<T>,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Self {
+        let pool = IoTaskPool::get();
+        match completion {
+            TaskCompletion::Polling => BackgroundTask::Polled(pool.spawn(future)),
+            TaskCompletion::Channel => {
+                let sender = channel.sender.clone();
+                BackgroundTask::Sending(pool.spawn(async move {
+                    // The receiver lives as long as the app, nothing waits for the result after it
+                    let _ = sender.send((entity, future.await));
+                }))
+            }
+        }
+    }
+
+    /// The result of a [`BackgroundTask::Polled`] task once it is done, `None` for the other ones.
+    pub fn poll(&mut self) -> Option<T> {
+        match self {
+            BackgroundTask::Polled(task) => block_on(future::poll_once(task)),
+            BackgroundTask::Sending(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::tasks::TaskPool;
+
+    use super::*;
+
+    #[test]
+    fn receive_the_results_over_the_channel() {
+        IoTaskPool::get_or_init(TaskPool::new);
+        let channel = TaskChannel::default();
+        let entity = World::new().spawn_empty().id();
+
+        let mut task = BackgroundTask::spawn(TaskCompletion::Channel, entity, &channel, async {
+            "created"
+        });
+        let BackgroundTask::Sending(sending) = &mut task else {
+            panic!("the task sends its result");
+        };
+        block_on(sending);
+        assert_eq!(task.poll(), None, "only the channel has the result");
+        assert_eq!(channel.drain(), [(entity, "created")]);
+        assert!(channel.drain().is_empty());
+
+        let mut task = BackgroundTask::spawn(TaskCompletion::Polling, entity, &channel, async {
+            "polled"
+        });
+        let result = loop {
+            if let Some(result) = task.poll() {
+                break result;
+            }
+            std::thread::yield_now();
+        };
+        assert_eq!(result, "polled");
+        assert!(channel.drain().is_empty());
+    }
+}
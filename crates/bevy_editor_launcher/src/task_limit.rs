@@ -0,0 +1,213 @@
+//! Bounding how many of the heavy background tasks run at once, independently of the size of the
+//! [`IoTaskPool`](bevy::tasks::IoTaskPool).
+//!
+//! Creating, checking, cleaning and updating projects all run cargo, and running many of them at
+//! once thrashes small machines. These tasks first wait for a [`TaskSlot`] of the [`TaskSlots`],
+//! so at most [`LauncherSettings::max_concurrent_tasks`] of them run while the others are queued.
+//! The footer shows how many are running and queued.
+
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+};
+
+use bevy::prelude::*;
+use bevy_footer_bar::FooterBarInfo;
+
+use crate::settings::LauncherSettings;
+
+/// Plugin limiting the heavy tasks to the [`LauncherSettings::max_concurrent_tasks`].
+pub struct TaskLimitPlugin;
+
+impl Plugin for TaskLimitPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TaskSlots>()
+            .add_systems(Update, (sync_task_limit, show_task_counts).chain());
+    }
+}
+
+/// The slots the heavy tasks wait for before running, shared with the tasks.
+#[derive(Resource, Clone, Default)]
+pub(crate) struct TaskSlots(Arc<Mutex<SlotsState>>);
+
+#[derive(Default)]
+struct SlotsState {
+    /// How many tasks can hold a slot at once, `None` for no limit
+    limit: Option<usize>,
+    running: usize,
+    queued: usize,
+    /// The queued tasks to wake once a slot is free
+    waiters: VecDeque<Waker>,
+}
+
+impl TaskSlots {
+    fn state(&self) -> MutexGuard<'_, SlotsState> {
+        // The state stays consistent when a task panics, its counts are only changed under the lock
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Change the number of slots, a lower limit lets the running tasks finish.
+    pub fn set_limit(&self, limit: Option<usize>) {
+        let mut state = self.state();
+        // A limit of 0 would never run anything
+        state.limit = limit.map(|limit| limit.max(1));
+        state.waiters.drain(..).for_each(Waker::wake);
+    }
+
+    /// The number of tasks holding a slot, and of tasks waiting for one.
+    pub fn counts(&self) -> (usize, usize) {
+        let state = self.state();
+        (state.running, state.queued)
+    }
+
+    /// Wait for a free slot, held until the returned [`TaskSlot`] is dropped.
+    pub fn acquire(&self) -> AcquireSlot {
+        AcquireSlot {
+            slots: self.clone(),
+            queued: false,
+        }
+    }
+
+    /// Take a free slot without waiting, `None` when all are held or tasks are queued for them.
+    pub fn try_acquire(&self) -> Option<TaskSlot> {
+        let mut state = self.state();
+        let free = state.limit.is_none_or(|limit| state.running < limit);
+        if !free || state.queued > 0 {
+            return None;
+        }
+        state.running += 1;
+        Some(TaskSlot(self.clone()))
+    }
+
+    /// Run the future once a slot is free, holding the slot until it is done.
+    pub fn run<T>(&self, future: impl Future<Output = T>) -> impl Future<Output = T> {
+        let acquire = self.acquire();
+        async move {
+            let _slot = acquire.await;
+            future.await
+        }
+    }
+}
+
+/// The future returned by [`TaskSlots::acquire`].
+///
+/// Dropping it before it is done leaves the queue, like when the task waiting for it is cancelled.
+pub(crate) struct AcquireSlot {
+    slots: TaskSlots,
+    queued: bool,
+}
+
+impl Future for AcquireSlot {
+    type Output = TaskSlot;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<TaskSlot> {
+        let slots = self.slots.clone();
+        let mut state = slots.state();
+        // The queued tasks go first, so that a stream of new tasks can't starve them
+        let first_in_line = self.queued || state.queued == 0;
+        let free = state.limit.is_none_or(|limit| state.running < limit);
+        if first_in_line && free {
+            state.running += 1;
+            if self.queued {
+                state.queued -= 1;
+                self.queued = false;
+            }
+            return Poll::Ready(TaskSlot(slots.clone()));
+        }
+        if !self.queued {
+            state.queued += 1;
+            self.queued = true;
+        }
+        state.waiters.push_back(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for AcquireSlot {
+    fn drop(&mut self) {
+        if self.queued {
+            let mut state = self.slots.state();
+            state.queued -= 1;
+            // It may have been woken for the slot it leaves
+            state.waiters.drain(..).for_each(Waker::wake);
+        }
+    }
+}
+
+/// A slot of the [`TaskSlots`], freed when dropped.
+pub(crate) struct TaskSlot(TaskSlots);
+
+impl Drop for TaskSlot {
+    fn drop(&mut self) {
+        let mut state = self.0.state();
+        state.running -= 1;
+        // The queued tasks race for the slot again, the counts decide who gets it
+        state.waiters.drain(..).for_each(Waker::wake);
+    }
+}
+
+/// Apply the [`LauncherSettings::max_concurrent_tasks`] to the [`TaskSlots`].
+fn sync_task_limit(settings: Res<LauncherSettings>, slots: Res<TaskSlots>) {
+    if settings.is_changed() {
+        slots.set_limit(settings.max_concurrent_tasks);
+    }
+}
+
+/// Show the numbers of running and queued tasks in the footer, nothing while none runs.
+fn show_task_counts(slots: Res<TaskSlots>, mut infos: Query<&mut Text, With<FooterBarInfo>>) {
+    let text = match slots.counts() {
+        (0, 0) => String::new(),
+        (running, 0) => format!("{running} running"),
+        (running, queued) => format!("{running} running, {queued} queued"),
+    };
+    for mut info in &mut infos {
+        if info.0 != text {
+            info.0.clone_from(&text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::tasks::{block_on, futures_lite::future};
+
+    use super::*;
+
+    #[test]
+    fn respect_the_limit() {
+        let slots = TaskSlots::default();
+        slots.set_limit(Some(2));
+        let first = block_on(future::poll_once(slots.acquire())).expect("a slot is free");
+        let _second = block_on(future::poll_once(slots.acquire())).expect("a slot is free");
+        let mut third = slots.acquire();
+        let mut fourth = slots.acquire();
+        assert!(block_on(future::poll_once(&mut third)).is_none());
+        assert!(block_on(future::poll_once(&mut fourth)).is_none());
+        assert_eq!(slots.counts(), (2, 2));
+
+        drop(first);
+        let mut late = slots.acquire();
+        assert!(
+            block_on(future::poll_once(&mut late)).is_none(),
+            "the queued tasks go first"
+        );
+        let _third = block_on(future::poll_once(&mut third)).expect("the freed slot");
+        assert!(block_on(future::poll_once(&mut fourth)).is_none());
+        assert_eq!(slots.counts(), (2, 2));
+
+        drop(fourth);
+        drop(late);
+        assert_eq!(slots.counts(), (2, 0), "cancelled tasks leave the queue");
+        assert!(slots.try_acquire().is_none());
+
+        slots.set_limit(None);
+        let _unlimited = block_on(future::poll_once(slots.acquire())).expect("no limit");
+        let _taken = slots.try_acquire().expect("no limit");
+        assert_eq!(slots.counts(), (4, 0));
+    }
+}
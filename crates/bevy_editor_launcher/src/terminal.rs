@@ -0,0 +1,139 @@
+//! Opening a terminal in a project folder.
+
+use std::{
+    io,
+    path::Path,
+    process::{Child, Command},
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    settings::LauncherSettings,
+    toast::{ToastLevel, push_toast},
+    ui::ProjectNode,
+};
+
+/// The terminals tried in order on Linux, after the one set in the `TERMINAL` environment variable.
+#[cfg(target_os = "linux")]
+const LINUX_TERMINALS: &[&str] = &[
+    "x-terminal-emulator",
+    "gnome-terminal",
+    "konsole",
+    "xfce4-terminal",
+    "alacritty",
+    "kitty",
+    "xterm",
+];
+
+/// Open a terminal in the given folder.
+///
+/// `custom_command` overrides the platform terminal, its `{path}` placeholders are replaced by the folder.
+/// Returns a [`io::ErrorKind::NotFound`] error when no terminal could be started, and an
+/// [`io::ErrorKind::NotADirectory`] one when the folder doesn't exist.
+pub fn open_terminal(path: &Path, custom_command: Option<&str>) -> io::Result<()> {
+    // A missing working directory fails like a missing program
+    if !path.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotADirectory,
+            format!("'{}' isn't a folder", path.display()),
+        ));
+    }
+    match custom_command.filter(|command| !command.trim().is_empty()) {
+        Some(command) => {
+            let mut parts = expand_command(command, path).into_iter();
+            let program = parts.next().unwrap_or_default();
+            Command::new(program)
+                .args(parts)
+                .current_dir(path)
+                .spawn()?
+        }
+        None => spawn_platform_terminal(path)?,
+    };
+    info!("Opened a terminal in '{}'", path.display());
+    Ok(())
+}
+
+/// Split a command template into its program and arguments, replacing `{path}` by the folder.
+pub(crate) fn expand_command(command: &str, path: &Path) -> Vec<String> {
+    let path_text = path.to_string_lossy();
+    command
+        .split_whitespace()
+        .map(|part| part.replace("{path}", &path_text))
+        .collect()
+}
+
+#[cfg(target_os = "macos")]
+fn spawn_platform_terminal(path: &Path) -> io::Result<Child> {
+    let app = if Path::new("/Applications/iTerm.app").exists() {
+        "iTerm"
+    } else {
+        "Terminal"
+    };
+    Command::new("open").args(["-a", app]).arg(path).spawn()
+}
+
+#[cfg(target_os = "windows")]
+fn spawn_platform_terminal(path: &Path) -> io::Result<Child> {
+    match Command::new("wt").arg("-d").arg(path).spawn() {
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Command::new("cmd")
+            .args(["/C", "start", "cmd", "/K"])
+            .current_dir(path)
+            .spawn(),
+        result => result,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn spawn_platform_terminal(path: &Path) -> io::Result<Child> {
+    let from_env = std::env::var("TERMINAL").ok();
+    for terminal in from_env
+        .iter()
+        .map(String::as_str)
+        .chain(LINUX_TERMINALS.iter().copied())
+    {
+        match Command::new(terminal).current_dir(path).spawn() {
+            Err(error) if error.kind() == io::ErrorKind::NotFound => continue,
+            result => return result,
+        }
+    }
+    Err(io::Error::new(io::ErrorKind::NotFound, "No terminal found"))
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+fn spawn_platform_terminal(_path: &Path) -> io::Result<Child> {
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        "No terminal known for this platform",
+    ))
+}
+
+/// Open a terminal in the folder of the given [`ProjectNode`].
+pub(crate) fn open_terminal_for_node(
+    In(project_entity): In<Entity>,
+    mut commands: Commands,
+    query_nodes: Query<&ProjectNode>,
+    settings: Res<LauncherSettings>,
+) {
+    let Ok(ProjectNode(path)) = query_nodes.get(project_entity) else {
+        return;
+    };
+    open_terminal_with_toast(&mut commands, path, &settings);
+}
+
+/// Open a terminal in the folder with the terminal of the settings, telling the user when it fails.
+pub(crate) fn open_terminal_with_toast(
+    commands: &mut Commands,
+    path: &Path,
+    settings: &LauncherSettings,
+) {
+    if let Err(error) = open_terminal(path, settings.terminal_command.as_deref()) {
+        let message = match error.kind() {
+            io::ErrorKind::NotFound => {
+                "No terminal found, set one in the launcher settings".to_string()
+            }
+            _ => format!("Failed to open a terminal: {error}"),
+        };
+        push_toast(commands, ToastLevel::Error, message);
+    }
+}
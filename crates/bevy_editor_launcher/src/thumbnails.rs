@@ -0,0 +1,301 @@
+//! The thumbnails of the project cards, captured by the editor while the project runs.
+//!
+//! See [`bevy_editor::project::thumbnail`] for the convention. The thumbnails are decoded in the
+//! background and cached with the modification time of their file, which is checked every
+//! [`CHECK_INTERVAL`] so a new capture replaces the old one. A project without a thumbnail, or
+//! whose thumbnail fails to load, shows a placeholder generated from its name.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use bevy::{
+    asset::RenderAssetUsages,
+    image::{CompressedImageFormats, ImageSampler, ImageType},
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use bevy_editor::project::{ProjectInfo, thumbnail::thumbnail_path};
+use bevy_editor_styles::Theme;
+
+use crate::ui::ProjectNode;
+
+/// How often the thumbnail files are checked for changes.
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Plugin loading the thumbnails of the [`ProjectNode`]s and keeping them up to date.
+pub struct ThumbnailsPlugin;
+
+impl Plugin for ThumbnailsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ThumbnailCache>()
+            .init_resource::<ThumbnailLoads>()
+            .add_systems(
+                Update,
+                (check_thumbnails, finish_thumbnail_loads, show_thumbnails).chain(),
+            );
+    }
+}
+
+/// The preview area of a [`ProjectNode`], showing its thumbnail or its placeholder.
+#[derive(Component)]
+struct ProjectPreview;
+
+/// The image of a [`ProjectPreview`], hidden while the project has no thumbnail.
+#[derive(Component)]
+struct PreviewImage;
+
+/// The generated placeholder of a [`ProjectPreview`], hidden while the thumbnail is shown.
+#[derive(Component)]
+struct PreviewPlaceholder;
+
+/// The loaded thumbnails, by project path.
+#[derive(Resource, Default)]
+struct ThumbnailCache(HashMap<PathBuf, Thumbnail>);
+
+struct Thumbnail {
+    /// The modification time of the file the thumbnail was loaded from.
+    modified: SystemTime,
+    /// The decoded image, `None` when the file couldn't be decoded.
+    image: Option<Handle<Image>>,
+}
+
+/// The thumbnails being decoded, by project path, with the modification time of their file.
+#[derive(Resource, Default)]
+struct ThumbnailLoads(HashMap<PathBuf, (SystemTime, Task<Result<Image, String>>)>);
+
+/// Spawn the preview area of the card of the project, showing the placeholder until the
+/// thumbnail is loaded.
+pub(crate) fn spawn_project_preview(
+    parent: &mut ChildSpawnerCommands,
+    theme: &Theme,
+    project: &ProjectInfo,
+) {
+    let name = project.name().unwrap_or_default();
+    parent.spawn((
+        ProjectPreview,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            overflow: Overflow::clip(),
+            flex_grow: 1.0,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BorderRadius::top(Val::Px(15.0)),
+        children![
+            (
+                PreviewPlaceholder,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                BackgroundColor(placeholder_color(&name)),
+                Pickable::IGNORE,
+                children![(
+                    Text::new(placeholder_initials(&name)),
+                    TextFont {
+                        font: theme.text.font.clone(),
+                        font_size: 48.0,
+                        ..default()
+                    },
+                    TextColor(theme.text.high_priority.with_alpha(0.8)),
+                    Pickable::IGNORE,
+                )],
+            ),
+            (
+                PreviewImage,
+                ImageNode::default(),
+                // Covering the width, the overflow is clipped
+                Node {
+                    display: Display::None,
+                    width: Val::Percent(100.0),
+                    ..default()
+                },
+                Pickable::IGNORE,
+            ),
+        ],
+    ));
+}
+
+/// The letters of the placeholder: the first letter of the first two words of the name, like
+/// `MG` for `my_game`.
+pub(crate) fn placeholder_initials(name: &str) -> String {
+    name.split(|character: char| !character.is_alphanumeric())
+        .filter_map(|word| word.chars().next())
+        .take(2)
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+/// The background of the placeholder, its hue picked from the name so each project keeps its own.
+pub(crate) fn placeholder_color(name: &str) -> Color {
+    // FNV-1a, stable across runs and Rust versions unlike the std hasher
+    let hash = name.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x01000193)
+    });
+    Color::oklch(0.4, 0.08, (hash % 360) as f32)
+}
+
+/// The modification time of the thumbnail of the project, `None` when it doesn't exist.
+fn thumbnail_modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(thumbnail_path(path))
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Decode the thumbnail of the project at `path`.
+fn load_thumbnail(path: &Path) -> Result<Image, String> {
+    decode_image(&thumbnail_path(path))
+}
+
+/// Decode the image file, its format picked from its extension.
+pub(crate) fn decode_image(path: &Path) -> Result<Image, String> {
+    let bytes = fs::read(path).map_err(|error| error.to_string())?;
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or("png");
+    Image::from_buffer(
+        &bytes,
+        ImageType::Extension(extension),
+        CompressedImageFormats::NONE,
+        true,
+        ImageSampler::Default,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+    .map_err(|error| error.to_string())
+}
+
+/// Start loading the thumbnails that are new or changed, and forget the deleted ones.
+///
+/// The new [`ProjectNode`]s are checked right away, the other ones every [`CHECK_INTERVAL`].
+fn check_thumbnails(
+    mut cache: ResMut<ThumbnailCache>,
+    mut loads: ResMut<ThumbnailLoads>,
+    nodes: Query<&ProjectNode>,
+    added: Query<(), Added<ProjectNode>>,
+    time: Res<Time>,
+    mut since_check: Local<Duration>,
+) {
+    *since_check += time.delta();
+    if *since_check < CHECK_INTERVAL && added.is_empty() {
+        return;
+    }
+    *since_check = Duration::ZERO;
+
+    for ProjectNode(path) in &nodes {
+        let Some(modified) = thumbnail_modified(path) else {
+            if cache.0.contains_key(path) {
+                cache.0.remove(path);
+            }
+            continue;
+        };
+        let cached = cache.0.get(path).map(|thumbnail| thumbnail.modified);
+        let loading = loads.0.get(path).map(|(modified, _)| *modified);
+        if cached == Some(modified) || loading == Some(modified) {
+            continue;
+        }
+        let project = path.clone();
+        let task = IoTaskPool::get().spawn(async move { load_thumbnail(&project) });
+        // Replacing a load of an older file cancels it
+        loads.0.insert(path.clone(), (modified, task));
+    }
+}
+
+/// Cache the thumbnails that finished loading.
+fn finish_thumbnail_loads(
+    mut cache: ResMut<ThumbnailCache>,
+    mut loads: ResMut<ThumbnailLoads>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    if loads.0.is_empty() {
+        return;
+    }
+    loads.0.retain(|path, (modified, task)| {
+        let Some(result) = block_on(future::poll_once(task)) else {
+            return true;
+        };
+        let image = match result {
+            Ok(image) => Some(images.add(image)),
+            Err(error) => {
+                // Cached anyway, so it isn't loaded again until the file changes
+                warn!(
+                    "Couldn't load the thumbnail of '{}': {error}",
+                    path.display()
+                );
+                None
+            }
+        };
+        cache.0.insert(
+            path.clone(),
+            Thumbnail {
+                modified: *modified,
+                image,
+            },
+        );
+        false
+    });
+}
+
+/// Show the cached thumbnail of each [`ProjectNode`], or its placeholder.
+fn show_thumbnails(
+    cache: Res<ThumbnailCache>,
+    nodes: Query<(&ProjectNode, &Children)>,
+    added: Query<(), Added<ProjectPreview>>,
+    previews: Query<&Children, With<ProjectPreview>>,
+    mut preview_images: Query<(&mut ImageNode, &mut Node), With<PreviewImage>>,
+    mut placeholders: Query<&mut Node, (With<PreviewPlaceholder>, Without<PreviewImage>)>,
+) {
+    if !cache.is_changed() && added.is_empty() {
+        return;
+    }
+    for (ProjectNode(path), children) in &nodes {
+        let thumbnail = cache
+            .0
+            .get(path)
+            .and_then(|thumbnail| thumbnail.image.clone());
+        let (image_display, placeholder_display) = match thumbnail {
+            Some(_) => (Display::Flex, Display::None),
+            None => (Display::None, Display::Flex),
+        };
+        let preview_children = children
+            .iter()
+            .filter_map(|child| previews.get(child).ok())
+            .flat_map(|preview_children| preview_children.iter());
+        for child in preview_children {
+            if let Ok((mut image_node, mut node)) = preview_images.get_mut(child) {
+                if let Some(image) = &thumbnail
+                    && image_node.image != *image
+                {
+                    image_node.image = image.clone();
+                }
+                node.display = image_display;
+            } else if let Ok(mut node) = placeholders.get_mut(child) {
+                node.display = placeholder_display;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_the_placeholders() {
+        assert_eq!(placeholder_initials("my_game"), "MG");
+        assert_eq!(placeholder_initials("space-shooter-3d"), "SS");
+        assert_eq!(placeholder_initials("demo"), "D");
+        assert_eq!(placeholder_initials(""), "");
+        assert_eq!(placeholder_color("demo"), placeholder_color("demo"));
+        assert_ne!(placeholder_color("demo"), placeholder_color("game"));
+    }
+}
@@ -0,0 +1,217 @@
+//! Small, auto-dismissing notifications stacked in a corner of the launcher.
+//!
+//! Toasts are used for transient failures and confirmations that don't warrant a full overlay.
+//! They never block input and fade in and out on their own.
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_editor_styles::{Theme, fonts::FontFallback};
+
+/// How long a toast stays on screen, in seconds.
+const TOAST_LIFETIME: f32 = 4.0;
+/// How long the fade in and fade out animations last, in seconds.
+const TOAST_FADE_DURATION: f32 = 0.25;
+/// How far a toast slides in from the right, in pixels.
+const TOAST_SLIDE_DISTANCE: f32 = 40.0;
+/// The maximum amount of toasts visible at once, the oldest ones are dismissed first.
+const MAX_VISIBLE_TOASTS: usize = 5;
+
+/// Plugin handling the toast notifications.
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_toast_container).add_systems(
+            Update,
+            (
+                notify_font_fallback.run_if(resource_changed::<FontFallback>),
+                setup_new_toasts,
+                animate_toasts,
+            )
+                .chain(),
+        );
+    }
+}
+
+/// The severity of a toast, which decides its accent color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    /// Neutral information.
+    Info,
+    /// An action completed successfully.
+    Success,
+    /// Something went wrong but the launcher could recover.
+    Warning,
+    /// An action failed.
+    Error,
+}
+
+impl ToastLevel {
+    fn color(self, theme: &Theme) -> Color {
+        match self {
+            ToastLevel::Info => theme.status.info,
+            ToastLevel::Success => theme.status.success,
+            ToastLevel::Warning => theme.status.warning,
+            ToastLevel::Error => theme.status.error,
+        }
+    }
+}
+
+/// A single toast notification.
+#[derive(Component)]
+pub struct Toast {
+    level: ToastLevel,
+    message: String,
+    timer: Timer,
+}
+
+/// The node toasts are stacked into.
+#[derive(Component)]
+struct ToastContainer;
+
+/// Show a toast with the given severity and message.
+pub fn push_toast(commands: &mut Commands, level: ToastLevel, message: impl Into<String>) {
+    let message = message.into();
+    match level {
+        ToastLevel::Info | ToastLevel::Success => info!("{message}"),
+        ToastLevel::Warning => warn!("{message}"),
+        ToastLevel::Error => error!("{message}"),
+    }
+    commands.spawn(Toast {
+        level,
+        message,
+        timer: Timer::from_seconds(TOAST_LIFETIME, TimerMode::Once),
+    });
+}
+
+/// Tell the user when a font of the theme failed to load, instead of leaving them wondering why the
+/// text looks different.
+fn notify_font_fallback(
+    mut commands: Commands,
+    fallback: Res<FontFallback>,
+    mut notified: Local<FontFallback>,
+) {
+    let fonts = [
+        (&fallback.text, &notified.text),
+        (&fallback.icon, &notified.icon),
+    ];
+    let newly_failed = fonts
+        .into_iter()
+        .filter(|(failed, known)| failed != known)
+        .filter_map(|(failed, _)| failed.as_ref());
+    for failed in newly_failed {
+        push_toast(
+            &mut commands,
+            ToastLevel::Warning,
+            format!("The font '{failed}' couldn't be loaded, a default font is used instead"),
+        );
+    }
+    notified.clone_from(&fallback);
+}
+
+fn spawn_toast_container(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(16.0),
+            bottom: Val::Px(36.0),
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::FlexEnd,
+            row_gap: Val::Px(8.0),
+            ..default()
+        },
+        GlobalZIndex(100),
+        Pickable::IGNORE,
+        ToastContainer,
+    ));
+}
+
+fn setup_new_toasts(
+    mut commands: Commands,
+    new_toasts: Query<(Entity, &Toast), Added<Toast>>,
+    container: Single<(Entity, Option<&Children>), With<ToastContainer>>,
+    theme: Res<Theme>,
+) {
+    let (container, existing) = *container;
+    // The visible toasts, oldest first, including the ones spawned by this loop
+    let mut visible: VecDeque<Entity> = existing
+        .into_iter()
+        .flat_map(|children| children.iter())
+        .collect();
+
+    for (entity, toast) in &new_toasts {
+        // Make room for the new toast by dismissing the oldest ones
+        while visible.len() >= MAX_VISIBLE_TOASTS {
+            let Some(oldest) = visible.pop_front() else {
+                break;
+            };
+            commands.entity(oldest).despawn();
+        }
+        visible.push_back(entity);
+
+        commands
+            .entity(entity)
+            .insert((
+                Node {
+                    max_width: Val::Px(360.0),
+                    padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                    border: UiRect::left(Val::Px(4.0)),
+                    left: Val::Px(TOAST_SLIDE_DISTANCE),
+                    ..default()
+                },
+                BackgroundColor(theme.menu.background_color.with_alpha(0.0)),
+                BorderColor::all(toast.level.color(&theme).with_alpha(0.0)),
+                theme.general.border_radius,
+                Pickable::IGNORE,
+                ChildOf(container),
+            ))
+            .with_child((
+                Text::new(toast.message.clone()),
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(theme.text.text_color.with_alpha(0.0)),
+                Pickable::IGNORE,
+            ));
+    }
+}
+
+fn animate_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    theme: Res<Theme>,
+    mut toasts: Query<(
+        Entity,
+        &mut Toast,
+        &mut Node,
+        &mut BackgroundColor,
+        &mut BorderColor,
+        &Children,
+    )>,
+    mut texts: Query<&mut TextColor>,
+) {
+    for (entity, mut toast, mut node, mut background, mut border, children) in &mut toasts {
+        toast.timer.tick(time.delta());
+        if toast.timer.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        let elapsed = toast.timer.elapsed_secs();
+        let remaining = TOAST_LIFETIME - elapsed;
+        let opacity = (elapsed.min(remaining) / TOAST_FADE_DURATION).clamp(0.0, 1.0);
+
+        node.left = Val::Px((1.0 - opacity) * TOAST_SLIDE_DISTANCE);
+        background.0 = theme.menu.background_color.with_alpha(opacity * 0.95);
+        *border = BorderColor::all(toast.level.color(&theme).with_alpha(opacity));
+        for child in children.iter() {
+            if let Ok(mut text_color) = texts.get_mut(child) {
+                text_color.0 = theme.text.text_color.with_alpha(opacity);
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Keeping the launcher running in the system tray when its window is closed.
+//!
+//! The tray icon needs the `tray` feature, and is only supported on Windows and macOS.
+//! Without it, or if the icon can't be created, closing the window quits the launcher as usual.
+//! Minimizing to the tray requires [`WindowPlugin::close_when_requested`] to be disabled,
+//! the close requests are then handled by the [`TrayPlugin`]. Quitting, from the window or the
+//! tray, asks first when something is pending, see [`request_quit`].
+
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowCloseRequested},
+};
+
+use crate::{quit::request_quit, settings::LauncherSettings};
+
+/// Plugin adding the tray icon and handling the window close requests.
+pub struct TrayPlugin;
+
+impl Plugin for TrayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, close_or_hide_windows);
+
+        #[cfg(all(feature = "tray", any(target_os = "windows", target_os = "macos")))]
+        app.add_systems(Startup, icon::spawn_tray_icon)
+            .add_systems(Update, icon::handle_tray_events);
+
+        #[cfg(all(feature = "tray", not(any(target_os = "windows", target_os = "macos"))))]
+        warn!("The tray icon isn't supported on this platform, closing the launcher will quit it");
+    }
+}
+
+/// Inserted once the tray icon has been created, so the launcher can be brought back from it.
+#[derive(Resource)]
+pub struct TrayAvailable;
+
+/// Hide the closed windows instead of closing them if the launcher minimizes to the tray, quit
+/// otherwise.
+///
+/// Only closing the primary window, or the last one, quits or hides the launcher. The other windows
+/// are closed like Bevy does without the [`TrayPlugin`].
+fn close_or_hide_windows(
+    mut commands: Commands,
+    mut close_requests: EventReader<WindowCloseRequested>,
+    mut windows: Query<(&mut Window, Has<PrimaryWindow>)>,
+    settings: Res<LauncherSettings>,
+    tray: Option<Res<TrayAvailable>>,
+) {
+    let mut open = windows.iter().count();
+    for request in close_requests.read() {
+        let Ok((mut window, primary)) = windows.get_mut(request.window) else {
+            continue;
+        };
+        if !primary && open > 1 {
+            commands.entity(request.window).despawn();
+            open -= 1;
+            continue;
+        }
+        if settings.minimize_to_tray && tray.is_some() {
+            info!("Minimizing the launcher to the tray");
+            window.visible = false;
+            continue;
+        }
+        commands.run_system_cached_with(request_quit, Some(request.window));
+    }
+}
+
+#[cfg(all(feature = "tray", any(target_os = "windows", target_os = "macos")))]
+mod icon {
+    use bevy::{prelude::*, window::PrimaryWindow};
+    use tray_icon::{
+        Icon, MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent,
+        menu::{Menu, MenuEvent, MenuId, MenuItem, PredefinedMenuItem},
+    };
+
+    use super::TrayAvailable;
+    use crate::{new_project::open_new_project_window, quit::request_quit};
+
+    /// The size of the generated tray icon, in pixels.
+    const ICON_SIZE: u32 = 32;
+
+    /// The tray icon, which must stay alive to stay visible, and the ids of its menu items.
+    pub(super) struct LauncherTray {
+        _icon: TrayIcon,
+        show: MenuId,
+        new_project: MenuId,
+        quit: MenuId,
+    }
+
+    /// A round icon in the Bevy blue.
+    fn tray_icon_image() -> Result<Icon, tray_icon::BadIcon> {
+        let center = ICON_SIZE as f32 / 2.0;
+        let rgba = (0..ICON_SIZE * ICON_SIZE)
+            .flat_map(|index| {
+                let x = (index % ICON_SIZE) as f32 + 0.5 - center;
+                let y = (index / ICON_SIZE) as f32 + 0.5 - center;
+                let alpha = if x * x + y * y <= center * center {
+                    255
+                } else {
+                    0
+                };
+                [0x23, 0x6d, 0xc8, alpha]
+            })
+            .collect();
+        Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE)
+    }
+
+    /// Create the tray icon, logging a warning on platforms where it isn't supported.
+    pub(super) fn spawn_tray_icon(world: &mut World) {
+        let menu = Menu::new();
+        let show = MenuItem::new("Show launcher", true, None);
+        let new_project = MenuItem::new("New project...", true, None);
+        let quit = MenuItem::new("Quit", true, None);
+        let tray = menu
+            .append_items(&[&show, &new_project, &PredefinedMenuItem::separator(), &quit])
+            .map_err(|error| error.to_string())
+            .and_then(|()| tray_icon_image().map_err(|error| error.to_string()))
+            .and_then(|icon| {
+                TrayIconBuilder::new()
+                    .with_menu(Box::new(menu))
+                    .with_tooltip("Bevy Editor Launcher")
+                    .with_icon(icon)
+                    .build()
+                    .map_err(|error| error.to_string())
+            });
+
+        match tray {
+            Ok(icon) => {
+                world.insert_non_send_resource(LauncherTray {
+                    _icon: icon,
+                    show: show.id().clone(),
+                    new_project: new_project.id().clone(),
+                    quit: quit.id().clone(),
+                });
+                world.insert_resource(TrayAvailable);
+            }
+            Err(error) => {
+                warn!("The tray icon isn't available, closing the launcher will quit it: {error}");
+            }
+        }
+    }
+
+    /// Handle the clicks on the tray icon and its menu items.
+    pub(super) fn handle_tray_events(
+        mut commands: Commands,
+        tray: Option<NonSend<LauncherTray>>,
+        mut window: Query<&mut Window, With<PrimaryWindow>>,
+    ) {
+        let Some(tray) = tray else {
+            return;
+        };
+        let mut show = false;
+        while let Ok(event) = TrayIconEvent::receiver().try_recv() {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                show = true;
+            }
+        }
+        while let Ok(event) = MenuEvent::receiver().try_recv() {
+            if event.id == tray.show {
+                show = true;
+            } else if event.id == tray.new_project {
+                show = true;
+                commands.run_system_cached(open_new_project_window);
+            } else if event.id == tray.quit {
+                commands.run_system_cached_with(request_quit, None);
+            }
+        }
+
+        if show && let Ok(mut window) = window.single_mut() {
+            window.visible = true;
+            window.focused = true;
+        }
+    }
+}
@@ -1,84 +1,63 @@
-use std::io::ErrorKind;
-use std::path::Path;
+use std::path::PathBuf;
 
-use bevy::{prelude::*, ui::RelativeCursorPosition};
-use bevy_editor::project::{ProjectInfo, run_project, set_project_list, templates::Templates};
-use bevy_editor_styles::Theme;
+use bevy::{input_focus::tab_navigation::TabGroup, prelude::*, ui::RelativeCursorPosition};
+use bevy_context_menu::{ContextMenu, ContextMenuOption};
+use bevy_editor::project::ProjectInfo;
+use bevy_editor_styles::{Theme, busy::BusyAction, dialog::spawn_dialog_button};
 use bevy_footer_bar::FooterBarNode;
 
-use bevy_scroll_box::spawn_scroll_box;
+use bevy_scroll_box::{ScrollJumpButtons, ScrollShadows, spawn_scroll_box};
 
-use crate::ProjectInfoList;
+use crate::{
+    ProjectInfoList,
+    badges::show_badge_settings,
+    bevy_version::show_bevy_version_dialog,
+    bug_report::report_project_node,
+    clean::clean_project_node,
+    clipboard::{CopiedText, copy_project_text},
+    details::show_project_details,
+    first_run::show_setup_dialog,
+    focus::{Activate, Focusable},
+    health::{HEALTH_ACTION, start_health_check},
+    hover::CardElevation,
+    keybindings::Keybindings,
+    new_project::open_new_project_window,
+    open::OpenProjectEvent,
+    project_icons::spawn_project_icon,
+    quick_access::{spawn_quick_access_row, toggle_pinned},
+    refresh::{REFRESH_ACTION, RefreshProjectsEvent},
+    rename::show_rename_dialog,
+    scan::{SCAN_ACTION, start_project_scan},
+    search::{project_name_label, spawn_search_box},
+    selection::{SELECTION_BORDER, SelectMode, select_project_node, spawn_selection_bar},
+    settings::{LauncherSettings, OpenBehavior, OpenWith},
+    sort::spawn_sort_dropdown,
+    terminal::open_terminal_for_node,
+    thumbnails::spawn_project_preview,
+};
+
+/// The file opened by the "Open main.rs" option, relative to the project.
+const MAIN_FILE: &str = "src/main.rs";
 
 #[derive(Component)]
 #[require(Node)]
 pub struct ProjectList;
 
-/// Component for notification popup
+/// The button at the end of the [`ProjectList`] used to create a new project.
 #[derive(Component)]
-pub struct NotificationPopup {
-    pub timer: Timer,
-}
+pub struct NewProjectButton;
 
-/// System to handle notification popups
-pub fn handle_notification_popups(
-    mut commands: Commands,
-    time: Res<Time>,
-    mut query: Query<(Entity, &mut NotificationPopup)>,
-) {
-    for (entity, mut popup) in query.iter_mut() {
-        popup.timer.tick(time.delta());
-        if popup.timer.is_finished() {
-            commands.entity(entity).despawn();
-        }
-    }
-}
-
-/// Spawn a notification popup with a message
-pub fn spawn_notification_popup(commands: &mut Commands, theme: &Theme, message: &str) -> Entity {
-    commands
-        .spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(0.0),
-                right: Val::Px(0.0),
-                top: Val::Px(50.0),
-                margin: UiRect::horizontal(Val::Auto),
-                padding: UiRect::all(Val::Px(12.0)),
-                width: Val::Auto,
-                height: Val::Auto,
-                align_items: AlignItems::Center,
-                justify_content: JustifyContent::Center,
-                ..default()
-            },
-            BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.9)),
-            BorderRadius::all(Val::Px(8.0)),
-            NotificationPopup {
-                timer: Timer::from_seconds(3.0, TimerMode::Once),
-            },
-        ))
-        .with_child((
-            Text::new(message.to_string()),
-            TextFont {
-                font: theme.text.font.clone(),
-                font_size: 18.0,
-                ..default()
-            },
-            TextColor(Color::WHITE),
-            Outline {
-                width: Val::Px(0.5),
-                color: Color::BLACK,
-                ..default()
-            },
-        ))
-        .id()
-}
+/// A node of the [`ProjectList`] displaying the project at the given path.
+#[derive(Component, Clone)]
+pub struct ProjectNode(pub PathBuf);
 
 pub fn setup(
     mut commands: Commands,
     theme: Res<Theme>,
     asset_server: Res<AssetServer>,
     project_list: Res<ProjectInfoList>,
+    keybindings: Res<Keybindings>,
+    settings: Res<LauncherSettings>,
 ) {
     commands.spawn((
         Camera2d,
@@ -98,8 +77,95 @@ pub fn setup(
                 ..default()
             },
             theme.pane.area_background_color,
+            TabGroup::new(0),
+        ))
+        .id();
+
+    let top_bar = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(root),
         ))
         .id();
+    spawn_search_box(&mut commands, &theme, &keybindings).insert(ChildOf(top_bar));
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Refresh",
+        theme.button.background_color.0,
+    )
+    .insert((Focusable(0), BusyAction(REFRESH_ACTION), ChildOf(top_bar)))
+    .observe(
+        |_trigger: On<Pointer<Click>>, mut events: EventWriter<RefreshProjectsEvent>| {
+            events.write(RefreshProjectsEvent);
+        },
+    )
+    .observe(
+        |_trigger: On<Activate>, mut events: EventWriter<RefreshProjectsEvent>| {
+            events.write(RefreshProjectsEvent);
+        },
+    );
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Scan folder",
+        theme.button.background_color.0,
+    )
+    .insert((Focusable(0), BusyAction(SCAN_ACTION), ChildOf(top_bar)))
+    .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
+        commands.run_system_cached(start_project_scan);
+    })
+    .observe(|_trigger: On<Activate>, mut commands: Commands| {
+        commands.run_system_cached(start_project_scan);
+    });
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Check projects",
+        theme.button.background_color.0,
+    )
+    .insert((Focusable(0), BusyAction(HEALTH_ACTION), ChildOf(top_bar)))
+    .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
+        commands.run_system_cached(start_health_check);
+    })
+    .observe(|_trigger: On<Activate>, mut commands: Commands| {
+        commands.run_system_cached(start_health_check);
+    });
+    spawn_sort_dropdown(&mut commands, &theme, settings.project_sort)
+        .insert((Focusable(0), ChildOf(top_bar)));
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Badges",
+        theme.button.background_color.0,
+    )
+    .insert((Focusable(0), ChildOf(top_bar)))
+    .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
+        commands.run_system_cached(show_badge_settings);
+    })
+    .observe(|_trigger: On<Activate>, mut commands: Commands| {
+        commands.run_system_cached(show_badge_settings);
+    });
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Settings",
+        theme.button.background_color.0,
+    )
+    .insert((Focusable(0), ChildOf(top_bar)))
+    .observe(|_trigger: On<Pointer<Click>>, mut commands: Commands| {
+        commands.run_system_cached_with(show_setup_dialog, false);
+    })
+    .observe(|_trigger: On<Activate>, mut commands: Commands| {
+        commands.run_system_cached_with(show_setup_dialog, false);
+    });
+
+    spawn_quick_access_row(&mut commands, &theme, &settings).insert(ChildOf(root));
 
     let main = commands
         .spawn((
@@ -121,7 +187,7 @@ pub fn setup(
             content_ec.insert(ProjectList);
             content_ec.with_children(|parent| {
                 for project in project_list.0.iter() {
-                    spawn_project_node(parent, &theme, &asset_server, project);
+                    spawn_project_node(parent, &theme, project);
                 }
                 parent
                     .spawn((
@@ -141,6 +207,8 @@ pub fn setup(
                         },
                         BorderRadius::all(Val::Px(20.0)),
                         BorderColor::all(theme.button.background_color.0),
+                        NewProjectButton,
+                        Focusable(2),
                     ))
                     .with_child((
                         Node {
@@ -151,27 +219,95 @@ pub fn setup(
                         ImageNode::new(asset_server.load("plus.png")),
                     ))
                     .observe(|_trigger: On<Pointer<Release>>, mut commands: Commands| {
-                        let new_project_path = rfd::FileDialog::new().pick_folder();
-                        if let Some(path) = new_project_path {
-                            crate::spawn_create_new_project_task(
-                                &mut commands,
-                                Templates::Blank,
-                                path,
-                            );
-                        }
+                        commands.run_system_cached(open_new_project_window);
+                    })
+                    .observe(|_trigger: On<Activate>, mut commands: Commands| {
+                        commands.run_system_cached(open_new_project_window);
                     });
             });
         }),
     )
-    .insert(ChildOf(main));
+    .insert((ScrollShadows, ScrollJumpButtons, ChildOf(main)));
+
+    spawn_selection_bar(&mut commands, &theme).insert(ChildOf(root));
 
     let _footer = commands.spawn(FooterBarNode).insert(ChildOf(root)).id();
 }
 
+/// The [`ContextMenu`] of a [`ProjectNode`], its pin option depending on whether the project is pinned.
+pub(crate) fn project_context_menu(pinned: bool) -> ContextMenu {
+    // The context menus have no submenus, the "Open with" options are listed first instead
+    let open_with = [OpenWith::Editor, OpenWith::VsCode, OpenWith::Custom].map(|tool| {
+        ContextMenuOption::new(
+            format!("Open with {}", tool.label()),
+            move |mut commands, entity| {
+                commands.run_system_cached_with(open_project_node_with, (entity, tool));
+            },
+        )
+    });
+    ContextMenu::new(open_with.into_iter().chain([
+        ContextMenuOption::new("Open main.rs", |mut commands, entity| {
+            commands.run_system_cached_with(open_project_file, (entity, MAIN_FILE.into()));
+        }),
+        ContextMenuOption::new(
+            if pinned {
+                "Unpin from quick access"
+            } else {
+                "Pin to quick access"
+            },
+            |mut commands, entity| {
+                commands.run_system_cached_with(toggle_pinned, entity);
+            },
+        ),
+        ContextMenuOption::new("Rename...", |mut commands, entity| {
+            commands.run_system_cached_with(show_rename_dialog, entity);
+        }),
+        ContextMenuOption::new("Show details", |mut commands, entity| {
+            commands.run_system_cached_with(show_project_details, entity);
+        }),
+        ContextMenuOption::new("Change Bevy version...", |mut commands, entity| {
+            commands.run_system_cached_with(show_bevy_version_dialog, entity);
+        }),
+        ContextMenuOption::new("Clean build artifacts...", |mut commands, entity| {
+            commands.run_system_cached_with(clean_project_node, entity);
+        }),
+        ContextMenuOption::new("Create a bug report...", |mut commands, entity| {
+            commands.run_system_cached_with(report_project_node, entity);
+        }),
+        ContextMenuOption::new("Open terminal here", |mut commands, entity| {
+            commands.run_system_cached_with(open_terminal_for_node, entity);
+        }),
+        ContextMenuOption::new("Copy path", |mut commands, entity| {
+            commands.run_system_cached_with(copy_project_text, (entity, CopiedText::Path));
+        }),
+        ContextMenuOption::new("Copy name", |mut commands, entity| {
+            commands.run_system_cached_with(copy_project_text, (entity, CopiedText::Name));
+        }),
+    ]))
+}
+
+/// Spawn a [`ProjectNode`] at the end of the [`ProjectList`], keeping the [`NewProjectButton`] last.
+pub(crate) fn append_project_node(
+    commands: &mut Commands,
+    project_list: Entity,
+    new_project_button: Entity,
+    theme: &Theme,
+    project: &ProjectInfo,
+) -> Entity {
+    let mut node = None;
+    commands.entity(project_list).with_children(|builder| {
+        node = Some(spawn_project_node(builder, theme, project).id());
+    });
+    // Re-parenting moves the button to the end of the children
+    commands
+        .entity(new_project_button)
+        .insert(ChildOf(project_list));
+    node.unwrap()
+}
+
 pub(crate) fn spawn_project_node<'a>(
     commands: &'a mut ChildSpawnerCommands,
     theme: &Theme,
-    asset_server: &Res<AssetServer>,
     project: &ProjectInfo,
 ) -> EntityCommands<'a> {
     let mut root_ec = commands.spawn((
@@ -181,121 +317,49 @@ pub(crate) fn spawn_project_node<'a>(
             margin: UiRect::all(Val::Px(5.0)),
             width: Val::Px(250.0),
             height: Val::Px(200.0),
+            border: UiRect::all(Val::Px(SELECTION_BORDER)),
             ..default()
         },
         RelativeCursorPosition::default(),
         BorderRadius::new(Val::Px(15.0), Val::Px(15.0), Val::Px(15.0), Val::Px(15.0)),
+        BorderColor::all(Color::NONE),
         theme.button.background_color,
+        ProjectNode(project.path.clone()),
+        Focusable(1),
+        CardElevation::default(),
+        project_context_menu(project.pinned),
     ));
 
-    root_ec.observe(
-        |trigger: On<Pointer<Release>>,
-         mut commands: Commands,
-         query_children: Query<&Children>,
-         query_text: Query<&Text>,
-         mut exit: EventWriter<AppExit>,
-         mut project_list: ResMut<ProjectInfoList>,
-         theme: Res<Theme>| {
-            let project = {
-                let text = {
-                    let project_entity = trigger.target();
-                    let project_children = query_children.get(project_entity).unwrap();
-                    let text_container = project_children.get(1).expect(
-                        "Expected project node to have 2 children, (the second being a container for the name)"
-                    );
-                    let text_container_children = query_children.get(*text_container).unwrap();
-                    let text_entity = text_container_children
-                        .first()
-                        .expect("Expected text container to have 1 child, the text entity");
-                    query_text
-                        .get(*text_entity)
-                        .expect("Expected text entity to have a Text component")
-                };
-
-                project_list
-                    .0
-                    .iter()
-                    .find(|p| p.name().unwrap() == text.0)
-                    .unwrap()
-                    .clone()
-            };
-
-            // Check if project directory exists before trying to run it
-            if !Path::new(&project.path).exists() {
-                // Show notification popup
-                let project_name = project.name().unwrap_or_else(|| "Unknown".to_string());
-                spawn_notification_popup(
-                    &mut commands,
-                    &theme,
-                    &format!("Project not found: '{project_name}'"),
-                );
-                // Remove project from list
-                project_list.0.retain(|p| p.path != project.path);
-                set_project_list(project_list.0.clone());
-                // Remove project node from UI
-                let project_entity = trigger.target();
-                commands.entity(project_entity).despawn();
-                return;
-            }
-
-            // Project exists, try to run it
-            match run_project(&project) {
-                Ok(_) => {
-                    exit.write(AppExit::Success);
-                }
-                Err(error) => {
-                    error!("Failed to run project: {:?}", error);
-                    match error.kind() {
-                        ErrorKind::NotFound | ErrorKind::InvalidData => {
-                            // Show notification popup
-                            let project_name = project.name().unwrap_or_else(|| "Unknown".to_string());
-                            spawn_notification_popup(
-                                &mut commands,
-                                &theme,
-                                &format!("Failed to run project: '{project_name}'"),
-                            );
-                            // Remove project from list
-                            project_list.0.retain(|p| p.path != project.path);
-                            set_project_list(project_list.0.clone());
-                            // Remove project node from UI
-                            let project_entity = trigger.target();
-                            commands.entity(project_entity).despawn();
-                        }
-                        _ => {
-                            // Show generic error notification
-                            spawn_notification_popup(
-                                &mut commands,
-                                &theme,
-                                &format!("Error running project: '{error}'"),
+    root_ec
+        .observe(
+            |trigger: On<Pointer<Release>>,
+             mut commands: Commands,
+             keys: Res<ButtonInput<KeyCode>>| {
+                let behavior = match trigger.event().button {
+                    PointerButton::Primary => {
+                        // A click with a modifier selects the project instead of opening it
+                        if let Some(mode) = SelectMode::from_keys(&keys) {
+                            commands.run_system_cached_with(
+                                select_project_node,
+                                (trigger.target(), mode),
                             );
+                            return;
                         }
+                        None
                     }
-                }
-            }
-        },
-    );
+                    // Middle-click always opens the project next to the launcher, like a browser tab
+                    PointerButton::Middle => Some(OpenBehavior::NewWindow),
+                    PointerButton::Secondary => return,
+                };
+                commands.run_system_cached_with(open_project_node, (trigger.target(), behavior));
+            },
+        )
+        .observe(|trigger: On<Activate>, mut commands: Commands| {
+            commands.run_system_cached_with(open_project_node, (trigger.target(), None));
+        });
 
     root_ec.with_children(|parent| {
-        // Project preview (TODO: add thumbnail)
-        parent
-            .spawn((Node {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                overflow: Overflow::clip(),
-                flex_grow: 1.0,
-                align_items: AlignItems::Center,
-                justify_content: JustifyContent::Center,
-                ..default()
-            },))
-            .with_children(|parent| {
-                parent.spawn((
-                    ImageNode::new(asset_server.load("image-off.png")),
-                    Node {
-                        width: Val::Percent(30.0),
-                        ..default()
-                    },
-                ));
-            });
+        spawn_project_preview(parent, theme, project);
         // Project name
         parent
             .spawn((
@@ -307,18 +371,67 @@ pub(crate) fn spawn_project_node<'a>(
                     align_items: AlignItems::Center,
                     ..default()
                 },
-                BackgroundColor(Color::oklch(0.209, 0.0, 0.0)),
+                theme.general.background_color,
                 BorderRadius::new(Val::Px(0.0), Val::Px(0.0), Val::Px(15.0), Val::Px(15.0)),
             ))
-            .with_child((
-                Text::new(project.name().unwrap().to_string()),
-                TextFont {
-                    font: theme.text.font.clone(),
-                    font_size: 16.0,
-                    ..default()
-                },
-            ));
+            .with_children(|name_bar| {
+                spawn_project_icon(name_bar, theme, project);
+                name_bar.spawn(project_name_label(
+                    theme,
+                    project.name().unwrap().to_string(),
+                ));
+            });
     });
 
     root_ec
 }
+
+/// Open the project displayed by the given [`ProjectNode`] with the tool, which is remembered for the next ones.
+fn open_project_node_with(
+    In((project_entity, tool)): In<(Entity, OpenWith)>,
+    query_nodes: Query<&ProjectNode>,
+    mut settings: ResMut<LauncherSettings>,
+    mut open_events: EventWriter<OpenProjectEvent>,
+) {
+    if let Ok(ProjectNode(path)) = query_nodes.get(project_entity) {
+        settings.open_with = tool;
+        open_events.write(OpenProjectEvent {
+            path: path.clone(),
+            behavior: None,
+            with: Some(tool),
+            target: None,
+        });
+    }
+}
+
+/// Open the project displayed by the given [`ProjectNode`] at a file, relative to the project.
+fn open_project_file(
+    In((project_entity, target)): In<(Entity, PathBuf)>,
+    query_nodes: Query<&ProjectNode>,
+    mut open_events: EventWriter<OpenProjectEvent>,
+) {
+    if let Ok(ProjectNode(path)) = query_nodes.get(project_entity) {
+        open_events.write(OpenProjectEvent {
+            path: path.clone(),
+            behavior: None,
+            with: None,
+            target: Some(target),
+        });
+    }
+}
+
+/// Open the project displayed by the given [`ProjectNode`], optionally overriding the [`OpenBehavior`].
+fn open_project_node(
+    In((project_entity, behavior)): In<(Entity, Option<OpenBehavior>)>,
+    query_nodes: Query<&ProjectNode>,
+    mut open_events: EventWriter<OpenProjectEvent>,
+) {
+    if let Ok(ProjectNode(path)) = query_nodes.get(project_entity) {
+        open_events.write(OpenProjectEvent {
+            path: path.clone(),
+            behavior,
+            with: None,
+            target: None,
+        });
+    }
+}
@@ -0,0 +1,241 @@
+//! A "What's new" panel shown once after the launcher is updated to a new version.
+//!
+//! The version last seen by the user is kept in [`LauncherSettings::last_seen_version`], and the
+//! panel lists the section of the bundled changelog for the current version. New users see the
+//! first run setup instead, the current version is marked as seen for them right away.
+
+use bevy::prelude::*;
+use bevy_editor_styles::{
+    Theme,
+    dialog::{DialogResult, close_dialog, spawn_dialog_button, spawn_dialog_root},
+};
+use bevy_scroll_box::{ScrollShadows, spawn_scroll_box};
+
+use crate::{
+    settings::{LauncherSettings, SettingsFile},
+    ui,
+};
+
+/// The version of the launcher, compared to the last seen one.
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The changelog bundled with the launcher, a `## <version>` heading for each version.
+const CHANGELOG: &str = include_str!("../assets/whats_new.md");
+
+/// Plugin showing the [`WhatsNewPanel`] at startup when the version changed.
+pub struct WhatsNewPlugin;
+
+impl Plugin for WhatsNewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, show_whats_new_on_update.after(ui::setup));
+    }
+}
+
+/// The dialog listing the changes of the current version.
+#[derive(Component)]
+struct WhatsNewPanel;
+
+/// A line of the changelog, as displayed in the [`WhatsNewPanel`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ChangelogLine {
+    Heading(String),
+    Item(String),
+    Text(String),
+}
+
+/// The lines of the section of the changelog for `version`, without its heading.
+///
+/// Returns the lines of all the sections when `version` has none, so that the panel isn't empty.
+fn changelog_lines(changelog: &str, version: &str) -> Vec<ChangelogLine> {
+    let heading = format!("## {version}");
+    let section = match changelog.lines().position(|line| line.trim() == heading) {
+        Some(start) => changelog
+            .lines()
+            .skip(start + 1)
+            .take_while(|line| !line.starts_with("## "))
+            .collect::<Vec<_>>(),
+        None => changelog
+            .lines()
+            .filter(|line| !line.starts_with("# "))
+            .collect(),
+    };
+    section
+        .into_iter()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            if let Some(heading) = line.strip_prefix("## ").or(line.strip_prefix("### ")) {
+                ChangelogLine::Heading(heading.to_string())
+            } else if let Some(item) = line.strip_prefix("- ").or(line.strip_prefix("* ")) {
+                ChangelogLine::Item(item.to_string())
+            } else {
+                ChangelogLine::Text(line.to_string())
+            }
+        })
+        .collect()
+}
+
+/// Show the [`WhatsNewPanel`] once the launcher runs a version the user hasn't seen yet.
+fn show_whats_new_on_update(
+    mut commands: Commands,
+    mut settings: ResMut<LauncherSettings>,
+    settings_file: Res<SettingsFile>,
+) {
+    if settings.last_seen_version.as_deref() == Some(VERSION) {
+        return;
+    }
+    // The first run setup writes the settings, with the version marked as seen
+    if !settings_file.0.exists() {
+        settings.last_seen_version = Some(VERSION.to_string());
+        return;
+    }
+    info!("Showing what's new in version {VERSION}");
+    commands.run_system_cached(show_whats_new);
+}
+
+/// Open the [`WhatsNewPanel`] of the current version, marking the version as seen once dismissed.
+pub(crate) fn show_whats_new(
+    mut commands: Commands,
+    panels: Query<(), With<WhatsNewPanel>>,
+    theme: Res<Theme>,
+) {
+    if !panels.is_empty() {
+        return;
+    }
+
+    let dialog = spawn_dialog_root(&mut commands);
+    commands.entity(dialog).insert(WhatsNewPanel).observe(
+        |_trigger: On<DialogResult>, mut settings: ResMut<LauncherSettings>| {
+            // Dismissing it in any way counts as seen
+            if settings.last_seen_version.as_deref() != Some(VERSION) {
+                settings.last_seen_version = Some(VERSION.to_string());
+            }
+        },
+    );
+
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(480.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(format!("What's new in {VERSION}")),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+
+    let content = commands
+        .spawn((
+            Node {
+                max_height: Val::Px(320.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    let theme_ref: &Theme = &theme;
+    spawn_scroll_box(
+        &mut commands,
+        &theme,
+        Overflow::scroll_y(),
+        Some(move |commands: &mut Commands, scroll_content: Entity| {
+            let lines = commands
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        display: Display::Flex,
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(6.0),
+                        ..default()
+                    },
+                    ChildOf(scroll_content),
+                ))
+                .id();
+            for line in changelog_lines(CHANGELOG, VERSION) {
+                let (text, size, color) = match line {
+                    ChangelogLine::Heading(text) => (text, 15.0, theme_ref.text.text_color),
+                    ChangelogLine::Item(text) => {
+                        (format!("• {text}"), 14.0, theme_ref.text.text_color)
+                    }
+                    ChangelogLine::Text(text) => (text, 14.0, theme_ref.text.low_priority),
+                };
+                commands.spawn((
+                    Text::new(text),
+                    TextFont {
+                        font: theme_ref.text.font.clone(),
+                        font_size: size,
+                        ..default()
+                    },
+                    TextColor(color),
+                    ChildOf(lines),
+                ));
+            }
+        }),
+    )
+    .insert((ScrollShadows, ChildOf(content)));
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(&mut commands, &theme, "Got it", theme.button.hover_color)
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                close_dialog(&mut commands, dialog, true);
+            },
+        );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_the_section_of_the_version() {
+        let changelog = "# What's new\n\n## 0.2.0\n\n- Faster\nAnd more.\n\n## 0.1.0\n\n- First\n";
+        assert_eq!(
+            changelog_lines(changelog, "0.2.0"),
+            vec![
+                ChangelogLine::Item("Faster".to_string()),
+                ChangelogLine::Text("And more.".to_string()),
+            ]
+        );
+        assert_eq!(
+            changelog_lines(changelog, "0.1.0"),
+            vec![ChangelogLine::Item("First".to_string())]
+        );
+        assert_eq!(
+            changelog_lines(changelog, "0.3.0").first(),
+            Some(&ChangelogLine::Heading("0.2.0".to_string())),
+            "all the sections without one for the version"
+        );
+        // Without it, all the sections would be shown
+        assert!(
+            CHANGELOG
+                .lines()
+                .any(|line| line.trim() == format!("## {VERSION}")),
+            "the bundled changelog has a section for {VERSION}"
+        );
+    }
+}
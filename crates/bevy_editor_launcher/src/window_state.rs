@@ -0,0 +1,139 @@
+//! Restore the launcher window on the monitor, position and size it was last left at.
+
+use bevy::{
+    prelude::*,
+    window::{Monitor, PrimaryWindow, WindowMoved, WindowResized},
+};
+
+use crate::settings::{LauncherSettings, WindowState};
+
+/// Plugin restoring and tracking the [`WindowState`].
+pub struct WindowStatePlugin;
+
+impl Plugin for WindowStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                restore_window_state.run_if(not(resource_exists::<WindowStateRestored>)),
+                track_window_state.run_if(resource_exists::<WindowStateRestored>),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Inserted once the [`WindowState`] has been applied to the primary window.
+#[derive(Resource)]
+struct WindowStateRestored;
+
+/// Apply the saved window size to the window configuration, before the window is created.
+pub fn apply_window_size(window: &mut Window, state: &WindowState) {
+    if let Some((width, height)) = state.size {
+        window.resolution.set(width, height);
+    }
+}
+
+/// Monitors sorted by their position, so that indices are stable between runs.
+fn sorted_monitors<'a>(monitors: impl Iterator<Item = &'a Monitor>) -> Vec<&'a Monitor> {
+    let mut monitors: Vec<_> = monitors.collect();
+    monitors.sort_by_key(|monitor| (monitor.physical_position.x, monitor.physical_position.y));
+    monitors
+}
+
+/// Wait for the monitors to be known, then move the window to the saved monitor.
+fn restore_window_state(
+    mut commands: Commands,
+    mut window: Single<&mut Window, With<PrimaryWindow>>,
+    monitors: Query<&Monitor>,
+    settings: Res<LauncherSettings>,
+) {
+    if monitors.is_empty() {
+        return;
+    }
+    commands.insert_resource(WindowStateRestored);
+
+    let state = &settings.window;
+    let monitors = sorted_monitors(monitors.iter());
+    let monitor = state
+        .monitor_name
+        .as_ref()
+        .and_then(|name| {
+            monitors
+                .iter()
+                .find(|monitor| monitor.name.as_ref() == Some(name))
+        })
+        .or_else(|| state.monitor_index.and_then(|index| monitors.get(index)));
+
+    let Some(monitor) = monitor else {
+        if state.monitor_name.is_some() || state.monitor_index.is_some() {
+            info!("Saved monitor is no longer available, using the primary monitor");
+            window.position = WindowPosition::Centered(MonitorSelection::Primary);
+        }
+        return;
+    };
+
+    // The position is saved in logical pixels, so that it maps to the same place on monitors with a different DPI
+    let scale = monitor.scale_factor as f32;
+    let origin = monitor.physical_position.as_vec2();
+    let monitor_size = Vec2::new(
+        monitor.physical_width as f32,
+        monitor.physical_height as f32,
+    );
+    let window_size = window.resolution.size() * scale;
+    let max = (monitor_size - window_size).max(Vec2::ZERO);
+    let offset = match state.position {
+        Some((x, y)) => (Vec2::new(x, y) * scale).clamp(Vec2::ZERO, max),
+        None => max / 2.0,
+    };
+    window.position = WindowPosition::At((origin + offset).as_ivec2());
+}
+
+/// Save the monitor, position and size of the window when it is moved or resized.
+fn track_window_state(
+    mut moved: EventReader<WindowMoved>,
+    mut resized: EventReader<WindowResized>,
+    primary_window: Single<Entity, With<PrimaryWindow>>,
+    monitors: Query<&Monitor>,
+    mut settings: ResMut<LauncherSettings>,
+) {
+    let primary_window = *primary_window;
+    let mut state = settings.window.clone();
+
+    if let Some(event) = moved
+        .read()
+        .filter(|event| event.window == primary_window)
+        .last()
+    {
+        let monitors = sorted_monitors(monitors.iter());
+        let position = event.position;
+        let found = monitors.iter().enumerate().find(|(_, monitor)| {
+            let min = monitor.physical_position;
+            let max = min
+                + IVec2::new(
+                    monitor.physical_width as i32,
+                    monitor.physical_height as i32,
+                );
+            position.x >= min.x && position.y >= min.y && position.x < max.x && position.y < max.y
+        });
+        if let Some((index, monitor)) = found {
+            let offset =
+                (position - monitor.physical_position).as_vec2() / monitor.scale_factor as f32;
+            state.monitor_name = monitor.name.clone();
+            state.monitor_index = Some(index);
+            state.position = Some((offset.x, offset.y));
+        }
+    }
+
+    if let Some(event) = resized
+        .read()
+        .filter(|event| event.window == primary_window)
+        .last()
+    {
+        state.size = Some((event.width, event.height));
+    }
+
+    if state != settings.window {
+        settings.window = state;
+    }
+}
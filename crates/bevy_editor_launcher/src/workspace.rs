@@ -0,0 +1,427 @@
+//! The projects that are cargo workspaces, whose [`ProjectNode`] expands to list their member crates.
+//!
+//! Running a workspace runs its [`default_member`](ProjectInfo::default_member), picked from the
+//! member list or from the dialog shown when opening a workspace with several binaries.
+
+use std::path::Path;
+
+use bevy::{
+    prelude::*,
+    tasks::{IoTaskPool, Task, block_on, futures_lite::future},
+};
+use bevy_editor::project::{
+    ProjectInfo,
+    workspace::{WorkspaceMember, workspace_members},
+};
+use bevy_editor_styles::{
+    Theme,
+    chip::{Chip, ChipClicked},
+    dialog::{DialogResult, close_dialog, spawn_dialog_button, spawn_dialog_root},
+    dropdown::{Dropdown, spawn_dropdown},
+};
+
+use crate::{
+    ProjectInfoList, ProjectListStore, open::OpenProjectEvent, refresh::RefreshProjectsEvent,
+    settings::OpenWith, ui::ProjectNode,
+};
+
+/// Plugin finding the members of the workspace projects and displaying them.
+pub struct WorkspacePlugin;
+
+impl Plugin for WorkspacePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (start_member_tasks, poll_member_tasks).chain());
+    }
+}
+
+/// The member crates of the workspace displayed by a [`ProjectNode`].
+#[derive(Component, Debug, Default)]
+struct WorkspaceMembers(Vec<WorkspaceMember>);
+
+/// The background search of the [`WorkspaceMembers`] of a [`ProjectNode`].
+#[derive(Component)]
+struct MemberTask(Task<Option<Vec<WorkspaceMember>>>);
+
+/// The [`Chip`] of a workspace [`ProjectNode`] expanding its [`MemberList`], placed over the node.
+#[derive(Component)]
+struct MembersToggle;
+
+/// The member crates listed over a workspace [`ProjectNode`].
+#[derive(Component)]
+struct MemberList;
+
+/// The dialog picking the member to run when opening a workspace without a default one.
+#[derive(Component)]
+struct MemberPicker {
+    /// The open request, sent again once the member is picked.
+    event: OpenProjectEvent,
+    /// The names of the binary members, in the order of the dropdown options.
+    members: Vec<String>,
+    /// The dropdown of the [`members`](Self::members).
+    dropdown: Entity,
+}
+
+/// The names of the members that can be run.
+pub(crate) fn binary_members(members: &[WorkspaceMember]) -> Vec<String> {
+    members
+        .iter()
+        .filter(|member| member.is_binary)
+        .map(|member| member.name.clone())
+        .collect()
+}
+
+/// The text of the [`MembersToggle`] of a workspace with this many members.
+fn toggle_label(members: usize, expanded: bool) -> String {
+    let crates = if members == 1 { "crate" } else { "crates" };
+    let arrow = if expanded { "▴" } else { "▾" };
+    format!("{members} {crates} {arrow}")
+}
+
+/// Find the members of the new [`ProjectNode`]s, and of all of them on refresh.
+fn start_member_tasks(
+    mut commands: Commands,
+    mut refresh_events: EventReader<RefreshProjectsEvent>,
+    added: Query<(Entity, &ProjectNode), Added<ProjectNode>>,
+    nodes: Query<(Entity, &ProjectNode)>,
+) {
+    let refresh = refresh_events.read().count() > 0;
+    let nodes = if refresh {
+        nodes.iter().collect::<Vec<_>>()
+    } else {
+        added.iter().collect()
+    };
+    for (entity, ProjectNode(path)) in nodes {
+        let path = path.clone();
+        let task = IoTaskPool::get().spawn(async move { workspace_members(&path) });
+        // The node may have been removed by the same refresh
+        commands.entity(entity).try_insert(MemberTask(task));
+    }
+}
+
+/// Store the found [`WorkspaceMembers`] on their [`ProjectNode`], with a [`MembersToggle`] for the
+/// workspaces.
+fn poll_member_tasks(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut MemberTask, Option<&Children>)>,
+    widgets: Query<(), Or<(With<MembersToggle>, With<MemberList>)>>,
+    theme: Res<Theme>,
+) {
+    for (entity, mut task, children) in &mut tasks {
+        let Some(members) = block_on(future::poll_once(&mut task.0)) else {
+            continue;
+        };
+        commands.entity(entity).remove::<MemberTask>();
+        for child in children.into_iter().flat_map(|children| children.iter()) {
+            if widgets.contains(child) {
+                commands.entity(child).despawn();
+            }
+        }
+        let Some(members) = members else {
+            commands.entity(entity).remove::<WorkspaceMembers>();
+            continue;
+        };
+
+        let toggle = commands
+            .spawn((
+                MembersToggle,
+                Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(8.0),
+                    left: Val::Px(8.0),
+                    ..default()
+                },
+                ChildOf(entity),
+            ))
+            .id();
+        Chip::new(toggle_label(members.len(), false))
+            .clickable()
+            .spawn(&mut commands, &theme)
+            .insert(ChildOf(toggle))
+            .observe(move |_trigger: On<ChipClicked>, mut commands: Commands| {
+                commands.run_system_cached_with(toggle_member_list, entity);
+            });
+        commands.entity(entity).insert(WorkspaceMembers(members));
+    }
+}
+
+/// Expand or collapse the [`MemberList`] of the given workspace [`ProjectNode`].
+fn toggle_member_list(
+    In(project_entity): In<Entity>,
+    mut commands: Commands,
+    nodes: Query<(&ProjectNode, &WorkspaceMembers, &Children)>,
+    lists: Query<(), With<MemberList>>,
+    toggles: Query<&Children, With<MembersToggle>>,
+    mut chips: Query<&mut Chip>,
+    project_list: Res<ProjectInfoList>,
+    theme: Res<Theme>,
+) {
+    let Ok((ProjectNode(path), members, children)) = nodes.get(project_entity) else {
+        return;
+    };
+    let expanded = children.iter().find(|child| lists.contains(*child));
+    let label = toggle_label(members.0.len(), expanded.is_none());
+    for toggle in children.iter().filter_map(|child| toggles.get(child).ok()) {
+        if let Some(mut chip) = toggle.first().and_then(|chip| chips.get_mut(*chip).ok()) {
+            chip.label.clone_from(&label);
+        }
+    }
+    if let Some(list) = expanded {
+        commands.entity(list).despawn();
+        return;
+    }
+
+    let default_member = project_list
+        .0
+        .iter()
+        .find(|project| project.path == *path)
+        .and_then(|project| project.default_member.clone());
+    let list = commands
+        .spawn((
+            MemberList,
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(30.0),
+                left: Val::Px(8.0),
+                right: Val::Px(8.0),
+                max_height: Val::Px(120.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(2.0),
+                padding: UiRect::all(Val::Px(4.0)),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            GlobalZIndex(3),
+            ChildOf(project_entity),
+        ))
+        .id();
+    for member in &members.0 {
+        let is_default = default_member.as_ref() == Some(&member.name);
+        let label = match (member.is_binary, is_default) {
+            (true, true) => format!("▶ {} (default)", member.name),
+            (true, false) => format!("▶ {}", member.name),
+            (false, _) => format!("   {} (library)", member.name),
+        };
+        let mut row = commands.spawn((
+            Node {
+                padding: UiRect::axes(Val::Px(4.0), Val::Px(2.0)),
+                ..default()
+            },
+            BorderRadius::all(Val::Px(4.0)),
+            ChildOf(list),
+            children![(
+                Text::new(label),
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(if member.is_binary {
+                    theme.text.text_color
+                } else {
+                    theme.text.low_priority
+                }),
+                Pickable::IGNORE,
+            )],
+        ));
+        if !member.is_binary {
+            // The libraries are only listed, clicking them does nothing instead of opening the project
+            row.observe(|mut trigger: On<Pointer<Release>>| trigger.propagate(false));
+            continue;
+        }
+        let name = member.name.clone();
+        row.observe(
+            move |mut trigger: On<Pointer<Release>>, mut commands: Commands| {
+                trigger.propagate(false);
+                if trigger.event().button == PointerButton::Primary {
+                    commands.run_system_cached_with(
+                        run_workspace_member,
+                        (project_entity, name.clone()),
+                    );
+                }
+            },
+        );
+    }
+}
+
+/// Make the member the default one of the workspace at `path`, saving the project list.
+fn set_default_member(
+    projects: &mut [ProjectInfo],
+    store: &ProjectListStore,
+    path: &Path,
+    member: &str,
+) -> bool {
+    let Some(project) = projects.iter_mut().find(|project| project.path == path) else {
+        return false;
+    };
+    if project.default_member.as_deref() != Some(member) {
+        project.default_member = Some(member.to_string());
+        store.set(projects.to_vec());
+    }
+    true
+}
+
+/// Run the member of the workspace displayed by the given [`ProjectNode`], making it the default one.
+fn run_workspace_member(
+    In((project_entity, member)): In<(Entity, String)>,
+    nodes: Query<&ProjectNode>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    mut open_events: EventWriter<OpenProjectEvent>,
+) {
+    let Ok(ProjectNode(path)) = nodes.get(project_entity) else {
+        return;
+    };
+    if set_default_member(&mut project_list.0, &store, path, &member) {
+        open_events.write(OpenProjectEvent {
+            path: path.clone(),
+            behavior: None,
+            with: Some(OpenWith::Editor),
+            target: None,
+        });
+    }
+}
+
+/// Ask which of the binary `members` to run for the open request of a workspace.
+pub(crate) fn show_member_picker(
+    In((event, members)): In<(OpenProjectEvent, Vec<String>)>,
+    mut commands: Commands,
+    theme: Res<Theme>,
+) {
+    let name = event
+        .path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let dialog = spawn_dialog_root(&mut commands);
+    let panel = commands
+        .spawn((
+            Node {
+                width: Val::Px(380.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(16.0)),
+                ..default()
+            },
+            theme.pane.area_background_color,
+            theme.general.border_radius,
+            ChildOf(dialog),
+        ))
+        .id();
+    commands.spawn((
+        Text::new(format!("Which crate of {name} should run?")),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        ChildOf(panel),
+    ));
+    commands.spawn((
+        Text::new("The choice is remembered, pick another one from the crates of the project."),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.low_priority),
+        ChildOf(panel),
+    ));
+    let dropdown = spawn_dropdown(&mut commands, &theme, members.clone(), 0)
+        .insert(ChildOf(panel))
+        .id();
+    commands
+        .entity(dialog)
+        .insert(MemberPicker {
+            event,
+            members,
+            dropdown,
+        })
+        .observe(finish_member_picker);
+
+    let buttons = commands
+        .spawn((
+            Node {
+                display: Display::Flex,
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            ChildOf(panel),
+        ))
+        .id();
+    spawn_dialog_button(
+        &mut commands,
+        &theme,
+        "Cancel",
+        theme.button.background_color.0,
+    )
+    .insert(ChildOf(buttons))
+    .observe(
+        move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+            close_dialog(&mut commands, dialog, false);
+        },
+    );
+    spawn_dialog_button(&mut commands, &theme, "Run", theme.button.hover_color)
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                close_dialog(&mut commands, dialog, true);
+            },
+        );
+}
+
+/// Store the member picked in the [`MemberPicker`] as the default one, and open the project again.
+fn finish_member_picker(
+    trigger: On<DialogResult>,
+    pickers: Query<&MemberPicker>,
+    dropdowns: Query<&Dropdown>,
+    mut project_list: ResMut<ProjectInfoList>,
+    store: Res<ProjectListStore>,
+    mut open_events: EventWriter<OpenProjectEvent>,
+) {
+    let Ok(picker) = pickers.get(trigger.target()) else {
+        return;
+    };
+    if !trigger.event().0 {
+        return;
+    }
+    let selected = dropdowns
+        .get(picker.dropdown)
+        .map_or(0, |dropdown| dropdown.selected);
+    let Some(member) = picker.members.get(selected) else {
+        return;
+    };
+    if set_default_member(&mut project_list.0, &store, &picker.event.path, member) {
+        open_events.write(picker.event.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn list_the_binary_members() {
+        let member = |name: &str, is_binary| WorkspaceMember {
+            name: name.to_string(),
+            path: PathBuf::from("/projects/game").join(name),
+            is_binary,
+        };
+        let members = [
+            member("client", true),
+            member("physics", false),
+            member("server", true),
+        ];
+        assert_eq!(binary_members(&members), ["client", "server"]);
+        assert_eq!(toggle_label(1, false), "1 crate ▾");
+        assert_eq!(toggle_label(3, true), "3 crates ▴");
+    }
+}
@@ -0,0 +1,259 @@
+//! Compare screenshots of the launcher with golden images, catching the visual regressions.
+//!
+//! The launcher is rendered offscreen, with the dark theme and a fixed project list, which needs a
+//! GPU or a software renderer. The test is behind the `screenshot_tests` feature, and ignored so
+//! that the CI building with all the features doesn't run it:
+//!
+//! ```sh
+//! cargo test -p bevy_editor_launcher --features screenshot_tests --test screenshots -- --ignored
+//! ```
+//!
+//! The goldens are in `tests/goldens`, and the screenshots of the last run in
+//! `target/screenshots`. The cases without a golden fail, see `tests/goldens/README.md` for
+//! generating the first ones. After an intended change of the UI, regenerate the goldens with the
+//! `UPDATE_GOLDENS` environment variable set, then review them before committing:
+//!
+//! ```sh
+//! UPDATE_GOLDENS=1 cargo test -p bevy_editor_launcher --features screenshot_tests --test screenshots -- --ignored
+//! ```
+//!
+//! The screens compared are the empty project list and a populated one, see [`cases`].
+
+use std::{
+    fs,
+    path::Path,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use bevy::{
+    asset::RenderAssetUsages,
+    image::{CompressedImageFormats, ImageSampler, ImageType},
+    log::LogPlugin,
+    prelude::*,
+    render::{
+        camera::RenderTarget,
+        pipelined_rendering::PipelinedRenderingPlugin,
+        render_resource::{Extent3d, TextureFormat, TextureUsages},
+        view::screenshot::{Screenshot, save_to_disk},
+    },
+    winit::WinitPlugin,
+};
+use bevy_editor::project::{MemoryProjectStore, ProjectInfo};
+use bevy_editor_launcher::{LauncherPlugin, LauncherSettings, ProjectListStore, SettingsFile};
+use bevy_editor_styles::ThemeMode;
+
+/// The size of the screenshots, in pixels.
+const SIZE: (u32, u32) = (1024, 640);
+/// How many frames the launcher runs before its screenshot, so that the fonts are loaded and the
+/// layout settled.
+const WARM_UP_FRAMES: u32 = 120;
+/// How many frames to wait for the screenshot to be written, before giving up.
+const CAPTURE_FRAMES: u32 = 120;
+/// How much a channel of a pixel can differ from the golden, to absorb the differences between
+/// the GPUs and drivers.
+const CHANNEL_TOLERANCE: u8 = 8;
+/// The fraction of the pixels that can differ more than the [`CHANNEL_TOLERANCE`].
+const PIXEL_TOLERANCE: f64 = 0.005;
+
+/// A screen of the launcher compared with its golden image.
+struct Case {
+    name: &'static str,
+    projects: Vec<ProjectInfo>,
+}
+
+/// A project listed as last opened `days` ago, so that its age reads the same on every run.
+fn project(name: &str, days: u64, pinned: bool) -> ProjectInfo {
+    ProjectInfo {
+        last_opened: SystemTime::now() - Duration::from_secs(days * 86_400),
+        pinned,
+        ..ProjectInfo::new(Path::new("/screenshots/projects").join(name))
+    }
+}
+
+fn cases() -> Vec<Case> {
+    vec![
+        Case {
+            name: "empty",
+            projects: Vec::new(),
+        },
+        Case {
+            name: "populated",
+            projects: vec![
+                project("space_shooter", 1, true),
+                project("platformer", 3, false),
+                project("city_builder", 12, false),
+            ],
+        },
+    ]
+}
+
+/// The settings of the screenshots, animations and background checks turned off.
+fn settings() -> LauncherSettings {
+    LauncherSettings {
+        theme: ThemeMode::Dark,
+        reduce_motion: true,
+        restore_session: false,
+        check_connectivity: false,
+        detect_bevy_version: false,
+        // The what's new panel would cover the launcher
+        last_seen_version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        ..default()
+    }
+}
+
+/// Render the launcher showing the projects offscreen, and write its screenshot to `output`.
+fn capture(projects: Vec<ProjectInfo>, settings_file: &Path, output: &Path) {
+    let mut app = App::new();
+    app.insert_resource(settings())
+        .insert_resource(ThemeMode::Dark)
+        // An existing settings file skips the first run setup
+        .insert_resource(SettingsFile(settings_file.to_path_buf()))
+        .insert_resource(ProjectListStore(Arc::new(MemoryProjectStore::new(
+            projects,
+        ))))
+        .add_plugins((
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: bevy::window::ExitCondition::DontExit,
+                    ..default()
+                })
+                .disable::<WinitPlugin>()
+                .disable::<PipelinedRenderingPlugin>()
+                .disable::<LogPlugin>(),
+            LauncherPlugin::default(),
+        ))
+        .add_systems(PostStartup, render_offscreen);
+    app.finish();
+    app.cleanup();
+
+    for _ in 0..WARM_UP_FRAMES {
+        app.update();
+    }
+    let target = app.world().resource::<OffscreenTarget>().0.clone();
+    app.world_mut()
+        .spawn(Screenshot::image(target))
+        .observe(save_to_disk(output.to_path_buf()));
+    for _ in 0..CAPTURE_FRAMES {
+        app.update();
+        if output.exists() {
+            // The file may still be written by the observer of the render world
+            app.update();
+            return;
+        }
+    }
+    panic!("The screenshot '{}' wasn't written", output.display());
+}
+
+/// The image the launcher is rendered to.
+#[derive(Resource)]
+struct OffscreenTarget(Handle<Image>);
+
+/// Render the cameras of the launcher, and so its UI, to an [`OffscreenTarget`].
+fn render_offscreen(
+    mut commands: Commands,
+    mut cameras: Query<(Entity, &mut Camera)>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let mut image = Image::default();
+    image.resize(Extent3d {
+        width: SIZE.0,
+        height: SIZE.1,
+        depth_or_array_layers: 1,
+    });
+    image.texture_descriptor.usage |= TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC;
+    image.texture_descriptor.format = TextureFormat::Bgra8UnormSrgb;
+    let target = images.add(image);
+    for (camera_entity, mut camera) in &mut cameras {
+        camera.target = RenderTarget::Image(target.clone().into());
+        commands.entity(camera_entity).insert(IsDefaultUiCamera);
+    }
+    commands.insert_resource(OffscreenTarget(target));
+}
+
+/// The size and RGBA pixels of the PNG file.
+fn read_pixels(path: &Path) -> (UVec2, Vec<u8>) {
+    let bytes = fs::read(path).unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+    let image = Image::from_buffer(
+        &bytes,
+        ImageType::Extension("png"),
+        CompressedImageFormats::NONE,
+        true,
+        ImageSampler::Default,
+        RenderAssetUsages::MAIN_WORLD,
+    )
+    .unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+    let size = image.size();
+    (size, image.data.unwrap_or_default())
+}
+
+/// The fraction of the pixels of `actual` differing from `golden` by more than the
+/// [`CHANNEL_TOLERANCE`], or why they can't be compared.
+fn difference(actual: &Path, golden: &Path) -> Result<f64, String> {
+    let (actual_size, actual) = read_pixels(actual);
+    let (golden_size, golden) = read_pixels(golden);
+    if actual_size != golden_size {
+        return Err(format!(
+            "the screenshot is {actual_size} pixels, the golden {golden_size}"
+        ));
+    }
+    let pixels = actual.len() / 4;
+    let differing = actual
+        .chunks_exact(4)
+        .zip(golden.chunks_exact(4))
+        .filter(|(actual, golden)| {
+            actual
+                .iter()
+                .zip(golden.iter())
+                .any(|(actual, golden)| actual.abs_diff(*golden) > CHANNEL_TOLERANCE)
+        })
+        .count();
+    Ok(differing as f64 / pixels.max(1) as f64)
+}
+
+#[test]
+#[ignore = "needs a GPU, run it with --ignored"]
+fn launcher_matches_the_goldens() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let goldens = manifest.join("tests/goldens");
+    let screenshots = manifest.join("../../target/screenshots");
+    fs::create_dir_all(&screenshots).unwrap();
+    let settings_file = screenshots.join("settings.ron");
+    fs::write(&settings_file, "()").unwrap();
+    let update = std::env::var_os("UPDATE_GOLDENS").is_some();
+
+    // The cases share the GPU, they run one after the other
+    let mut failures = Vec::new();
+    for case in cases() {
+        let actual = screenshots.join(format!("{}.png", case.name));
+        let golden = goldens.join(format!("{}.png", case.name));
+        let _ = fs::remove_file(&actual);
+        capture(case.projects, &settings_file, &actual);
+
+        if update {
+            fs::create_dir_all(&goldens).unwrap();
+            fs::copy(&actual, &golden).unwrap();
+            println!("Updated the golden '{}'", golden.display());
+            continue;
+        }
+        if !golden.exists() {
+            failures.push(format!(
+                "{}: no golden, generate it with UPDATE_GOLDENS=1, see tests/goldens/README.md",
+                case.name
+            ));
+            continue;
+        }
+        match difference(&actual, &golden) {
+            Ok(fraction) if fraction <= PIXEL_TOLERANCE => {}
+            Ok(fraction) => failures.push(format!(
+                "{}: {:.2}% of the pixels differ, see '{}'",
+                case.name,
+                fraction * 100.0,
+                actual.display()
+            )),
+            Err(reason) => failures.push(format!("{}: {reason}", case.name)),
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
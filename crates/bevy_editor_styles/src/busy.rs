@@ -0,0 +1,180 @@
+//! A busy state for the buttons starting background tasks, so they can't be triggered twice.
+//!
+//! The buttons are tagged with the [`BusyAction`] they start. While the [`BusyActions`] resource
+//! holds a running task for that action, the buttons are disabled and show an inline spinner.
+
+use std::{collections::HashMap, time::Duration};
+
+use bevy::{prelude::*, ui::InteractionDisabled};
+
+use crate::Theme;
+
+/// How long each frame of a [`BusySpinner`] is shown.
+const SPINNER_FRAME_DURATION: Duration = Duration::from_millis(250);
+/// The frames of a [`BusySpinner`], shown in a loop.
+const SPINNER_FRAMES: [&str; 4] = ["·", "··", "···", ""];
+
+/// The action started by a button, like `"scan"`, making it busy while a task of that action runs.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusyAction(pub &'static str);
+
+/// The running tasks of each [`BusyAction`], by the entity holding the task.
+///
+/// A task is done once it is [finished](Self::finish) or its entity is despawned.
+#[derive(Resource, Debug, Default)]
+pub struct BusyActions(HashMap<&'static str, Vec<Entity>>);
+
+impl BusyActions {
+    /// Record that the `task` entity runs a task of the action.
+    pub fn start(&mut self, action: &'static str, task: Entity) {
+        let tasks = self.0.entry(action).or_default();
+        if !tasks.contains(&task) {
+            tasks.push(task);
+        }
+    }
+
+    /// Record that the task of the action held by the `task` entity is done.
+    pub fn finish(&mut self, action: &'static str, task: Entity) {
+        if let Some(tasks) = self.0.get_mut(action) {
+            tasks.retain(|running| *running != task);
+        }
+    }
+
+    /// Whether a task of the action is running.
+    pub fn is_busy(&self, action: &str) -> bool {
+        self.0.get(action).is_some_and(|tasks| !tasks.is_empty())
+    }
+}
+
+/// The state of a busy [`BusyAction`] button, holding what to restore once it isn't busy anymore.
+#[derive(Component)]
+pub(crate) struct Busy {
+    /// The [`Pickable`] the button had before being disabled.
+    pickable: Option<Pickable>,
+}
+
+/// The spinner shown at the end of a busy button.
+#[derive(Component)]
+struct BusySpinner;
+
+/// Forget the tasks whose entity is gone, and make the [`BusyAction`] buttons busy while their
+/// action has running tasks.
+pub(crate) fn update_busy_buttons(
+    mut commands: Commands,
+    mut busy_actions: ResMut<BusyActions>,
+    entities: Query<()>,
+    buttons: Query<(Entity, &BusyAction, Option<&Busy>, Option<&Pickable>)>,
+    theme: Res<Theme>,
+) {
+    for tasks in busy_actions.0.values_mut() {
+        tasks.retain(|task| entities.contains(*task));
+    }
+
+    for (button, action, busy, pickable) in &buttons {
+        match (busy_actions.is_busy(action.0), busy) {
+            (true, None) => {
+                commands
+                    .entity(button)
+                    .insert((
+                        Busy {
+                            pickable: pickable.cloned(),
+                        },
+                        InteractionDisabled,
+                        Pickable::IGNORE,
+                    ))
+                    .with_child((
+                        BusySpinner,
+                        Node {
+                            width: Val::Px(16.0),
+                            margin: UiRect::left(Val::Px(6.0)),
+                            ..default()
+                        },
+                        Text::new(SPINNER_FRAMES[0]),
+                        TextFont {
+                            font: theme.text.font.clone(),
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(theme.text.low_priority),
+                        Pickable::IGNORE,
+                    ));
+            }
+            (false, Some(busy)) => {
+                let mut button_ec = commands.entity(button);
+                button_ec.remove::<(Busy, InteractionDisabled)>();
+                match &busy.pickable {
+                    Some(pickable) => button_ec.insert(pickable.clone()),
+                    None => button_ec.remove::<Pickable>(),
+                };
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Animate the [`BusySpinner`]s, and remove the ones of the buttons that aren't busy anymore.
+pub(crate) fn animate_busy_spinners(
+    mut commands: Commands,
+    mut spinners: Query<(Entity, &ChildOf, &mut Text), With<BusySpinner>>,
+    busy: Query<(), With<Busy>>,
+    time: Res<Time>,
+) {
+    let frame = (time.elapsed().as_millis() / SPINNER_FRAME_DURATION.as_millis()) as usize
+        % SPINNER_FRAMES.len();
+    for (spinner, child_of, mut text) in &mut spinners {
+        if !busy.contains(child_of.parent()) {
+            commands.entity(spinner).despawn();
+            continue;
+        }
+        if text.0 != SPINNER_FRAMES[frame] {
+            text.0 = SPINNER_FRAMES[frame].to_string();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::theme_app;
+
+    #[test]
+    fn disable_buttons_while_their_action_runs() {
+        let mut app = theme_app();
+        app.init_resource::<BusyActions>()
+            .add_systems(Update, (update_busy_buttons, animate_busy_spinners).chain());
+        let button = app
+            .world_mut()
+            .spawn((Node::default(), BusyAction("scan")))
+            .id();
+        let other = app
+            .world_mut()
+            .spawn((Node::default(), BusyAction("refresh")))
+            .id();
+        let task = app.world_mut().spawn_empty().id();
+
+        app.world_mut()
+            .resource_mut::<BusyActions>()
+            .start("scan", task);
+        app.update();
+        assert!(app.world().entity(button).contains::<InteractionDisabled>());
+        assert!(!app.world().entity(other).contains::<InteractionDisabled>());
+        assert_eq!(
+            app.world().entity(button).get::<Pickable>(),
+            Some(&Pickable::IGNORE)
+        );
+
+        // Despawning the task entity is enough to finish it
+        app.world_mut().despawn(task);
+        app.update();
+        app.update();
+        assert!(!app.world().resource::<BusyActions>().is_busy("scan"));
+        assert!(!app.world().entity(button).contains::<InteractionDisabled>());
+        assert!(!app.world().entity(button).contains::<Pickable>());
+        assert!(
+            app.world()
+                .entity(button)
+                .get::<Children>()
+                .is_none_or(|children| children.is_empty())
+        );
+    }
+}
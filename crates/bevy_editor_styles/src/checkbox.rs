@@ -0,0 +1,101 @@
+//! A themed checkbox with a label.
+
+use bevy::prelude::*;
+
+use crate::Theme;
+
+/// A checkbox, toggled when clicked.
+///
+/// [`CheckboxChanged`] is triggered on the entity every time the user toggles it.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Checkbox {
+    /// Whether the checkbox is checked.
+    pub checked: bool,
+}
+
+/// Triggered on a [`Checkbox`] when the user toggles it, holding the new state.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct CheckboxChanged(pub bool);
+
+/// The square box of a [`Checkbox`], filled when checked.
+#[derive(Component)]
+struct CheckboxMark;
+
+/// Spawn a [`Checkbox`] with the given label and initial state.
+pub fn spawn_checkbox<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    label: impl Into<String>,
+    checked: bool,
+) -> EntityCommands<'a> {
+    let mut checkbox = commands.spawn((
+        Checkbox { checked },
+        Node {
+            display: Display::Flex,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        },
+    ));
+    checkbox
+        .with_children(|parent| {
+            parent.spawn((
+                CheckboxMark,
+                Node {
+                    width: Val::Px(14.0),
+                    height: Val::Px(14.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BorderColor::all(theme.button.hover_color),
+                BackgroundColor(mark_color(theme, checked)),
+                theme.button.border_radius,
+                Pickable::IGNORE,
+            ));
+            parent.spawn((
+                Text::new(label.into()),
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(theme.text.text_color),
+                Pickable::IGNORE,
+            ));
+        })
+        .observe(
+            |trigger: On<Pointer<Click>>,
+             mut commands: Commands,
+             mut checkboxes: Query<&mut Checkbox>| {
+                let entity = trigger.target();
+                if let Ok(mut checkbox) = checkboxes.get_mut(entity) {
+                    checkbox.checked = !checkbox.checked;
+                    commands.trigger_targets(CheckboxChanged(checkbox.checked), entity);
+                }
+            },
+        );
+    checkbox
+}
+
+fn mark_color(theme: &Theme, checked: bool) -> Color {
+    if checked {
+        theme.button.hover_color
+    } else {
+        Color::NONE
+    }
+}
+
+/// Keep the mark of the checkboxes in sync with their state.
+pub(crate) fn update_checkbox_marks(
+    checkboxes: Query<(&Checkbox, &Children), Changed<Checkbox>>,
+    mut marks: Query<&mut BackgroundColor, With<CheckboxMark>>,
+    theme: Res<Theme>,
+) {
+    for (checkbox, children) in &checkboxes {
+        for child in children.iter() {
+            if let Ok(mut background) = marks.get_mut(child) {
+                background.0 = mark_color(&theme, checkbox.checked);
+            }
+        }
+    }
+}
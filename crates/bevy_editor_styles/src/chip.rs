@@ -0,0 +1,327 @@
+//! A themed chip, the small labeled pill of the tags, versions, statuses and counts.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_editor_styles::{Theme, chip::{Chip, ChipRemoved, ChipRole}};
+//!
+//! fn spawn_tag(mut commands: Commands, theme: Res<Theme>) {
+//!     Chip::new("prototype")
+//!         .role(ChipRole::Info)
+//!         .removable()
+//!         .spawn(&mut commands, &theme)
+//!         .observe(|trigger: On<ChipRemoved>, mut commands: Commands| {
+//!             commands.entity(trigger.target()).despawn();
+//!         });
+//! }
+//! ```
+//!
+//! A chip is static by default, like a version, the clicks going through it to what is under it.
+//! A [clickable](Chip::clickable) one triggers [`ChipClicked`], and a [removable](Chip::removable)
+//! one, like a tag, ends with a button triggering [`ChipRemoved`].
+
+use bevy::prelude::*;
+
+use crate::{Theme, colors::contrast_ratio};
+
+/// The radius of the corners of a [`Chip`], in pixels, rounding its ends.
+const CHIP_RADIUS: f32 = 8.0;
+/// The size of the text of a [`Chip`].
+const CHIP_FONT_SIZE: f32 = 11.0;
+/// The opacity of the remove button of a [`Chip`] while it isn't hovered.
+const REMOVE_BUTTON_ALPHA: f32 = 0.7;
+
+/// The [`Theme`] color a [`Chip`] is filled with, by what it conveys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChipRole {
+    /// Plain metadata, like a version or a count.
+    #[default]
+    Neutral,
+    /// Highlighted metadata, like the current branch.
+    Accent,
+    /// An informational status, [`StatusStyles::info`](crate::StatusStyles::info).
+    Info,
+    /// A good status, [`StatusStyles::success`](crate::StatusStyles::success).
+    Success,
+    /// A status needing attention, [`StatusStyles::warning`](crate::StatusStyles::warning).
+    Warning,
+    /// A failed status, [`StatusStyles::error`](crate::StatusStyles::error).
+    Error,
+}
+
+impl ChipRole {
+    /// The background color of the chips of this role.
+    pub fn color(self, theme: &Theme) -> Color {
+        match self {
+            ChipRole::Neutral => theme.pane.area_background_color.0,
+            ChipRole::Accent => theme.button.hover_color,
+            ChipRole::Info => theme.status.info,
+            ChipRole::Success => theme.status.success,
+            ChipRole::Warning => theme.status.warning,
+            ChipRole::Error => theme.status.error,
+        }
+    }
+
+    /// The color of the label and the icon of the chips of this role.
+    ///
+    /// It is the text color of the theme or its background color, whichever stands out more on the
+    /// [`color`](Self::color) of the chip, so that the bright status colors get dark text.
+    pub fn text_color(self, theme: &Theme) -> Color {
+        let fill = self.color(theme);
+        let text = theme.text.text_color;
+        let background = theme.general.background_color.0;
+        if contrast_ratio(text, fill) >= contrast_ratio(background, fill) {
+            text
+        } else {
+            background
+        }
+    }
+
+    /// The color of the remove button of the chips of this role, brighter while hovered.
+    fn remove_button_color(self, theme: &Theme, hovered: bool) -> Color {
+        let color = self.text_color(theme);
+        if hovered {
+            color
+        } else {
+            color.with_alpha(REMOVE_BUTTON_ALPHA)
+        }
+    }
+}
+
+/// A chip, built then spawned with [`Chip::spawn`].
+///
+/// Changing the label or the role of a spawned chip updates it.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct Chip {
+    /// The text of the chip.
+    pub label: String,
+    /// The glyph shown before the label, from the [`icons`](crate::icons).
+    pub icon: Option<&'static str>,
+    /// The color of the chip.
+    pub role: ChipRole,
+    /// Whether a click on the chip triggers [`ChipClicked`] instead of going through it.
+    pub clickable: bool,
+    /// Whether the chip has a button triggering [`ChipRemoved`].
+    pub removable: bool,
+}
+
+/// Triggered on a [clickable](Chip::clickable) [`Chip`] when it is clicked.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct ChipClicked;
+
+/// Triggered on a [removable](Chip::removable) [`Chip`] when its remove button is clicked.
+///
+/// The chip isn't despawned, the observer decides, like after asking for a confirmation.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct ChipRemoved;
+
+/// The text of a [`Chip`] showing its label.
+#[derive(Component)]
+struct ChipLabel;
+
+/// The text of a [`Chip`] showing its icon.
+#[derive(Component)]
+struct ChipIcon;
+
+/// The button of a [removable](Chip::removable) [`Chip`] triggering [`ChipRemoved`].
+#[derive(Component)]
+struct ChipRemoveButton;
+
+impl Chip {
+    /// A static [`ChipRole::Neutral`] chip with the label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            role: ChipRole::default(),
+            clickable: false,
+            removable: false,
+        }
+    }
+
+    /// Show the glyph of the [`icons`](crate::icons) before the label.
+    pub fn icon(mut self, icon: &'static str) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Fill the chip with the color of the role.
+    pub fn role(mut self, role: ChipRole) -> Self {
+        self.role = role;
+        self
+    }
+
+    /// Trigger [`ChipClicked`] when the chip is clicked.
+    pub fn clickable(mut self) -> Self {
+        self.clickable = true;
+        self
+    }
+
+    /// End the chip with a button triggering [`ChipRemoved`].
+    pub fn removable(mut self) -> Self {
+        self.removable = true;
+        self
+    }
+
+    /// Spawn the chip, returning its entity.
+    pub fn spawn<'a>(self, commands: &'a mut Commands, theme: &Theme) -> EntityCommands<'a> {
+        let text_font = |font: &Handle<Font>| TextFont {
+            font: font.clone(),
+            font_size: CHIP_FONT_SIZE,
+            ..default()
+        };
+        let label = self.label.clone();
+        let icon = self.icon;
+        let removable = self.removable;
+        let text_color = self.role.text_color(theme);
+        let remove_color = self.role.remove_button_color(theme, false);
+        let mut chip = commands.spawn((
+            Node {
+                display: Display::Flex,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(4.0),
+                padding: UiRect::axes(Val::Px(6.0), Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(self.role.color(theme)),
+            BorderRadius::all(Val::Px(CHIP_RADIUS)),
+            self,
+        ));
+        chip.with_children(|parent| {
+            if let Some(icon) = icon {
+                parent.spawn((
+                    ChipIcon,
+                    Text::new(icon),
+                    text_font(&theme.icon.font),
+                    TextColor(text_color),
+                    Pickable::IGNORE,
+                ));
+            }
+            parent.spawn((
+                ChipLabel,
+                Text::new(label),
+                text_font(&theme.text.font),
+                TextColor(text_color),
+                Pickable::IGNORE,
+            ));
+            if removable {
+                parent
+                    .spawn((
+                        ChipRemoveButton,
+                        Text::new("×"),
+                        text_font(&theme.text.font),
+                        TextColor(remove_color),
+                    ))
+                    .observe(|trigger: On<Pointer<Over>>, mut commands: Commands| {
+                        commands.run_system_cached_with(
+                            highlight_remove_button,
+                            (trigger.target(), true),
+                        );
+                    })
+                    .observe(|trigger: On<Pointer<Out>>, mut commands: Commands| {
+                        commands.run_system_cached_with(
+                            highlight_remove_button,
+                            (trigger.target(), false),
+                        );
+                    })
+                    .observe(
+                        |mut trigger: On<Pointer<Release>>,
+                         mut commands: Commands,
+                         parents: Query<&ChildOf>| {
+                            // Neither the chip nor what is under it are clicked
+                            trigger.propagate(false);
+                            if trigger.event().button != PointerButton::Primary {
+                                return;
+                            }
+                            if let Ok(ChildOf(chip)) = parents.get(trigger.target()) {
+                                commands.trigger_targets(ChipRemoved, *chip);
+                            }
+                        },
+                    );
+            }
+        })
+        .observe(
+            |mut trigger: On<Pointer<Release>>, mut commands: Commands, chips: Query<&Chip>| {
+                let entity = trigger.target();
+                if !chips.get(entity).is_ok_and(|chip| chip.clickable) {
+                    return;
+                }
+                // What is under the chip would be clicked otherwise
+                trigger.propagate(false);
+                if trigger.event().button == PointerButton::Primary {
+                    commands.trigger_targets(ChipClicked, entity);
+                }
+            },
+        );
+        chip
+    }
+}
+
+/// Brighten the remove button of a [`Chip`] while it is hovered.
+fn highlight_remove_button(
+    In((button, hovered)): In<(Entity, bool)>,
+    mut colors: Query<&mut TextColor, With<ChipRemoveButton>>,
+    parents: Query<&ChildOf>,
+    chips: Query<&Chip>,
+    theme: Res<Theme>,
+) {
+    let Ok(chip) = parents
+        .get(button)
+        .and_then(|ChildOf(chip)| chips.get(*chip))
+    else {
+        return;
+    };
+    if let Ok(mut color) = colors.get_mut(button) {
+        color.0 = chip.role.remove_button_color(&theme, hovered);
+    }
+}
+
+/// Keep the label and the colors of the chips in sync with their [`Chip`].
+pub(crate) fn update_chips(
+    mut chips: Query<(&Chip, &mut BackgroundColor, &Children), Changed<Chip>>,
+    mut labels: Query<&mut Text, With<ChipLabel>>,
+    mut colors: Query<
+        (&mut TextColor, Has<ChipRemoveButton>),
+        Or<(With<ChipLabel>, With<ChipIcon>, With<ChipRemoveButton>)>,
+    >,
+    theme: Res<Theme>,
+) {
+    for (chip, mut background, children) in &mut chips {
+        background.set_if_neq(BackgroundColor(chip.role.color(&theme)));
+        for child in children.iter() {
+            if let Ok(mut label) = labels.get_mut(child)
+                && label.0 != chip.label
+            {
+                label.0.clone_from(&chip.label);
+            }
+            if let Ok((mut color, is_remove_button)) = colors.get_mut(child) {
+                let text_color = if is_remove_button {
+                    chip.role.remove_button_color(&theme, false)
+                } else {
+                    chip.role.text_color(&theme)
+                };
+                color.set_if_neq(TextColor(text_color));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chips_are_static_unless_asked() {
+        let version = Chip::new("0.16");
+        assert_eq!(version.role, ChipRole::Neutral);
+        assert!(!version.clickable && !version.removable);
+        assert_eq!(version.icon, None);
+
+        let tag = Chip::new("prototype")
+            .role(ChipRole::Info)
+            .icon(crate::icons::GRIP_VERTICAL)
+            .removable();
+        assert!(tag.removable && !tag.clickable);
+        assert_eq!(tag.role, ChipRole::Info);
+        assert!(Chip::new("Outdated").clickable().clickable);
+    }
+}
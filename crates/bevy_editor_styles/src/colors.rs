@@ -88,6 +88,127 @@ impl EditorColors {
     /// Success/enabled state - green
     pub const SUCCESS: Color = Color::srgb(0.36, 0.7, 0.05);
 
+    /// Warning state - amber
+    pub const WARNING: Color = Color::srgb(0.85, 0.6, 0.15);
+
     /// Error/disabled state - red  
     pub const ERROR: Color = Color::srgb(0.8, 0.3, 0.3);
 }
+
+/// The colors of the light theme replacing the dark ones of [`EditorColors`].
+///
+/// The accent, axis and status colors are shared by both themes.
+pub struct LightEditorColors;
+
+impl LightEditorColors {
+    /// Main editor background color - CSS: #F2F2F4
+    pub const BACKGROUND: Color = Color::srgb(0.949, 0.949, 0.957);
+
+    /// Panel and content area background - CSS: #FFFFFF
+    pub const PANEL_BACKGROUND: Color = Color::srgb(1.0, 1.0, 1.0);
+
+    /// Default button background - CSS: #E1E1E5
+    pub const BUTTON_DEFAULT: Color = Color::srgb(0.882, 0.882, 0.898);
+
+    /// Button hover state - slightly darker than default
+    pub const BUTTON_HOVER: Color = Color::srgb(0.831, 0.831, 0.851);
+
+    /// Primary text color - CSS: #1F1F24
+    pub const TEXT_PRIMARY: Color = Color::srgb(0.122, 0.122, 0.141);
+
+    /// Muted/secondary text color - CSS: #6B6B70
+    pub const TEXT_MUTED: Color = Color::srgb(0.420, 0.420, 0.439);
+
+    /// Major grid lines - CSS: #C4C4C8
+    pub const GRID_MAJOR: Color = Color::srgb(0.769, 0.769, 0.784);
+
+    /// Minor grid lines and borders - CSS: #DCDCE0
+    pub const GRID_MINOR: Color = Color::srgb(0.863, 0.863, 0.878);
+
+    /// General border color
+    pub const BORDER: Color = Color::srgb(0.847, 0.847, 0.859);
+}
+
+/// The colors of the high contrast theme, for users with low vision.
+///
+/// Unlike [`LightEditorColors`], it replaces the accent and status colors too, so that every text
+/// reaches a contrast ratio of at least 7:1 with the backgrounds it is drawn on, and the controls
+/// and focus ring at least 3:1, see [`contrast_ratio`].
+pub struct HighContrastEditorColors;
+
+impl HighContrastEditorColors {
+    /// Main editor background color - CSS: #000000
+    pub const BACKGROUND: Color = Color::srgb(0.0, 0.0, 0.0);
+
+    /// Panel and content area background - CSS: #101010
+    pub const PANEL_BACKGROUND: Color = Color::srgb(0.063, 0.063, 0.063);
+
+    /// Default button background - CSS: #262626
+    pub const BUTTON_DEFAULT: Color = Color::srgb(0.149, 0.149, 0.149);
+
+    /// Button hover state, as bright as the text - CSS: #FFFFFF
+    pub const BUTTON_HOVER: Color = Color::srgb(1.0, 1.0, 1.0);
+
+    /// Primary text color - CSS: #FFFFFF
+    pub const TEXT_PRIMARY: Color = Color::srgb(1.0, 1.0, 1.0);
+
+    /// Muted/secondary text color, still far from the backgrounds - CSS: #C8C8C8
+    pub const TEXT_MUTED: Color = Color::srgb(0.784, 0.784, 0.784);
+
+    /// Accent behind white text, like the primary buttons - CSS: #3370E0
+    pub const ACCENT: Color = Color::srgb(0.200, 0.439, 0.878);
+
+    /// Accent of the highlighted text and the information messages - CSS: #7CC4FF
+    pub const ACCENT_TEXT: Color = Color::srgb(0.486, 0.769, 1.0);
+
+    /// The ring around the focused control - CSS: #FFD600
+    pub const FOCUS_RING: Color = Color::srgb(1.0, 0.839, 0.0);
+
+    /// Major grid lines - CSS: #808080
+    pub const GRID_MAJOR: Color = Color::srgb(0.502, 0.502, 0.502);
+
+    /// Minor grid lines - CSS: #404040
+    pub const GRID_MINOR: Color = Color::srgb(0.251, 0.251, 0.251);
+
+    /// General border color - CSS: #C8C8C8
+    pub const BORDER: Color = Color::srgb(0.784, 0.784, 0.784);
+
+    /// Success/enabled state - CSS: #4CE24C
+    pub const SUCCESS: Color = Color::srgb(0.298, 0.886, 0.298);
+
+    /// Warning state - CSS: #FFC233
+    pub const WARNING: Color = Color::srgb(1.0, 0.761, 0.2);
+
+    /// Error/disabled state - CSS: #FF7070
+    pub const ERROR: Color = Color::srgb(1.0, 0.439, 0.439);
+}
+
+/// The contrast ratio between two opaque colors as defined by the WCAG, from 1:1 for the same
+/// color to 21:1 for black and white.
+///
+/// The WCAG asks for at least 4.5:1 for text, 7:1 for the enhanced level, and 3:1 for the controls.
+pub fn contrast_ratio(a: Color, b: Color) -> f32 {
+    let luminance = |color: Color| {
+        let color = color.to_linear();
+        0.2126 * color.red + 0.7152 * color.green + 0.0722 * color.blue
+    };
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_the_contrast_ratios() {
+        let black = HighContrastEditorColors::BACKGROUND;
+        let white = HighContrastEditorColors::TEXT_PRIMARY;
+        assert!((contrast_ratio(black, white) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(white, black) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(white, white) - 1.0).abs() < 0.01);
+        // The usual #767676 on white, just above the 4.5:1 of the WCAG
+        let grey = Color::srgb(0.463, 0.463, 0.463);
+        assert!((contrast_ratio(grey, white) - 4.54).abs() < 0.05);
+    }
+}
@@ -0,0 +1,463 @@
+//! Themed modal dialogs.
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_editor_styles::{Theme, dialog::{ConfirmationDialog, DialogResult}};
+//!
+//! fn ask(mut commands: Commands, theme: Res<Theme>) {
+//!     ConfirmationDialog::new("Delete project", "This can't be undone.")
+//!         .confirm_label("Delete")
+//!         .spawn(&mut commands, &theme)
+//!         .observe(|trigger: On<DialogResult>| {
+//!             if trigger.event().0 {
+//!                 info!("Confirmed");
+//!             }
+//!         });
+//! }
+//! ```
+//!
+//! The open dialogs are stacked in the [`ModalStack`], in opening order. Only the top one dims
+//! what is behind it and receives the input, <kbd>Escape</kbd> closes it first.
+//!
+//! A [`ConfirmationDialog`] with a [`dont_ask_again`](ConfirmationDialog::dont_ask_again) key
+//! offers to skip it in the future. Once confirmed with the checkbox checked, its key is added to
+//! the [`SuppressedConfirmations`] and the next dialogs with that key confirm themselves without
+//! showing. [`ConfirmationSuppressed`] is triggered on the dialog first, to remember the key.
+
+use std::collections::BTreeSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    Theme,
+    checkbox::{Checkbox, spawn_checkbox},
+};
+
+/// The [`GlobalZIndex`] of the bottom [`Dialog`], each dialog above it is one higher.
+pub const MODAL_Z_INDEX: i32 = 50;
+/// The color covering what is behind the top [`Dialog`].
+const BACKDROP_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.5);
+
+/// The root entity of a dialog, covering the whole window to block the input behind it.
+#[derive(Component)]
+pub struct Dialog;
+
+/// The open [`Dialog`]s, from the bottom one to the top one.
+#[derive(Resource, Debug, Default)]
+pub struct ModalStack(Vec<Entity>);
+
+impl ModalStack {
+    /// The dialog above all the others, the only one receiving the input.
+    pub fn top(&self) -> Option<Entity> {
+        self.0.last().copied()
+    }
+
+    /// Whether no dialog is open.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The open dialogs, from the bottom one to the top one.
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+/// Triggered on a [`Dialog`] entity when the user makes a choice, right before the dialog is despawned.
+///
+/// `true` means the dialog was confirmed, `false` that it was cancelled, including with <kbd>Escape</kbd>.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct DialogResult(pub bool);
+
+/// Triggered on a [`ConfirmationDialog`] confirmed with its "Don't ask again" checkbox checked,
+/// holding its key, before its [`DialogResult`].
+#[derive(EntityEvent, Clone, Debug)]
+pub struct ConfirmationSuppressed(pub String);
+
+/// The keys of the [`ConfirmationDialog`]s the user asked not to show again.
+///
+/// Clearing it asks for all the confirmations again.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SuppressedConfirmations(pub BTreeSet<String>);
+
+impl SuppressedConfirmations {
+    /// Whether the dialogs with the key are skipped.
+    pub fn contains(&self, key: &str) -> bool {
+        self.0.contains(key)
+    }
+}
+
+/// A yes/no dialog with a title, a message and two labeled buttons.
+#[derive(Clone, Debug)]
+pub struct ConfirmationDialog {
+    title: String,
+    message: String,
+    confirm_label: String,
+    cancel_label: String,
+    dont_ask_again: Option<String>,
+}
+
+/// The key of a [`ConfirmationDialog`] offering not to ask again, and its checkbox.
+///
+/// The dialog stays hidden until [`skip_suppressed_confirmations`] looks the key up.
+#[derive(Component, Debug)]
+struct DontAskAgain {
+    key: String,
+    checkbox: Entity,
+}
+
+impl ConfirmationDialog {
+    /// Create a new [`ConfirmationDialog`] with "Confirm" and "Cancel" buttons.
+    pub fn new(title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            message: message.into(),
+            confirm_label: "Confirm".to_string(),
+            cancel_label: "Cancel".to_string(),
+            dont_ask_again: None,
+        }
+    }
+
+    /// Set the label of the button confirming the dialog.
+    pub fn confirm_label(mut self, label: impl Into<String>) -> Self {
+        self.confirm_label = label.into();
+        self
+    }
+
+    /// Set the label of the button cancelling the dialog.
+    pub fn cancel_label(mut self, label: impl Into<String>) -> Self {
+        self.cancel_label = label.into();
+        self
+    }
+
+    /// Offer a "Don't ask again" checkbox, remembering the choice under the key.
+    ///
+    /// When the key is in the [`SuppressedConfirmations`], the dialog is confirmed right away
+    /// instead of showing, so the [`DialogResult`] observers still run.
+    pub fn dont_ask_again(mut self, key: impl Into<String>) -> Self {
+        self.dont_ask_again = Some(key.into());
+        self
+    }
+
+    /// Spawn the dialog, returning the [`Dialog`] entity to observe [`DialogResult`] on.
+    pub fn spawn<'a>(self, commands: &'a mut Commands, theme: &Theme) -> EntityCommands<'a> {
+        let root = spawn_dialog_root(commands);
+
+        let panel = commands
+            .spawn((
+                Node {
+                    width: Val::Px(400.0),
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(12.0),
+                    padding: UiRect::all(Val::Px(16.0)),
+                    ..default()
+                },
+                theme.pane.area_background_color,
+                theme.general.border_radius,
+                ChildOf(root),
+            ))
+            .id();
+
+        commands.spawn((
+            Text::new(self.title),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(theme.text.text_color),
+            ChildOf(panel),
+        ));
+        commands.spawn((
+            Text::new(self.message),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(theme.text.low_priority),
+            ChildOf(panel),
+        ));
+        if let Some(key) = self.dont_ask_again {
+            let checkbox = spawn_checkbox(commands, theme, "Don't ask again", false)
+                .insert(ChildOf(panel))
+                .id();
+            // Hidden until the key is looked up, so a skipped dialog never flashes
+            commands
+                .entity(root)
+                .insert((DontAskAgain { key, checkbox }, Visibility::Hidden));
+        }
+
+        let buttons = commands
+            .spawn((
+                Node {
+                    display: Display::Flex,
+                    justify_content: JustifyContent::FlexEnd,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                },
+                ChildOf(panel),
+            ))
+            .id();
+
+        spawn_dialog_button(
+            commands,
+            theme,
+            self.cancel_label,
+            theme.button.background_color.0,
+        )
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>, mut commands: Commands| {
+                close_dialog(&mut commands, root, false);
+            },
+        );
+        spawn_dialog_button(
+            commands,
+            theme,
+            self.confirm_label,
+            theme.button.hover_color,
+        )
+        .insert(ChildOf(buttons))
+        .observe(
+            move |_trigger: On<Pointer<Click>>,
+                  mut commands: Commands,
+                  mut suppressed: ResMut<SuppressedConfirmations>,
+                  dont_ask_again: Query<&DontAskAgain>,
+                  checkboxes: Query<&Checkbox>| {
+                if let Ok(dont_ask_again) = dont_ask_again.get(root)
+                    && checkboxes
+                        .get(dont_ask_again.checkbox)
+                        .is_ok_and(|checkbox| checkbox.checked)
+                {
+                    suppressed.0.insert(dont_ask_again.key.clone());
+                    commands
+                        .trigger_targets(ConfirmationSuppressed(dont_ask_again.key.clone()), root);
+                }
+                close_dialog(&mut commands, root, true);
+            },
+        );
+
+        commands.entity(root)
+    }
+}
+
+/// Spawn the full window [`Dialog`] root centering its content and blocking the input behind it.
+pub fn spawn_dialog_root(commands: &mut Commands) -> Entity {
+    commands
+        .spawn((
+            Dialog,
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(BACKDROP_COLOR),
+            GlobalZIndex(MODAL_Z_INDEX),
+        ))
+        .id()
+}
+
+/// Trigger the [`DialogResult`] on the dialog and despawn it.
+pub fn close_dialog(commands: &mut Commands, dialog: Entity, confirmed: bool) {
+    commands.trigger_targets(DialogResult(confirmed), dialog);
+    commands.entity(dialog).despawn();
+}
+
+/// Spawn a button with the given label and background color, as used in dialogs.
+pub fn spawn_dialog_button<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    label: impl Into<String>,
+    color: Color,
+) -> EntityCommands<'a> {
+    let mut button = commands.spawn((
+        Node {
+            padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+            ..default()
+        },
+        BackgroundColor(color),
+        theme.button.border_radius,
+    ));
+    button.with_child((
+        Text::new(label.into()),
+        TextFont {
+            font: theme.text.font.clone(),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(theme.text.text_color),
+        Pickable::IGNORE,
+    ));
+    button
+}
+
+/// Stack the new dialogs on the [`ModalStack`] and keep the z-order and backdrops in sync with it.
+///
+/// The lower dialogs lose their backdrop, they are already dimmed by the one of the top dialog.
+pub(crate) fn update_modal_stack(
+    mut stack: ResMut<ModalStack>,
+    added: Query<Entity, Added<Dialog>>,
+    mut dialogs: Query<(&mut GlobalZIndex, &mut BackgroundColor), With<Dialog>>,
+) {
+    let count = stack.0.len();
+    stack.0.retain(|dialog| dialogs.contains(*dialog));
+    stack.0.extend(added.iter());
+    if stack.0.len() == count && added.is_empty() {
+        return;
+    }
+
+    let top = stack.top();
+    for (index, dialog) in stack.0.iter().enumerate() {
+        let Ok((mut z_index, mut background)) = dialogs.get_mut(*dialog) else {
+            continue;
+        };
+        z_index.set_if_neq(GlobalZIndex(MODAL_Z_INDEX + index as i32));
+        let color = if Some(*dialog) == top {
+            BACKDROP_COLOR
+        } else {
+            Color::NONE
+        };
+        background.set_if_neq(BackgroundColor(color));
+    }
+}
+
+/// Confirm the new [`ConfirmationDialog`]s whose key is in the [`SuppressedConfirmations`], and
+/// show the other ones.
+///
+/// This runs after the commands spawning the dialog, so the [`DialogResult`] observers added to it
+/// are there to see the result.
+pub(crate) fn skip_suppressed_confirmations(
+    mut commands: Commands,
+    suppressed: Res<SuppressedConfirmations>,
+    mut dialogs: Query<(Entity, &DontAskAgain, &mut Visibility), Added<DontAskAgain>>,
+) {
+    for (dialog, dont_ask_again, mut visibility) in &mut dialogs {
+        if suppressed.contains(&dont_ask_again.key) {
+            close_dialog(&mut commands, dialog, true);
+        } else {
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+/// Cancel the top dialog of the [`ModalStack`] when <kbd>Escape</kbd> is pressed.
+pub(crate) fn cancel_dialogs_on_escape(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    stack: Res<ModalStack>,
+) {
+    if !keys.just_pressed(KeyCode::Escape) {
+        return;
+    }
+    if let Some(dialog) = stack.top() {
+        close_dialog(&mut commands, dialog, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::theme_app;
+
+    #[test]
+    fn escape_closes_the_top_dialog() {
+        let mut app = App::new();
+        app.init_resource::<ModalStack>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(
+                Update,
+                (update_modal_stack, cancel_dialogs_on_escape).chain(),
+            );
+
+        let spawn_dialog = |app: &mut App| {
+            app.world_mut()
+                .spawn((
+                    Dialog,
+                    BackgroundColor(BACKDROP_COLOR),
+                    GlobalZIndex(MODAL_Z_INDEX),
+                ))
+                .id()
+        };
+        let bottom = spawn_dialog(&mut app);
+        app.update();
+        let top = spawn_dialog(&mut app);
+        app.update();
+        assert_eq!(
+            app.world()
+                .resource::<ModalStack>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![bottom, top]
+        );
+        let backdrop = |app: &App, dialog| app.world().get::<BackgroundColor>(dialog).unwrap().0;
+        assert_eq!(backdrop(&app, bottom), Color::NONE);
+        assert_eq!(backdrop(&app, top), BACKDROP_COLOR);
+        assert_eq!(
+            app.world().get::<GlobalZIndex>(top),
+            Some(&GlobalZIndex(MODAL_Z_INDEX + 1))
+        );
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Escape);
+        app.update();
+        assert!(app.world().get_entity(top).is_err());
+        assert!(app.world().get_entity(bottom).is_ok());
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+        app.update();
+        assert_eq!(app.world().resource::<ModalStack>().top(), Some(bottom));
+        assert_eq!(backdrop(&app, bottom), BACKDROP_COLOR);
+    }
+
+    #[test]
+    fn skip_the_suppressed_confirmations() {
+        let mut app = theme_app();
+        app.init_resource::<SuppressedConfirmations>()
+            .add_systems(Update, skip_suppressed_confirmations);
+        app.world_mut()
+            .resource_mut::<SuppressedConfirmations>()
+            .0
+            .insert("clean".to_string());
+
+        #[derive(Resource)]
+        struct Confirmed(bool);
+        let spawn = |app: &mut App, key: &str| {
+            let world = app.world_mut();
+            let dialog = world.resource_scope(|world, theme: Mut<Theme>| {
+                let mut commands = world.commands();
+                ConfirmationDialog::new("Clean", "Remove the build artifacts?")
+                    .dont_ask_again(key)
+                    .spawn(&mut commands, &theme)
+                    .observe(|trigger: On<DialogResult>, mut commands: Commands| {
+                        commands.insert_resource(Confirmed(trigger.event().0));
+                    })
+                    .id()
+            });
+            world.flush();
+            dialog
+        };
+
+        let skipped = spawn(&mut app, "clean");
+        let shown = spawn(&mut app, "restore");
+        assert_eq!(
+            app.world().get::<Visibility>(shown),
+            Some(&Visibility::Hidden),
+            "hidden until looked up"
+        );
+        app.update();
+        assert!(app.world().get_entity(skipped).is_err());
+        assert!(app.world().resource::<Confirmed>().0);
+        assert_eq!(
+            app.world().get::<Visibility>(shown),
+            Some(&Visibility::Inherited)
+        );
+    }
+}
@@ -0,0 +1,414 @@
+//! A themed dropdown, showing its selected option and opening the list of options below it when
+//! clicked.
+
+use bevy::{
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::FocusedInput,
+    prelude::*,
+    window::PrimaryWindow,
+};
+
+use crate::{Theme, dialog::MODAL_Z_INDEX};
+
+/// The width of the popup listing the options, in pixels.
+const POPUP_WIDTH: f32 = 200.0;
+/// The height of an option in the popup, in pixels.
+const OPTION_HEIGHT: f32 = 24.0;
+/// The padding around the options in the popup, in pixels.
+const POPUP_PADDING: f32 = 3.0;
+
+/// A dropdown, selecting one of its labeled options.
+///
+/// [`DropdownChanged`] is triggered on the entity every time the user selects another option.
+/// The focused dropdown opens with <kbd>Enter</kbd>, <kbd>Space</kbd> or <kbd>↓</kbd>. The popup
+/// closes on a click outside of it or on <kbd>Escape</kbd>, and can be navigated with the arrow
+/// keys and <kbd>Enter</kbd>.
+#[derive(Component, Debug, Clone, Default)]
+pub struct Dropdown {
+    /// The labels of the options.
+    pub options: Vec<String>,
+    /// The index of the selected option.
+    pub selected: usize,
+}
+
+impl Dropdown {
+    /// The label of the selected option, empty if there are no options.
+    pub fn selected_label(&self) -> &str {
+        self.options
+            .get(self.selected)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+/// Triggered on a [`Dropdown`] when the user selects another option, holding its index.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct DropdownChanged(pub usize);
+
+/// The text of a [`Dropdown`] showing its selected option.
+#[derive(Component)]
+struct DropdownLabel;
+
+/// The window-wide node holding an open popup, closing it when clicked.
+#[derive(Component)]
+pub(crate) struct DropdownPopup {
+    /// The dropdown the popup was opened from.
+    dropdown: Entity,
+    /// The option highlighted with the keyboard.
+    highlighted: usize,
+}
+
+/// An option of a [`DropdownPopup`].
+#[derive(Component)]
+struct DropdownOption(usize);
+
+/// Spawn a [`Dropdown`] with the given options, the `selected` one being shown.
+pub fn spawn_dropdown<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    options: impl IntoIterator<Item = impl Into<String>>,
+    selected: usize,
+) -> EntityCommands<'a> {
+    let dropdown = Dropdown {
+        options: options.into_iter().map(Into::into).collect(),
+        selected,
+    };
+    let label = dropdown.selected_label().to_string();
+    let mut dropdown_ec = commands.spawn((
+        dropdown,
+        Node {
+            display: Display::Flex,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::SpaceBetween,
+            column_gap: Val::Px(8.0),
+            min_width: Val::Px(120.0),
+            height: Val::Px(26.0),
+            padding: UiRect::horizontal(Val::Px(8.0)),
+            ..default()
+        },
+        theme.button.background_color,
+        theme.button.border_radius,
+        children![
+            (
+                DropdownLabel,
+                Text::new(label),
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(theme.text.text_color),
+                Pickable::IGNORE,
+            ),
+            (
+                Text::new("▾"),
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(theme.text.low_priority),
+                Pickable::IGNORE,
+            ),
+        ],
+    ));
+    dropdown_ec
+        .observe(open_dropdown_popup_on_click)
+        .observe(open_dropdown_popup_on_key);
+    dropdown_ec
+}
+
+/// Where to put a popup of the given size opened at `anchor`, moved to stay inside the window.
+pub(crate) fn popup_position(anchor: Vec2, size: Vec2, window_size: Vec2) -> Vec2 {
+    let max = (window_size - size).max(Vec2::ZERO);
+    anchor.clamp(Vec2::ZERO, max)
+}
+
+/// The bottom left corner of a dropdown whose node is centered on `center`, where its popup
+/// opens, in logical pixels.
+fn popup_anchor(node: &ComputedNode, center: Vec2) -> Vec2 {
+    // The transforms of the nodes are in physical pixels
+    let size = node.size();
+    (center + Vec2::new(-size.x, size.y) / 2.0) * node.inverse_scale_factor()
+}
+
+fn open_dropdown_popup_on_click(
+    mut trigger: On<Pointer<Click>>,
+    mut commands: Commands,
+    dropdowns: Query<(), With<Dropdown>>,
+) {
+    if trigger.event().button != PointerButton::Primary {
+        return;
+    }
+    let entity = trigger.target();
+    if !dropdowns.contains(entity) {
+        return;
+    }
+    trigger.propagate(false);
+    commands.run_system_cached_with(open_dropdown_popup, entity);
+}
+
+/// Open the popup of the focused dropdown on <kbd>Enter</kbd>, <kbd>Space</kbd> or <kbd>↓</kbd>.
+fn open_dropdown_popup_on_key(
+    mut trigger: On<FocusedInput<KeyboardInput>>,
+    mut commands: Commands,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    dropdowns: Query<(), With<Dropdown>>,
+) {
+    let input = &trigger.event().input;
+    if input.state != ButtonState::Pressed
+        || input.repeat
+        || !matches!(
+            input.key_code,
+            KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space | KeyCode::ArrowDown
+        )
+    {
+        return;
+    }
+    let key = input.key_code;
+    let entity = trigger.target();
+    if !dropdowns.contains(entity) {
+        return;
+    }
+    trigger.propagate(false);
+    // Otherwise the popup handles the same press, selecting its highlighted option right away
+    keys.clear_just_pressed(key);
+    commands.run_system_cached_with(open_dropdown_popup, entity);
+}
+
+/// Open the popup listing the options of the dropdown, below it.
+fn open_dropdown_popup(
+    In(entity): In<Entity>,
+    mut commands: Commands,
+    dropdowns: Query<(&Dropdown, &ComputedNode, &UiGlobalTransform)>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    theme: Res<Theme>,
+) {
+    let Ok((dropdown, node, transform)) = dropdowns.get(entity) else {
+        return;
+    };
+
+    let size = Vec2::new(
+        POPUP_WIDTH,
+        dropdown.options.len() as f32 * OPTION_HEIGHT + 2.0 * POPUP_PADDING,
+    );
+    let position = popup_position(
+        popup_anchor(node, transform.translation),
+        size,
+        window.size(),
+    );
+
+    // Covers the whole window, so a click outside of the popup closes it
+    let popup = commands
+        .spawn((
+            DropdownPopup {
+                dropdown: entity,
+                highlighted: dropdown.selected,
+            },
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            // Above the dialogs the dropdown may be in
+            GlobalZIndex(MODAL_Z_INDEX + 40),
+        ))
+        .observe(|trigger: On<Pointer<Press>>, mut commands: Commands| {
+            commands.entity(trigger.target()).despawn();
+        })
+        .id();
+    let list = commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(position.x),
+                top: Val::Px(position.y),
+                width: Val::Px(size.x),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(POPUP_PADDING)),
+                ..default()
+            },
+            BoxShadow::new(
+                theme.general.elevation_color,
+                Val::ZERO,
+                Val::Px(2.0),
+                Val::ZERO,
+                Val::Px(6.0),
+            ),
+            theme.context_menu.background_color,
+            theme.general.border_radius,
+            ChildOf(popup),
+        ))
+        .observe(|mut trigger: On<Pointer<Press>>| {
+            // Clicking the options shouldn't close the popup before they are selected
+            trigger.propagate(false);
+        })
+        .id();
+
+    for (index, label) in dropdown.options.iter().enumerate() {
+        commands
+            .spawn((
+                DropdownOption(index),
+                Node {
+                    height: Val::Px(OPTION_HEIGHT),
+                    align_items: AlignItems::Center,
+                    padding: UiRect::horizontal(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                theme.context_menu.option_border_radius,
+                ChildOf(list),
+                children![(
+                    Text::new(label.clone()),
+                    TextFont {
+                        font: theme.text.font.clone(),
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(theme.text.text_color),
+                    Pickable::IGNORE,
+                )],
+            ))
+            .observe(
+                move |_trigger: On<Pointer<Over>>, mut popups: Query<&mut DropdownPopup>| {
+                    if let Ok(mut popup) = popups.get_mut(popup) {
+                        popup.highlighted = index;
+                    }
+                },
+            )
+            .observe(move |trigger: On<Pointer<Click>>, mut commands: Commands| {
+                if trigger.event().button == PointerButton::Primary {
+                    commands.run_system_cached_with(select_dropdown_option, (popup, index));
+                }
+            });
+    }
+}
+
+/// Select the option of the dropdown of the popup, closing the popup.
+fn select_dropdown_option(
+    In((popup_entity, index)): In<(Entity, usize)>,
+    mut commands: Commands,
+    popups: Query<&DropdownPopup>,
+    mut dropdowns: Query<&mut Dropdown>,
+) {
+    let Ok(popup) = popups.get(popup_entity) else {
+        return;
+    };
+    commands.entity(popup_entity).despawn();
+    let Ok(mut dropdown) = dropdowns.get_mut(popup.dropdown) else {
+        return;
+    };
+    if index < dropdown.options.len() && dropdown.selected != index {
+        dropdown.selected = index;
+        commands.trigger_targets(DropdownChanged(index), popup.dropdown);
+    }
+}
+
+/// Move the highlight of the open popups with the arrow keys, select with <kbd>Enter</kbd>
+/// and close with <kbd>Escape</kbd>.
+///
+/// The keys handled are cleared, so <kbd>Escape</kbd> doesn't close the dialog under the popup.
+pub(crate) fn navigate_dropdown_popups(
+    mut commands: Commands,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut popups: Query<(Entity, &mut DropdownPopup)>,
+    dropdowns: Query<&Dropdown>,
+) {
+    for (entity, mut popup) in &mut popups {
+        let count = dropdowns
+            .get(popup.dropdown)
+            .map_or(0, |dropdown| dropdown.options.len());
+        if keys.clear_just_pressed(KeyCode::Escape) || count == 0 {
+            commands.entity(entity).despawn();
+        } else if keys.clear_just_pressed(KeyCode::ArrowDown) {
+            popup.highlighted = (popup.highlighted + 1) % count;
+        } else if keys.clear_just_pressed(KeyCode::ArrowUp) {
+            popup.highlighted = (popup.highlighted + count - 1) % count;
+        } else if keys.clear_just_pressed(KeyCode::Enter)
+            || keys.clear_just_pressed(KeyCode::NumpadEnter)
+        {
+            commands.run_system_cached_with(select_dropdown_option, (entity, popup.highlighted));
+        }
+    }
+}
+
+/// Keep the labels of the dropdowns and the highlight of the popups in sync.
+pub(crate) fn update_dropdowns(
+    dropdowns: Query<(&Dropdown, &Children), Changed<Dropdown>>,
+    mut labels: Query<&mut Text, With<DropdownLabel>>,
+    popups: Query<(&DropdownPopup, &Children)>,
+    children: Query<&Children>,
+    mut options: Query<(&DropdownOption, &mut BackgroundColor)>,
+    theme: Res<Theme>,
+) {
+    for (dropdown, dropdown_children) in &dropdowns {
+        for child in dropdown_children.iter() {
+            if let Ok(mut label) = labels.get_mut(child) {
+                label.0 = dropdown.selected_label().to_string();
+            }
+        }
+    }
+
+    for (popup, popup_children) in &popups {
+        for list in popup_children.iter() {
+            for option in children.iter_descendants(list) {
+                let Ok((DropdownOption(index), mut background)) = options.get_mut(option) else {
+                    continue;
+                };
+                let color = if *index == popup.highlighted {
+                    theme.context_menu.hover_color.0
+                } else {
+                    Color::NONE
+                };
+                background.set_if_neq(BackgroundColor(color));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn popups_stay_in_the_window() {
+        let window = Vec2::new(800.0, 600.0);
+        let size = Vec2::new(200.0, 100.0);
+        assert_eq!(
+            popup_position(Vec2::new(10.0, 20.0), size, window),
+            Vec2::new(10.0, 20.0)
+        );
+        assert_eq!(
+            popup_position(Vec2::new(700.0, 550.0), size, window),
+            Vec2::new(600.0, 500.0)
+        );
+        assert_eq!(
+            popup_position(Vec2::new(50.0, 50.0), Vec2::new(900.0, 100.0), window),
+            Vec2::new(0.0, 50.0)
+        );
+    }
+
+    #[test]
+    fn popups_open_below_the_dropdown() {
+        let node = ComputedNode {
+            size: Vec2::new(240.0, 52.0),
+            inverse_scale_factor: 0.5,
+            ..default()
+        };
+        assert_eq!(
+            popup_anchor(&node, Vec2::new(300.0, 100.0)),
+            Vec2::new(90.0, 63.0)
+        );
+    }
+
+    #[test]
+    fn selected_label_of_empty_dropdowns() {
+        let dropdown = Dropdown {
+            options: vec!["Name".to_string(), "Date".to_string()],
+            selected: 1,
+        };
+        assert_eq!(dropdown.selected_label(), "Date");
+        assert_eq!(Dropdown::default().selected_label(), "");
+    }
+}
@@ -0,0 +1,94 @@
+//! Falling back on the bundled fonts when the fonts of the [`Theme`] fail to load.
+//!
+//! A font that fails to load leaves every text using it blank, so the failed font is replaced by
+//! the bundled one in the theme and in the spawned texts, and by Bevy's default font when the
+//! bundled one fails too. The [`FontFallback`] resource tells which fonts were replaced.
+
+use bevy::{
+    asset::{AssetLoadFailedEvent, AssetPath},
+    prelude::*,
+};
+
+use crate::Theme;
+
+/// The bundled font of the text.
+pub const TEXT_FONT: &str = "embedded://bevy_editor_styles/assets/fonts/Inter-Regular.ttf";
+/// The bundled font of the icons.
+pub const ICON_FONT: &str = "embedded://bevy_editor_styles/assets/icons/Lucide.ttf";
+
+/// The fonts of the [`Theme`] that failed to load, and were replaced by a fallback.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct FontFallback {
+    /// The path of the text font that failed, if it did.
+    pub text: Option<String>,
+    /// The path of the icon font that failed, if it did.
+    pub icon: Option<String>,
+}
+
+impl FontFallback {
+    /// Whether a font of the theme was replaced by a fallback.
+    pub fn is_active(&self) -> bool {
+        self.text.is_some() || self.icon.is_some()
+    }
+}
+
+/// The font to load in place of the one at `failed`, `None` for Bevy's default font.
+///
+/// The `bundled` font is tried first, unless it is the one that failed.
+fn fallback_path(failed: &AssetPath, bundled: &'static str) -> Option<&'static str> {
+    (*failed != AssetPath::parse(bundled)).then_some(bundled)
+}
+
+/// Replace the fonts of the [`Theme`] that failed to load, in the theme and in the texts.
+pub(crate) fn fall_back_on_failed_fonts(
+    mut failures: EventReader<AssetLoadFailedEvent<Font>>,
+    mut theme: ResMut<Theme>,
+    mut fallback: ResMut<FontFallback>,
+    mut texts: Query<&mut TextFont>,
+    asset_server: Res<AssetServer>,
+) {
+    for failure in failures.read() {
+        let (font, bundled, failed_path) = if failure.id == theme.text.font.id() {
+            (&mut theme.text.font, TEXT_FONT, &mut fallback.text)
+        } else if failure.id == theme.icon.font.id() {
+            (&mut theme.icon.font, ICON_FONT, &mut fallback.icon)
+        } else {
+            continue;
+        };
+        let path = fallback_path(&failure.path, bundled);
+        warn!(
+            "The font '{}' failed to load, falling back on {}: {}",
+            failure.path,
+            path.map_or("the default font".to_string(), |path| format!("'{path}'")),
+            failure.error
+        );
+        let replacement = path.map_or_else(Handle::default, |path| asset_server.load(path));
+        *font = replacement.clone();
+        // The first failure is the one the user configured
+        failed_path.get_or_insert_with(|| failure.path.to_string());
+
+        for mut text in &mut texts {
+            if text.font.id() == failure.id {
+                text.font = replacement.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fall_back_on_the_bundled_fonts() {
+        let custom = AssetPath::parse("fonts/Missing.ttf");
+        assert_eq!(fallback_path(&custom, TEXT_FONT), Some(TEXT_FONT));
+        assert_eq!(fallback_path(&custom, ICON_FONT), Some(ICON_FONT));
+        assert_eq!(
+            fallback_path(&AssetPath::parse(TEXT_FONT), TEXT_FONT),
+            None,
+            "the bundled font failed too"
+        );
+        assert!(!FontFallback::default().is_active());
+    }
+}
@@ -21,9 +21,20 @@
 //! }
 //! ```
 use bevy::{asset::embedded_asset, prelude::*};
+use bevy_text_editing::EditableTextLinePlugin;
+use serde::{Deserialize, Serialize};
 
+pub mod busy;
+pub mod checkbox;
+pub mod chip;
 pub mod colors;
+pub mod dialog;
+pub mod dropdown;
+pub mod fonts;
 pub mod icons;
+pub mod splitter;
+pub mod text_input;
+pub mod tooltip;
 
 /// The Pallet Plugin.
 pub struct StylesPlugin;
@@ -32,10 +43,59 @@ impl Plugin for StylesPlugin {
     fn build(&self, app: &mut App) {
         embedded_asset!(app, "assets/fonts/Inter-Regular.ttf");
         embedded_asset!(app, "assets/icons/Lucide.ttf");
-        app.init_resource::<Theme>();
+        if !app.is_plugin_added::<EditableTextLinePlugin>() {
+            app.add_plugins(EditableTextLinePlugin);
+        }
+        app.init_resource::<Theme>()
+            .init_resource::<dialog::ModalStack>()
+            .init_resource::<dialog::SuppressedConfirmations>()
+            .init_resource::<busy::BusyActions>()
+            .init_resource::<fonts::FontFallback>()
+            .init_resource::<tooltip::HoveredTooltip>()
+            .add_systems(
+                Update,
+                (
+                    // The popups take Escape first, so the dialog under them stays open
+                    (
+                        dialog::skip_suppressed_confirmations,
+                        dialog::update_modal_stack,
+                        dropdown::navigate_dropdown_popups,
+                        dialog::cancel_dialogs_on_escape,
+                    )
+                        .chain(),
+                    dropdown::update_dropdowns,
+                    checkbox::update_checkbox_marks,
+                    chip::update_chips,
+                    (
+                        text_input::schedule_text_input_validation,
+                        text_input::validate_text_inputs,
+                        text_input::show_validation_messages,
+                        text_input::update_text_inputs,
+                    )
+                        .chain(),
+                    (busy::update_busy_buttons, busy::animate_busy_spinners).chain(),
+                    (tooltip::show_tooltips, tooltip::keep_tooltips_in_window).chain(),
+                    fonts::fall_back_on_failed_fonts,
+                ),
+            );
     }
 }
 
+/// The preset the [`Theme`] is built from.
+///
+/// Insert it before the [`StylesPlugin`] to pick the mode, the theme is dark otherwise.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThemeMode {
+    /// Light text on dark backgrounds.
+    #[default]
+    Dark,
+    /// Dark text on light backgrounds.
+    Light,
+    /// White text on black backgrounds with bright accents and a thick focus ring, for users with
+    /// low vision, see [`HighContrastEditorColors`](colors::HighContrastEditorColors).
+    HighContrast,
+}
+
 /// The core resource for the editor's color palette and fonts. This resource is used to store the current theme of the editor.
 /// All colors in the editor should be derived from this resource.
 /// All colors should use OKLCH color space, use <https://oklch.com/> to get colors. This ensures that the colors are perceptually uniform and work well for accessibility such as color blind adjustments.
@@ -59,6 +119,8 @@ pub struct Theme {
     pub viewport: ViewportStyles,
     /// The styles for scroll boxes in the editor.
     pub scroll_box: ScrollBoxStyles,
+    /// The colors used to convey the severity of a status or message.
+    pub status: StatusStyles,
 }
 
 /// The general styles for the editor.
@@ -67,6 +129,12 @@ pub struct GeneralStyles {
     pub border_radius: BorderRadius,
     /// The common background color of the editor.
     pub background_color: BackgroundColor,
+    /// The color of the ring drawn around the control that has keyboard focus.
+    pub focus_ring_color: Color,
+    /// The width of the ring drawn around the control that has keyboard focus.
+    pub focus_ring_width: Val,
+    /// The color of the shadows under raised elements, like hovered cards.
+    pub elevation_color: Color,
 }
 
 /// The styles for buttons in the editor.
@@ -87,6 +155,8 @@ pub struct TextStyles {
     pub text_color: Color,
     /// The color of high priority text.
     pub high_priority: Color,
+    /// The background color of the selected text in the text inputs.
+    pub selection_color: Color,
     /// The font for the text.
     pub font: Handle<Font>,
 }
@@ -143,63 +213,265 @@ pub struct ViewportStyles {
 pub struct ScrollBoxStyles {
     /// The background color of the scroll box.
     pub background_color: BackgroundColor,
+    /// The background color of the scroll bar track while hovered.
+    pub background_hover_color: Color,
     /// The color of the scroll handle.
     pub handle_color: Color,
+    /// The color of the scroll handle while hovered.
+    pub handle_hover_color: Color,
+    /// The color of the shadows indicating there is more content to scroll to.
+    pub shadow_color: Color,
     /// The border radius of the scroll box.
     pub border_radius: BorderRadius,
 }
 
+/// The colors used to convey the severity of a status or message.
+pub struct StatusStyles {
+    /// The color of informational messages.
+    pub info: Color,
+    /// The color of success messages.
+    pub success: Color,
+    /// The color of warning messages.
+    pub warning: Color,
+    /// The color of error messages.
+    pub error: Color,
+}
+
 impl FromWorld for Theme {
     fn from_world(world: &mut World) -> Self {
-        let asset_server = world.resource::<AssetServer>();
+        let mode = world
+            .get_resource::<ThemeMode>()
+            .copied()
+            .unwrap_or_default();
+        Theme::new(mode, world.resource::<AssetServer>())
+    }
+}
+
+impl Theme {
+    /// The theme of the given mode, its fonts loaded with the asset server.
+    pub fn new(mode: ThemeMode, asset_server: &AssetServer) -> Self {
         use colors::EditorColors;
+        let palette = Palette::of(mode);
+        // The ring must stand out for the users who need the high contrast
+        let focus_ring_width = match mode {
+            ThemeMode::HighContrast => Val::Px(3.0),
+            _ => Val::Px(2.0),
+        };
 
         Theme {
             general: GeneralStyles {
                 border_radius: BorderRadius::all(Val::Px(8.)),
-                background_color: BackgroundColor(EditorColors::BACKGROUND),
+                background_color: BackgroundColor(palette.background),
+                focus_ring_color: palette.focus_ring,
+                focus_ring_width,
+                elevation_color: palette.elevation,
             },
             button: ButtonStyles {
                 border_radius: BorderRadius::all(Val::Px(5.)),
-                background_color: BackgroundColor(EditorColors::BUTTON_DEFAULT),
-                hover_color: EditorColors::ACCENT_BLUE,
+                background_color: BackgroundColor(palette.button_default),
+                hover_color: palette.accent,
             },
             text: TextStyles {
-                low_priority: EditorColors::TEXT_MUTED,
-                text_color: EditorColors::TEXT_PRIMARY,
-                high_priority: EditorColors::ACCENT_BLUE,
-                font: asset_server
-                    .load("embedded://bevy_editor_styles/assets/fonts/Inter-Regular.ttf"),
+                low_priority: palette.text_muted,
+                text_color: palette.text_primary,
+                high_priority: palette.accent_text,
+                selection_color: palette.selection,
+                font: asset_server.load(fonts::TEXT_FONT),
             },
             icon: IconStyles {
-                font: asset_server.load("embedded://bevy_editor_styles/assets/icons/Lucide.ttf"),
+                font: asset_server.load(fonts::ICON_FONT),
             },
             pane: PaneStyles {
-                header_background_color: BackgroundColor(EditorColors::BACKGROUND),
-                area_background_color: BackgroundColor(EditorColors::PANEL_BACKGROUND),
+                header_background_color: BackgroundColor(palette.background),
+                area_background_color: BackgroundColor(palette.panel_background),
                 header_border_radius: BorderRadius::top(Val::Px(6.)),
             },
             menu: MenuStyles {
-                background_color: EditorColors::BACKGROUND,
+                background_color: palette.background,
             },
             context_menu: ContextMenuStyles {
-                background_color: BackgroundColor(EditorColors::BACKGROUND),
-                hover_color: BackgroundColor(EditorColors::BUTTON_DEFAULT),
+                background_color: BackgroundColor(palette.background),
+                hover_color: BackgroundColor(palette.button_default),
                 option_border_radius: BorderRadius::all(Val::Px(4.)),
             },
             viewport: ViewportStyles {
-                background_color: EditorColors::PANEL_BACKGROUND,
+                background_color: palette.panel_background,
                 x_axis_color: EditorColors::AXIS_X,
                 y_axis_color: EditorColors::AXIS_Y,
                 z_axis_color: EditorColors::AXIS_Z,
-                grid_major_line_color: EditorColors::GRID_MAJOR,
-                grid_minor_line_color: EditorColors::GRID_MINOR,
+                grid_major_line_color: palette.grid_major,
+                grid_minor_line_color: palette.grid_minor,
             },
             scroll_box: ScrollBoxStyles {
-                background_color: BackgroundColor(EditorColors::BUTTON_DEFAULT),
-                handle_color: EditorColors::BORDER,
+                background_color: BackgroundColor(palette.button_default),
+                background_hover_color: palette.button_default,
+                handle_color: palette.border,
+                handle_hover_color: palette.button_hover,
+                shadow_color: palette.shadow,
                 border_radius: BorderRadius::all(Val::Px(5.)),
             },
+            status: StatusStyles {
+                info: palette.accent_text,
+                success: palette.success,
+                warning: palette.warning,
+                error: palette.error,
+            },
+        }
+    }
+}
+
+/// The colors of a [`Theme`] that depend on its [`ThemeMode`].
+struct Palette {
+    background: Color,
+    panel_background: Color,
+    button_default: Color,
+    button_hover: Color,
+    text_primary: Color,
+    text_muted: Color,
+    border: Color,
+    grid_major: Color,
+    grid_minor: Color,
+    elevation: Color,
+    shadow: Color,
+    accent: Color,
+    accent_text: Color,
+    focus_ring: Color,
+    selection: Color,
+    success: Color,
+    warning: Color,
+    error: Color,
+}
+
+impl Palette {
+    fn of(mode: ThemeMode) -> Self {
+        use colors::{EditorColors, HighContrastEditorColors, LightEditorColors};
+
+        match mode {
+            ThemeMode::Dark => Self {
+                background: EditorColors::BACKGROUND,
+                panel_background: EditorColors::PANEL_BACKGROUND,
+                button_default: EditorColors::BUTTON_DEFAULT,
+                button_hover: EditorColors::BUTTON_HOVER,
+                text_primary: EditorColors::TEXT_PRIMARY,
+                text_muted: EditorColors::TEXT_MUTED,
+                border: EditorColors::BORDER,
+                grid_major: EditorColors::GRID_MAJOR,
+                grid_minor: EditorColors::GRID_MINOR,
+                elevation: Color::srgba(0.0, 0.0, 0.0, 0.5),
+                shadow: Color::srgba(0.0, 0.0, 0.0, 0.35),
+                accent: EditorColors::ACCENT_BLUE,
+                accent_text: EditorColors::ACCENT_BLUE,
+                focus_ring: EditorColors::ACCENT_BLUE_BRIGHT,
+                selection: EditorColors::ACCENT_BLUE,
+                success: EditorColors::SUCCESS,
+                warning: EditorColors::WARNING,
+                error: EditorColors::ERROR,
+            },
+            // The shadows are lighter, they would look like dirt on the light backgrounds
+            ThemeMode::Light => Self {
+                background: LightEditorColors::BACKGROUND,
+                panel_background: LightEditorColors::PANEL_BACKGROUND,
+                button_default: LightEditorColors::BUTTON_DEFAULT,
+                button_hover: LightEditorColors::BUTTON_HOVER,
+                text_primary: LightEditorColors::TEXT_PRIMARY,
+                text_muted: LightEditorColors::TEXT_MUTED,
+                border: LightEditorColors::BORDER,
+                grid_major: LightEditorColors::GRID_MAJOR,
+                grid_minor: LightEditorColors::GRID_MINOR,
+                elevation: Color::srgba(0.0, 0.0, 0.0, 0.2),
+                shadow: Color::srgba(0.0, 0.0, 0.0, 0.12),
+                accent: EditorColors::ACCENT_BLUE,
+                accent_text: EditorColors::ACCENT_BLUE,
+                focus_ring: EditorColors::ACCENT_BLUE_BRIGHT,
+                // A pale blue, the dark text stays readable on it
+                selection: Color::srgb(0.702, 0.831, 1.0),
+                success: EditorColors::SUCCESS,
+                warning: EditorColors::WARNING,
+                error: EditorColors::ERROR,
+            },
+            // The shadows are light, dark ones can't be seen on the black backgrounds
+            ThemeMode::HighContrast => Self {
+                background: HighContrastEditorColors::BACKGROUND,
+                panel_background: HighContrastEditorColors::PANEL_BACKGROUND,
+                button_default: HighContrastEditorColors::BUTTON_DEFAULT,
+                button_hover: HighContrastEditorColors::BUTTON_HOVER,
+                text_primary: HighContrastEditorColors::TEXT_PRIMARY,
+                text_muted: HighContrastEditorColors::TEXT_MUTED,
+                border: HighContrastEditorColors::BORDER,
+                grid_major: HighContrastEditorColors::GRID_MAJOR,
+                grid_minor: HighContrastEditorColors::GRID_MINOR,
+                elevation: Color::srgba(1.0, 1.0, 1.0, 0.25),
+                shadow: Color::srgba(1.0, 1.0, 1.0, 0.3),
+                accent: HighContrastEditorColors::ACCENT,
+                accent_text: HighContrastEditorColors::ACCENT_TEXT,
+                focus_ring: HighContrastEditorColors::FOCUS_RING,
+                selection: HighContrastEditorColors::ACCENT,
+                success: HighContrastEditorColors::SUCCESS,
+                warning: HighContrastEditorColors::WARNING,
+                error: HighContrastEditorColors::ERROR,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{chip::ChipRole, colors::contrast_ratio, *};
+
+    /// An app with the [`Theme`], for testing the widget systems.
+    pub(crate) fn theme_app() -> App {
+        let mut app = App::new();
+        // The theme loads its fonts from the asset server
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_resource::<Theme>();
+        app
+    }
+
+    #[test]
+    fn high_contrast_meets_the_contrast_targets() {
+        let palette = Palette::of(ThemeMode::HighContrast);
+        let backgrounds = [palette.background, palette.panel_background];
+        for background in backgrounds {
+            for text in [
+                palette.text_primary,
+                palette.text_muted,
+                palette.accent_text,
+            ] {
+                assert!(contrast_ratio(text, background) >= 7.0);
+            }
+            for status in [palette.success, palette.warning, palette.error] {
+                assert!(contrast_ratio(status, background) >= 7.0);
+            }
+            for control in [palette.focus_ring, palette.accent, palette.border] {
+                assert!(contrast_ratio(control, background) >= 3.0);
+            }
+        }
+        // The text of the buttons, the primary ones and the selected text
+        assert!(contrast_ratio(palette.text_primary, palette.button_default) >= 7.0);
+        assert!(contrast_ratio(palette.text_muted, palette.button_default) >= 7.0);
+        assert!(contrast_ratio(palette.text_primary, palette.accent) >= 4.5);
+        assert!(contrast_ratio(palette.text_primary, palette.selection) >= 4.5);
+        assert!(contrast_ratio(palette.border, palette.button_default) >= 3.0);
+
+        // The labels of the chips, dark on the bright status colors, and as readable as the
+        // primary buttons on the accent
+        let app = theme_app();
+        let theme = Theme::new(
+            ThemeMode::HighContrast,
+            app.world().resource::<AssetServer>(),
+        );
+        for role in [
+            ChipRole::Neutral,
+            ChipRole::Accent,
+            ChipRole::Info,
+            ChipRole::Success,
+            ChipRole::Warning,
+            ChipRole::Error,
+        ] {
+            assert!(
+                contrast_ratio(role.text_color(&theme), role.color(&theme)) >= 4.5,
+                "the label of the {role:?} chips"
+            );
         }
     }
 }
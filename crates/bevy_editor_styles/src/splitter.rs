@@ -0,0 +1,214 @@
+//! A handle on the edge of a side panel, dragged to resize the panel.
+
+use bevy::{feathers::cursor::EntityCursor, prelude::*, window::SystemCursorIcon};
+
+use crate::Theme;
+
+/// The width of the [`Splitter`] handles, in pixels, half of it outside of the panel.
+const SPLITTER_WIDTH: f32 = 6.0;
+
+/// The edge of its panel a [`Splitter`] is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitterEdge {
+    /// The left edge, for the panels on the right of the window.
+    Left,
+    /// The right edge, for the panels on the left of the window.
+    Right,
+}
+
+/// A handle resizing its parent panel when dragged, keeping its width between `min_width` and
+/// `max_width` pixels.
+///
+/// [`SplitterResized`] is triggered on the handle once a drag ends, so the width can be persisted.
+/// The content of the panel is laid out again at each step, scroll boxes included.
+#[derive(Component, Debug, Clone, Copy)]
+#[require(SplitterState)]
+pub struct Splitter {
+    /// The edge of the panel the handle is on.
+    pub edge: SplitterEdge,
+    /// The smallest width of the panel.
+    pub min_width: f32,
+    /// The largest width of the panel.
+    pub max_width: f32,
+}
+
+/// Triggered on a [`Splitter`] when the user is done resizing its panel, holding the new width.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct SplitterResized(pub f32);
+
+/// Whether a [`Splitter`] is hovered, and the width of its panel when its drag started.
+#[derive(Component, Default)]
+struct SplitterState {
+    hovered: bool,
+    drag_start_width: Option<f32>,
+}
+
+impl SplitterState {
+    fn color(&self, theme: &Theme) -> Color {
+        if self.hovered || self.drag_start_width.is_some() {
+            theme.button.hover_color
+        } else {
+            Color::NONE
+        }
+    }
+}
+
+impl Splitter {
+    /// A handle on the `edge` of its panel, keeping it between `min_width` and `max_width` pixels.
+    pub fn new(edge: SplitterEdge, min_width: f32, max_width: f32) -> Self {
+        Self {
+            edge,
+            min_width,
+            max_width,
+        }
+    }
+
+    /// The width of the panel once the handle is dragged by `distance` pixels to the right, from
+    /// a panel `start_width` pixels wide.
+    pub fn dragged_width(&self, start_width: f32, distance: f32) -> f32 {
+        let width = match self.edge {
+            SplitterEdge::Left => start_width - distance,
+            SplitterEdge::Right => start_width + distance,
+        };
+        width.clamp(self.min_width, self.max_width.max(self.min_width))
+    }
+}
+
+/// Spawn the handle of a [`Splitter`], to be added as the last child of the panel it resizes.
+///
+/// The panel needs a [`Val::Px`] width, and to not clip its children along the handle.
+pub fn spawn_splitter<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    splitter: Splitter,
+) -> EntityCommands<'a> {
+    let offset = Val::Px(-SPLITTER_WIDTH / 2.0);
+    let mut handle = commands.spawn((
+        splitter,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(0.0),
+            bottom: Val::Px(0.0),
+            left: match splitter.edge {
+                SplitterEdge::Left => offset,
+                SplitterEdge::Right => Val::Auto,
+            },
+            right: match splitter.edge {
+                SplitterEdge::Left => Val::Auto,
+                SplitterEdge::Right => offset,
+            },
+            width: Val::Px(SPLITTER_WIDTH),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        theme.button.border_radius,
+        // Shown by the `CursorIconPlugin` of `bevy_feathers`
+        EntityCursor::System(SystemCursorIcon::EwResize),
+        ZIndex(1),
+    ));
+    handle
+        .observe(
+            |trigger: On<Pointer<Over>>,
+             mut states: Query<(&mut SplitterState, &mut BackgroundColor)>,
+             theme: Res<Theme>| {
+                if let Ok((mut state, mut background)) = states.get_mut(trigger.target()) {
+                    state.hovered = true;
+                    background.0 = state.color(&theme);
+                }
+            },
+        )
+        .observe(
+            |trigger: On<Pointer<Out>>,
+             mut states: Query<(&mut SplitterState, &mut BackgroundColor)>,
+             theme: Res<Theme>| {
+                if let Ok((mut state, mut background)) = states.get_mut(trigger.target()) {
+                    state.hovered = false;
+                    background.0 = state.color(&theme);
+                }
+            },
+        )
+        .observe(
+            |mut trigger: On<Pointer<DragStart>>,
+             mut states: Query<(&mut SplitterState, &mut BackgroundColor, &ChildOf)>,
+             panels: Query<&ComputedNode>,
+             theme: Res<Theme>| {
+                trigger.propagate(false);
+                if trigger.event().button != PointerButton::Primary {
+                    return;
+                }
+                let Ok((mut state, mut background, child_of)) = states.get_mut(trigger.target())
+                else {
+                    return;
+                };
+                let Ok(panel) = panels.get(child_of.parent()) else {
+                    return;
+                };
+                state.drag_start_width = Some(panel.size().x * panel.inverse_scale_factor());
+                background.0 = state.color(&theme);
+            },
+        )
+        .observe(
+            |mut trigger: On<Pointer<Drag>>,
+             splitters: Query<(&Splitter, &SplitterState, &ChildOf)>,
+             mut panels: Query<&mut Node>| {
+                trigger.propagate(false);
+                let Ok((splitter, state, child_of)) = splitters.get(trigger.target()) else {
+                    return;
+                };
+                let Some(start_width) = state.drag_start_width else {
+                    return;
+                };
+                let width = splitter.dragged_width(start_width, trigger.event().distance.x);
+                if let Ok(mut panel) = panels.get_mut(child_of.parent()) {
+                    panel.width = Val::Px(width);
+                }
+            },
+        )
+        .observe(
+            |mut trigger: On<Pointer<DragEnd>>,
+             mut commands: Commands,
+             mut states: Query<(&mut SplitterState, &mut BackgroundColor, &ChildOf)>,
+             panels: Query<&Node>,
+             theme: Res<Theme>| {
+                trigger.propagate(false);
+                let handle = trigger.target();
+                let Ok((mut state, mut background, child_of)) = states.get_mut(handle) else {
+                    return;
+                };
+                if state.drag_start_width.take().is_none() {
+                    return;
+                }
+                background.0 = state.color(&theme);
+                if let Ok(Node {
+                    width: Val::Px(width),
+                    ..
+                }) = panels.get(child_of.parent())
+                {
+                    commands.trigger_targets(SplitterResized(*width), handle);
+                }
+            },
+        );
+    handle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_the_dragged_width() {
+        let left = Splitter::new(SplitterEdge::Left, 200.0, 600.0);
+        assert_eq!(left.dragged_width(300.0, -50.0), 350.0, "dragged outwards");
+        assert_eq!(left.dragged_width(300.0, 250.0), 200.0);
+        let right = Splitter::new(SplitterEdge::Right, 200.0, 600.0);
+        assert_eq!(right.dragged_width(300.0, 50.0), 350.0);
+        assert_eq!(right.dragged_width(300.0, 500.0), 600.0);
+
+        let inverted = Splitter::new(SplitterEdge::Right, 200.0, 100.0);
+        assert_eq!(
+            inverted.dragged_width(150.0, 0.0),
+            200.0,
+            "the minimum wins"
+        );
+    }
+}
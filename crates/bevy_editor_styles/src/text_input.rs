@@ -0,0 +1,401 @@
+//! A themed single line text input, built on the editable text line of `bevy_text_editing`.
+//!
+//! The editing itself (caret, selection, clipboard, deletion) is handled by the
+//! [`EditableTextLine`], this module adds the styling, a placeholder, the [`TextInputValue`]
+//! and the [`TextInputSubmit`] and [`TextInputCancel`] events.
+//!
+//! A [`TextInputValidator`] checks the text as it is typed, coloring the border of the input and
+//! showing the error below it.
+
+use bevy::{
+    input::{ButtonState, keyboard::KeyboardInput},
+    input_focus::FocusedInput,
+    prelude::*,
+};
+use bevy_text_editing::{EditableTextLine, HasFocus, TextChanged, TextSelectionColor};
+
+use crate::Theme;
+
+/// The height of a text input, in pixels.
+const TEXT_INPUT_HEIGHT: f32 = 26.0;
+/// The horizontal padding of the text of a text input, in pixels.
+const TEXT_INPUT_PADDING: f32 = 8.0;
+/// How long the text must stay unchanged before it is validated, in seconds, to not flicker while
+/// typing.
+const VALIDATION_DELAY: f32 = 0.3;
+/// The room left below a text input for its validation message, in pixels.
+const VALIDATION_MESSAGE_HEIGHT: f32 = 18.0;
+
+/// A text input, with its placeholder shown while it is empty and unfocused.
+///
+/// The text is edited by an [`EditableTextLine`] child, the current text is mirrored in the
+/// [`TextInputValue`] of this entity.
+#[derive(Component, Debug, Clone, Default)]
+#[require(TextInputValue)]
+pub struct TextInput {
+    /// The hint shown while the input is empty.
+    pub placeholder: String,
+}
+
+/// The current text of a [`TextInput`].
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextInputValue(pub String);
+
+/// Triggered on a [`TextInput`] when <kbd>Enter</kbd> is pressed while it is focused, holding its text.
+#[derive(EntityEvent, Debug, Clone)]
+pub struct TextInputSubmit(pub String);
+
+/// Triggered on a [`TextInput`] when <kbd>Escape</kbd> is pressed while it is focused.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct TextInputCancel;
+
+/// Checks the text of a [`TextInput`] shortly after each change, the result is kept in its
+/// [`TextInputValidity`].
+///
+/// The border of the input turns to the success or error color of the theme, and the error is
+/// shown below the input. An empty text isn't marked, the user didn't type anything yet.
+#[derive(Component)]
+#[require(TextInputValidity, PendingValidation)]
+pub struct TextInputValidator(Box<dyn Fn(&str) -> Result<(), String> + Send + Sync>);
+
+impl TextInputValidator {
+    /// A validator returning why the text isn't valid, if it isn't.
+    pub fn new(validate: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        Self(Box::new(validate))
+    }
+
+    /// Check the text, `None` for an empty one.
+    pub fn validate(&self, text: &str) -> Option<Result<(), String>> {
+        (!text.is_empty()).then(|| (self.0)(text))
+    }
+}
+
+/// The result of the [`TextInputValidator`] of a [`TextInput`], `None` until its text is checked.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextInputValidity(pub Option<Result<(), String>>);
+
+/// The time left before the text of a [`TextInputValidator`] is checked, restarted at each change.
+#[derive(Component, Default)]
+struct PendingValidation(Option<Timer>);
+
+/// The placeholder of a [`TextInput`].
+#[derive(Component)]
+struct TextInputPlaceholder;
+
+/// The error below a [`TextInput`] whose text isn't valid.
+#[derive(Component)]
+struct ValidationMessage;
+
+/// What a key press does to a [`TextInput`], besides editing its text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextInputAction {
+    Submit,
+    Cancel,
+}
+
+/// The action of the key, `None` for the keys left to the [`EditableTextLine`].
+fn key_action(key_code: KeyCode, state: ButtonState, repeat: bool) -> Option<TextInputAction> {
+    if state != ButtonState::Pressed || repeat {
+        return None;
+    }
+    match key_code {
+        KeyCode::Enter | KeyCode::NumpadEnter => Some(TextInputAction::Submit),
+        KeyCode::Escape => Some(TextInputAction::Cancel),
+        _ => None,
+    }
+}
+
+/// Spawn a [`TextInput`] with the given initial text and placeholder.
+///
+/// The returned entity is the container, the [`EditableTextLine`] is its first child.
+pub fn spawn_text_input<'a>(
+    commands: &'a mut Commands,
+    theme: &Theme,
+    text: impl Into<String>,
+    placeholder: impl Into<String>,
+) -> EntityCommands<'a> {
+    let text = text.into();
+    let placeholder = placeholder.into();
+    let mut input = commands.spawn((
+        TextInput {
+            placeholder: placeholder.clone(),
+        },
+        TextInputValue(text.clone()),
+        Node {
+            height: Val::Px(TEXT_INPUT_HEIGHT),
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BorderColor::all(theme.button.background_color.0),
+        theme.button.background_color,
+        theme.button.border_radius,
+    ));
+    input
+        .with_children(|parent| {
+            parent.spawn((
+                EditableTextLine::new(text),
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    padding: UiRect::horizontal(Val::Px(TEXT_INPUT_PADDING)),
+                    ..default()
+                },
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(theme.text.text_color),
+                TextSelectionColor(theme.text.selection_color),
+            ));
+            // A sibling of the text line, as it manages its own children
+            parent.spawn((
+                TextInputPlaceholder,
+                Text::new(placeholder),
+                TextFont {
+                    font: theme.text.font.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(theme.text.low_priority),
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(TEXT_INPUT_PADDING),
+                    top: Val::Px(4.0),
+                    ..default()
+                },
+                Pickable::IGNORE,
+            ));
+        })
+        .observe(
+            |trigger: On<TextChanged>, mut values: Query<&mut TextInputValue>| {
+                if let Ok(mut value) = values.get_mut(trigger.target()) {
+                    value.set_if_neq(TextInputValue(trigger.event().new_text.clone()));
+                }
+            },
+        )
+        .observe(
+            |mut trigger: On<FocusedInput<KeyboardInput>>,
+             mut commands: Commands,
+             values: Query<&TextInputValue>| {
+                let input = &trigger.event().input;
+                let Some(action) = key_action(input.key_code, input.state, input.repeat) else {
+                    return;
+                };
+                let entity = trigger.target();
+                let Ok(value) = values.get(entity) else {
+                    return;
+                };
+                trigger.propagate(false);
+                match action {
+                    TextInputAction::Submit => {
+                        commands.trigger_targets(TextInputSubmit(value.0.clone()), entity);
+                    }
+                    TextInputAction::Cancel => commands.trigger_targets(TextInputCancel, entity),
+                }
+            },
+        );
+    input
+}
+
+/// Restart the [`PendingValidation`] of the inputs whose text changed.
+pub(crate) fn schedule_text_input_validation(
+    mut inputs: Query<&mut PendingValidation, (With<TextInputValidator>, Changed<TextInputValue>)>,
+) {
+    for mut pending in &mut inputs {
+        pending.0 = Some(Timer::from_seconds(VALIDATION_DELAY, TimerMode::Once));
+    }
+}
+
+/// Check the text of the inputs once their [`PendingValidation`] is over.
+pub(crate) fn validate_text_inputs(
+    mut inputs: Query<(
+        &TextInputValidator,
+        &TextInputValue,
+        &mut PendingValidation,
+        &mut TextInputValidity,
+    )>,
+    time: Res<Time>,
+) {
+    for (validator, value, mut pending, mut validity) in &mut inputs {
+        let Some(timer) = &mut pending.0 else {
+            continue;
+        };
+        if !timer.tick(time.delta()).is_finished() {
+            continue;
+        }
+        pending.0 = None;
+        validity.set_if_neq(TextInputValidity(validator.validate(&value.0)));
+    }
+}
+
+/// Show the error of the inputs whose text isn't valid below them, making room for it.
+pub(crate) fn show_validation_messages(
+    mut commands: Commands,
+    mut inputs: Query<(Entity, &TextInputValidity, &mut Node), Changed<TextInputValidity>>,
+    messages: Query<(Entity, &ChildOf), With<ValidationMessage>>,
+    theme: Res<Theme>,
+) {
+    for (input, validity, mut node) in &mut inputs {
+        let mut had_message = false;
+        for (message, child_of) in &messages {
+            if child_of.parent() == input {
+                commands.entity(message).despawn();
+                had_message = true;
+            }
+        }
+        let Some(Err(error)) = &validity.0 else {
+            if had_message {
+                node.margin.bottom = Val::ZERO;
+            }
+            continue;
+        };
+        node.margin.bottom = Val::Px(VALIDATION_MESSAGE_HEIGHT);
+        commands.spawn((
+            ValidationMessage,
+            Text::new(error.clone()),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(theme.status.error),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::ZERO,
+                top: Val::Px(TEXT_INPUT_HEIGHT),
+                ..default()
+            },
+            Pickable::IGNORE,
+            ChildOf(input),
+        ));
+    }
+}
+
+/// Show the placeholders of the empty, unfocused inputs and highlight the border of the focused
+/// ones, or of the validated ones with the result of their validation.
+pub(crate) fn update_text_inputs(
+    mut inputs: Query<
+        (
+            &TextInputValue,
+            &Children,
+            &mut BorderColor,
+            Option<&TextInputValidity>,
+        ),
+        With<TextInput>,
+    >,
+    lines: Query<&HasFocus, With<EditableTextLine>>,
+    mut placeholders: Query<&mut Visibility, With<TextInputPlaceholder>>,
+    theme: Res<Theme>,
+) {
+    for (value, children, mut border, validity) in &mut inputs {
+        let focused = children
+            .iter()
+            .any(|child| lines.get(child).is_ok_and(|has_focus| has_focus.0));
+        let border_color = match validity.and_then(|validity| validity.0.as_ref()) {
+            Some(Ok(())) => theme.status.success,
+            Some(Err(_)) => theme.status.error,
+            None if focused => theme.general.focus_ring_color,
+            None => theme.button.background_color.0,
+        };
+        border.set_if_neq(BorderColor::all(border_color));
+
+        let visibility = if value.0.is_empty() && !focused {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        for child in children.iter() {
+            if let Ok(mut placeholder) = placeholders.get_mut(child) {
+                placeholder.set_if_neq(visibility);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn enter_submits_and_escape_cancels() {
+        assert_eq!(
+            key_action(KeyCode::Enter, ButtonState::Pressed, false),
+            Some(TextInputAction::Submit)
+        );
+        assert_eq!(
+            key_action(KeyCode::NumpadEnter, ButtonState::Pressed, false),
+            Some(TextInputAction::Submit)
+        );
+        assert_eq!(
+            key_action(KeyCode::Escape, ButtonState::Pressed, false),
+            Some(TextInputAction::Cancel)
+        );
+        assert_eq!(key_action(KeyCode::KeyA, ButtonState::Pressed, false), None);
+        assert_eq!(
+            key_action(KeyCode::Enter, ButtonState::Released, false),
+            None
+        );
+        assert_eq!(key_action(KeyCode::Enter, ButtonState::Pressed, true), None);
+    }
+
+    #[test]
+    fn validate_after_a_pause_in_typing() {
+        let mut app = App::new();
+        app.init_resource::<Time>().add_systems(
+            Update,
+            (schedule_text_input_validation, validate_text_inputs).chain(),
+        );
+        let input = app
+            .world_mut()
+            .spawn((
+                TextInputValue("my game".to_string()),
+                TextInputValidator::new(|text| {
+                    if text.contains('/') {
+                        Err("no slashes".to_string())
+                    } else {
+                        Ok(())
+                    }
+                }),
+            ))
+            .id();
+        let step = |app: &mut App, seconds: f32| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_secs_f32(seconds));
+            app.update();
+            app.world()
+                .get::<TextInputValidity>(input)
+                .unwrap()
+                .0
+                .clone()
+        };
+        let type_text = |app: &mut App, text: &str| {
+            app.world_mut().get_mut::<TextInputValue>(input).unwrap().0 = text.to_string();
+            step(app, 0.0)
+        };
+
+        assert_eq!(step(&mut app, 0.0), None, "not validated while typing");
+        assert_eq!(step(&mut app, VALIDATION_DELAY), Some(Ok(())));
+
+        type_text(&mut app, "my/game");
+        assert_eq!(
+            step(&mut app, VALIDATION_DELAY / 2.0),
+            Some(Ok(())),
+            "the previous result until the pause is over"
+        );
+        assert_eq!(
+            step(&mut app, VALIDATION_DELAY),
+            Some(Err("no slashes".to_string()))
+        );
+
+        type_text(&mut app, "");
+        assert_eq!(
+            step(&mut app, VALIDATION_DELAY),
+            None,
+            "an empty text isn't marked"
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! A themed tooltip, showing a text next to the pointer once an entity has been hovered for a moment.
+
+use std::time::Duration;
+
+use bevy::{
+    ecs::{lifecycle::HookContext, world::DeferredWorld},
+    input::mouse::MouseWheel,
+    prelude::*,
+    window::PrimaryWindow,
+};
+
+use crate::{Theme, dialog::MODAL_Z_INDEX, dropdown::popup_position};
+
+/// How long an entity must be hovered before its tooltip shows, so moving the pointer over a list
+/// or scrolling it doesn't flicker popups.
+pub const TOOLTIP_DELAY: Duration = Duration::from_millis(400);
+/// The width past which the text of a tooltip wraps, unless given another one.
+const TOOLTIP_MAX_WIDTH: f32 = 360.0;
+/// The distance between the pointer and the top left corner of the tooltip, in pixels.
+const POINTER_OFFSET: Vec2 = Vec2::new(12.0, 16.0);
+
+/// A text shown in a popup next to the pointer, once the entity is hovered for [`TOOLTIP_DELAY`].
+///
+/// The text wraps within the `max_width` of the popup, breaking long words like paths if needed.
+/// The popup hides when the pointer leaves the entity or the mouse wheel scrolls. When nested
+/// entities have a tooltip, the innermost one is shown.
+#[derive(Component, Debug, Clone)]
+#[component(on_add = watch_tooltip_hovers)]
+pub struct Tooltip {
+    /// The text of the popup, which may have several lines.
+    pub text: String,
+    /// The maximum width of the popup, in pixels.
+    pub max_width: f32,
+}
+
+impl Tooltip {
+    /// A tooltip showing the text, wrapped at the default width.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            max_width: TOOLTIP_MAX_WIDTH,
+        }
+    }
+
+    /// Wrap the text at the given width, in pixels.
+    pub fn with_max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+}
+
+/// The [`Tooltip`] entity being hovered, waiting for its delay before showing.
+#[derive(Resource, Default)]
+pub(crate) struct HoveredTooltip(Option<PendingTooltip>);
+
+struct PendingTooltip {
+    /// The entity with the [`Tooltip`].
+    owner: Entity,
+    /// The last position of the pointer over the entity.
+    position: Vec2,
+    timer: Timer,
+}
+
+/// The popup of the hovered [`Tooltip`].
+#[derive(Component)]
+struct TooltipPopup;
+
+fn watch_tooltip_hovers(mut world: DeferredWorld, context: HookContext) {
+    world
+        .commands()
+        .entity(context.entity)
+        .observe(start_tooltip_hover)
+        .observe(follow_tooltip_hover)
+        .observe(end_tooltip_hover);
+}
+
+fn hide_tooltips(commands: &mut Commands, popups: &Query<Entity, With<TooltipPopup>>) {
+    for popup in popups {
+        commands.entity(popup).despawn();
+    }
+}
+
+fn start_tooltip_hover(
+    mut trigger: On<Pointer<Over>>,
+    mut commands: Commands,
+    mut hovered: ResMut<HoveredTooltip>,
+    popups: Query<Entity, With<TooltipPopup>>,
+) {
+    // The innermost tooltip wins over the ones of the ancestors
+    trigger.propagate(false);
+    hide_tooltips(&mut commands, &popups);
+    hovered.0 = Some(PendingTooltip {
+        owner: trigger.target(),
+        position: trigger.event().pointer_location.position,
+        timer: Timer::new(TOOLTIP_DELAY, TimerMode::Once),
+    });
+}
+
+fn follow_tooltip_hover(trigger: On<Pointer<Move>>, mut hovered: ResMut<HoveredTooltip>) {
+    if let Some(pending) = &mut hovered.0
+        && pending.owner == trigger.target()
+        && !pending.timer.finished()
+    {
+        pending.position = trigger.event().pointer_location.position;
+    }
+}
+
+fn end_tooltip_hover(
+    trigger: On<Pointer<Out>>,
+    mut commands: Commands,
+    mut hovered: ResMut<HoveredTooltip>,
+    popups: Query<Entity, With<TooltipPopup>>,
+) {
+    if hovered
+        .0
+        .as_ref()
+        .is_some_and(|pending| pending.owner == trigger.target())
+    {
+        hovered.0 = None;
+        hide_tooltips(&mut commands, &popups);
+    }
+}
+
+/// Show the popup of the [`HoveredTooltip`] once its delay is over.
+///
+/// Scrolling moves the content under the pointer, so it hides the popup and restarts the delay.
+pub(crate) fn show_tooltips(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut hovered: ResMut<HoveredTooltip>,
+    mut wheel: EventReader<MouseWheel>,
+    tooltips: Query<&Tooltip>,
+    popups: Query<Entity, With<TooltipPopup>>,
+    theme: Res<Theme>,
+) {
+    let scrolled = wheel.read().count() > 0;
+    let Some(pending) = &mut hovered.0 else {
+        return;
+    };
+    let Ok(tooltip) = tooltips.get(pending.owner) else {
+        // The entity was despawned or lost its tooltip while hovered
+        hovered.0 = None;
+        hide_tooltips(&mut commands, &popups);
+        return;
+    };
+    if scrolled {
+        pending.timer.reset();
+        hide_tooltips(&mut commands, &popups);
+        return;
+    }
+    if !pending.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let position = pending.position + POINTER_OFFSET;
+    commands.spawn((
+        TooltipPopup,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(position.x),
+            top: Val::Px(position.y),
+            max_width: Val::Px(tooltip.max_width),
+            padding: UiRect::all(Val::Px(6.0)),
+            ..default()
+        },
+        BoxShadow::new(
+            theme.general.elevation_color,
+            Val::ZERO,
+            Val::Px(2.0),
+            Val::ZERO,
+            Val::Px(6.0),
+        ),
+        theme.context_menu.background_color,
+        theme.general.border_radius,
+        // Above the dropdown popups
+        GlobalZIndex(MODAL_Z_INDEX + 45),
+        Pickable::IGNORE,
+        children![(
+            Text::new(tooltip.text.clone()),
+            TextFont {
+                font: theme.text.font.clone(),
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(theme.text.text_color),
+            TextLayout::new_with_linebreak(LineBreak::WordOrCharacter),
+            Pickable::IGNORE,
+        )],
+    ));
+}
+
+/// Move the tooltips inside the window once their wrapped size is known.
+pub(crate) fn keep_tooltips_in_window(
+    mut popups: Query<(&mut Node, &ComputedNode), (With<TooltipPopup>, Changed<ComputedNode>)>,
+    window: Single<&Window, With<PrimaryWindow>>,
+) {
+    for (mut node, computed) in &mut popups {
+        let (Val::Px(left), Val::Px(top)) = (node.left, node.top) else {
+            continue;
+        };
+        let size = computed.size() * computed.inverse_scale_factor();
+        let position = popup_position(Vec2::new(left, top), size, window.size());
+        if position != Vec2::new(left, top) {
+            node.left = Val::Px(position.x);
+            node.top = Val::Px(position.y);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::theme_app;
+
+    #[test]
+    fn show_tooltips_after_the_delay() {
+        let mut app = theme_app();
+        app.init_resource::<HoveredTooltip>()
+            .add_event::<MouseWheel>()
+            .add_systems(Update, show_tooltips);
+        let owner = app.world_mut().spawn(Tooltip::new("Full line")).id();
+        app.world_mut().resource_mut::<HoveredTooltip>().0 = Some(PendingTooltip {
+            owner,
+            position: Vec2::ZERO,
+            timer: Timer::new(TOOLTIP_DELAY, TimerMode::Once),
+        });
+        let popups = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), With<TooltipPopup>>()
+                .iter(app.world())
+                .count()
+        };
+
+        app.update();
+        assert_eq!(popups(&mut app), 0, "still waiting for the delay");
+        app.world_mut()
+            .resource_mut::<HoveredTooltip>()
+            .0
+            .as_mut()
+            .unwrap()
+            .timer
+            .set_elapsed(TOOLTIP_DELAY - Duration::from_millis(1));
+        std::thread::sleep(Duration::from_millis(5));
+        app.update();
+        assert_eq!(popups(&mut app), 1);
+
+        // Scrolling hides the popup until the delay is over again
+        app.world_mut().write_event(MouseWheel {
+            unit: bevy::input::mouse::MouseScrollUnit::Line,
+            x: 0.0,
+            y: 1.0,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+        assert_eq!(popups(&mut app), 0);
+
+        // Despawning the hovered entity forgets it
+        app.world_mut().despawn(owner);
+        app.update();
+        assert!(app.world().resource::<HoveredTooltip>().0.is_none());
+    }
+}